@@ -1,4 +1,5 @@
 use crate::sidebar;
+use dynamo_color::{PaletteLibrary, ToneAdjustment};
 use dynamo_common::prelude::*;
 use dynamo_core::prelude::*;
 use dynamo_gui::hotkeys::{
@@ -9,6 +10,7 @@ use dynamo_gui::interface::{Interface, MainInterface};
 use dynamo_profiles::Mandelbrot;
 use egui::Ui;
 use egui_dock::{NodeIndex, SurfaceIndex};
+use std::collections::VecDeque;
 
 #[cfg(feature = "scripting")]
 use crate::script_editor::*;
@@ -47,6 +49,7 @@ impl MenuState
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TabID
 {
     pub surface: SurfaceIndex,
@@ -71,12 +74,22 @@ impl From<TabID> for (SurfaceIndex, NodeIndex)
     }
 }
 
+/// Number of fractals left earlier that a tab keeps to restore when returning to them.
+pub const MAX_SAVED_INTERFACES: usize = 4;
+
 pub struct FractalTab
 {
     pub interface: Box<dyn Interface>,
     pub id: TabID,
+    /// Path through the sidebar menu of the fractal shown, or `None` for the default fractal or
+    /// one loaded from a script.
+    pub profile: Option<String>,
     pub menu_state: MenuState,
     pub sidebar_menu: sidebar::menu::Menu,
+    /// Interfaces of the fractals shown most recently before the current one, by profile, so that
+    /// returning to one restores its view, selection and coloring. The last one left is at the
+    /// back; at most [`MAX_SAVED_INTERFACES`] are kept.
+    saved_interfaces: VecDeque<(String, Box<dyn Interface>)>,
     #[cfg(feature = "scripting")]
     pub popup: Option<Popup>,
     #[cfg(feature = "scripting")]
@@ -92,6 +105,51 @@ impl FractalTab
         self
     }
 
+    /// Show the fractal with the given path through the sidebar menu, if there is one.
+    #[must_use]
+    pub fn with_profile(mut self, profile: &str) -> Self
+    {
+        if let Some(create_interface) = self.sidebar_menu.find(profile) {
+            self.interface = create_interface();
+            self.profile = Some(profile.to_owned());
+        } else {
+            println!("No fractal named {profile} in the menu");
+        }
+        self
+    }
+
+    /// Show the fractal with the given path through the sidebar menu, restoring it as it was
+    /// left if it is among the last few shown, or else calling `create_interface`. Choosing the
+    /// fractal already shown resets it.
+    pub fn change_fractal(&mut self, profile: String, create_interface: fn() -> Box<dyn Interface>)
+    {
+        if self.profile.as_ref() == Some(&profile) {
+            self.interface = create_interface();
+            return;
+        }
+        let interface = self
+            .saved_interfaces
+            .iter()
+            .position(|(saved_profile, _)| *saved_profile == profile)
+            .and_then(|i| self.saved_interfaces.remove(i))
+            .map_or_else(create_interface, |(_, interface)| interface);
+        let previous = std::mem::replace(&mut self.interface, interface);
+        if let Some(previous_profile) = self.profile.replace(profile) {
+            if self.saved_interfaces.len() == MAX_SAVED_INTERFACES {
+                self.saved_interfaces.pop_front();
+            }
+            self.saved_interfaces
+                .push_back((previous_profile, previous));
+        }
+    }
+
+    /// Number of fractals kept to be restored when returning to them.
+    #[must_use]
+    pub fn saved_interface_count(&self) -> usize
+    {
+        self.saved_interfaces.len()
+    }
+
     pub fn update(&mut self, ui: &mut Ui)
     {
         egui::SidePanel::left("Fractal")
@@ -144,8 +202,8 @@ impl FractalTab
         use sidebar::menu::Action::ChangeFractal;
         if let Some(action) = self.sidebar_menu.show_and_get_action(ui) {
             match action {
-                ChangeFractal(interface) => {
-                    self.interface = interface;
+                ChangeFractal(profile, create_interface) => {
+                    self.change_fractal(profile, create_interface);
                 }
             }
         }
@@ -169,9 +227,39 @@ impl FractalTab
             for hotkey in &OUTCOLORING_HOTKEYS {
                 self.hotkey_button(ui, hotkey);
             }
+            ui.menu_button("Tone", |ui| {
+                self.tone_sliders(ui);
+            });
         });
     }
 
+    fn tone_sliders(&mut self, ui: &mut Ui)
+    {
+        let mut tone = self.interface.get_tone();
+        let mut changed = false;
+        changed |= ui
+            .add(
+                egui::Slider::new(&mut tone.gamma, 0.2..=5.)
+                    .logarithmic(true)
+                    .text("Gamma"),
+            )
+            .changed();
+        changed |= ui
+            .add(egui::Slider::new(&mut tone.brightness, -1.0..=1.).text("Brightness"))
+            .changed();
+        changed |= ui
+            .add(egui::Slider::new(&mut tone.contrast, 0.0..=3.).text("Contrast"))
+            .changed();
+        if ui.button("Reset").clicked() {
+            tone = ToneAdjustment::IDENTITY;
+            changed = true;
+        }
+        if changed {
+            self.interface.set_tone(tone);
+            self.interface.consume_click();
+        }
+    }
+
     fn image_menu(&mut self, ui: &mut Ui)
     {
         ui.menu_button("Image", |ui| {
@@ -300,6 +388,7 @@ impl FractalTab
         unsafe {
             let int = loader.run()?;
             self.interface = Box::new(int);
+            self.profile = None;
         }
         Ok(())
     }
@@ -349,7 +438,10 @@ impl Default for FractalTab
         let parent_plane = Profile::default().with_res_y(height).with_max_iter(1024);
         let child_plane = JuliaSet::from(parent_plane.clone());
 
-        let interface = Box::new(MainInterface::new(parent_plane, child_plane, height));
+        let interface = Box::new(
+            MainInterface::new(parent_plane, child_plane, height)
+                .with_palette_library(PaletteLibrary::load_default()),
+        );
         let sidebar_menu = sidebar::create_menu();
 
         Self {
@@ -357,6 +449,8 @@ impl Default for FractalTab
             sidebar_menu,
             menu_state: MenuState::default(),
             id: TabID::default(),
+            profile: None,
+            saved_interfaces: VecDeque::new(),
             #[cfg(feature = "scripting")]
             popup: None,
             #[cfg(feature = "scripting")]
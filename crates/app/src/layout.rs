@@ -0,0 +1,91 @@
+//! Saving the arrangement of tabs, so that it can be restored in the next session.
+
+use crate::fractal_tab::{FractalTab, TabID};
+use egui_dock::DockState;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What is needed to rebuild a tab: its place in the dock and the fractal it shows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TabRecord
+{
+    pub id: TabID,
+    pub profile: Option<String>,
+}
+
+impl From<&FractalTab> for TabRecord
+{
+    fn from(tab: &FractalTab) -> Self
+    {
+        Self {
+            id: tab.id,
+            profile: tab.profile.clone(),
+        }
+    }
+}
+
+/// Arrangement of the tabs in a dock, with each tab reduced to a [`TabRecord`].
+#[must_use]
+pub fn dock_layout(dock_state: &DockState<FractalTab>) -> DockState<TabRecord>
+{
+    dock_state.map_tabs(TabRecord::from)
+}
+
+/// Rebuild the tabs of a saved layout, each showing the fractal it showed before.
+#[must_use]
+pub fn restore_dock(layout: &DockState<TabRecord>) -> DockState<FractalTab>
+{
+    layout.map_tabs(|record: &TabRecord| {
+        let tab = FractalTab::default().with_id(record.id);
+        match &record.profile {
+            Some(profile) => tab.with_profile(profile),
+            None => tab,
+        }
+    })
+}
+
+#[cfg(feature = "serde")]
+fn layout_path() -> Option<std::path::PathBuf>
+{
+    Some(dynamo_common::prelude::config_dir()?.join("layout.json"))
+}
+
+/// The layout saved in the previous session, if there is one.
+#[cfg(feature = "serde")]
+#[must_use]
+pub fn load_layout() -> Option<DockState<FractalTab>>
+{
+    let text = std::fs::read_to_string(layout_path()?).ok()?;
+    match serde_json::from_str::<DockState<TabRecord>>(&text) {
+        Ok(layout) => Some(restore_dock(&layout)),
+        Err(e) => {
+            println!("Error reading saved layout: {e}");
+            None
+        }
+    }
+}
+
+/// Save the layout of the dock, unless it is unchanged from `previous`. Returns the newly
+/// saved layout.
+#[cfg(feature = "serde")]
+pub fn save_layout(dock_state: &DockState<FractalTab>, previous: &str) -> Option<String>
+{
+    let text = match serde_json::to_string(&dock_layout(dock_state)) {
+        Ok(text) => text,
+        Err(e) => {
+            println!("Error serializing layout: {e}");
+            return None;
+        }
+    };
+    if text == previous {
+        return None;
+    }
+    if let Some(path) = layout_path() {
+        if let Err(e) = std::fs::write(path, &text) {
+            println!("Error saving layout: {e}");
+        }
+    }
+    Some(text)
+}
@@ -3,6 +3,7 @@
 use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex};
 
 pub mod fractal_tab;
+pub mod layout;
 pub mod macros;
 #[cfg(feature = "scripting")]
 pub mod script_editor;
@@ -76,12 +77,27 @@ pub struct FractalApp
 {
     dock_state: DockState<FractalTab>,
     tab_count: usize,
+    /// Layout as last written to the config directory.
+    #[cfg(feature = "serde")]
+    saved_layout: String,
 }
 
 impl Default for FractalApp
 {
     fn default() -> Self
     {
+        #[cfg(feature = "serde")]
+        if let Some(dock_state) = layout::load_layout() {
+            let tab_count = dock_state.iter_all_tabs().count();
+            if tab_count > 0 {
+                return Self {
+                    dock_state,
+                    tab_count,
+                    saved_layout: String::new(),
+                };
+            }
+        }
+
         let tab0 = FractalTab::default();
 
         let dock_state = DockState::new(vec![tab0]);
@@ -89,6 +105,8 @@ impl Default for FractalApp
         Self {
             dock_state,
             tab_count: 1,
+            #[cfg(feature = "serde")]
+            saved_layout: String::new(),
         }
     }
 }
@@ -127,6 +145,11 @@ impl eframe::App for FractalApp
             self.dock_state
                 .remove_tab((surface, node, self.tab_count.into()));
         }
+
+        #[cfg(feature = "serde")]
+        if let Some(saved) = layout::save_layout(&self.dock_state, &self.saved_layout) {
+            self.saved_layout = saved;
+        }
     }
 }
 
@@ -154,4 +177,722 @@ mod tests
             interface.child_mut().process_tasks();
         }
     }
+
+    #[test]
+    fn swap_panes_twice()
+    {
+        use dynamo_common::prelude::Bounds;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_gui::pane::id::PaneID;
+        use dynamo_profiles::{CoshNewton, Mandelbrot};
+
+        let parent = CoshNewton::default().with_res_y(32);
+        let child = parent.clone().with_bounds(Bounds::centered_square(1.5));
+        let mut interface = MainInterface::new(parent, child, 32);
+        interface.set_active_pane(Some(PaneID::Parent));
+        let parent_bounds = interface.parent().grid().bounds.clone();
+        let child_bounds = interface.child().grid().bounds.clone();
+        assert_ne!(parent_bounds, child_bounds);
+
+        assert!(interface.swap_panes());
+        assert_eq!(interface.parent().grid().bounds, child_bounds);
+        assert_eq!(interface.child().grid().bounds, parent_bounds);
+        // The active pane moves along with the swap
+        assert_eq!(
+            interface
+                .get_active_pane()
+                .map(|pane| pane.grid().bounds.clone()),
+            Some(parent_bounds.clone())
+        );
+
+        assert!(interface.swap_panes());
+        assert_eq!(interface.parent().grid().bounds, parent_bounds);
+        assert_eq!(interface.child().grid().bounds, child_bounds);
+        assert_eq!(
+            interface
+                .get_active_pane()
+                .map(|pane| pane.grid().bounds.clone()),
+            Some(parent_bounds)
+        );
+
+        // A Julia set is not a standalone parameter plane
+        let mandelbrot = Mandelbrot::default().with_res_y(32);
+        let julia = JuliaSet::from(mandelbrot.clone());
+        assert!(!MainInterface::new(mandelbrot, julia, 32).swap_panes());
+    }
+
+    #[test]
+    fn search_fractals()
+    {
+        use crate::sidebar::create_menu;
+        use dynamo_gui::interface::Interactive;
+
+        let menu = create_menu();
+        let results: Vec<_> = menu.search("quadrat").collect();
+        assert!(results
+            .iter()
+            .all(|(name, _)| name.to_lowercase().contains("quadrat")));
+        for name in ["QuadRat Per(2) / Moduli space", "QuadRat Per(5)"] {
+            assert!(
+                results.iter().any(|(path, _)| path.ends_with(name)),
+                "{name}"
+            );
+        }
+        assert!(!results
+            .iter()
+            .any(|(path, _)| path.contains("Transcendental")));
+
+        let (_, cons) = menu
+            .search("quadrat per(5)")
+            .next()
+            .expect("QuadRat Per(5) should be found");
+        assert_eq!(cons().name(), "QuadRatPer5");
+    }
+
+    #[test]
+    fn ray_julia_frames_match_count()
+    {
+        use dynamo_common::prelude::RationalAngle;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+
+        let dir = std::env::temp_dir().join("dynamo_ray_julia_frames");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // One frame is saved per update
+        interface.start_ray_julia_export(RationalAngle::new(1, 3), 5, dir.clone());
+        let mut updates = 0;
+        while interface.is_exporting_ray_julia_frames() {
+            interface.update_panes();
+            updates += 1;
+        }
+        let files = std::fs::read_dir(&dir).unwrap().count();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(updates, 5);
+        assert_eq!(files, 5);
+    }
+
+    #[test]
+    fn dock_layout_restored()
+    {
+        use crate::fractal_tab::{FractalTab, TabID};
+        use crate::layout::{dock_layout, restore_dock, TabRecord};
+        use egui_dock::{DockState, NodeIndex, SurfaceIndex};
+
+        const PROFILE: &str = "Transcendental / z -> λexp(z)";
+
+        let first = FractalTab::default();
+        let second_id = TabID {
+            surface: SurfaceIndex::main(),
+            node: NodeIndex(1),
+        };
+        let second = FractalTab::default()
+            .with_id(second_id)
+            .with_profile(PROFILE);
+        assert_eq!(second.profile.as_deref(), Some(PROFILE));
+        let dock_state = DockState::new(vec![first, second]);
+
+        let layout = dock_layout(&dock_state);
+        #[cfg(feature = "serde")]
+        let layout: DockState<TabRecord> =
+            serde_json::from_str(&serde_json::to_string(&layout).unwrap()).unwrap();
+
+        let restored = restore_dock(&layout);
+        let records: Vec<TabRecord> = restored
+            .iter_all_tabs()
+            .map(|(_, tab)| TabRecord::from(tab))
+            .collect();
+        assert_eq!(
+            records,
+            vec![
+                TabRecord {
+                    id: TabID::default(),
+                    profile: None,
+                },
+                TabRecord {
+                    id: second_id,
+                    profile: Some(PROFILE.to_owned()),
+                },
+            ]
+        );
+
+        let names: Vec<String> = restored
+            .iter_all_tabs()
+            .map(|(_, tab)| tab.interface.name())
+            .collect();
+        let originals: Vec<String> = dock_state
+            .iter_all_tabs()
+            .map(|(_, tab)| tab.interface.name())
+            .collect();
+        assert_eq!(names, originals);
+    }
+
+    #[test]
+    fn recommended_max_iter_on_load()
+    {
+        use crate::sidebar::load_parent;
+        use dynamo_core::dynamics::FamilyDefaults;
+        use dynamo_profiles::{Mandelbrot, RiemannXi};
+
+        let xi = load_parent(RiemannXi::default);
+        assert_eq!(xi.max_iter(), RiemannXi::default().recommended_max_iter());
+        assert!(xi.max_iter() < 1024);
+        assert_eq!(load_parent(Mandelbrot::default).max_iter(), 1024);
+    }
+
+    #[test]
+    fn reset_coloring_keeps_view()
+    {
+        use dynamo_color::IncoloringAlgorithm;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_core::dynamics::FamilyDefaults;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_gui::pane::id::PaneID;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let default_algorithm = mandelbrot.default_coloring().get_algorithm().clone();
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+        interface.set_active_pane(Some(PaneID::Parent));
+
+        interface.process_action(&Action::SetColoring(IncoloringAlgorithm::Solid));
+        interface.process_action(&Action::Zoom(0.5));
+        let bounds = interface.parent().grid().bounds.clone();
+        assert_eq!(
+            interface.parent().get_coloring().get_algorithm(),
+            &IncoloringAlgorithm::Solid
+        );
+
+        interface.process_action(&Action::ResetColoring);
+        assert_eq!(
+            interface.parent().get_coloring().get_algorithm(),
+            &default_algorithm
+        );
+        assert_eq!(interface.parent().grid().bounds, bounds);
+    }
+
+    #[test]
+    fn label_bulb_on_real_axis()
+    {
+        use dynamo_common::prelude::Cplx;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_gui::pane::id::PaneID;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+        interface.set_active_pane(Some(PaneID::Parent));
+
+        let basilica = Cplx::new(-1., 0.);
+        interface.parent_mut().select_point(basilica);
+        interface.process_action(&Action::LabelBulb);
+        let pins: Vec<_> = interface.parent().marking().pins().collect();
+        assert_eq!(pins, vec![(0, basilica, "1/2")]);
+
+        // Satellites of the basilica bulb are not attached to the main cardioid
+        interface.parent_mut().select_point(Cplx::new(-1.31, 0.));
+        interface.process_action(&Action::LabelBulb);
+        assert_eq!(interface.parent().marking().pins().count(), 1);
+    }
+
+    #[test]
+    fn shared_level_range_colors_alike()
+    {
+        use dynamo_common::prelude::{Cplx, PointInfo};
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_profiles::Mandelbrot;
+        use egui::Color32;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(32);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(32);
+        let mut interface = MainInterface::new(mandelbrot, julia, 32);
+        interface.update_panes();
+
+        let parent_range = interface.parent().escape_level_range().unwrap();
+        let child_range = interface.child().escape_level_range().unwrap();
+        assert_eq!(interface.parent().get_coloring().level_range(), None);
+
+        interface.process_action(&Action::ToggleSharedLevelRange);
+        let range = interface.parent().get_coloring().level_range().unwrap();
+        assert_eq!(interface.child().get_coloring().level_range(), Some(range));
+        assert_eq!(range.0, parent_range.0.min(child_range.0));
+        assert_eq!(range.1, parent_range.1.max(child_range.1));
+
+        for potential in [3., 20., 150.] {
+            let point: PointInfo<Cplx> = PointInfo::Escaping {
+                potential,
+                phase: None,
+            };
+            let parent_color: Color32 = interface.parent().get_coloring().map(&point);
+            let child_color: Color32 = interface.child().get_coloring().map(&point);
+            assert_eq!(parent_color, child_color);
+        }
+
+        // The shared range follows the images once a pane finishes recomputing
+        interface.child_mut().zoom(0.5, Cplx::new(1.5, 0.));
+        assert_eq!(interface.child().get_coloring().level_range(), Some(range));
+        while interface.child().tasks().compute.is_pending() {
+            interface.update_panes();
+        }
+        let child_range = interface.child().escape_level_range().unwrap();
+        let range = interface.parent().get_coloring().level_range().unwrap();
+        assert_eq!(range.0, parent_range.0.min(child_range.0));
+        assert_eq!(range.1, parent_range.1.max(child_range.1));
+
+        interface.process_action(&Action::ToggleSharedLevelRange);
+        assert_eq!(interface.parent().get_coloring().level_range(), None);
+        assert_eq!(interface.child().get_coloring().level_range(), None);
+    }
+
+    #[test]
+    fn double_buffered_pane_keeps_frame_until_recomputed()
+    {
+        use dynamo_common::prelude::Cplx;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_gui::pane::Pane;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(32);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(32);
+        let mut interface = MainInterface::new(mandelbrot, julia, 32);
+        interface.update_panes();
+        let pane = interface.parent_mut();
+        assert!(pane.get_image_frame().is_double_buffered());
+
+        // Without double buffering, the first frame after zooming is a coarse preview
+        pane.toggle_double_buffering();
+        pane.zoom(0.5, Cplx::new(-0.5, 0.));
+        pane.process_tasks();
+        let preview = pane.get_image_frame().image.clone();
+        while !pane.tasks().preview.is_done() {
+            pane.process_tasks();
+        }
+        pane.process_tasks();
+        assert_ne!(pane.get_image_frame().image, preview);
+
+        // With it, the frame on screen is replaced only by the finished recompute
+        pane.toggle_double_buffering();
+        let before = pane.get_image_frame().image.clone();
+        pane.zoom(0.5, Cplx::new(-0.5, 0.));
+        assert!(pane.tasks().preview.is_done());
+        pane.process_tasks();
+        let finished = pane.get_image_frame().image.clone();
+        assert_ne!(finished, before);
+        pane.schedule_redraw();
+        pane.process_tasks();
+        assert_eq!(pane.get_image_frame().image, finished);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn annotation_overlay_round_trip()
+    {
+        use dynamo_common::prelude::{Cplx, RationalAngle};
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_gui::marked_points::{AnnotationOverlay, ContourType};
+        use dynamo_profiles::Mandelbrot;
+
+        let make_interface = || {
+            let mandelbrot = Mandelbrot::default().with_res_y(16);
+            let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+            MainInterface::new(mandelbrot, julia, 16)
+        };
+
+        let mut interface = make_interface();
+        let pane = interface.parent_mut();
+        let marking = pane.marking_mut();
+        marking.toggle_ray(RationalAngle::new(1, 3));
+        marking.toggle_cycles_of_period(2);
+        marking.toggle_contour(ContourType::Equipotential, Cplx::new(0.5, 0.5));
+        pane.schedule_redraw();
+        interface.update_panes();
+        let overlay = interface.parent().marking().annotation_overlay();
+        assert!(!overlay.is_empty());
+
+        // Through the JSON file written by the pane
+        let path = std::env::temp_dir().join("dynamo_annotation_overlay.json");
+        interface.parent().save_annotations(&path);
+        let json = std::fs::read_to_string(&path).unwrap();
+        let parsed: AnnotationOverlay = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, overlay);
+
+        let mut reloaded = make_interface();
+        reloaded.parent_mut().load_annotations(&path);
+        let _ = std::fs::remove_file(&path);
+        reloaded.update_panes();
+        assert_eq!(reloaded.parent().marking().annotation_overlay(), overlay);
+    }
+
+    #[test]
+    fn equipotentials_dashed_rays_solid()
+    {
+        use dynamo_common::prelude::{Cplx, RationalAngle};
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_gui::marked_points::{ContourType, DashStyle, DashStyles, Marking};
+        use dynamo_profiles::Mandelbrot;
+
+        let annotate = |mark: &dyn Fn(&mut Marking)| {
+            let mandelbrot = Mandelbrot::default().with_res_y(128);
+            let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(128);
+            let mut interface = MainInterface::new(mandelbrot, julia, 128);
+            mark(interface.parent_mut().marking_mut());
+            interface.parent_mut().schedule_redraw();
+            interface.update_panes();
+            interface
+        };
+        let base = annotate(&|_| {}).parent_mut().render_image(128);
+        // Number of pixels covered by the annotations
+        let painted = |interface: &mut MainInterface<_, _>, dash_styles: DashStyles| {
+            let pane = interface.parent_mut();
+            pane.marking_mut().set_dash_styles(dash_styles);
+            let image = pane.render_image(128);
+            image
+                .pixels()
+                .zip(base.pixels())
+                .filter(|(a, b)| a != b)
+                .count()
+        };
+        let solid = DashStyles {
+            rays: DashStyle::Solid,
+            equipotentials: DashStyle::Solid,
+            other: DashStyle::Solid,
+        };
+
+        let mut equipotential = annotate(&|marking| {
+            marking.toggle_contour(ContourType::Equipotential, Cplx::new(0.5, 0.5));
+        });
+        let dashed = painted(&mut equipotential, DashStyles::default());
+        let full = painted(&mut equipotential, solid);
+        assert!(dashed > 0 && dashed * 5 < full * 4, "{dashed} of {full}");
+
+        let mut ray = annotate(&|marking| marking.toggle_ray(RationalAngle::new(1, 3)));
+        let full = painted(&mut ray, solid);
+        assert!(full > 0);
+        assert_eq!(painted(&mut ray, DashStyles::default()), full);
+    }
+
+    #[test]
+    fn julia_preview_follows_resting_cursor()
+    {
+        use dynamo_common::prelude::Cplx;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::MainInterface;
+        use dynamo_gui::julia_preview::JULIA_PREVIEW_DELAY;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+
+        let c = Cplx::new(-0.12, 0.75);
+        interface.hover_julia_preview(c, 0.);
+        assert!(interface.julia_preview().is_none());
+        interface.hover_julia_preview(c, 0.5 * JULIA_PREVIEW_DELAY);
+        assert!(interface.julia_preview().is_none());
+        interface.hover_julia_preview(c, 2. * JULIA_PREVIEW_DELAY);
+        assert_eq!(interface.julia_preview().unwrap().local_param, c);
+
+        // Moving the cursor discards the preview until it rests again
+        let d = Cplx::new(0.3, 0.);
+        interface.hover_julia_preview(d, 3. * JULIA_PREVIEW_DELAY);
+        assert!(interface.julia_preview().is_none());
+        interface.hover_julia_preview(d, 5. * JULIA_PREVIEW_DELAY);
+        assert_eq!(interface.julia_preview().unwrap().local_param, d);
+    }
+
+    #[test]
+    fn power_of_two_zoom_steps()
+    {
+        use dynamo_common::prelude::Bounds;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::Action;
+        use dynamo_gui::interface::{Interactive, MainInterface, PanePair};
+        use dynamo_gui::navigation::Navigation;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default()
+            .with_bounds(Bounds::centered_square(2.))
+            .with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16)
+            .with_navigation(Navigation::default().with_power_of_two_zoom(true));
+
+        let range = interface.parent().grid().bounds.range_x();
+        for _ in 0..3 {
+            interface.process_action(&Action::Zoom(0.8));
+        }
+        assert_eq!(interface.parent().grid().bounds.range_x(), range / 8.);
+
+        let mut navigation = Navigation::default().with_power_of_two_zoom(true);
+        assert_eq!(navigation.zoom_scale(1.25), 2.);
+        assert_eq!(navigation.zoom_scale(0.125), 0.125);
+        // Small pointer zooms add up to a single doubling
+        let zooms: Vec<_> = (0..4).map(|_| navigation.pointer_zoom_scale(1.5)).collect();
+        assert_eq!(zooms, [1., 2., 1., 2.]);
+    }
+
+    #[test]
+    fn profile_view_restored_on_return()
+    {
+        use crate::fractal_tab::FractalTab;
+        use dynamo_color::ToneAdjustment;
+        use dynamo_gui::actions::Action;
+
+        const MANDELBROT: &str = "Polynomial / Quadratic Family / Base Curve";
+        const QUAD_RAT_PER_2: &str = "Rational / QuadRat Per(2) / Moduli space";
+
+        let mut tab = FractalTab::default().with_profile(MANDELBROT);
+        let default_view = tab.interface.view_as_rust();
+        tab.interface.process_action(&Action::Pan(0.25, 0.));
+        tab.interface.process_action(&Action::Zoom(0.5));
+        let tone = ToneAdjustment {
+            gamma: 1.5,
+            ..ToneAdjustment::default()
+        };
+        tab.interface.set_tone(tone);
+        let view = tab.interface.view_as_rust();
+        assert_ne!(view, default_view);
+
+        let create_interface = tab.sidebar_menu.find(QUAD_RAT_PER_2).unwrap();
+        tab.change_fractal(QUAD_RAT_PER_2.to_owned(), create_interface);
+        assert_ne!(tab.interface.name(), "Mandelbrot");
+
+        let create_interface = tab.sidebar_menu.find(MANDELBROT).unwrap();
+        tab.change_fractal(MANDELBROT.to_owned(), create_interface);
+        assert_eq!(tab.profile.as_deref(), Some(MANDELBROT));
+        assert_eq!(tab.interface.view_as_rust(), view);
+        assert_eq!(tab.interface.get_tone(), tone);
+    }
+
+    #[test]
+    fn saved_profiles_bounded()
+    {
+        use crate::fractal_tab::{FractalTab, MAX_SAVED_INTERFACES};
+
+        const MANDELBROT: &str = "Polynomial / Quadratic Family / Base Curve";
+
+        let mut tab = FractalTab::default().with_profile(MANDELBROT);
+        tab.interface
+            .process_action(&dynamo_gui::actions::Action::Zoom(0.5));
+        let view = tab.interface.view_as_rust();
+        let fractals: Vec<_> = tab
+            .sidebar_menu
+            .search("")
+            .filter(|(name, _)| name != MANDELBROT)
+            .take(MAX_SAVED_INTERFACES + 2)
+            .cloned()
+            .collect();
+        assert_eq!(fractals.len(), MAX_SAVED_INTERFACES + 2);
+
+        for (i, (profile, create_interface)) in fractals.into_iter().enumerate() {
+            tab.change_fractal(profile, create_interface);
+            assert_eq!(
+                tab.saved_interface_count(),
+                (i + 1).min(MAX_SAVED_INTERFACES)
+            );
+        }
+
+        // The fractal left first has been evicted, so returning to it starts afresh
+        let create_interface = tab.sidebar_menu.find(MANDELBROT).unwrap();
+        tab.change_fractal(MANDELBROT.to_owned(), create_interface);
+        assert_ne!(tab.interface.view_as_rust(), view);
+        assert_eq!(tab.saved_interface_count(), MAX_SAVED_INTERFACES);
+    }
+
+    #[test]
+    fn period_probe_replaces_cycles()
+    {
+        use dynamo_common::prelude::Cplx;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_profiles::Mandelbrot;
+
+        // The child is the Julia set of z^2, whose period n points are roots of unity
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+        interface.child_mut().schedule_redraw();
+        interface.update_panes();
+        let baseline: Vec<Cplx> = interface
+            .child()
+            .marking()
+            .iter_points()
+            .map(|p| p.point)
+            .collect();
+
+        let mut probe = |period| {
+            interface.set_probed_period(Some(period));
+            interface.update_panes();
+            interface
+                .child()
+                .marking()
+                .iter_points()
+                .map(|p| p.point)
+                .filter(|z| baseline.iter().all(|w| (z - w).norm() > 1e-6))
+                .collect::<Vec<_>>()
+        };
+        let is_periodic = |z: &Cplx, n: u32| (z.powi(1 << n) - z).norm() < 1e-6 && z.norm() > 0.5;
+
+        let period_2 = probe(2);
+        assert_eq!(period_2.len(), 2);
+        assert!(period_2.iter().all(|z| is_periodic(z, 2)));
+
+        let period_3 = probe(3);
+        assert_eq!(period_3.len(), 6);
+        assert!(period_3
+            .iter()
+            .all(|z| is_periodic(z, 3) && !is_periodic(z, 2)));
+
+        // Cycles marked by hand stay when the probe passes over them and closes
+        interface.set_probed_period(None);
+        interface
+            .child_mut()
+            .marking_mut()
+            .toggle_cycles_of_period(2);
+        for period in [2, 3] {
+            interface.set_probed_period(Some(period));
+            interface.update_panes();
+        }
+        interface.set_probed_period(None);
+        interface.update_panes();
+        let marked: Vec<Cplx> = interface
+            .child()
+            .marking()
+            .iter_points()
+            .map(|p| p.point)
+            .filter(|z| baseline.iter().all(|w| (z - w).norm() > 1e-6))
+            .collect();
+        assert_eq!(marked.len(), 2);
+        assert!(marked.iter().all(|z| is_periodic(z, 2)));
+    }
+
+    #[test]
+    fn infinity_basin_color()
+    {
+        use dynamo_color::prelude::FractalImage;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_core::prelude::*;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_profiles::{Mandelbrot, QuadRatPer4};
+        use egui::Color32;
+
+        let color = Color32::from_rgb(48, 96, 192);
+
+        let plane = QuadRatPer4::default().with_res_y(32);
+        let julia = JuliaSet::from(plane.clone()).with_res_y(32);
+        let mut interface = MainInterface::new(plane.clone(), julia, 32);
+        interface.set_infinity_color(Some(color));
+        assert_eq!(
+            interface.parent().get_coloring().infinity_color(),
+            Some(color)
+        );
+        assert_eq!(
+            interface.child().get_coloring().infinity_color(),
+            Some(color)
+        );
+
+        let iter_plane = plane.compute();
+        let image = iter_plane.render(interface.parent().get_coloring());
+        let width = iter_plane.point_grid.res_x;
+        let height = iter_plane.point_grid.res_y;
+        let (mut basin, mut elsewhere) = (0, 0);
+        for ((x, y), point_info) in iter_plane.iter_counts.indexed_iter() {
+            let pixel = image.pixels[x + (height - y - 1) * width];
+            if point_info.is_escaping() {
+                assert_eq!(pixel, color);
+                basin += 1;
+            } else if pixel != color {
+                elsewhere += 1;
+            }
+        }
+        assert!(basin > 0);
+        assert!(elsewhere > 0);
+
+        // Escaping points of polynomials are not an attracting basin, so keep their gradient
+        let mandelbrot = Mandelbrot::default().with_res_y(16);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+        let mut interface = MainInterface::new(mandelbrot, julia, 16);
+        interface.set_infinity_color(Some(color));
+        assert_eq!(interface.parent().get_coloring().infinity_color(), None);
+    }
+
+    #[test]
+    fn boettcher_texture_toggle_restores_compute_mode()
+    {
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::actions::ChangeBoolean;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_gui::pane::Pane;
+        use dynamo_profiles::Mandelbrot;
+
+        let mandelbrot = Mandelbrot::default().with_res_y(32);
+        let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(32);
+        let mut interface = MainInterface::new(mandelbrot, julia, 32);
+        interface.update_panes();
+        let pane = interface.parent_mut();
+        pane.change_compute_mode(ChangeBoolean::Enable);
+        pane.process_tasks();
+        let distance_estimate = pane.get_image_frame().image.clone();
+
+        pane.toggle_boettcher_texture();
+        pane.process_tasks();
+        assert_ne!(pane.get_image_frame().image, distance_estimate);
+
+        // Switching the texture off goes back to distance estimation, not smooth potential
+        pane.toggle_boettcher_texture();
+        pane.process_tasks();
+        assert_eq!(pane.get_image_frame().image, distance_estimate);
+    }
+
+    #[test]
+    fn batched_rays_match_scheduled_rays()
+    {
+        use dynamo_common::prelude::RationalAngle;
+        use dynamo_core::dynamics::julia::JuliaSet;
+        use dynamo_gui::interface::{MainInterface, PanePair};
+        use dynamo_profiles::Mandelbrot;
+
+        let make_interface = || {
+            let mandelbrot = Mandelbrot::default().with_res_y(16);
+            let julia = JuliaSet::from(mandelbrot.clone()).with_res_y(16);
+            MainInterface::new(mandelbrot, julia, 16)
+        };
+        let angles: Vec<_> = (1..=20).map(|k| RationalAngle::new(k, 31)).collect();
+
+        let mut scheduled = make_interface();
+        for angle in &angles {
+            scheduled.parent_mut().marking_mut().enable_ray(*angle);
+        }
+        scheduled.update_panes();
+
+        // The rays are drawn over several updates, a batch at a time
+        let mut batched = make_interface();
+        batched.parent_mut().draw_rays(&angles);
+        batched.update_panes();
+        assert!(batched.parent().marking().has_pending_rays());
+        while batched.parent().marking().has_pending_rays() {
+            batched.update_panes();
+        }
+        assert_eq!(
+            batched.parent().marking().annotation_overlay(),
+            scheduled.parent().marking().annotation_overlay()
+        );
+    }
 }
@@ -43,4 +43,15 @@ macro_rules! interface_mis {
     };
 }
 
-pub(crate) use {interface, interface_dyn, interface_mc, interface_mis};
+macro_rules! interface_comp {
+    ($first: ty, $second: ty) => {
+        || {
+            create_interface(
+                || Composition::new(<$first>::default(), <$second>::default()),
+                JuliaSet::from,
+            )
+        }
+    };
+}
+
+pub(crate) use {interface, interface_comp, interface_dyn, interface_mc, interface_mis};
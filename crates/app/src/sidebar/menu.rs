@@ -1,9 +1,13 @@
 use dynamo_gui::interface::Interface;
 use egui::{Color32, Ui};
 
+/// Name of a fractal, including the submenus leading to it, and its constructor.
+pub type FractalEntry = (String, fn() -> Box<dyn Interface>);
+
 pub enum Action
 {
-    ChangeFractal(Box<dyn Interface>),
+    /// Show the fractal with the given path through the menu, constructing it if it is not saved.
+    ChangeFractal(String, fn() -> Box<dyn Interface>),
 }
 
 #[derive(Default)]
@@ -112,6 +116,31 @@ impl State
         self.add_tile(name, item);
     }
 
+    /// Every fractal reachable from this menu, named by its path through the submenus.
+    #[must_use]
+    pub fn fractals(&self) -> Vec<FractalEntry>
+    {
+        let mut fractals = Vec::new();
+        self.collect_fractals("", &mut fractals);
+        fractals
+    }
+
+    fn collect_fractals(&self, prefix: &str, fractals: &mut Vec<FractalEntry>)
+    {
+        for tile in &self.tiles {
+            let name = if prefix.is_empty() {
+                tile.name.clone()
+            } else {
+                format!("{prefix} / {}", tile.name)
+            };
+            match &tile.item {
+                Item::ChangeFractal(cons) => fractals.push((name, *cons)),
+                Item::Submenu(create_menu) => create_menu().collect_fractals(&name, fractals),
+                Item::GoToParent => {}
+            }
+        }
+    }
+
     fn with_tile(mut self, name: &str, item: Item) -> Self
     {
         let tile = Tile {
@@ -146,24 +175,60 @@ pub struct Menu
 {
     pub state: State,
     above: Vec<State>,
+    /// Names of the submenus leading to the current state.
+    path: Vec<String>,
+    /// Text filtering the flat list of fractals; the menu tree is shown if it is empty.
+    query: String,
+    /// Every fractal in the menu, named by its path.
+    fractals: Vec<FractalEntry>,
 }
 
 impl Menu
 {
     #[must_use]
-    pub const fn new(state: State) -> Self
+    pub fn new(state: State) -> Self
     {
+        let fractals = state.fractals();
         Self {
             state,
             above: Vec::new(),
+            path: Vec::new(),
+            query: String::new(),
+            fractals,
         }
     }
 
+    /// Fractals whose path in the menu contains the query, ignoring case.
+    pub fn search<'a>(&'a self, query: &str) -> impl Iterator<Item = &'a FractalEntry> + 'a
+    {
+        let query = query.to_lowercase();
+        self.fractals
+            .iter()
+            .filter(move |(name, _)| name.to_lowercase().contains(&query))
+    }
+
+    /// Constructor of the fractal with the given path through the menu.
+    #[must_use]
+    pub fn find(&self, path: &str) -> Option<fn() -> Box<dyn Interface>>
+    {
+        self.fractals
+            .iter()
+            .find(|(name, _)| name == path)
+            .map(|(_, cons)| *cons)
+    }
+
     pub fn show_and_get_action(&mut self, ui: &mut Ui) -> Option<Action>
     {
         let mut nav_action: NavAction = NavAction::DoNothing;
 
-        ui.add_space(50.);
+        ui.add_space(10.);
+        ui.add(egui::TextEdit::singleline(&mut self.query).hint_text("Search fractals"));
+        ui.add_space(32.);
+
+        if !self.query.trim().is_empty() {
+            return self.show_search_results(ui);
+        }
+
         for tile in &mut self.state.tiles {
             if tile.draw_rect(ui).clicked() {
                 match &tile.item {
@@ -171,9 +236,19 @@ impl Menu
                         nav_action = NavAction::Ascend;
                         break;
                     }
-                    Item::ChangeFractal(cons) => return Some(Action::ChangeFractal(cons())),
+                    Item::ChangeFractal(cons) => {
+                        let path = self
+                            .path
+                            .iter()
+                            .chain(std::iter::once(&tile.name))
+                            .map(String::as_str)
+                            .collect::<Vec<_>>()
+                            .join(" / ");
+                        return Some(Action::ChangeFractal(path, *cons));
+                    }
                     Item::Submenu(create_menu) => {
                         nav_action = NavAction::Descend(create_menu());
+                        self.path.push(tile.name.clone());
                         break;
                     }
                 }
@@ -182,6 +257,7 @@ impl Menu
         match nav_action {
             NavAction::Ascend => {
                 self.state = self.above.pop()?;
+                self.path.pop();
             }
             NavAction::Descend(state) => {
                 let old_state = std::mem::replace(&mut self.state, state);
@@ -191,4 +267,17 @@ impl Menu
         }
         None
     }
+
+    fn show_search_results(&self, ui: &mut Ui) -> Option<Action>
+    {
+        let mut chosen = None;
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (name, cons) in self.search(self.query.trim()) {
+                if ui.button(name).clicked() {
+                    chosen = Some((name.clone(), *cons));
+                }
+            }
+        });
+        chosen.map(|(name, cons)| Action::ChangeFractal(name, cons))
+    }
 }
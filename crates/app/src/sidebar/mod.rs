@@ -1,5 +1,6 @@
 pub mod menu;
-use crate::macros::{interface, interface_dyn, interface_mc, interface_mis};
+use crate::macros::{interface, interface_comp, interface_dyn, interface_mc, interface_mis};
+use dynamo_color::PaletteLibrary;
 use dynamo_common::prelude::*;
 use dynamo_core::prelude::*;
 use dynamo_gui::interface::{Interface, MainInterface, PanePair};
@@ -7,12 +8,14 @@ use dynamo_profiles::{
     BiquadraticMult, BiquadraticMultParam, BiquadraticMultSection, BurningShip, Chebyshev,
     CoshNewton, Cosine, CosineAdd, CubicMarked2Cycle, CubicPer1Lambda, CubicPer1LambdaModuli,
     CubicPer1LambdaParam, CubicPer1_0, CubicPer1_1, CubicPer2CritMarked, CubicPer2Lambda,
-    CubicPer2LambdaParam, CubicPer3_0, EisensteinMandel, Exponential, GaussianMandel, Gudermannian,
-    Mandelbrot, McMullenFamily, MinsikHanPhi, NewtonCubic, OddCubic, QuadRatPer1Lambda,
+    CubicPer2LambdaParam, CubicPer3_0, EisensteinMandel, Exponential, GaussianMandel,
+    GaussianNewton, GeneralizedCollatz, Gudermannian, Mandelbrot, McMullenFamily, MinsikHanPhi,
+    NewtonCubic, NovaMandelbrot, OddCubic, PhoenixMandelbrot, PhoenixSlice, QuadRatPer1Lambda,
     QuadRatPer1LambdaParam, QuadRatPer1_1, QuadRatPer2, QuadRatPer2InfPuncture, QuadRatPer2Lambda,
     QuadRatPer2LambdaParam, QuadRatPer3, QuadRatPer4, QuadRatPer5, QuadRatPreper21,
     QuadRatPreper22, QuadRatSymmetryLocus, RealCubicImagCrit, RealCubicRealCrit, RiemannXi,
-    RiemannXiNewton, Rulkov, Sailboat, SineWander, Tricorne, Unicorn, Unicritical,
+    RiemannXiNewton, Rulkov, Sailboat, SineWander, Tangent, Tricorne, TrigKind, TrigWander,
+    Unicorn, Unicritical, UnicriticalDyn,
 };
 use menu::{Menu, State};
 use seq_macro::seq;
@@ -24,7 +27,8 @@ pub fn create_menu() -> Menu
         .with_submenu("Rational", rational_maps_menu)
         .with_submenu("Transcendental", transcendental_menu)
         .with_submenu("Non-Analytic", non_analytic_menu)
-        .with_submenu("Arithmetic", arithmetic_menu);
+        .with_submenu("Arithmetic", arithmetic_menu)
+        .with_submenu("Compositions", compositions_menu);
     Menu::new(state)
 }
 
@@ -237,8 +241,17 @@ fn polynomials_menu() -> State
             seq!(D in 4..=8 {
                 submenu.add_fractal_button(&format!("Degree {}", D), interface!(Unicritical<D>));
             });
+            submenu.add_fractal_button("Any Degree", interface!(UnicriticalDyn));
             submenu
         })
+        .with_submenu("Phoenix Maps\n(z, y) -> (z^2 + c + py, z)", || {
+            State::submenu()
+                .with_fractal_button("c-plane, p = -0.5", interface!(PhoenixMandelbrot))
+                .with_fractal_button(
+                    "p-plane, c = 0.5667",
+                    interface!(PhoenixMandelbrot, with_slice, PhoenixSlice::P),
+                )
+        })
         .with_submenu("Chebyshev family\nz -> (-1)^k * c * T_2k(z/2)", || {
             let mut submenu = State::submenu();
             seq!(D in 1..=5 {
@@ -363,6 +376,7 @@ fn rational_maps_menu() -> State
     })
     .with_fractal_button("QuadRat Symmetry Locus", interface!(QuadRatSymmetryLocus))
         .with_fractal_button("Newton Cubic", interface!(NewtonCubic))
+        .with_fractal_button("Nova-Mandelbrot", interface!(NovaMandelbrot))
         .with_submenu("McMullen Family\nz -> z^m + 1/(c*z^n)", || {
             let mut submenu = State::submenu();
             seq!(N in 2..=8 {
@@ -395,8 +409,27 @@ fn transcendental_menu() -> State
         .with_fractal_button("z -> λcos(z)", interface!(Cosine))
         .with_fractal_button("z -> cos(z) + c", interface!(CosineAdd))
         .with_fractal_button("z -> sin(z) + z + τc", interface!(SineWander))
+        .with_fractal_button("z -> λtan(z)", interface!(Tangent))
+        .with_submenu("z -> g(z) + az + τc", || {
+            State::submenu()
+                .with_fractal_button("g = sin", interface!(TrigWander))
+                .with_fractal_button("g = cos", interface!(TrigWander, with_trig, TrigKind::Cos))
+                .with_fractal_button("g = tan", interface!(TrigWander, with_trig, TrigKind::Tan))
+        })
         .with_fractal_button("Cosh Newton", interface!(CoshNewton, CoshNewton))
         .with_fractal_button("z -> λarctan(sinh(z))", interface!(Gudermannian))
+        .with_submenu("Generalized Collatz", || {
+            State::submenu()
+                .with_fractal_button("mz + 1", interface!(GeneralizedCollatz))
+                .with_fractal_button(
+                    "mz - 1",
+                    interface!(GeneralizedCollatz, with_param, Cplx::from(-1.)),
+                )
+                .with_fractal_button(
+                    "mz + 3",
+                    interface!(GeneralizedCollatz, with_param, Cplx::from(3.)),
+                )
+        })
         .with_fractal_button(
             "Riemann Xi Newton [SLOW!]",
             interface!(RiemannXi, RiemannXiNewton),
@@ -408,7 +441,16 @@ fn non_analytic_menu() -> State
     State::submenu()
         .with_submenu("Tricorne", || {
             let mut submenu = State::submenu();
-            seq!(D in 2..=5 {
+            submenu.add_submenu("Degree 2", || {
+                State::submenu()
+                    .with_fractal_button("Base curve", interface!(Tricorne<2>))
+                    .with_submenu("Marked Cycle", || {
+                        State::submenu()
+                            .with_fractal_button("Period 1", interface_mc!(Tricorne<2>, 1))
+                            .with_fractal_button("Period 2", interface_mc!(Tricorne<2>, 2))
+                    })
+            });
+            seq!(D in 3..=5 {
                 submenu.add_fractal_button(&format!("Degree {d}", d=D), interface!(Tricorne<D>));
             });
             submenu
@@ -431,6 +473,28 @@ fn non_analytic_menu() -> State
         .with_fractal_button("Rulkov Map", interface!(Rulkov))
 }
 
+/// Families whose map is a step of the first profile followed by a step of the second.
+fn compositions_menu() -> State
+{
+    State::submenu()
+        .with_fractal_button(
+            "Mandelbrot, then Mandelbrot",
+            interface_comp!(Mandelbrot, Mandelbrot),
+        )
+        .with_fractal_button(
+            "Mandelbrot, then Tricorne",
+            interface_comp!(Mandelbrot, Tricorne<2>),
+        )
+        .with_fractal_button(
+            "Tricorne, then Mandelbrot",
+            interface_comp!(Tricorne<2>, Mandelbrot),
+        )
+        .with_fractal_button(
+            "Mandelbrot, then Exponential",
+            interface_comp!(Mandelbrot, Exponential),
+        )
+}
+
 fn arithmetic_menu() -> State
 {
     State::submenu()
@@ -444,6 +508,12 @@ fn arithmetic_menu() -> State
                 .with_fractal_button("Mod 107", interface!(GaussianMandel<107, 0>))
                 .with_fractal_button("Mod 311", interface!(GaussianMandel<311, 0>))
         })
+        .with_submenu("Gaussian Int Newton", || {
+            State::submenu()
+                .with_fractal_button("Extent 1", interface!(GaussianNewton<1>))
+                .with_fractal_button("Extent 2", interface!(GaussianNewton<2>))
+                .with_fractal_button("Extent 3", interface!(GaussianNewton<3>))
+        })
         .with_submenu("Eisenstein Int Mandel", || {
             State::submenu()
                 .with_fractal_button("Mod 2+ω", interface!(EisensteinMandel<2, 1>))
@@ -456,17 +526,22 @@ fn arithmetic_menu() -> State
         })
 }
 
+/// Create a parameter plane as it is first shown, with the iteration limit recommended for it.
+pub(crate) fn load_parent<P: Displayable>(create_parent: fn() -> P) -> P
+{
+    create_parent().with_recommended_max_iter().with_res_y(768)
+}
+
 fn create_interface<P, J>(create_parent: fn() -> P, create_child: fn(P) -> J) -> Box<dyn Interface>
 where
     P: Displayable + HasChild<J> + Clone + 'static,
     J: Displayable + Clone + 'static,
 {
-    let max_iters = 1024;
-
-    let parent_plane = create_parent().with_max_iter(max_iters).with_res_y(768);
+    let parent_plane = load_parent(create_parent);
     let child_plane = create_child(parent_plane.clone());
 
-    let mut interface = MainInterface::new(parent_plane, child_plane, 768);
+    let mut interface = MainInterface::new(parent_plane, child_plane, 768)
+        .with_palette_library(PaletteLibrary::load_default());
     interface.update_panes();
     Box::new(interface)
 }
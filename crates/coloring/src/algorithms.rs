@@ -9,11 +9,41 @@ use serde::{Deserialize, Serialize};
 
 const PERIOD_LUMA_MODIFIER: f32 = 1.0;
 
+/// Largest distance of $|\lambda|$ from $1$ at which a cycle is always treated as parabolic.
+const PARABOLIC_TOLERANCE: Real = 1e-5;
+
+/// Largest order $q$ of the roots of unity $e^{2\pi i p/q}$ near which a cycle is treated as
+/// parabolic.
+const MAX_PARABOLIC_ORDER: Period = 16;
+
+/// Radius, in units of the detection distance $\sqrt{\text{tol}}$, of the neighborhood of a
+/// root of unity in which a multiplier is treated as parabolic. The multiplier is computed at
+/// the iterate where the cycle was detected rather than on the cycle itself, so for a parabolic
+/// cycle it misses the root of unity by a multiple of this distance.
+const PARABOLIC_WINDOW_SCALE: Real = 8.;
+
+/// Approximate distance from $\lambda$ to the nearest root of unity of order at most
+/// [`MAX_PARABOLIC_ORDER`].
+fn root_of_unity_distance(mult_norm: Real, mult_arg: Real) -> Real
+{
+    let turns = mult_arg / TAU;
+    (1..=MAX_PARABOLIC_ORDER)
+        .map(|q| {
+            let x = turns * Real::from(q);
+            let angle_dist = TAU * (x - x.round()).abs() / Real::from(q);
+            (1. - mult_norm).hypot(angle_dist)
+        })
+        .fold(Real::INFINITY, Real::min)
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum IncoloringAlgorithm
 {
     PeriodMultiplier,
+    /// Solid color for each period, from the discrete period coloring of the palette. These
+    /// match the colors of marked cycles of the same period, and repeat after
+    /// [`DiscretePalette::num_colors`](crate::palette::DiscretePalette::num_colors) periods.
     Period,
     Solid,
     InternalPotential
@@ -41,6 +71,27 @@ pub enum IncoloringAlgorithm
 }
 impl IncoloringAlgorithm
 {
+    /// Weight in $[0, 1]$ of the parabolic branch of the internal potential, for a cycle
+    /// detected at squared distance `tol`.
+    ///
+    /// Near a parabolic parameter the orbit approaches the cycle at an algebraic rather than a
+    /// geometric rate, so the logarithm of the final error in base $|\lambda|$ is unstable. The
+    /// weight is $1$ within [`PARABOLIC_WINDOW_SCALE`] detection distances of a root of unity,
+    /// and falls off linearly to $0$ at twice that, so that the two branches blend without a
+    /// seam. The window widens with the tolerance, which bounds how close the computed
+    /// multiplier of a parabolic cycle comes to the root of unity.
+    #[must_use]
+    pub fn parabolic_weight<D: Polar<Real>>(multiplier: D, tol: Real) -> Real
+    {
+        let mult_norm = multiplier.norm();
+        if (1. - mult_norm).abs() <= PARABOLIC_TOLERANCE {
+            return 1.;
+        }
+        let window = PARABOLIC_WINDOW_SCALE * tol.sqrt().max(PARABOLIC_TOLERANCE);
+        let dist = root_of_unity_distance(mult_norm, multiplier.arg());
+        (2. - dist / window).clamp(0., 1.)
+    }
+
     fn multiplier_coloring_rate(mult_norm: Real, fill_rate: Real) -> f64
     {
         let scaling_rate = mult_norm;
@@ -100,20 +151,19 @@ impl IncoloringAlgorithm
 
                 let mult_norm = point_info.multiplier.norm();
 
-                let potential = Self::internal_potential(
-                    point_info.final_error,
-                    *periodicity_tolerance,
-                    mult_norm,
-                    *crit_degree,
-                );
+                let potential =
+                    Self::internal_potential(point_info, *periodicity_tolerance, *crit_degree);
 
                 let val = k / n - potential;
                 let luma = val.powi(2) * n;
 
-                let coloring_rate = if mult_norm <= 1e-10 || (1. - mult_norm).abs() < 1e-5 {
+                let coloring_rate = if mult_norm <= 1e-10 {
                     0.1
                 } else {
-                    Self::multiplier_coloring_rate(mult_norm, *fill_rate)
+                    let weight =
+                        Self::parabolic_weight(point_info.multiplier, *periodicity_tolerance);
+                    let rate = Self::multiplier_coloring_rate(mult_norm, *fill_rate);
+                    weight.mul_add(0.1 - rate, rate)
                 };
 
                 let luminosity_modifier = (coloring_rate * luma).tanh();
@@ -136,25 +186,30 @@ impl IncoloringAlgorithm
         }
     }
 
-    fn internal_potential(
-        err: IterCountSmooth,
+    fn internal_potential<D: Polar<Real>>(
+        point_info: &PointInfoPeriodic<D>,
         tol: IterCountSmooth,
-        mult_norm: IterCountSmooth,
         crit_degree: f64,
     ) -> IterCountSmooth
     {
+        let err = point_info.final_error;
+        let mult_norm = point_info.multiplier.norm();
+
         // Superattracting case
         // Assumes the first return map has local degree 2.
         // This could be improved to handle higher order critical points,
         // but we would need access to more information to estimate the order
         let potential = if mult_norm <= 1e-10 {
             2. * (err.log(tol)).log(crit_degree as IterCountSmooth) as IterCountSmooth
-        }
-        // Parabolic case
-        else if (1. - mult_norm).abs() <= 1e-5 {
-            err / tol
         } else {
-            (err / tol).log(mult_norm) as IterCountSmooth
+            // Blend of the parabolic and attracting cases
+            let weight = Self::parabolic_weight(point_info.multiplier, tol);
+            if weight >= 1. {
+                err / tol
+            } else {
+                let attracting = (err / tol).log(mult_norm) as IterCountSmooth;
+                weight.mul_add(err / tol - attracting, attracting)
+            }
         };
 
         if !potential.is_finite() {
@@ -174,10 +229,7 @@ impl IncoloringAlgorithm
         let n = IterCountSmooth::from(point_info.period);
         let k = point_info.preperiod as IterCountSmooth;
 
-        let mult_norm = point_info.multiplier.norm();
-
-        let potential =
-            Self::internal_potential(point_info.final_error, tol, mult_norm, crit_degree);
+        let potential = Self::internal_potential(point_info, tol, crit_degree);
 
         let val = k / n - potential;
 
@@ -1,16 +1,66 @@
 use crate::Coloring;
 use dynamo_common::prelude::*;
 use egui::{Color32, ColorImage};
-use image::{ImageBuffer, Rgb};
+use image::{ImageBuffer, Luma, Rgb, Rgba};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How a grayscale transparency mask is derived from the escape field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlphaMaskMode
+{
+    /// White on the filled set, black on the escape set.
+    #[default]
+    Interior,
+    /// White on the filled set, and on the escape set a gray level rising with the escape
+    /// time, from black where points escape fastest.
+    Potential,
+}
+
+impl std::fmt::Display for AlphaMaskMode
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Interior => write!(f, "interior"),
+            Self::Potential => write!(f, "potential"),
+        }
+    }
+}
+
+/// Center of the pixel in column `x` and row `row` from the top, as fractions of the image width
+/// and height.
+fn pixel_fraction(x: usize, row: usize, width: usize, height: usize) -> (Real, Real)
+{
+    (
+        (x as Real + 0.5) / width as Real,
+        (row as Real + 0.5) / height as Real,
+    )
+}
 
 pub trait FractalImage
 {
     type Image;
     fn point_grid(&self) -> &PointGrid;
     fn render(&self, coloring: &Coloring) -> ColorImage;
+    /// Render into `image`, reusing its allocation if it already has the size of the grid.
     fn render_into(&self, image: &mut ColorImage, coloring: &Coloring);
     fn save(&self, coloring: &Coloring, filename: String);
     fn write_image(&self, coloring: &Coloring) -> Self::Image;
+    /// Like [`Self::write_image`], but into `image`, which is only reallocated if its size
+    /// differs from the grid's.
+    fn write_image_into(&self, image: &mut Self::Image, coloring: &Coloring);
+    fn write_image_rgba(&self, coloring: &Coloring) -> ImageBuffer<Rgba<u8>, Vec<u8>>;
+    /// RGBA counterpart of [`Self::write_image_into`].
+    fn write_image_rgba_into(
+        &self,
+        image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>,
+        coloring: &Coloring,
+    );
+    /// Grayscale mask of the escape field, for compositing the image in an external editor.
+    fn render_alpha_mask(&self, mode: AlphaMaskMode) -> ImageBuffer<Luma<u8>, Vec<u8>>;
 }
 
 impl<D> FractalImage for IterPlane<D>
@@ -24,27 +74,26 @@ where
     }
     fn render(&self, coloring: &Coloring) -> ColorImage
     {
-        let width = self.point_grid().res_x;
-        let height = self.point_grid().res_y;
-        let mut img = ColorImage::new([width, height], Color32::default());
-
-        self.iter_counts
-            .indexed_iter()
-            .for_each(|((x, y), point_info)| {
-                img.pixels[x + (height - y - 1) * width] = coloring.map(point_info);
-            });
+        let mut img = ColorImage::default();
+        self.render_into(&mut img, coloring);
         img
     }
     fn render_into(&self, image: &mut ColorImage, coloring: &Coloring)
     {
         let width = self.point_grid().res_x;
         let height = self.point_grid().res_y;
+        if image.size != [width, height] {
+            *image = ColorImage::new([width, height], Color32::default());
+        }
 
         self.iter_counts
             .indexed_iter()
             .for_each(|((x, y), point_info)| {
-                image.pixels[x + (height - y - 1) * width] = coloring.map(point_info);
+                let row = height - y - 1;
+                let (u, v) = pixel_fraction(x, row, width, height);
+                image.pixels[x + row * width] = coloring.map_at(point_info, u, v);
             });
+        coloring.tone().apply_color_image(image);
     }
     fn save(&self, coloring: &Coloring, filename: String)
     {
@@ -54,8 +103,10 @@ where
 
         for (x, y, pixel) in image.enumerate_pixels_mut() {
             let iter_count = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
-            *pixel = coloring.map::<_, Rgb<u8>>(iter_count);
+            let (u, v) = pixel_fraction(x as usize, y as usize, res_x as usize, res_y as usize);
+            *pixel = coloring.map_at::<_, Rgb<u8>>(iter_count, u, v);
         }
+        coloring.tone().apply(&mut image);
         if let Err(e) = image.save(filename.clone()) {
             println!("Error saving file: {e:?}");
         } else {
@@ -63,14 +114,75 @@ where
         }
     }
     fn write_image(&self, coloring: &Coloring) -> Self::Image
+    {
+        let mut image = ImageBuffer::new(0, 0);
+        self.write_image_into(&mut image, coloring);
+        image
+    }
+
+    fn write_image_into(&self, image: &mut Self::Image, coloring: &Coloring)
     {
         let res_x = u32::try_from(self.point_grid().res_x).unwrap_or(u32::MAX);
         let res_y = u32::try_from(self.point_grid().res_y).unwrap_or(u32::MAX);
-        let mut image = ImageBuffer::new(res_x, res_y);
+        if image.dimensions() != (res_x, res_y) {
+            *image = ImageBuffer::new(res_x, res_y);
+        }
 
         for (x, y, pixel) in image.enumerate_pixels_mut() {
             let iter_count = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
-            *pixel = coloring.map(iter_count);
+            let (u, v) = pixel_fraction(x as usize, y as usize, res_x as usize, res_y as usize);
+            *pixel = coloring.map_at(iter_count, u, v);
+        }
+        coloring.tone().apply(image);
+    }
+
+    fn write_image_rgba(&self, coloring: &Coloring) -> ImageBuffer<Rgba<u8>, Vec<u8>>
+    {
+        let mut image = ImageBuffer::new(0, 0);
+        self.write_image_rgba_into(&mut image, coloring);
+        image
+    }
+
+    fn write_image_rgba_into(&self, image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>, coloring: &Coloring)
+    {
+        let res_x = u32::try_from(self.point_grid().res_x).unwrap_or(u32::MAX);
+        let res_y = u32::try_from(self.point_grid().res_y).unwrap_or(u32::MAX);
+        if image.dimensions() != (res_x, res_y) {
+            *image = ImageBuffer::new(res_x, res_y);
+        }
+
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            let iter_count = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
+            let (u, v) = pixel_fraction(x as usize, y as usize, res_x as usize, res_y as usize);
+            let Rgb([r, g, b]) = coloring.map_at::<_, Rgb<u8>>(iter_count, u, v);
+            *pixel = Rgba([r, g, b, coloring.alpha(iter_count)]);
+        }
+        coloring.tone().apply(image);
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn render_alpha_mask(&self, mode: AlphaMaskMode) -> ImageBuffer<Luma<u8>, Vec<u8>>
+    {
+        let res_x = u32::try_from(self.point_grid().res_x).unwrap_or(u32::MAX);
+        let res_y = u32::try_from(self.point_grid().res_y).unwrap_or(u32::MAX);
+        let mut image = ImageBuffer::new(res_x, res_y);
+
+        let (min_level, max_level) = self.escape_level_range().unwrap_or((0., 0.));
+        let range = max_level - min_level;
+
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            let point_info = &self.iter_counts[(x as usize, (res_y - y - 1) as usize)];
+            let value = match (mode, point_info) {
+                (_, PointInfo::Unknown | PointInfo::UndefinedParameter) => 0,
+                (_, p) if !p.is_escaping() => u8::MAX,
+                (AlphaMaskMode::Interior, _) => 0,
+                (AlphaMaskMode::Potential, p) => {
+                    p.escape_level().filter(|_| range > 0.).map_or(0, |level| {
+                        (((level - min_level) / range).clamp(0., 1.) * 255.).round() as u8
+                    })
+                }
+            };
+            *pixel = Luma([value]);
         }
         image
     }
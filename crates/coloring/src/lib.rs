@@ -1,16 +1,26 @@
 #![allow(dead_code)]
 
 use dynamo_common::prelude::*;
+use egui::Color32;
 
 pub mod algorithms;
 pub mod fractal_image;
 pub mod palette;
+pub mod palette_library;
 pub mod prelude;
+pub mod texture;
+pub mod tone;
 pub mod types;
+#[cfg(feature = "serde")]
+pub mod versioning;
 
 pub use algorithms::IncoloringAlgorithm;
+pub use fractal_image::AlphaMaskMode;
 pub use palette::Palette;
-use types::{FromColor, Hsv};
+pub use palette_library::PaletteLibrary;
+use texture::Checkerboard;
+pub use tone::ToneAdjustment;
+use types::{FromColor, Hsv, RgbLinear};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -25,9 +35,34 @@ pub struct Coloring
     palette: Palette,
     esc_period: Period,
     do_escape_phase_coloring: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    transparent_bounded: bool,
+    /// Gamma, brightness and contrast applied to rendered images.
+    #[cfg_attr(feature = "serde", serde(default))]
+    tone: ToneAdjustment,
+    /// Texture shading points computed with their Böttcher coordinates.
+    #[cfg_attr(feature = "serde", serde(default))]
+    texture: Checkerboard,
+    /// Interior algorithm below the diagonal of a split image, for comparison with `algorithm`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    split_algorithm: Option<IncoloringAlgorithm>,
+    /// Range of escape levels stretched over [`Self::NORMALIZED_LEVEL_SPAN`], shared between
+    /// images so that their colors can be compared.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    level_range: Option<(Real, Real)>,
+    /// Solid color of points attracted to a cycle through infinity, in place of the escape
+    /// gradient.
+    #[cfg_attr(feature = "serde", serde(default))]
+    infinity_color: Option<Color32>,
 }
 impl Coloring
 {
+    /// Brightness of the dark cells of a texture, relative to the light ones.
+    const TEXTURE_SHADE: f32 = 0.55;
+
+    /// Length of the palette scale onto which a normalized range of escape levels is mapped.
+    pub const NORMALIZED_LEVEL_SPAN: Real = 8.;
+
     #[must_use]
     pub const fn new(algorithm: IncoloringAlgorithm, palette: Palette) -> Self
     {
@@ -36,39 +71,111 @@ impl Coloring
             palette,
             esc_period: 1,
             do_escape_phase_coloring: false,
+            transparent_bounded: false,
+            tone: ToneAdjustment::IDENTITY,
+            texture: Checkerboard::DEFAULT,
+            split_algorithm: None,
+            level_range: None,
+            infinity_color: None,
         }
     }
 
+    /// Color of a single point. Escaping points are colored by their absolute potential, with no
+    /// normalization to the range of potentials in the image, so that colors stay fixed across
+    /// the frames of an animation. If a level range is set, it is used for normalization
+    /// instead; see [`Self::set_level_range`].
     #[must_use]
     pub fn map<D, T>(&self, point_info: &PointInfo<D>) -> T
+    where
+        D: Polar<Real>,
+        T: FromColor,
+    {
+        self.map_with_algorithm(point_info, &self.algorithm)
+    }
+
+    /// Color of the pixel at `(u, v)`, given as fractions of the image width and height from the
+    /// top left corner. Points of a split image use the interior algorithm at that position.
+    #[must_use]
+    pub fn map_at<D, T>(&self, point_info: &PointInfo<D>, u: Real, v: Real) -> T
+    where
+        D: Polar<Real>,
+        T: FromColor,
+    {
+        self.map_with_algorithm(point_info, self.algorithm_at(u, v))
+    }
+
+    /// Interior algorithm at `(u, v)`, given as fractions of the image width and height from the
+    /// top left corner. A split image uses the split algorithm below the diagonal from the top
+    /// left to the bottom right corner, and the interior algorithm above it.
+    #[must_use]
+    pub fn algorithm_at(&self, u: Real, v: Real) -> &IncoloringAlgorithm
+    {
+        match &self.split_algorithm {
+            Some(algorithm) if v > u => algorithm,
+            _ => &self.algorithm,
+        }
+    }
+
+    fn map_with_algorithm<D, T>(
+        &self,
+        point_info: &PointInfo<D>,
+        algorithm: &IncoloringAlgorithm,
+    ) -> T
     where
         D: Polar<Real>,
         T: FromColor,
     {
         use PointInfo::{
-            Bounded, DistanceEstimate, Escaping, MarkedPoint, Periodic, PeriodicKnownPotential,
-            Unknown, Wandering,
+            Boettcher, Bounded, DistanceEstimate, Escaping, LogDerivative, MarkedPoint, Periodic,
+            PeriodicKnownPotential, UndefinedParameter, Unknown, Wandering,
         };
+        if let Some(color) = self.infinity_color.filter(|_| point_info.is_escaping()) {
+            return T::from_color32(color);
+        }
         match point_info {
             Escaping {
-                potential,
-                phase: Some(phase),
-            } if self.do_escape_phase_coloring => {
-                self.palette
-                    .map_phase(potential.ln(), *phase, self.esc_period)
+                phase: Some(phase), ..
             }
-            Escaping { potential, .. } => self.palette.map(potential.ln()),
-            Periodic(data) => self.algorithm.color_periodic(&self.palette, data),
-            PeriodicKnownPotential(data) => {
-                self.algorithm.color_known_potential(&self.palette, data)
-            }
-            Bounded => T::from_color32(self.palette.in_color),
-            DistanceEstimate { distance, phase } if self.do_escape_phase_coloring => self
+            | LogDerivative {
+                phase: Some(phase), ..
+            } if self.do_escape_phase_coloring => self.palette.map_phase(
+                self.normalize_level(point_info.escape_level().unwrap_or_default()),
+                *phase,
+                self.esc_period,
+            ),
+            Escaping { .. } | LogDerivative { .. } => self
                 .palette
-                .map_phase(-distance.ln() / 2., *phase, self.esc_period),
-            DistanceEstimate { distance, .. } => self.palette.map(-distance.ln() / 2.),
+                .map(self.normalize_level(point_info.escape_level().unwrap_or_default())),
+            Boettcher { potential, angle } => {
+                let color: RgbLinear = self.palette.map(self.normalize_level(potential.ln()));
+                let shade = if self.texture.is_dark(*potential, *angle) {
+                    Self::TEXTURE_SHADE
+                } else {
+                    1.
+                };
+                RgbLinear {
+                    r: color.r * shade,
+                    g: color.g * shade,
+                    b: color.b * shade,
+                }
+                .into()
+            }
+            Periodic(data) => algorithm.color_periodic(&self.palette, data),
+            PeriodicKnownPotential(data) => algorithm.color_known_potential(&self.palette, data),
+            Bounded => T::from_color32(self.palette.bounded_color()),
+            DistanceEstimate { distance, phase } if self.do_escape_phase_coloring => {
+                self.palette.map_phase(
+                    self.normalize_level(-distance.ln() / 2.),
+                    *phase,
+                    self.esc_period,
+                )
+            }
+            DistanceEstimate { distance, .. } => {
+                self.palette.map(self.normalize_level(-distance.ln() / 2.))
+            }
             Wandering => T::from_color32(self.palette.wandering_color),
             Unknown => T::from_color32(self.palette.unknown_color),
+            UndefinedParameter => T::from_color32(self.palette.undefined_color),
             MarkedPoint {
                 class_id,
                 num_point_classes,
@@ -130,6 +237,26 @@ impl Coloring
         self
     }
 
+    #[must_use]
+    pub const fn split_algorithm(&self) -> Option<&IncoloringAlgorithm>
+    {
+        self.split_algorithm.as_ref()
+    }
+
+    /// Color the image below its diagonal with another interior algorithm, or with the same one
+    /// everywhere if `None`.
+    pub fn set_split_algorithm(&mut self, algorithm: Option<IncoloringAlgorithm>)
+    {
+        self.split_algorithm = algorithm;
+    }
+
+    #[must_use]
+    pub fn with_split_algorithm(mut self, algorithm: IncoloringAlgorithm) -> Self
+    {
+        self.split_algorithm = Some(algorithm);
+        self
+    }
+
     #[must_use]
     pub const fn with_escape_period(mut self, esc_period: Period) -> Self
     {
@@ -142,6 +269,113 @@ impl Coloring
         self.do_escape_phase_coloring ^= true;
     }
 
+    /// Whether undecided (`Bounded`) points are left transparent in exported images.
+    #[must_use]
+    pub const fn transparent_bounded(&self) -> bool
+    {
+        self.transparent_bounded
+    }
+
+    pub fn toggle_transparent_bounded(&mut self)
+    {
+        self.transparent_bounded ^= true;
+    }
+
+    #[must_use]
+    pub const fn with_transparent_bounded(mut self, transparent_bounded: bool) -> Self
+    {
+        self.transparent_bounded = transparent_bounded;
+        self
+    }
+
+    #[must_use]
+    pub const fn texture(&self) -> &Checkerboard
+    {
+        &self.texture
+    }
+
+    #[must_use]
+    pub const fn with_texture(mut self, texture: Checkerboard) -> Self
+    {
+        self.texture = texture;
+        self
+    }
+
+    #[must_use]
+    pub const fn level_range(&self) -> Option<(Real, Real)>
+    {
+        self.level_range
+    }
+
+    /// Color escaping points by where their escape level falls in the range `(min, max)`, so
+    /// that images given the same range color equal levels alike. With `None`, points are
+    /// colored by their absolute escape level.
+    pub fn set_level_range(&mut self, level_range: Option<(Real, Real)>)
+    {
+        self.level_range = level_range;
+    }
+
+    /// Escape level rescaled to the level range, if one is set.
+    fn normalize_level(&self, level: Real) -> Real
+    {
+        match self.level_range {
+            Some((min, max)) if max > min => {
+                (level - min) / (max - min) * Self::NORMALIZED_LEVEL_SPAN
+            }
+            _ => level,
+        }
+    }
+
+    #[must_use]
+    pub const fn tone(&self) -> &ToneAdjustment
+    {
+        &self.tone
+    }
+
+    pub fn set_tone(&mut self, tone: ToneAdjustment)
+    {
+        self.tone = tone;
+    }
+
+    #[must_use]
+    pub const fn with_tone(mut self, tone: ToneAdjustment) -> Self
+    {
+        self.tone = tone;
+        self
+    }
+
+    #[must_use]
+    pub const fn infinity_color(&self) -> Option<Color32>
+    {
+        self.infinity_color
+    }
+
+    /// Color the basin of an attracting cycle through infinity with a single color, or by
+    /// escape time if `None`. Only meaningful for families whose infinity is attracting, since
+    /// it applies to every escaping point.
+    pub fn set_infinity_color(&mut self, infinity_color: Option<Color32>)
+    {
+        self.infinity_color = infinity_color;
+    }
+
+    #[must_use]
+    pub const fn with_infinity_color(mut self, infinity_color: Color32) -> Self
+    {
+        self.infinity_color = Some(infinity_color);
+        self
+    }
+
+    /// Alpha channel for a point, taking transparency settings into account.
+    #[must_use]
+    pub const fn alpha<D>(&self, point_info: &PointInfo<D>) -> u8
+    {
+        if self.transparent_bounded && matches!(point_info, PointInfo::Bounded) {
+            0
+        } else {
+            u8::MAX
+        }
+    }
+
     #[cfg(feature = "serde")]
     pub fn save_to_file<P>(&self, filename: P) -> std::io::Result<()>
     where
@@ -149,8 +383,10 @@ impl Coloring
     {
         use std::io::Write;
 
-        let toml_string =
-            toml::to_string(self.get_palette()).expect("Failed to serialize palette.");
+        let toml_string = self
+            .get_palette()
+            .to_toml()
+            .expect("Failed to serialize palette.");
         let mut file = std::fs::File::create(filename)?;
         file.write_all(toml_string.as_bytes())?;
 
@@ -162,8 +398,16 @@ impl Coloring
     where
         P: AsRef<std::path::Path>,
     {
-        let content = std::fs::read_to_string(path)?;
-        let palette: Palette = toml::from_str(&content)?;
+        let path = path.as_ref();
+        let palette = if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+        {
+            Palette::from_image(path)?
+        } else {
+            let content = std::fs::read_to_string(path)?;
+            Palette::from_toml(&content)?
+        };
         self.palette = palette;
         Ok(())
     }
@@ -222,4 +466,343 @@ mod tests
         let xyz = Xyz::from(luv);
         dbg!(xyz);
     }
+
+    #[test]
+    fn palette_library_cycle()
+    {
+        use crate::{Palette, PaletteLibrary};
+
+        let palettes = vec![
+            Palette::black(16.),
+            Palette::white(16.),
+            Palette::new(3., 5., 7.),
+        ];
+        let mut library = PaletteLibrary::new(palettes.clone());
+
+        assert_eq!(library.current(), Some(palettes[0]));
+        for _ in 0..3 {
+            library.next_palette();
+        }
+        assert_eq!(library.current(), Some(palettes[0]));
+        assert_eq!(library.prev_palette(), Some(palettes[2]));
+    }
+
+    #[test]
+    fn transparent_export()
+    {
+        use crate::prelude::*;
+        use dynamo_common::prelude::*;
+
+        let grid = PointGrid::new(4, 2, Bounds::centered_square(1.));
+        let mut iter_plane = IterPlane::<Cplx>::create(grid);
+        iter_plane.iter_counts[(0, 0)] = PointInfo::Escaping {
+            potential: 2.,
+            phase: None,
+        };
+
+        let coloring = Coloring::default().with_transparent_bounded(true);
+        let image = iter_plane.write_image_rgba(&coloring);
+
+        // Row 0 of the image corresponds to the top of the grid
+        assert_eq!(image.get_pixel(0, 1).0[3], u8::MAX);
+        assert_eq!(image.get_pixel(1, 1).0[3], 0);
+        assert_eq!(image.get_pixel(3, 0).0[3], 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn palette_v1_migration()
+    {
+        use crate::versioning::PALETTE_VERSION;
+        use crate::Palette;
+
+        let palette = Palette::new(0.3, 0.5, 0.7);
+        let mut table = toml::Table::try_from(palette).unwrap();
+        table.remove("bounded_color");
+        table.insert("obsolete_field".to_owned(), toml::Value::Boolean(true));
+        let v1 = toml::to_string(&table).unwrap();
+        assert!(!v1.contains("version"));
+
+        let loaded = Palette::from_toml(&v1).unwrap();
+        assert_eq!(loaded.bounded_color, None);
+        assert_eq!(loaded, palette);
+
+        let v2 = loaded.to_toml().unwrap();
+        assert!(v2.contains(&format!("version = {PALETTE_VERSION}")));
+        assert_eq!(Palette::from_toml(&v2).unwrap(), palette);
+    }
+
+    #[test]
+    fn tone_gamma()
+    {
+        use crate::tone::ToneAdjustment;
+        use image::{Rgb, RgbImage};
+
+        let identity = ToneAdjustment::default();
+        assert!((0..=255).all(|v| identity.apply_channel(v) == v));
+
+        let mut image = RgbImage::from_fn(16, 16, |x, y| Rgb([(x * 16 + y) as u8, 128, 255]));
+        let original = image.clone();
+        identity.apply(&mut image);
+        assert_eq!(image, original);
+
+        // Gamma 2 darkens midtones, while keeping black and white fixed
+        let gamma = ToneAdjustment::IDENTITY.with_gamma(2.);
+        assert_eq!(gamma.apply_channel(128), 64);
+        assert_eq!(gamma.apply_channel(0), 0);
+        assert_eq!(gamma.apply_channel(255), 255);
+        gamma.apply(&mut image);
+        assert!(image
+            .pixels()
+            .zip(original.pixels())
+            .all(|(p, q)| p[0] <= q[0] && p[1] < q[1] && p[2] == q[2]));
+
+        // Adjustments are clamped to the valid range
+        let bright = ToneAdjustment::IDENTITY
+            .with_brightness(0.5)
+            .with_contrast(2.);
+        assert_eq!(bright.apply_channel(200), 255);
+        assert_eq!(bright.apply_channel(0), 0);
+    }
+
+    #[test]
+    fn period_solid_colors()
+    {
+        use egui::Color32;
+
+        use crate::{algorithms::IncoloringAlgorithm, Palette};
+        use dynamo_common::prelude::{Cplx, PointInfoPeriodic};
+
+        let mut palette = Palette::default();
+        let color_of = |palette: &Palette, period| -> Color32 {
+            let info = PointInfoPeriodic {
+                preperiod: 0,
+                period,
+                multiplier: Cplx::new(0.3, 0.),
+                final_error: 0.,
+            };
+            IncoloringAlgorithm::Period.color_periodic(palette, &info)
+        };
+
+        // Components of different periods are told apart, in the colors of their marked cycles
+        let c1 = color_of(&palette, 1);
+        let c2 = color_of(&palette, 2);
+        assert_ne!(c1, c2);
+        assert_eq!(c1, palette.period_coloring.map::<Color32>(1., 1.));
+
+        // The hues repeat after `num_colors` periods
+        assert_eq!(color_of(&palette, 8), c1);
+        palette.period_coloring.num_colors = 12.;
+        assert_ne!(color_of(&palette, 8), color_of(&palette, 1));
+    }
+
+    #[test]
+    fn gradient_image_samples()
+    {
+        use crate::palette_library::read_gradient;
+        use egui::Color32;
+        use image::{Rgb, RgbImage};
+
+        let colors = [Rgb([255, 0, 0]), Rgb([0, 255, 0]), Rgb([0, 0, 255])];
+        let gradient = RgbImage::from_fn(3, 2, |x, _| colors[x as usize]);
+        let path = std::env::temp_dir().join("dynamo_gradient_palette.png");
+        gradient.save(&path).unwrap();
+
+        let sampled = read_gradient(&path, 5).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(sampled.len(), 5);
+        assert_eq!(sampled[0], Color32::from_rgb(255, 0, 0));
+        assert_eq!(sampled[2], Color32::from_rgb(0, 255, 0));
+        assert_eq!(sampled[4], Color32::from_rgb(0, 0, 255));
+        // Between samples, neighboring pixels are blended
+        assert_eq!(sampled[1], Color32::from_rgb(128, 128, 0));
+    }
+
+    #[test]
+    fn escape_colors_use_absolute_potential()
+    {
+        use crate::prelude::*;
+        use dynamo_common::prelude::*;
+
+        let frame = |potentials: [Real; 3]| {
+            let grid = PointGrid::new(4, 2, Bounds::centered_square(1.));
+            let mut iter_plane = IterPlane::<Cplx>::create(grid);
+            for (x, potential) in potentials.into_iter().enumerate() {
+                iter_plane.iter_counts[(x, 0)] = PointInfo::Escaping {
+                    potential,
+                    phase: None,
+                };
+            }
+            iter_plane
+        };
+
+        let coloring = Coloring::default();
+        let narrow = frame([2., 3., 5.]).write_image(&coloring);
+        let wide = frame([40., 3., 900.]).write_image(&coloring);
+        assert_eq!(narrow.get_pixel(1, 1), wide.get_pixel(1, 1));
+        assert_ne!(narrow.get_pixel(0, 1), wide.get_pixel(0, 1));
+    }
+
+    #[test]
+    fn log_derivative_uses_escape_phase()
+    {
+        use crate::prelude::*;
+        use dynamo_common::prelude::*;
+        use egui::Color32;
+
+        let info = |phase| PointInfo::<Cplx>::LogDerivative {
+            potential: 3.,
+            log_derivative: 40.,
+            phase,
+        };
+        let mut coloring = Coloring::default().with_escape_period(3);
+        let plain: Color32 = coloring.map(&info(Some(1)));
+        assert_eq!(plain, coloring.map::<_, Color32>(&info(None)));
+
+        coloring.toggle_escape_phase_coloring();
+        let level = info(Some(1)).escape_level().unwrap();
+        let by_phase: Color32 = coloring.map(&info(Some(1)));
+        assert_eq!(by_phase, coloring.get_palette().map_phase(level, 1, 3));
+        assert_ne!(by_phase, coloring.map::<_, Color32>(&info(Some(2))));
+    }
+
+    #[test]
+    fn boettcher_checkerboard_tiles()
+    {
+        use crate::prelude::*;
+        use dynamo_common::prelude::*;
+        use egui::Color32;
+
+        let texture = Checkerboard::DEFAULT;
+        let (columns, rows) = (Real::from(texture.columns), Real::from(texture.rows));
+        let center =
+            |column: i64, row: i64| ((row as Real + 0.5) / rows, (column as Real + 0.5) / columns);
+
+        // Moving along an equipotential or a ray, the cells alternate at every step
+        for row in 0..6 {
+            for column in 0..texture.columns as i64 {
+                let (potential, angle) = center(column, row);
+                let (next_potential, next_angle) = center(column + 1, row + 1);
+                assert_eq!(texture.cell(potential, angle), (column, row));
+                assert_ne!(
+                    texture.is_dark(potential, angle),
+                    texture.is_dark(potential, next_angle)
+                );
+                assert_ne!(
+                    texture.is_dark(potential, angle),
+                    texture.is_dark(next_potential, angle)
+                );
+            }
+        }
+
+        // Angles are taken mod 1, and the columns close up around the full turn
+        assert_eq!(texture.cell(0.2, 1.01), texture.cell(0.2, 0.01));
+        assert_eq!(texture.cell(0.2, -0.01), (texture.columns as i64 - 1, 0));
+
+        // Dark cells are shaded versions of the palette color at the same potential
+        let coloring = Coloring::default();
+        let light: Color32 = coloring.map(&PointInfo::<Cplx>::Boettcher {
+            potential: 3.1,
+            angle: 0.01,
+        });
+        let dark: Color32 = coloring.map(&PointInfo::<Cplx>::Boettcher {
+            potential: 3.1,
+            angle: 0.07,
+        });
+        assert!(dark.r() <= light.r() && dark.g() <= light.g() && dark.b() <= light.b());
+        assert_ne!(dark, light);
+    }
+
+    #[test]
+    fn seeded_palette_is_reproducible()
+    {
+        use crate::palette::Palette;
+
+        let seed = 0x5eed_cafe;
+        assert_eq!(
+            Palette::new_seeded(seed, 0.45, 0.38),
+            Palette::new_seeded(seed, 0.45, 0.38)
+        );
+        assert_ne!(
+            Palette::new_seeded(seed, 0.45, 0.38),
+            Palette::new_seeded(seed + 1, 0.45, 0.38)
+        );
+    }
+
+    #[test]
+    fn split_coloring_by_diagonal()
+    {
+        use crate::fractal_image::FractalImage;
+        use crate::{Coloring, IncoloringAlgorithm};
+        use dynamo_common::prelude::*;
+        use image::Rgb;
+
+        let grid = PointGrid::new(32, 32, Bounds::centered_square(2.));
+        let mut plane: IterPlane<Cplx> = IterPlane::create(grid);
+        let info = PointInfo::Periodic(PointInfoPeriodic {
+            preperiod: 5,
+            period: 3,
+            multiplier: Cplx::new(0.3, 0.4),
+            final_error: 1e-12,
+        });
+        plane.iter_counts.fill(info.clone());
+
+        let coloring = Coloring::default()
+            .with_interior_algorithm(IncoloringAlgorithm::Period)
+            .with_split_algorithm(IncoloringAlgorithm::Multiplier);
+        let above: Rgb<u8> = coloring.map(&info);
+        let below: Rgb<u8> = coloring
+            .clone()
+            .with_interior_algorithm(IncoloringAlgorithm::Multiplier)
+            .map(&info);
+        assert_ne!(above, below);
+
+        let image = plane.write_image(&coloring);
+        for (x, y, pixel) in image.enumerate_pixels() {
+            if y < x {
+                assert_eq!(*pixel, above, "({x}, {y})");
+            } else if y > x {
+                assert_eq!(*pixel, below, "({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn render_into_reuses_buffer()
+    {
+        use crate::prelude::*;
+        use dynamo_common::prelude::*;
+        use egui::ColorImage;
+        use image::{ImageBuffer, Rgb};
+
+        let grid = PointGrid::new(6, 4, Bounds::centered_square(1.));
+        let mut iter_plane = IterPlane::<Cplx>::create(grid);
+        for (k, info) in iter_plane.iter_counts.iter_mut().enumerate() {
+            if k % 3 != 0 {
+                *info = PointInfo::Escaping {
+                    potential: 2. + k as Real,
+                    phase: None,
+                };
+            }
+        }
+        let coloring = Coloring::default();
+
+        let mut image: ImageBuffer<Rgb<u8>, Vec<u8>> = ImageBuffer::new(2, 2);
+        iter_plane.write_image_into(&mut image, &coloring);
+        assert_eq!(image, iter_plane.write_image(&coloring));
+        let buffer = image.as_raw().as_ptr();
+        iter_plane.write_image_into(&mut image, &coloring);
+        assert_eq!(image.as_raw().as_ptr(), buffer);
+
+        let mut rgba = ImageBuffer::new(0, 0);
+        iter_plane.write_image_rgba_into(&mut rgba, &coloring);
+        assert_eq!(rgba, iter_plane.write_image_rgba(&coloring));
+
+        let mut color_image = ColorImage::default();
+        iter_plane.render_into(&mut color_image, &coloring);
+        assert_eq!(color_image, iter_plane.render(&coloring));
+        let pixels = color_image.pixels.as_ptr();
+        iter_plane.render_into(&mut color_image, &coloring);
+        assert_eq!(color_image.pixels.as_ptr(), pixels);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::palette_library::read_gradient;
 use crate::types::{FromCartesian, FromPolar, Lchab, RgbLinear, Xyz};
 
 use super::Hsv;
@@ -7,6 +8,7 @@ use dynamo_common::{symbolic_dynamics::OrbitSchema, types::Period};
 use egui::Color32;
 use rand::prelude::*;
 use rand_distr::{ChiSquared, Distribution, Uniform};
+use std::path::Path;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -127,6 +129,11 @@ mod defaults
     {
         Color32::BROWN
     }
+
+    pub(super) const fn magenta() -> Color32
+    {
+        Color32::from_rgb(255, 0, 255)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -140,16 +147,26 @@ pub struct Palette
     pub period_coloring: DiscretePalette,
     #[cfg_attr(feature = "serde", serde(default = "defaults::black"))]
     pub in_color: Color32,
+    /// Color of points whose orbits neither escaped nor were found to be periodic.
+    /// Falls back to `in_color` if unset.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bounded_color: Option<Color32>,
     #[cfg_attr(feature = "serde", serde(default = "defaults::brown"))]
     pub wandering_color: Color32,
     #[cfg_attr(feature = "serde", serde(default = "defaults::gray"))]
     pub unknown_color: Color32,
+    /// Color of pixels where the parameter map is undefined, such as at its poles.
+    #[cfg_attr(feature = "serde", serde(default = "defaults::magenta"))]
+    pub undefined_color: Color32,
     #[cfg_attr(feature = "serde", serde(default = "CartesianColorSpace::default"))]
     pub color_space: CartesianColorSpace,
 }
 
 impl Palette
 {
+    /// Number of colors sampled across a gradient image by [`Palette::from_image`].
+    pub const IMAGE_GRADIENT_COLORS: usize = 16;
+
     #[must_use]
     pub const fn new(period_r: f64, period_g: f64, period_b: f64) -> Self
     {
@@ -159,8 +176,10 @@ impl Palette
             color_map_b: Sinusoid::new(period_b),
             period_coloring: DiscretePalette::standard(),
             in_color: Color32::BLACK,
+            bounded_color: None,
             wandering_color: Color32::BROWN,
             unknown_color: Color32::GRAY,
+            undefined_color: defaults::magenta(),
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -175,8 +194,10 @@ impl Palette
             color_map_b: color_map,
             period_coloring: DiscretePalette::standard(),
             in_color: Color32::BLACK,
+            bounded_color: None,
             wandering_color: Color32::BROWN,
             unknown_color: Color32::GRAY,
+            undefined_color: defaults::magenta(),
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -197,8 +218,10 @@ impl Palette
             color_map_b: color_map,
             period_coloring: DiscretePalette::standard(),
             in_color: Color32::WHITE,
+            bounded_color: None,
             wandering_color: Color32::BROWN,
             unknown_color: Color32::GRAY,
+            undefined_color: defaults::magenta(),
             color_space: CartesianColorSpace::Rgb,
         }
     }
@@ -206,7 +229,22 @@ impl Palette
     #[must_use]
     pub fn new_random(contrast: f64, brightness: f64) -> Self
     {
-        let mut rng = thread_rng();
+        Self::new_seeded(Self::random_seed(), contrast, brightness)
+    }
+
+    /// Seed for [`new_seeded`](Self::new_seeded), drawn from the thread's random generator.
+    #[must_use]
+    pub fn random_seed() -> u64
+    {
+        thread_rng().gen()
+    }
+
+    /// Random palette determined by `seed`, so that a palette found with
+    /// [`new_random`](Self::new_random) can be recovered later.
+    #[must_use]
+    pub fn new_seeded(seed: u64, contrast: f64, brightness: f64) -> Self
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
 
         let phase_r = Uniform::new(0., 1.).sample(&mut rng);
         let phase_g = Uniform::new(0., 1.).sample(&mut rng);
@@ -224,6 +262,53 @@ impl Palette
         })
     }
 
+    /// Approximate a list of colors (e.g. from a GIMP palette) by fitting the
+    /// first Fourier mode of each channel, with one color per unit of potential.
+    #[must_use]
+    pub fn from_colors(colors: &[Color32]) -> Self
+    {
+        if colors.is_empty() {
+            return Self::default();
+        }
+        let n = colors.len() as f64;
+
+        let fit = |channel: fn(&Color32) -> u8| {
+            let (mut mean, mut a, mut b) = (0., 0., 0.);
+            for (j, color) in colors.iter().enumerate() {
+                let v = f64::from(channel(color)) / 255.;
+                let theta = TAU * j as f64 / n;
+                mean += v;
+                a += v * theta.cos();
+                b += v * theta.sin();
+            }
+            mean /= n;
+            a *= 2. / n;
+            b *= 2. / n;
+            Sinusoid {
+                period: n,
+                phase: b.atan2(a) / TAU,
+                amplitude: a.hypot(b).min(mean).min(1. - mean),
+                midline: mean,
+                degree: 1,
+            }
+        };
+
+        Self {
+            color_map_r: fit(Color32::r),
+            color_map_g: fit(Color32::g),
+            color_map_b: fit(Color32::b),
+            ..Self::new(n, n, n)
+        }
+    }
+
+    /// Approximate a gradient image, sampled across its width, in the same way as
+    /// [`Palette::from_colors`].
+    pub fn from_image(path: &Path) -> image::ImageResult<Self>
+    {
+        let colors = read_gradient(path, Self::IMAGE_GRADIENT_COLORS)?;
+        Ok(Self::from_colors(&colors))
+    }
+
     #[must_use]
     pub const fn with_phases(mut self, phase_r: f64, phase_g: f64, phase_b: f64) -> Self
     {
@@ -311,6 +396,12 @@ impl Palette
         }
     }
 
+    #[must_use]
+    pub fn bounded_color(&self) -> Color32
+    {
+        self.bounded_color.unwrap_or(self.in_color)
+    }
+
     pub fn scale_period(&mut self, scale_factor: f64)
     {
         *self.color_map_r.get_period_mut() *= scale_factor;
@@ -0,0 +1,179 @@
+use crate::palette::Palette;
+use egui::Color32;
+use image::RgbImage;
+use std::path::Path;
+
+/// A collection of palettes loaded from disk, with a cursor for quick-switching.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaletteLibrary
+{
+    palettes: Vec<Palette>,
+    index: usize,
+}
+
+impl PaletteLibrary
+{
+    #[must_use]
+    pub const fn new(palettes: Vec<Palette>) -> Self
+    {
+        Self { palettes, index: 0 }
+    }
+
+    /// Scan a directory for palette files (`.toml`, `.gpl` or `.png`), sorted by filename.
+    /// Files that fail to parse are skipped.
+    pub fn load_dir<P>(dir: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut paths: Vec<_> = std::fs::read_dir(dir)?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+        paths.sort();
+
+        let palettes = paths
+            .iter()
+            .filter_map(|path| match Self::load_file(path) {
+                Ok(palette) => palette,
+                Err(e) => {
+                    println!("Error loading palette {}: {e}", path.display());
+                    None
+                }
+            })
+            .collect();
+
+        Ok(Self::new(palettes))
+    }
+
+    /// Load the user's palette directory, or an empty library if it is unavailable.
+    #[must_use]
+    pub fn load_default() -> Self
+    {
+        dynamo_common::directories::palettes_dir()
+            .and_then(|dir| Self::load_dir(dir).ok())
+            .unwrap_or_default()
+    }
+
+    fn load_file(path: &Path) -> Result<Option<Palette>, Box<dyn std::error::Error>>
+    {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "serde")]
+            Some("toml") => {
+                let content = std::fs::read_to_string(path)?;
+                Ok(Some(Palette::from_toml(&content)?))
+            }
+            Some("gpl") => {
+                let content = std::fs::read_to_string(path)?;
+                Ok(Some(Palette::from_colors(&parse_gpl(&content))))
+            }
+            Some("png") => Ok(Some(Palette::from_image(path)?)),
+            _ => Ok(None),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.palettes.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.palettes.is_empty()
+    }
+
+    #[must_use]
+    pub fn current(&self) -> Option<Palette>
+    {
+        self.palettes.get(self.index).copied()
+    }
+
+    pub fn push(&mut self, palette: Palette)
+    {
+        self.palettes.push(palette);
+    }
+
+    /// Advance to the next palette, wrapping around at the end.
+    pub fn next_palette(&mut self) -> Option<Palette>
+    {
+        if self.is_empty() {
+            return None;
+        }
+        self.index = (self.index + 1) % self.len();
+        self.current()
+    }
+
+    /// Step back to the previous palette, wrapping around at the start.
+    pub fn prev_palette(&mut self) -> Option<Palette>
+    {
+        if self.is_empty() {
+            return None;
+        }
+        self.index = (self.index + self.len() - 1) % self.len();
+        self.current()
+    }
+}
+
+/// Parse the colors from a GIMP palette file, ignoring the header and comments.
+#[must_use]
+pub fn parse_gpl(content: &str) -> Vec<Color32>
+{
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut channels = line.split_whitespace().take(3).map(str::parse::<u8>);
+            match (channels.next(), channels.next(), channels.next()) {
+                (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => Some(Color32::from_rgb(r, g, b)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Read a gradient image and sample `num_colors` colors across its width.
+pub fn read_gradient(path: &Path, num_colors: usize) -> image::ImageResult<Vec<Color32>>
+{
+    let image = image::open(path)?.to_rgb8();
+    Ok(sample_gradient(&image, num_colors))
+}
+
+/// Sample `num_colors` evenly spaced colors along the middle row of a gradient image, from the
+/// left edge to the right edge, interpolating linearly between neighboring pixels.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn sample_gradient(image: &RgbImage, num_colors: usize) -> Vec<Color32>
+{
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return vec![];
+    }
+    let row = height / 2;
+    let last = f64::from(width - 1);
+    let step = if num_colors > 1 {
+        last / (num_colors - 1) as f64
+    } else {
+        0.
+    };
+
+    (0..num_colors)
+        .map(|k| {
+            let x = (k as f64 * step).min(last);
+            let x0 = x.floor() as u32;
+            let x1 = (x0 + 1).min(width - 1);
+            let s = x - f64::from(x0);
+            let [r0, g0, b0] = image.get_pixel(x0, row).0;
+            let [r1, g1, b1] = image.get_pixel(x1, row).0;
+            let mix =
+                |a: u8, b: u8| s.mul_add(f64::from(b) - f64::from(a), f64::from(a)).round() as u8;
+            Color32::from_rgb(mix(r0, r1), mix(g0, g1), mix(b0, b1))
+        })
+        .collect()
+}
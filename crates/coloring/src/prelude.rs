@@ -1,4 +1,7 @@
 pub use crate::algorithms::IncoloringAlgorithm;
-pub use crate::fractal_image::FractalImage;
+pub use crate::fractal_image::{AlphaMaskMode, FractalImage};
 pub use crate::palette::Palette;
+pub use crate::palette_library::PaletteLibrary;
+pub use crate::texture::Checkerboard;
+pub use crate::tone::ToneAdjustment;
 pub use crate::Coloring;
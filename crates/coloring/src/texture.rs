@@ -0,0 +1,53 @@
+//! Textures laid out on the escape region in Böttcher coordinates.
+
+use dynamo_common::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A checkerboard in the coordinates $(\theta, s)$ of an escaping point, where $\theta$ is its
+/// external angle as a fraction of a turn and $s$ is its smooth potential.
+///
+/// Cells are bounded by rays and equipotentials: each turn is split into `columns` columns, and
+/// each escape band into `rows` rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Checkerboard
+{
+    pub columns: u32,
+    pub rows: u32,
+}
+
+impl Default for Checkerboard
+{
+    fn default() -> Self
+    {
+        Self::DEFAULT
+    }
+}
+
+impl Checkerboard
+{
+    pub const DEFAULT: Self = Self {
+        columns: 16,
+        rows: 2,
+    };
+
+    /// Column and row of the cell containing the point.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn cell(&self, potential: IterCountSmooth, angle: Real) -> (i64, i64)
+    {
+        let column = (angle.rem_euclid(1.) * Real::from(self.columns)).floor() as i64;
+        let row = (potential * Real::from(self.rows)).floor() as i64;
+        (column, row)
+    }
+
+    /// Whether the point lies in a dark cell.
+    #[must_use]
+    pub fn is_dark(&self, potential: IterCountSmooth, angle: Real) -> bool
+    {
+        let (column, row) = self.cell(potential, angle);
+        (column + row).rem_euclid(2) == 1
+    }
+}
@@ -0,0 +1,119 @@
+use egui::{Color32, ColorImage};
+use image::{ImageBuffer, Pixel};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tonal adjustment applied to every pixel of a rendered image, after coloring.
+///
+/// Each channel value $x \in [0, 1]$ is mapped to
+/// $\text{contrast} \cdot (x^\gamma - \tfrac12) + \tfrac12 + \text{brightness}$,
+/// clamped to $[0, 1]$. Alpha is left unchanged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ToneAdjustment
+{
+    pub gamma: f32,
+    pub brightness: f32,
+    pub contrast: f32,
+}
+
+impl Default for ToneAdjustment
+{
+    fn default() -> Self
+    {
+        Self::IDENTITY
+    }
+}
+
+impl ToneAdjustment
+{
+    pub const IDENTITY: Self = Self {
+        gamma: 1.,
+        brightness: 0.,
+        contrast: 1.,
+    };
+
+    #[must_use]
+    pub const fn with_gamma(mut self, gamma: f32) -> Self
+    {
+        self.gamma = gamma;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_brightness(mut self, brightness: f32) -> Self
+    {
+        self.brightness = brightness;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_contrast(mut self, contrast: f32) -> Self
+    {
+        self.contrast = contrast;
+        self
+    }
+
+    #[must_use]
+    pub fn is_identity(&self) -> bool
+    {
+        *self == Self::IDENTITY
+    }
+
+    /// Adjust a single channel value.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn apply_channel(&self, value: u8) -> u8
+    {
+        let x = f32::from(value) / 255.;
+        let y = self
+            .contrast
+            .mul_add(x.powf(self.gamma) - 0.5, 0.5 + self.brightness)
+            .clamp(0., 1.);
+        (y * 255.).round() as u8
+    }
+
+    /// Adjusted value of every possible channel value.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn lookup_table(&self) -> [u8; 256]
+    {
+        std::array::from_fn(|i| self.apply_channel(i as u8))
+    }
+
+    /// Adjust the color channels of an image in place.
+    pub fn apply<Px>(&self, image: &mut ImageBuffer<Px, Vec<u8>>)
+    where
+        Px: Pixel<Subpixel = u8>,
+    {
+        if self.is_identity() {
+            return;
+        }
+        let table = self.lookup_table();
+        let num_colors = usize::from(Px::CHANNEL_COUNT.min(3));
+        for pixel in image.pixels_mut() {
+            for channel in &mut pixel.channels_mut()[..num_colors] {
+                *channel = table[usize::from(*channel)];
+            }
+        }
+    }
+
+    /// Adjust the color channels of an image shown in the UI in place.
+    pub fn apply_color_image(&self, image: &mut ColorImage)
+    {
+        if self.is_identity() {
+            return;
+        }
+        let table = self.lookup_table();
+        for pixel in &mut image.pixels {
+            let [r, g, b, a] = pixel.to_srgba_unmultiplied();
+            *pixel = Color32::from_rgba_unmultiplied(
+                table[usize::from(r)],
+                table[usize::from(g)],
+                table[usize::from(b)],
+                a,
+            );
+        }
+    }
+}
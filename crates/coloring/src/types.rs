@@ -1,7 +1,7 @@
 use std::f32::consts::TAU;
 
 use egui::Color32;
-use image::{Pixel, Rgb};
+use image::{Pixel, Rgb, Rgba};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -548,6 +548,14 @@ impl FromColor32 for Rgb<u8>
         Self([r, g, b])
     }
 }
+impl FromColor32 for Rgba<u8>
+{
+    #[inline]
+    fn from_color32(color32: Color32) -> Self
+    {
+        Self(color32.to_srgba_unmultiplied())
+    }
+}
 impl FromColor32 for Hsv
 {
     #[inline]
@@ -0,0 +1,104 @@
+//! Versioned TOML file formats, with migrations from older schemas.
+//!
+//! Files carry a top-level `version` key. Files without one are treated as version 1,
+//! which predates the tag. Unknown keys are reported and ignored rather than rejected,
+//! so that files written by newer versions still load where possible.
+
+use serde::{de::DeserializeOwned, Serialize};
+use toml::{Table, Value};
+
+use crate::palette::Palette;
+
+pub type Version = u32;
+
+const VERSION_KEY: &str = "version";
+
+/// Palette file format history:
+/// - v1: initial format, without a version tag;
+/// - v2: adds the version tag and the optional `bounded_color`.
+pub const PALETTE_VERSION: Version = 2;
+
+/// Serialize a value to TOML, tagging it with the given format version.
+pub fn to_versioned_toml<T>(value: &T, version: Version) -> Result<String, toml::ser::Error>
+where
+    T: Serialize,
+{
+    let mut table = Table::try_from(value)?;
+    table.insert(VERSION_KEY.to_owned(), Value::Integer(i64::from(version)));
+    toml::to_string(&table)
+}
+
+/// Deserialize a versioned TOML file, upgrading it to the current schema first.
+///
+/// `migrate` is called once for each version step, with the table and the version it is
+/// being upgraded *from*.
+pub fn from_versioned_toml<T, F>(
+    content: &str,
+    kind: &str,
+    current_version: Version,
+    mut migrate: F,
+) -> Result<T, toml::de::Error>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnMut(&mut Table, Version),
+{
+    let mut table: Table = toml::from_str(content)?;
+
+    let version = table
+        .remove(VERSION_KEY)
+        .and_then(|v| v.as_integer())
+        .and_then(|v| Version::try_from(v).ok())
+        .unwrap_or(1);
+
+    if version > current_version {
+        println!(
+            "Warning: {kind} file has version {version}, newer than supported version \
+             {current_version}; some settings may be lost"
+        );
+    }
+
+    for from_version in version..current_version {
+        migrate(&mut table, from_version);
+    }
+
+    let value: T = Value::Table(table.clone()).try_into()?;
+    warn_unknown_fields(&table, &value, kind);
+    Ok(value)
+}
+
+/// Report top-level keys that did not survive a round trip through `T`.
+fn warn_unknown_fields<T>(table: &Table, value: &T, kind: &str)
+where
+    T: Serialize,
+{
+    let Ok(known) = Table::try_from(value) else {
+        return;
+    };
+    for key in table.keys().filter(|key| !known.contains_key(*key)) {
+        println!("Warning: ignoring unknown field `{key}` in {kind} file");
+    }
+}
+
+impl Palette
+{
+    /// Serialize to TOML, tagged with the current palette format version.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error>
+    {
+        to_versioned_toml(self, PALETTE_VERSION)
+    }
+
+    /// Parse a palette file, migrating older formats to the current one.
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error>
+    {
+        from_versioned_toml(content, "palette", PALETTE_VERSION, migrate_palette)
+    }
+}
+
+fn migrate_palette(_table: &mut Table, from_version: Version)
+{
+    match from_version {
+        // v1 -> v2 only added `bounded_color`, which defaults to unset.
+        1 => {}
+        v => println!("Warning: no migration known from palette version {v}"),
+    }
+}
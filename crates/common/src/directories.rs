@@ -29,3 +29,30 @@ pub fn script_dir() -> Option<PathBuf>
     std::fs::create_dir_all(&scripts_dir).ok()?;
     Some(scripts_dir)
 }
+
+#[must_use]
+pub fn polynomials_dir() -> Option<PathBuf>
+{
+    let proj_dirs = ProjectDirs::from("com", "Zero Ideal", "Dynamo")?;
+    let polynomials_dir = proj_dirs.data_dir().join("polynomials");
+    std::fs::create_dir_all(&polynomials_dir).ok()?;
+    Some(polynomials_dir)
+}
+
+#[must_use]
+pub fn combinatorics_dir() -> Option<PathBuf>
+{
+    let proj_dirs = ProjectDirs::from("com", "Zero Ideal", "Dynamo")?;
+    let combinatorics_dir = proj_dirs.data_dir().join("combinatorics");
+    std::fs::create_dir_all(&combinatorics_dir).ok()?;
+    Some(combinatorics_dir)
+}
+
+#[must_use]
+pub fn config_dir() -> Option<PathBuf>
+{
+    let proj_dirs = ProjectDirs::from("com", "Zero Ideal", "Dynamo")?;
+    let config_dir = proj_dirs.config_dir().to_owned();
+    std::fs::create_dir_all(&config_dir).ok()?;
+    Some(config_dir)
+}
@@ -1,4 +1,5 @@
 use crate::types::Real;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 pub const RAY_DEPTH: u32 = 200;
 pub const RAY_SHARPNESS: u32 = 25;
@@ -9,6 +10,44 @@ pub const NEWTON_MIN_ERR: Real = 1e-12;
 /// Error threshold beyond which a solution is rejected as invalid
 pub const NEWTON_MAX_ERR: Real = 1e-5;
 
+/// Default relative step size for finite-difference derivatives
+pub const DEFAULT_FINITE_DIFF_H: Real = 1e-6;
+static FINITE_DIFF_H: AtomicU64 = AtomicU64::new(DEFAULT_FINITE_DIFF_H.to_bits());
+
+/// Relative step size used wherever derivatives are approximated numerically.
+#[must_use]
+pub fn finite_diff_h() -> Real
+{
+    Real::from_bits(FINITE_DIFF_H.load(Ordering::Relaxed))
+}
+
+/// Change the relative step size for finite-difference derivatives. Smaller steps reduce
+/// truncation error, at the cost of more cancellation error.
+pub fn set_finite_diff_h(h: Real)
+{
+    FINITE_DIFF_H.store(h.to_bits(), Ordering::Relaxed);
+}
+
+/// Cap on the number of render threads, or 0 to use rayon's default.
+static RENDER_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of threads that renders are limited to, if any.
+#[must_use]
+pub fn render_threads() -> Option<usize>
+{
+    match RENDER_THREADS.load(Ordering::Relaxed) {
+        0 => None,
+        n => Some(n),
+    }
+}
+
+/// Limit renders to the given number of threads, or lift the limit with `None`. A single thread
+/// makes renders sequential, which is useful for profiling.
+pub fn set_render_threads(num_threads: Option<usize>)
+{
+    RENDER_THREADS.store(num_threads.unwrap_or(0), Ordering::Relaxed);
+}
+
 pub const DISPLAY_PREC: usize = 12;
 
 pub const IMAGE_HEIGHT: usize = 768;
@@ -1,6 +1,8 @@
-use crate::point_grid::PointGrid;
+use crate::point_grid::{Bounds, PointGrid};
 
+use crate::math_utils::marching_squares::marching_squares;
 use crate::point_info::PointInfo;
+use crate::types::{Cplx, IterCount, Real};
 use ndarray::Array2;
 
 #[cfg(feature = "serde")]
@@ -28,4 +30,287 @@ where
             point_grid,
         }
     }
+
+    /// Nearest-neighbor upscaling of a coarse escape field onto a finer grid with the same bounds.
+    #[must_use]
+    pub fn upscaled(&self, point_grid: PointGrid) -> Self
+    {
+        let (src_x, src_y) = self.point_grid.shape();
+        let (res_x, res_y) = point_grid.shape();
+        let iter_counts = Array2::from_shape_fn(point_grid.shape(), |(x, y)| {
+            let i = (x * src_x / res_x).min(src_x - 1);
+            let j = (y * src_y / res_y).min(src_y - 1);
+            self.iter_counts[(i, j)].clone()
+        });
+        Self {
+            iter_counts,
+            point_grid,
+        }
+    }
+
+    /// Fill a larger grid from a field computed on one period of it, as given by
+    /// [`PointGrid::fundamental_tile`]. Each pixel is translated back into the tile by whole
+    /// periods of `domain`, and takes the value of the nearest tile pixel.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn tiled(&self, point_grid: PointGrid, domain: &Bounds) -> Self
+    {
+        let tile = &self.point_grid;
+        let wrap = |offset: Real, pixel: Real, period: Real, res: usize| {
+            let mut u = offset / pixel;
+            if period.is_finite() {
+                u = u.rem_euclid(period / pixel);
+            }
+            // Rounding up past the last pixel lands next to the start of the following period.
+            let i = u.round().max(0.) as usize;
+            if i >= res {
+                0
+            } else {
+                i
+            }
+        };
+        let iter_counts = Array2::from_shape_fn(point_grid.shape(), |(x, y)| {
+            let z = point_grid.map_pixel(x, y);
+            let i = wrap(
+                z.re - tile.bounds.min_x,
+                tile.pixel_width(),
+                domain.range_x(),
+                tile.res_x,
+            );
+            let j = wrap(
+                z.im - tile.bounds.min_y,
+                tile.pixel_height(),
+                domain.range_y(),
+                tile.res_y,
+            );
+            self.iter_counts[(i, j)].clone()
+        });
+        Self {
+            iter_counts,
+            point_grid,
+        }
+    }
+}
+
+/// Characters for escaping pixels in ASCII renders, from fast to slow escape. A pixel escaping
+/// after $n$ iterations is drawn with the character at index $\lfloor \log_2(n + 1) \rfloor$,
+/// capped at the last one.
+const ASCII_ESCAPE_RAMP: &[u8] = b" .,:;=+*%";
+/// Character for non-escaping pixels in ASCII renders.
+const ASCII_INTERIOR: char = '@';
+/// Character for pixels without an escape count in ASCII renders.
+const ASCII_UNKNOWN: char = '?';
+
+/// Number of boxes along the shorter side of the view at the coarsest box-counting scale.
+const MIN_BOX_COUNT: usize = 4;
+
+impl<D> IterPlane<D>
+{
+    /// Pixels on the boundary of the escape set, i.e. non-escaping pixels with an escaping
+    /// neighbor.
+    #[must_use]
+    pub fn boundary_mask(&self) -> Array2<bool>
+    {
+        let escaping = self.iter_counts.map(PointInfo::is_escaping);
+        let (res_x, res_y) = escaping.dim();
+        Array2::from_shape_fn((res_x, res_y), |(x, y)| {
+            !escaping[(x, y)]
+                && ((x > 0 && escaping[(x - 1, y)])
+                    || (x + 1 < res_x && escaping[(x + 1, y)])
+                    || (y > 0 && escaping[(x, y - 1)])
+                    || (y + 1 < res_y && escaping[(x, y + 1)]))
+        })
+    }
+
+    /// Box-counting estimate of the dimension of the escape set boundary within the view.
+    ///
+    /// Boundary pixels are covered by boxes of side $s = 2^k$ pixels, and the dimension is the
+    /// least-squares slope of $\log N(s)$ against $\log(1/s)$, where $N(s)$ is the number of
+    /// boxes meeting the boundary. Returns NaN if the view contains too little boundary.
+    #[must_use]
+    pub fn boundary_box_dimension(&self) -> Real
+    {
+        let mask = self.boundary_mask();
+        let (res_x, res_y) = mask.dim();
+
+        let mut samples = Vec::new();
+        let mut size = 1;
+        while res_x.min(res_y) / size >= MIN_BOX_COUNT {
+            let mut boxes = Array2::from_elem((res_x.div_ceil(size), res_y.div_ceil(size)), false);
+            for ((x, y), _) in mask.indexed_iter().filter(|&(_, &on_boundary)| on_boundary) {
+                boxes[(x / size, y / size)] = true;
+            }
+            let count = boxes.iter().filter(|&&b| b).count();
+            if count > 0 {
+                samples.push((-(size as Real).ln(), (count as Real).ln()));
+            }
+            size *= 2;
+        }
+
+        if samples.len() < 2 {
+            return Real::NAN;
+        }
+        let n = samples.len() as Real;
+        let mean_x = samples.iter().map(|(x, _)| x).sum::<Real>() / n;
+        let mean_y = samples.iter().map(|(_, y)| y).sum::<Real>() / n;
+        let (cov, var) = samples.iter().fold((0., 0.), |(cov, var), (x, y)| {
+            let dx = x - mean_x;
+            (dx.mul_add(y - mean_y, cov), dx.mul_add(dx, var))
+        });
+        cov / var
+    }
+
+    /// Estimate of the area of the filled Julia set, as the number of non-escaping pixels times
+    /// the area of a pixel. Pixels of unknown fate are not counted.
+    ///
+    /// This is only meaningful if the view contains the whole set; see
+    /// [`Self::contains_filled_set`].
+    #[must_use]
+    pub fn filled_area_estimate(&self) -> Real
+    {
+        let grid = &self.point_grid;
+        let pixel_area = grid.area() / (grid.res_x * grid.res_y) as Real;
+        let count = self
+            .iter_counts
+            .iter()
+            .filter(|info| {
+                !info.is_escaping()
+                    && !matches!(info, PointInfo::Unknown | PointInfo::UndefinedParameter)
+            })
+            .count();
+        count as Real * pixel_area
+    }
+
+    /// Whether every pixel on the edge of the view escapes, so that the non-escaping set lies
+    /// within the view.
+    #[must_use]
+    pub fn contains_filled_set(&self) -> bool
+    {
+        let (res_x, res_y) = self.iter_counts.dim();
+        self.iter_counts
+            .indexed_iter()
+            .filter(|((x, y), _)| *x == 0 || *y == 0 || *x + 1 == res_x || *y + 1 == res_y)
+            .all(|(_, info)| info.is_escaping())
+    }
+
+    /// Smallest and largest finite escape levels in the image, or `None` if no pixel escapes.
+    #[must_use]
+    pub fn escape_level_range(&self) -> Option<(Real, Real)>
+    {
+        self.iter_counts
+            .iter()
+            .filter_map(PointInfo::escape_level)
+            .filter(|level| level.is_finite())
+            .fold(None, |range, level| match range {
+                None => Some((level, level)),
+                Some((lo, hi)) => Some((level.min(lo), level.max(hi))),
+            })
+    }
+
+    /// Integer escape count of each pixel, with non-escaping pixels at infinity. Pixels without
+    /// a known count, such as distance estimates, are NaN.
+    #[must_use]
+    pub fn escape_count_field(&self) -> Array2<Real>
+    {
+        self.iter_counts.map(|info| match info {
+            PointInfo::Escaping { potential, .. }
+            | PointInfo::Boettcher { potential, .. }
+            | PointInfo::LogDerivative { potential, .. } => potential.floor(),
+            PointInfo::DistanceEstimate { .. }
+            | PointInfo::Unknown
+            | PointInfo::UndefinedParameter => Real::NAN,
+            _ => Real::INFINITY,
+        })
+    }
+
+    /// The escape field as text, one line per row of pixels from top to bottom. Escaping pixels
+    /// get denser characters the longer they take to escape, and non-escaping pixels are `@`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_ascii(&self) -> String
+    {
+        let counts = self.escape_count_field();
+        let (res_x, res_y) = counts.dim();
+        let mut text = String::with_capacity((res_x + 1) * res_y);
+        for y in (0..res_y).rev() {
+            for x in 0..res_x {
+                let count = counts[(x, y)];
+                let ch = if count.is_nan() {
+                    ASCII_UNKNOWN
+                } else if count.is_infinite() {
+                    ASCII_INTERIOR
+                } else {
+                    let index = (count.max(0.) + 1.).log2() as usize;
+                    char::from(ASCII_ESCAPE_RAMP[index.min(ASCII_ESCAPE_RAMP.len() - 1)])
+                };
+                text.push(ch);
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Curves on which the escape count steps up to each of `levels`, in plane coordinates.
+    ///
+    /// The isoline for $n$ separates pixels escaping in fewer than $n$ iterations from those
+    /// taking at least $n$, so isolines of higher levels are nested inside those of lower ones.
+    #[must_use]
+    pub fn escape_isolines(&self, levels: &[IterCount]) -> Vec<(IterCount, Vec<Vec<Cplx>>)>
+    {
+        let field = self.escape_count_field();
+        let grid = &self.point_grid;
+        let to_plane = |[x, y]: [Real; 2]| {
+            Cplx::new(
+                x.mul_add(grid.pixel_width(), grid.bounds.min_x),
+                y.mul_add(grid.pixel_height(), grid.bounds.min_y),
+            )
+        };
+        levels
+            .iter()
+            .map(|&n| {
+                let lines = marching_squares(&field, n as Real - 0.5)
+                    .into_iter()
+                    .map(|line| line.into_iter().map(to_plane).collect())
+                    .collect();
+                (n, lines)
+            })
+            .collect()
+    }
+}
+
+/// Levels of a multi-resolution preview, popped from coarsest to finest. Level $k$ is rendered at
+/// $2^{-k}$ times the full resolution, so level 0 is the full render.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MipmapLevels
+{
+    next_level: Option<u32>,
+}
+impl MipmapLevels
+{
+    #[must_use]
+    pub const fn new(num_levels: u32) -> Self
+    {
+        Self {
+            next_level: num_levels.checked_sub(1),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_done(&self) -> bool
+    {
+        self.next_level.is_none()
+    }
+
+    pub fn pop(&mut self) -> Option<u32>
+    {
+        let level = self.next_level?;
+        self.next_level = level.checked_sub(1);
+        Some(level)
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.next_level = None;
+    }
 }
@@ -6,10 +6,12 @@ pub mod globals;
 pub mod iter_plane;
 pub mod macros;
 pub mod math_utils;
+pub mod mobius;
 pub mod point_grid;
 pub mod point_info;
 pub mod prelude;
 pub mod rational_angle;
+pub mod render_cache;
 pub mod symbolic_dynamics;
 pub mod traits;
 pub mod types;
@@ -259,4 +261,432 @@ mod tests
         let s = format!("{it:>13}");
         assert_eq!(s, "    p01101001");
     }
+
+    #[test]
+    fn finite_diff_step_size()
+    {
+        use crate::globals::DEFAULT_FINITE_DIFF_H;
+        use crate::math_utils::numeric_derivative;
+
+        let z = Cplx::new(0.7, 0.3);
+        let error = |h| (numeric_derivative(Cplx::exp, z, h) - z.exp()).norm();
+
+        let err_default = error(DEFAULT_FINITE_DIFF_H);
+        assert!(err_default < 1e-8);
+        assert!(error(1e-2) > 10. * err_default);
+        assert!(error(1e-11) > 10. * err_default);
+    }
+
+    #[test]
+    fn freehand_path()
+    {
+        use crate::point_grid::{Bounds, PointGrid};
+        use crate::types::Cplx;
+
+        let grid = PointGrid::new(
+            200,
+            100,
+            Bounds {
+                min_x: -2.,
+                max_x: 2.,
+                min_y: -1.,
+                max_y: 1.,
+            },
+        );
+        let stroke = [[0., 0.], [100., 50.], [150., 25.], [200., 100.]];
+        let path = grid.map_path(stroke);
+        let expected = [
+            Cplx::new(-2., 1.),
+            Cplx::new(0., 0.),
+            Cplx::new(1., 0.5),
+            Cplx::new(2., -1.),
+        ];
+        assert_eq!(path.len(), expected.len());
+        for (z, w) in path.iter().zip(expected) {
+            assert!((z - w).norm() < 1e-12, "{z} != {w}");
+        }
+    }
+
+    #[test]
+    fn recenter_on_click()
+    {
+        use crate::point_grid::{Bounds, PointGrid};
+
+        let mut grid = PointGrid::new_by_res_y(
+            300,
+            Bounds {
+                min_x: -2.5,
+                max_x: 1.5,
+                min_y: -1.5,
+                max_y: 1.5,
+            },
+        );
+        let point = grid.map_pos([130., 75.]);
+        grid.recenter(point).unwrap();
+        grid.zoom(0.8, point).unwrap();
+        assert!((grid.center() - point).norm() < 1e-12);
+        assert!((grid.range_x() - 3.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn snap_to_pixel()
+    {
+        use crate::point_grid::{Bounds, PointGrid};
+
+        let bounds = Bounds {
+            min_x: -2.,
+            max_x: 2.,
+            min_y: -1.5,
+            max_y: 1.5,
+        };
+        let grid = PointGrid::new(400, 300, bounds);
+        let (w, h) = (grid.pixel_width(), grid.pixel_height());
+
+        for (px, py) in [(0, 0), (17, 42), (250, 123), (399, 299)] {
+            let sample = grid.map_pixel(px, py);
+            for (dx, dy) in [(0.3, -0.2), (-0.45, 0.1), (0., 0.)] {
+                let clicked = sample + Cplx::new(dx * w, dy * h);
+                assert_eq!(grid.snap_to_pixel(clicked), sample);
+            }
+        }
+
+        // A click mapped from the screen snaps to the sample of the pixel it lies in.
+        let clicked = grid.map_pos([17.2, 300. - 42.1]);
+        assert_eq!(grid.snap_to_pixel(clicked), grid.map_pixel(17, 42));
+    }
+
+    #[test]
+    fn circle_boundary_dimension()
+    {
+        use crate::iter_plane::IterPlane;
+        use crate::point_grid::{Bounds, PointGrid};
+        use crate::point_info::PointInfo;
+
+        let grid = PointGrid::new(512, 512, Bounds::centered_square(2.));
+        let mut plane: IterPlane<Cplx> = IterPlane::create(grid.clone());
+        for ((x, y), info) in plane.iter_counts.indexed_iter_mut() {
+            if grid.map_pixel(x, y).norm() > 1. {
+                *info = PointInfo::Escaping {
+                    potential: 1.,
+                    phase: None,
+                };
+            }
+        }
+
+        let dimension = plane.boundary_box_dimension();
+        assert!((dimension - 1.).abs() < 0.05, "{dimension}");
+    }
+
+    #[test]
+    fn escape_isolines_nested()
+    {
+        use crate::iter_plane::IterPlane;
+        use crate::point_grid::{Bounds, PointGrid};
+        use crate::point_info::PointInfo;
+
+        // Escape count decreasing linearly with distance from the origin
+        let grid = PointGrid::new(200, 200, Bounds::centered_square(5.));
+        let mut iter_plane: IterPlane<Cplx> = IterPlane::create(grid.clone());
+        for ((x, y), info) in iter_plane.iter_counts.indexed_iter_mut() {
+            let r = grid.map_pixel(x, y).norm();
+            *info = PointInfo::Escaping {
+                potential: (5. - r).max(0.),
+                phase: None,
+            };
+        }
+
+        let isolines = iter_plane.escape_isolines(&[1, 2, 3, 4]);
+        let radii: Vec<(f64, f64)> = isolines
+            .iter()
+            .map(|(_, lines)| {
+                assert_eq!(lines.len(), 1);
+                let norms = lines[0].iter().map(|z| z.norm());
+                let min = norms.clone().fold(f64::INFINITY, f64::min);
+                let max = norms.fold(0., f64::max);
+                (min, max)
+            })
+            .collect();
+
+        // The n = 1 isoline is outermost, and each level is nested inside the previous one
+        for pair in radii.windows(2) {
+            assert!(pair[1].1 < pair[0].0);
+        }
+    }
+
+    #[test]
+    fn bounds_rust_code_round_trip()
+    {
+        use crate::point_grid::Bounds;
+
+        let bounds = Bounds {
+            min_x: -2.123_456_789_012_345_6,
+            max_x: 0.1 + 0.2,
+            min_y: -1e-17,
+            max_y: 1.5,
+        };
+        let code = bounds.to_rust_code();
+
+        let field = |name: &str| -> f64 {
+            let start = code.find(&format!("{name}: ")).unwrap() + name.len() + 2;
+            let end = start + code[start..].find(',').unwrap();
+            code[start..end].parse().unwrap()
+        };
+        let parsed = Bounds {
+            min_x: field("min_x"),
+            max_x: field("max_x"),
+            min_y: field("min_y"),
+            max_y: field("max_y"),
+        };
+        assert!(code.starts_with("Bounds {"));
+        assert_eq!(parsed, bounds);
+    }
+
+    #[test]
+    fn pixel_offsets_keep_precision()
+    {
+        use crate::point_grid::{Bounds, PointGrid};
+
+        let center = Cplx::new(-1.749_721_5, 0.000_182_3);
+        let pixels = [(0, 0), (17, 42), (250, 123), (399, 299)];
+
+        // Old absolute mapping, measured from the center in units of pixels
+        let absolute_offset = |grid: &PointGrid, (px, py): (usize, usize)| {
+            let re = (px as f64).mul_add(grid.pixel_width(), grid.min_x);
+            let im = (py as f64).mul_add(grid.pixel_height(), grid.min_y);
+            Cplx::new(
+                (re - center.re) / grid.pixel_width(),
+                (im - center.im) / grid.pixel_height(),
+            )
+        };
+
+        let shallow = PointGrid::new(400, 300, Bounds::rect(2., 1.5, center));
+        for pixel in pixels {
+            let absolute = Cplx::new(
+                (pixel.0 as f64).mul_add(shallow.pixel_width(), shallow.min_x),
+                (pixel.1 as f64).mul_add(shallow.pixel_height(), shallow.min_y),
+            );
+            assert!((shallow.map_pixel(pixel.0, pixel.1) - absolute).norm() < 1e-14);
+        }
+
+        let deep = PointGrid::new(400, 300, Bounds::rect(2e-12, 1.5e-12, center));
+        let mut absolute_error: f64 = 0.;
+        for pixel in pixels {
+            let exact = Cplx::new(pixel.0 as f64 - 200., pixel.1 as f64 - 150.);
+            let to_pixels =
+                |z: Cplx| Cplx::new(z.re / deep.pixel_width(), z.im / deep.pixel_height());
+
+            let relative = to_pixels(deep.map_pixel_offset(pixel.0, pixel.1));
+            assert!((relative.re - exact.re).abs() < 1e-9);
+            assert!((relative.im - exact.im).abs() < 1e-9);
+
+            // The full sample point is still within a small fraction of a pixel on both axes
+            let point = to_pixels(deep.map_pixel(pixel.0, pixel.1) - center);
+            assert!((point.re - exact.re).abs() < 0.05, "{point} vs {exact}");
+            assert!((point.im - exact.im).abs() < 0.05, "{point} vs {exact}");
+
+            absolute_error = absolute_error.max((absolute_offset(&deep, pixel) - exact).norm());
+        }
+        assert!(absolute_error > 1e-6, "{absolute_error}");
+    }
+
+    #[test]
+    fn nan_zoom_rejected()
+    {
+        use crate::consts::ZERO;
+        use crate::point_grid::{Bounds, BoundsError, PointGrid};
+        use crate::types::Real;
+
+        let mut grid = PointGrid::new_by_res_y(100, Bounds::centered_square(2.));
+        let before = grid.clone();
+
+        let nan = Cplx::new(Real::NAN, 0.);
+        assert_eq!(grid.zoom(0.5, nan), Err(BoundsError::NonFinite));
+        assert_eq!(grid.translate(nan), Err(BoundsError::NonFinite));
+        assert_eq!(grid.zoom(0., ZERO), Err(BoundsError::Degenerate));
+        assert_eq!(grid, before);
+
+        let mut bounds = Bounds::centered_square(1.);
+        bounds.max_y = Real::INFINITY;
+        assert_eq!(grid.change_bounds(bounds), Err(BoundsError::NonFinite));
+        assert_eq!(grid, before);
+
+        assert!(grid.zoom(0.5, Cplx::new(1., 1.)).is_ok());
+        assert!((grid.range_x() - 2.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn scaled_cplx_beyond_f64()
+    {
+        use crate::math_utils::scaled::ScaledCplx;
+
+        let z = Cplx::new(3e200, -4e200);
+        let w = ScaledCplx::from(z) * ScaledCplx::from(z);
+        assert!(!(z * z).is_finite());
+        assert!((w.norm_sqr_ln() - 4. * (5e200_f64).ln()).abs() < 1e-9);
+        assert!(((w * ScaledCplx::from(z).powi(-2)).to_cplx() - Cplx::new(1., 0.)).norm() < 1e-12);
+        assert!(
+            ((ScaledCplx::from(z) + Cplx::new(1., 0.)).to_cplx() - z).norm() / z.norm() < 1e-15
+        );
+    }
+
+    #[test]
+    fn snap_to_rational_angle()
+    {
+        assert_eq!(
+            RationalAngle::nearest_with_max_denominator(0.333, 3),
+            RationalAngle::new(1, 3)
+        );
+        assert_eq!(
+            RationalAngle::nearest_with_max_denominator(-0.26, 8),
+            RationalAngle::new(3, 4)
+        );
+        assert_eq!(
+            RationalAngle::nearest_with_max_denominator(0.99, 5),
+            RationalAngle::ZERO
+        );
+    }
+
+    #[test]
+    fn mobius_parse_and_invert()
+    {
+        use crate::consts::ONE;
+        use crate::mobius::{Mobius, ParseMobiusError};
+        use crate::point_grid::{Bounds, PointGrid};
+
+        let mobius: Mobius = "2, 1+i, 1, -1".parse().unwrap();
+        assert_eq!(mobius.to_string().parse::<Mobius>(), Ok(mobius));
+        let z = Cplx::new(0.3, -1.7);
+        let w = mobius.apply(z).unwrap();
+        assert!((mobius.inverse().apply(w).unwrap() - z).norm() < 1e-12);
+        assert_eq!(mobius.apply(ONE), None);
+
+        assert_eq!(
+            "1, 2, 2, 4".parse::<Mobius>(),
+            Err(ParseMobiusError::Degenerate)
+        );
+        assert_eq!("1, 0, 0".parse::<Mobius>(), Err(ParseMobiusError::Format));
+
+        let grid =
+            PointGrid::new(16, 16, Bounds::centered_square(2.)).with_view_transform(Some(mobius));
+        let sample = grid.sample_point(3, 5).unwrap();
+        assert_eq!(grid.snap_to_pixel(sample), sample);
+    }
+
+    #[test]
+    fn orbit_schema_from_data()
+    {
+        use crate::symbolic_dynamics::{detect_orbit_schema, OrbitSchema};
+
+        let tail = [Cplx::new(3., 1.), Cplx::new(-2., 0.5), Cplx::new(0.25, 7.)];
+        let cycle = [
+            Cplx::new(1., 0.),
+            Cplx::new(0., 1.),
+            Cplx::new(-1., 0.),
+            Cplx::new(0., -1.),
+        ];
+        let points: Vec<_> = tail
+            .iter()
+            .chain(cycle.iter().cycle().take(40))
+            .copied()
+            .collect();
+        assert_eq!(
+            detect_orbit_schema(&points, 1e-9),
+            Some(OrbitSchema {
+                period: 4,
+                preperiod: 3
+            })
+        );
+        assert_eq!(detect_orbit_schema(&points[..5], 1e-9), None);
+    }
+
+    #[test]
+    fn sample_csv_corners_match_grid()
+    {
+        use crate::consts::ONE;
+        use crate::mobius::Mobius;
+        use crate::point_grid::{Bounds, PointGrid};
+
+        let grid = PointGrid::new(
+            7,
+            5,
+            Bounds {
+                min_x: -2.,
+                max_x: 1.,
+                min_y: -0.75,
+                max_y: 1.25,
+            },
+        );
+        let path = std::env::temp_dir().join("dynamo_sample_csv_corners.csv");
+        grid.write_sample_csv(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let rows: Vec<&str> = contents.lines().collect();
+        assert_eq!(rows[0], "x,y,re,im");
+        assert_eq!(rows.len(), 1 + grid.res_x * grid.res_y);
+
+        let parse = |row: &str| {
+            let fields: Vec<&str> = row.split(',').collect();
+            Cplx::new(fields[2].parse().unwrap(), fields[3].parse().unwrap())
+        };
+        assert_eq!(parse(rows[1]), grid.map_pixel(0, 0));
+        assert_eq!(
+            parse(rows[rows.len() - 1]),
+            grid.map_pixel(grid.res_x - 1, grid.res_y - 1)
+        );
+
+        // Under a view transform, the transformed points are written, and the pole is left empty
+        let inverted = PointGrid::new(4, 4, Bounds::centered_square(1.))
+            .with_view_transform(Some(Mobius::INVERSION));
+        let path = std::env::temp_dir().join("dynamo_sample_csv_inverted.csv");
+        inverted.write_sample_csv(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let rows: Vec<&str> = contents.lines().collect();
+        assert_eq!(rows[1 + 2 * 4 + 2], "2,2,,");
+        assert_eq!(parse(rows[1]), inverted.sample_point(0, 0).unwrap());
+        assert_eq!(parse(rows[1]), ONE / inverted.map_pixel(0, 0));
+    }
+
+    #[test]
+    fn bounds_round_outward_to_nice_values()
+    {
+        use crate::point_grid::Bounds;
+
+        let bounds = Bounds {
+            min_x: -2.013,
+            max_x: 0.497,
+            min_y: -1.2537,
+            max_y: 1.2562,
+        };
+        let nice = bounds.round_to_nice();
+        assert_eq!(
+            nice,
+            Bounds {
+                min_x: -2.1,
+                max_x: 0.5,
+                min_y: -1.3,
+                max_y: 1.3,
+            }
+        );
+        assert!(nice.min_x <= bounds.min_x && nice.max_x >= bounds.max_x);
+        assert!(nice.min_y <= bounds.min_y && nice.max_y >= bounds.max_y);
+
+        let small = Bounds {
+            min_x: 0.250_13,
+            max_x: 0.253_98,
+            min_y: -0.001_21,
+            max_y: 0.002_05,
+        };
+        assert_eq!(
+            small.round_to_nice(),
+            Bounds {
+                min_x: 0.2501,
+                max_x: 0.254,
+                min_y: -0.0013,
+                max_y: 0.0021,
+            }
+        );
+    }
 }
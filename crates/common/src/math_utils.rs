@@ -12,10 +12,43 @@ use std::f64::consts::PI;
 // pub mod erf;
 pub mod arithmetic;
 pub mod contour;
+pub mod marching_squares;
 pub mod newton;
 pub mod polynomial_roots;
+pub mod scaled;
 pub mod taylor;
 
+/// Step size for finite differences at `z`: the relative step `h`, scaled by $\max(1, |z|)$.
+#[must_use]
+pub fn finite_diff_step(z: Cplx, h: Real) -> Real
+{
+    h * z.norm().max(1.)
+}
+
+/// Central difference approximation to the derivative of a holomorphic function.
+#[must_use]
+pub fn numeric_derivative<F>(f: F, z: Cplx, h: Real) -> Cplx
+where
+    F: Fn(Cplx) -> Cplx,
+{
+    let step = finite_diff_step(z, h);
+    (f(z + step) - f(z - step)) / (2. * step)
+}
+
+/// Mean of a sequence of points, computed in one pass so that the points need not be
+/// collected. Returns `None` for an empty sequence.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn centroid<I>(points: I) -> Option<Cplx>
+where
+    I: IntoIterator<Item = Cplx>,
+{
+    let (sum, count) = points
+        .into_iter()
+        .fold((ZERO, 0_usize), |(sum, count), z| (sum + z, count + 1));
+    (count > 0).then(|| sum / count as Real)
+}
+
 #[must_use]
 pub fn weierstrass_p(g2: Cplx, g3: Cplx, z: Cplx, tolerance: Real) -> (Cplx, Cplx)
 {
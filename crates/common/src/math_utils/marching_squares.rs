@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use ndarray::Array2;
+
+use crate::types::Real;
+
+/// Edge of the sampling grid, identified by its lower-left sample and its direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Edge
+{
+    /// Between $(i, j)$ and $(i + 1, j)$
+    Horizontal(usize, usize),
+    /// Between $(i, j)$ and $(i, j + 1)$
+    Vertical(usize, usize),
+}
+
+/// Segments crossing each cell, by which of its corners are inside. Corners are numbered
+/// counterclockwise from $(i, j)$, and edges counterclockwise from the bottom. Saddles (cases 5
+/// and 10) are listed with the corners inside disconnected, and are flipped if the cell center
+/// is inside.
+const SEGMENTS: [&[(usize, usize)]; 16] = [
+    &[],
+    &[(3, 0)],
+    &[(0, 1)],
+    &[(3, 1)],
+    &[(1, 2)],
+    &[(3, 0), (1, 2)],
+    &[(0, 2)],
+    &[(3, 2)],
+    &[(2, 3)],
+    &[(0, 2)],
+    &[(0, 1), (2, 3)],
+    &[(1, 2)],
+    &[(1, 3)],
+    &[(0, 1)],
+    &[(3, 0)],
+    &[],
+];
+
+/// Extract the level set $f = \text{level}$ of a sampled field as polylines, in fractional
+/// index coordinates. A sample is inside if its value is at least `level`; crossings are placed
+/// by linear interpolation, or halfway along an edge with an infinite endpoint. Cells with a NaN
+/// corner are skipped. Closed curves repeat their first point at the end.
+#[must_use]
+pub fn marching_squares(field: &Array2<Real>, level: Real) -> Vec<Vec<[Real; 2]>>
+{
+    let (res_x, res_y) = field.dim();
+    let mut positions = HashMap::new();
+    let mut neighbors: HashMap<Edge, Vec<Edge>> = HashMap::new();
+
+    let mut crossing = |edge: Edge| {
+        let ((i0, j0), (i1, j1)) = match edge {
+            Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+            Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+        };
+        positions.entry(edge).or_insert_with(|| {
+            let (v0, v1) = (field[(i0, j0)], field[(i1, j1)]);
+            let t = (level - v0) / (v1 - v0);
+            let t = if t.is_finite() { t.clamp(0., 1.) } else { 0.5 };
+            [
+                (i1 as Real - i0 as Real).mul_add(t, i0 as Real),
+                (j1 as Real - j0 as Real).mul_add(t, j0 as Real),
+            ]
+        });
+        edge
+    };
+
+    for i in 0..res_x.saturating_sub(1) {
+        for j in 0..res_y.saturating_sub(1) {
+            let corners = [
+                field[(i, j)],
+                field[(i + 1, j)],
+                field[(i + 1, j + 1)],
+                field[(i, j + 1)],
+            ];
+            if corners.iter().any(|v| v.is_nan()) {
+                continue;
+            }
+
+            let case = corners
+                .iter()
+                .enumerate()
+                .filter(|&(_, &v)| v >= level)
+                .fold(0, |case, (k, _)| case | (1 << k));
+
+            let edges = [
+                Edge::Horizontal(i, j),
+                Edge::Vertical(i + 1, j),
+                Edge::Horizontal(i, j + 1),
+                Edge::Vertical(i, j),
+            ];
+
+            let center = corners.iter().sum::<Real>() / 4.;
+            let flip = (case == 5 || case == 10) && center >= level;
+            let segments = if flip {
+                SEGMENTS[15 - case]
+            } else {
+                SEGMENTS[case]
+            };
+
+            for &(a, b) in segments {
+                let (p, q) = (crossing(edges[a]), crossing(edges[b]));
+                neighbors.entry(p).or_default().push(q);
+                neighbors.entry(q).or_default().push(p);
+            }
+        }
+    }
+
+    // Start with the ends of open curves, so that they are traced from one end to the other
+    let mut starts: Vec<_> = neighbors.keys().copied().collect();
+    starts.sort_by_key(|edge| (neighbors[edge].len(), *edge));
+
+    let mut visited = HashSet::new();
+    let mut lines = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut line = vec![start];
+        let mut current = start;
+        while let Some(&next) = neighbors[&current].iter().find(|e| !visited.contains(*e)) {
+            visited.insert(next);
+            line.push(next);
+            current = next;
+        }
+        if line.len() > 2 && neighbors[&current].contains(&start) {
+            line.push(start);
+        }
+        lines.push(line.into_iter().map(|edge| positions[&edge]).collect());
+    }
+    lines
+}
@@ -130,10 +130,28 @@ where
 ///
 /// Returns the approximate solution, together with the value and derivative of the function there.
 pub fn find_target_newton_err_d<T, F>(
+    f_and_df: F,
+    start: T,
+    target: T,
+    error: Real,
+) -> NewtonResult<(T, T, T)>
+where
+    F: FnMut(T) -> (T, T),
+    T: Div<Output = T> + Sub<Output = T> + AddAssign + Dist<Real> + MaybeNan + Copy,
+{
+    find_target_newton_iters_d(f_and_df, start, target, error, NEWTON_MAX_ITERS)
+}
+
+/// Apply Newton's method until we obtain a value within `error` of `target`,
+/// giving up after `max_iters`.
+///
+/// Returns the approximate solution, together with the value and derivative of the function there.
+pub fn find_target_newton_iters_d<T, F>(
     mut f_and_df: F,
     start: T,
     target: T,
     error: Real,
+    max_iters: usize,
 ) -> NewtonResult<(T, T, T)>
 where
     F: FnMut(T) -> (T, T),
@@ -144,7 +162,7 @@ where
     let mut f = start;
     let mut df = start;
 
-    for _ in 0..NEWTON_MAX_ITERS {
+    for _ in 0..max_iters {
         z_old = z;
         (f, df) = f_and_df(z);
         z += (target - f) / df;
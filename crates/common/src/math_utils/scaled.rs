@@ -0,0 +1,194 @@
+//! Complex numbers with an extended exponent range, for iterating past the range of `f64`.
+
+use crate::types::{Cplx, Real};
+use std::f64::consts::LN_2;
+use std::ops::{Add, Mul};
+
+/// Largest power of two applied to a mantissa in a single step, safely inside the range of `f64`.
+const MAX_STEP_EXPONENT: i64 = 1000;
+
+/// A complex number $m \cdot 2^e$, with the mantissa $m$ kept near unit size and the exponent $e$
+/// tracked separately, so that magnitudes far beyond `f64::MAX` remain representable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaledCplx
+{
+    mantissa: Cplx,
+    exponent: i64,
+}
+
+/// Multiply `z` by $2^k$, in steps that neither overflow nor underflow prematurely.
+fn scale_by_pow2(mut z: Cplx, mut k: i64) -> Cplx
+{
+    while k != 0 {
+        let step = k.clamp(-MAX_STEP_EXPONENT, MAX_STEP_EXPONENT);
+        #[allow(clippy::cast_possible_truncation)]
+        let factor = Real::powi(2., step as i32);
+        z *= factor;
+        k -= step;
+        if z == Cplx::new(0., 0.) || !z.is_finite() {
+            break;
+        }
+    }
+    z
+}
+
+impl ScaledCplx
+{
+    #[must_use]
+    pub const fn mantissa(&self) -> Cplx
+    {
+        self.mantissa
+    }
+
+    #[must_use]
+    pub const fn exponent(&self) -> i64
+    {
+        self.exponent
+    }
+
+    /// Move powers of two from the mantissa into the exponent, so that the larger component of
+    /// the mantissa lies in $[1, 2)$.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    fn normalized(self) -> Self
+    {
+        let size = self.mantissa.re.abs().max(self.mantissa.im.abs());
+        if size == 0. || !size.is_finite() {
+            return self;
+        }
+        let shift = size.log2().floor() as i64;
+        Self {
+            mantissa: scale_by_pow2(self.mantissa, -shift),
+            exponent: self.exponent + shift,
+        }
+    }
+
+    /// $\log |z|^2$, which is finite even where $|z|^2$ itself would overflow.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn norm_sqr_ln(&self) -> Real
+    {
+        (2. * self.exponent as Real).mul_add(LN_2, self.mantissa.norm_sqr().ln())
+    }
+
+    /// Argument of $z$.
+    #[must_use]
+    pub fn arg(&self) -> Real
+    {
+        self.mantissa.arg()
+    }
+
+    /// Value as an ordinary complex number, which is infinite if out of range.
+    #[must_use]
+    pub fn to_cplx(&self) -> Cplx
+    {
+        scale_by_pow2(self.mantissa, self.exponent)
+    }
+
+    #[must_use]
+    pub fn powi(&self, n: i32) -> Self
+    {
+        let mut result = Self::from(Cplx::new(1., 0.));
+        let mut base = *self;
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        if n < 0 {
+            result.inv()
+        } else {
+            result
+        }
+    }
+
+    #[must_use]
+    pub fn inv(&self) -> Self
+    {
+        Self {
+            mantissa: self.mantissa.inv(),
+            exponent: -self.exponent,
+        }
+        .normalized()
+    }
+}
+
+impl From<Cplx> for ScaledCplx
+{
+    fn from(z: Cplx) -> Self
+    {
+        Self {
+            mantissa: z,
+            exponent: 0,
+        }
+        .normalized()
+    }
+}
+
+impl Mul for ScaledCplx
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Self {
+            mantissa: self.mantissa * rhs.mantissa,
+            exponent: self.exponent + rhs.exponent,
+        }
+        .normalized()
+    }
+}
+
+impl Mul<Cplx> for ScaledCplx
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Cplx) -> Self
+    {
+        self * Self::from(rhs)
+    }
+}
+
+impl Mul<Real> for ScaledCplx
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Real) -> Self
+    {
+        self * Self::from(Cplx::new(rhs, 0.))
+    }
+}
+
+impl Add for ScaledCplx
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        let (big, small) = if self.mantissa == Cplx::new(0., 0.) {
+            (rhs, self)
+        } else if rhs.mantissa == Cplx::new(0., 0.) || self.exponent >= rhs.exponent {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        Self {
+            mantissa: big.mantissa + scale_by_pow2(small.mantissa, small.exponent - big.exponent),
+            exponent: big.exponent,
+        }
+        .normalized()
+    }
+}
+
+impl Add<Cplx> for ScaledCplx
+{
+    type Output = Self;
+
+    fn add(self, rhs: Cplx) -> Self
+    {
+        self + Self::from(rhs)
+    }
+}
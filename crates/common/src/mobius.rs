@@ -0,0 +1,119 @@
+use crate::consts::{ONE, ZERO};
+use crate::types::Cplx;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The Möbius transformation $z \mapsto \frac{az + b}{cz + d}$.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mobius
+{
+    pub a: Cplx,
+    pub b: Cplx,
+    pub c: Cplx,
+    pub d: Cplx,
+}
+
+impl Mobius
+{
+    pub const IDENTITY: Self = Self::new(ONE, ZERO, ZERO, ONE);
+
+    /// The inversion $z \mapsto 1/z$, which swaps $0$ and $\infty$.
+    pub const INVERSION: Self = Self::new(ZERO, ONE, ONE, ZERO);
+
+    #[must_use]
+    pub const fn new(a: Cplx, b: Cplx, c: Cplx, d: Cplx) -> Self
+    {
+        Self { a, b, c, d }
+    }
+
+    #[must_use]
+    pub fn det(&self) -> Cplx
+    {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// A transformation with $ad - bc = 0$ is constant wherever it is defined.
+    #[must_use]
+    pub fn is_degenerate(&self) -> bool
+    {
+        self.det() == ZERO
+    }
+
+    /// Image of `z`, or `None` at the pole $z = -d/c$, which is sent to infinity.
+    #[must_use]
+    pub fn apply(&self, z: Cplx) -> Option<Cplx>
+    {
+        let w = (self.a * z + self.b) / (self.c * z + self.d);
+        w.is_finite().then_some(w)
+    }
+
+    /// The inverse transformation $w \mapsto \frac{dw - b}{-cw + a}$.
+    #[must_use]
+    pub fn inverse(&self) -> Self
+    {
+        Self::new(self.d, -self.b, -self.c, self.a)
+    }
+}
+
+impl Default for Mobius
+{
+    fn default() -> Self
+    {
+        Self::IDENTITY
+    }
+}
+
+impl std::fmt::Display for Mobius
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}, {}, {}, {}", self.a, self.b, self.c, self.d)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseMobiusError
+{
+    /// The input did not consist of exactly four comma-separated complex numbers.
+    Format,
+    /// The coefficients satisfy $ad - bc = 0$.
+    Degenerate,
+}
+
+impl std::fmt::Display for ParseMobiusError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Format => write!(f, "expected four complex coefficients <a, b, c, d>"),
+            Self::Degenerate => write!(f, "coefficients satisfy ad - bc = 0"),
+        }
+    }
+}
+
+impl std::error::Error for ParseMobiusError {}
+
+/// Parses the coefficients in the form `a, b, c, d`, as printed by `Display`.
+impl FromStr for Mobius
+{
+    type Err = ParseMobiusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let coeffs = s
+            .split(',')
+            .map(|x| x.trim().parse::<Cplx>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParseMobiusError::Format)?;
+        let [a, b, c, d] = coeffs[..] else {
+            return Err(ParseMobiusError::Format);
+        };
+        let mobius = Self::new(a, b, c, d);
+        if mobius.is_degenerate() {
+            return Err(ParseMobiusError::Degenerate);
+        }
+        Ok(mobius)
+    }
+}
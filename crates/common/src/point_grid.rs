@@ -1,9 +1,41 @@
+use crate::mobius::Mobius;
 use crate::types::{Cplx, Real};
 use ndarray::Array2;
 use rayon::iter::{IterBridge, ParallelBridge};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::ops::{Deref, DerefMut};
+use std::path::Path;
+
+/// Grids with more points than this are reported before their samples are exported.
+pub const LARGE_GRID_POINTS: usize = 1 << 22;
+
+/// Reason for rejecting a change of view that would leave the bounds unusable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundsError
+{
+    /// Some coordinate is NaN or infinite.
+    NonFinite,
+    /// The bounds have zero or negative width or height.
+    Degenerate,
+}
+
+impl std::fmt::Display for BoundsError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::NonFinite => write!(f, "bounds would not be finite"),
+            Self::Degenerate => write!(f, "bounds would have no area"),
+        }
+    }
+}
+
+impl std::error::Error for BoundsError {}
+
+pub type BoundsResult = Result<(), BoundsError>;
 
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -58,22 +90,58 @@ impl Bounds
         (self.max_y + self.min_y) / 2.
     }
 
-    pub fn translate(&mut self, translation: Cplx)
+    /// Shift the bounds by `translation`, leaving them unchanged if the result would be invalid.
+    pub fn translate(&mut self, translation: Cplx) -> BoundsResult
+    {
+        self.replace_with(Self {
+            min_x: self.min_x + translation.re,
+            max_x: self.max_x + translation.re,
+            min_y: self.min_y + translation.im,
+            max_y: self.max_y + translation.im,
+        })
+    }
+
+    /// Scale the bounds about `base_point`, leaving them unchanged if the result would be
+    /// invalid.
+    pub fn zoom(&mut self, scale: Real, base_point: Cplx) -> BoundsResult
     {
-        self.min_x += translation.re;
-        self.max_x += translation.re;
-        self.min_y += translation.im;
-        self.max_y += translation.im;
+        let scale_x = |x: Real| (x - base_point.re) * scale + base_point.re;
+        let scale_y = |y: Real| (y - base_point.im) * scale + base_point.im;
+        self.replace_with(Self {
+            min_x: scale_x(self.min_x),
+            max_x: scale_x(self.max_x),
+            min_y: scale_y(self.min_y),
+            max_y: scale_y(self.max_y),
+        })
     }
 
-    pub fn zoom(&mut self, scale: Real, base_point: Cplx)
+    /// Whether every coordinate is finite.
+    #[must_use]
+    pub fn is_finite(&self) -> bool
     {
-        self.translate(-base_point);
-        self.min_x *= scale;
-        self.max_x *= scale;
-        self.min_y *= scale;
-        self.max_y *= scale;
-        self.translate(base_point);
+        self.min_x.is_finite()
+            && self.max_x.is_finite()
+            && self.min_y.is_finite()
+            && self.max_y.is_finite()
+    }
+
+    /// Check that the bounds are finite and enclose a positive area.
+    pub fn validate(&self) -> BoundsResult
+    {
+        if !self.is_finite() || !self.range_x().is_finite() || !self.range_y().is_finite() {
+            return Err(BoundsError::NonFinite);
+        }
+        if self.range_x() <= 0. || self.range_y() <= 0. {
+            return Err(BoundsError::Degenerate);
+        }
+        Ok(())
+    }
+
+    fn replace_with(&mut self, new_bounds: Self) -> BoundsResult
+    {
+        new_bounds.validate()?;
+        *self = new_bounds;
+        Ok(())
     }
 
     #[inline]
@@ -86,10 +154,10 @@ impl Bounds
     }
 
     #[inline]
-    pub fn recenter(&mut self, new_center: Cplx)
+    pub fn recenter(&mut self, new_center: Cplx) -> BoundsResult
     {
         let old_center = self.center();
-        self.translate(new_center - old_center);
+        self.translate(new_center - old_center)
     }
 
     #[must_use]
@@ -126,6 +194,44 @@ impl Bounds
     {
         self.min_x.is_nan() || self.max_x.is_nan() || self.min_y.is_nan() || self.max_y.is_nan()
     }
+
+    /// Expand each edge outward to a round number. The grid spacing is a tenth of the largest
+    /// power of ten not exceeding the range along that axis, so that e.g. $[-2.013, 0.497]$
+    /// becomes $[-2.1, 0.5]$.
+    #[must_use]
+    pub fn round_to_nice(&self) -> Self
+    {
+        // Dividing by an exact power of ten keeps decimal steps such as 0.01 from drifting.
+        let round_out = |min: Real, max: Real| {
+            let exponent = (max - min).log10().floor() as i32 - 1;
+            if exponent < 0 {
+                let scale = Real::powi(10., -exponent);
+                ((min * scale).floor() / scale, (max * scale).ceil() / scale)
+            } else {
+                let step = Real::powi(10., exponent);
+                ((min / step).floor() * step, (max / step).ceil() * step)
+            }
+        };
+        let (min_x, max_x) = round_out(self.min_x, self.max_x);
+        let (min_y, max_y) = round_out(self.min_y, self.max_y);
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    /// Rust expression constructing these bounds. Coordinates are written in full precision, so
+    /// the expression evaluates to exactly the same bounds.
+    #[must_use]
+    pub fn to_rust_code(&self) -> String
+    {
+        format!(
+            "Bounds {{\n    min_x: {:?},\n    max_x: {:?},\n    min_y: {:?},\n    max_y: {:?},\n}}",
+            self.min_x, self.max_x, self.min_y, self.max_y
+        )
+    }
 }
 
 impl Default for Bounds
@@ -148,6 +254,10 @@ pub struct PointGrid
     pub res_x: usize,
     pub res_y: usize,
     pub bounds: Bounds,
+    /// Change of coordinates applied to each point of the view before it is iterated. Points on
+    /// screen are in the coordinates of `bounds`, and their images are the sample points.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub view_transform: Option<Mobius>,
 }
 
 impl PointGrid
@@ -159,9 +269,17 @@ impl PointGrid
             res_x,
             res_y,
             bounds,
+            view_transform: None,
         }
     }
 
+    #[must_use]
+    pub const fn with_view_transform(mut self, view_transform: Option<Mobius>) -> Self
+    {
+        self.view_transform = view_transform;
+        self
+    }
+
     #[must_use]
     #[allow(clippy::similar_names)]
     #[allow(clippy::cast_sign_loss)]
@@ -244,20 +362,132 @@ impl PointGrid
         Self::new_by_res_y(res_y, self.bounds)
     }
 
+    /// Grid with the same bounds at `1/factor` of the resolution, for coarse previews.
+    #[must_use]
+    pub fn downscaled(&self, factor: usize) -> Self
+    {
+        Self::new(
+            (self.res_x / factor).max(1),
+            (self.res_y / factor).max(1),
+            self.bounds.clone(),
+        )
+        .with_view_transform(self.view_transform)
+    }
+
+    /// Sample point of a pixel, as the center of the bounds plus the offset of the pixel from
+    /// it. Pixel $(0, 0)$ is at the lower-left corner `(min_x, min_y)`.
     #[must_use]
     pub fn map_pixel(&self, pixel_x: usize, pixel_y: usize) -> Cplx
     {
-        let re = (pixel_x as Real).mul_add(self.pixel_width(), self.bounds.min_x);
-        let im = (pixel_y as Real).mul_add(self.pixel_height(), self.bounds.min_y);
+        self.center() + self.map_pixel_offset(pixel_x, pixel_y)
+    }
+
+    /// Point to iterate for a pixel: the image of [`Self::map_pixel`] under the view transform,
+    /// or `None` if the pixel lies on its pole.
+    #[must_use]
+    pub fn sample_point(&self, pixel_x: usize, pixel_y: usize) -> Option<Cplx>
+    {
+        self.transform_point(self.map_pixel(pixel_x, pixel_y))
+    }
+
+    /// Image of a point of the view under the view transform.
+    #[must_use]
+    pub fn transform_point(&self, z: Cplx) -> Option<Cplx>
+    {
+        self.view_transform.map_or(Some(z), |t| t.apply(z))
+    }
+
+    /// Point of the view whose image under the view transform is `w`, or `None` if `w` is the
+    /// image of infinity.
+    #[must_use]
+    pub fn untransform_point(&self, w: Cplx) -> Option<Cplx>
+    {
+        self.view_transform
+            .map_or(Some(w), |t| t.inverse().apply(w))
+    }
+
+    /// Offset of the sample point of a pixel from the center of the bounds.
+    ///
+    /// This only involves numbers on the scale of the view, so unlike subtracting the center
+    /// from [`Self::map_pixel`], it keeps full relative precision when zoomed in far from the
+    /// origin.
+    #[must_use]
+    pub fn map_pixel_offset(&self, pixel_x: usize, pixel_y: usize) -> Cplx
+    {
+        let re = (pixel_x as Real - self.res_x as Real / 2.) * self.pixel_width();
+        let im = (pixel_y as Real - self.res_y as Real / 2.) * self.pixel_height();
         Cplx::new(re, im)
     }
 
+    /// Point under a position on screen, after the view transform. At the pole of the
+    /// transform, this is the untransformed point.
     #[must_use]
     pub fn map_pos(&self, pos: [f32; 2]) -> Cplx
     {
         let re = f64::from(pos[0]).mul_add(self.pixel_width(), self.bounds.min_x);
         let im = f64::from(pos[1]).mul_add(-self.pixel_height(), self.bounds.max_y);
-        Cplx::new(re, im)
+        let z = Cplx::new(re, im);
+        self.transform_point(z).unwrap_or(z)
+    }
+
+    /// Grid covering a single period of the view, for planes that are invariant under
+    /// translation by the width and height of `domain`. The tile starts at the same corner and
+    /// has the same pixel size as the view. Returns `None` if the view fits in one period, or
+    /// if a view transform breaks the periodicity.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn fundamental_tile(&self, domain: &Bounds) -> Option<Self>
+    {
+        if self.view_transform.is_some() {
+            return None;
+        }
+        let tile_res = |res: usize, range: Real, period: Real| {
+            if period.is_finite() && period < range {
+                ((period / range * res as Real).ceil() as usize).clamp(1, res)
+            } else {
+                res
+            }
+        };
+        let res_x = tile_res(self.res_x, self.bounds.range_x(), domain.range_x());
+        let res_y = tile_res(self.res_y, self.bounds.range_y(), domain.range_y());
+
+        if (res_x, res_y) == self.shape() {
+            return None;
+        }
+
+        let bounds = Bounds {
+            min_x: self.bounds.min_x,
+            max_x: (res_x as Real).mul_add(self.pixel_width(), self.bounds.min_x),
+            min_y: self.bounds.min_y,
+            max_y: (res_y as Real).mul_add(self.pixel_height(), self.bounds.min_y),
+        };
+        Some(Self::new(res_x, res_y, bounds))
+    }
+
+    /// Snap a point to the nearest sample point of the grid, as given by `sample_point`.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    pub fn snap_to_pixel(&self, z: Cplx) -> Cplx
+    {
+        let Some(w) = self.untransform_point(z) else {
+            return z;
+        };
+        let x = ((w.re - self.bounds.min_x) / self.pixel_width())
+            .round()
+            .clamp(0., (self.res_x - 1) as Real);
+        let y = ((w.im - self.bounds.min_y) / self.pixel_height())
+            .round()
+            .clamp(0., (self.res_y - 1) as Real);
+        self.sample_point(x as usize, y as usize).unwrap_or(z)
+    }
+
+    /// Map a sequence of pixel positions, such as a mouse drag, to a polyline in the plane.
+    #[must_use]
+    pub fn map_path<I>(&self, positions: I) -> Vec<Cplx>
+    where
+        I: IntoIterator<Item = [f32; 2]>,
+    {
+        positions.into_iter().map(|pos| self.map_pos(pos)).collect()
     }
 
     #[must_use]
@@ -289,9 +519,14 @@ impl PointGrid
         (self.res_x, self.res_y)
     }
 
+    /// Position on screen of a point, undoing the view transform. The image of infinity has no
+    /// position, and is placed at NaN.
     #[must_use]
     pub fn locate_point(&self, z: Cplx) -> [f32; 2]
     {
+        let Some(z) = self.untransform_point(z) else {
+            return [f32::NAN; 2];
+        };
         let x = (z.re - self.bounds.min_x) / (self.pixel_width());
         let y = (z.im - self.bounds.min_y) / (self.pixel_height());
 
@@ -302,10 +537,11 @@ impl PointGrid
     #[allow(clippy::cast_sign_loss)]
     pub fn locate_point_safe(&self, z: Cplx) -> Option<(usize, usize)>
     {
+        let z = self.untransform_point(z)?;
         if z.re >= self.bounds.max_x
             || z.re < self.bounds.min_x
             || z.im >= self.bounds.max_y
-            || z.re < self.bounds.min_y
+            || z.im < self.bounds.min_y
         {
             return None;
         }
@@ -325,16 +561,20 @@ impl PointGrid
         Cplx::new(re, im)
     }
 
-    pub fn recenter(&mut self, new_center: Cplx)
+    pub fn recenter(&mut self, new_center: Cplx) -> BoundsResult
     {
         let old_center = self.center();
-        self.translate(new_center - old_center);
+        self.translate(new_center - old_center)
     }
 
-    pub fn change_bounds(&mut self, new_bounds: Bounds)
+    /// Replace the bounds, keeping the width in pixels. Invalid bounds are rejected and leave
+    /// the grid unchanged.
+    pub fn change_bounds(&mut self, new_bounds: Bounds) -> BoundsResult
     {
+        new_bounds.validate()?;
         self.res_y = Self::infer_height(self.res_x, &new_bounds);
         self.bounds = new_bounds;
+        Ok(())
     }
 
     #[inline]
@@ -355,16 +595,31 @@ impl PointGrid
     pub fn to_array(&self) -> Array2<Cplx>
     {
         let mut points = Array2::zeros((self.res_x, self.res_y));
-        let pixel_width = self.pixel_width();
-        let pixel_height = self.pixel_height();
         points.indexed_iter_mut().for_each(|((i, j), value)| {
-            let re = (i as Real).mul_add(pixel_width, self.bounds.min_x);
-            let im = (j as Real).mul_add(pixel_height, self.bounds.min_y);
-            *value = Cplx::new(re, im);
+            *value = self.map_pixel(i, j);
         });
         points
     }
 
+    /// Write the point iterated for each pixel, as given by [`Self::sample_point`], to a CSV
+    /// file with columns `x,y,re,im`, one row per pixel in row-major order, so that a render can
+    /// be reproduced point for point elsewhere. Pixels on the pole of the view transform have
+    /// empty coordinates.
+    pub fn write_sample_csv<Q: AsRef<Path>>(&self, path: Q) -> std::io::Result<()>
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "x,y,re,im")?;
+        for j in 0..self.res_y {
+            for i in 0..self.res_x {
+                match self.sample_point(i, j) {
+                    Some(z) => writeln!(writer, "{i},{j},{},{}", z.re, z.im)?,
+                    None => writeln!(writer, "{i},{j},,")?,
+                }
+            }
+        }
+        writer.flush()
+    }
+
     #[must_use]
     pub fn par_iter(&self) -> IterBridge<PointGridIterator>
     {
@@ -386,6 +641,7 @@ impl Default for PointGrid
             res_x: 256,
             res_y: 256,
             bounds: Bounds::default(),
+            view_transform: None,
         }
     }
 }
@@ -425,8 +681,7 @@ pub struct PointGridIterator
     step_y: Real,
     res_x: usize,
     res_y: usize,
-    min_x: Real,
-    min_y: Real,
+    center: Cplx,
     idx_x: usize,
     idx_y: usize,
 }
@@ -444,8 +699,7 @@ impl PointGridIterator
             step_y,
             res_x,
             res_y,
-            min_x: bounds.min_x,
-            min_y: bounds.min_y,
+            center: bounds.center(),
             idx_x: 0,
             idx_y: 0,
         }
@@ -467,10 +721,11 @@ impl Iterator for PointGridIterator
 
         self.idx_x %= self.res_x;
 
-        let z = Cplx::new(
-            (self.idx_x as Real).mul_add(self.step_x, self.min_x),
-            (self.idx_y as Real).mul_add(self.step_y, self.min_y),
+        let offset = Cplx::new(
+            (self.idx_x as Real - self.res_x as Real / 2.) * self.step_x,
+            (self.idx_y as Real - self.res_y as Real / 2.) * self.step_y,
         );
+        let z = self.center + offset;
 
         Some(((self.idx_x, self.idx_y), z))
     }
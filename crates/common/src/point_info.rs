@@ -30,7 +30,83 @@ pub enum PointInfo<D>
         distance: Real,
         phase: Period,
     },
+    /// An escaping point with its external angle, as a fraction of a turn. Together with the
+    /// potential, this is a Böttcher coordinate of the point.
+    Boettcher
+    {
+        potential: IterCountSmooth,
+        angle: Real,
+    },
+    /// An escaping point with the sum of $\log|f'|$ over its orbit. For maps with very fast
+    /// escape, this varies over a much wider range than the potential.
+    LogDerivative
+    {
+        potential: IterCountSmooth,
+        log_derivative: Real,
+        phase: Option<Period>,
+    },
     Unknown,
+    /// The parameter map has no finite value at this point, e.g. at a pole.
+    UndefinedParameter,
+}
+
+impl<D> PointInfo<D>
+{
+    /// Whether the point lies in the escape set.
+    #[must_use]
+    pub const fn is_escaping(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Escaping { .. }
+                | Self::DistanceEstimate { .. }
+                | Self::Boettcher { .. }
+                | Self::LogDerivative { .. }
+        )
+    }
+
+    /// Logarithmic escape time of an escaping point, on the scale used by the palette.
+    #[must_use]
+    pub fn escape_level(&self) -> Option<Real>
+    {
+        match self {
+            Self::Escaping { potential, .. } | Self::Boettcher { potential, .. } => {
+                Some(potential.ln())
+            }
+            Self::DistanceEstimate { distance, .. } => Some(-distance.ln() / 2.),
+            Self::LogDerivative { log_derivative, .. } => Some(log_derivative.max(0.).ln_1p()),
+            _ => None,
+        }
+    }
+
+    /// One-line description of the point's fate, e.g. for a hover tooltip.
+    #[must_use]
+    pub fn summary(&self) -> String
+    {
+        match self {
+            Self::Escaping { potential, .. } => {
+                format!("Escaping after {potential:.1} iterations")
+            }
+            Self::DistanceEstimate { distance, .. } => {
+                format!("Escaping, distance estimate {distance:.3e}")
+            }
+            Self::Boettcher { potential, angle } => {
+                format!("Escaping after {potential:.1} iterations at angle {angle:.4}")
+            }
+            Self::LogDerivative { log_derivative, .. } => {
+                format!("Escaping with log derivative {log_derivative:.1}")
+            }
+            Self::Periodic(data) | Self::MarkedPoint { data, .. } => format!(
+                "Periodic, period {} after {} iterations",
+                data.period, data.preperiod
+            ),
+            Self::PeriodicKnownPotential(data) => format!("Periodic, period {}", data.period),
+            Self::Bounded => "Bounded".to_owned(),
+            Self::Wandering => "Wandering".to_owned(),
+            Self::Unknown => "Unknown".to_owned(),
+            Self::UndefinedParameter => "Parameter undefined".to_owned(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -2,9 +2,11 @@ pub use crate::consts::*;
 pub use crate::directories::*;
 pub use crate::globals::*;
 pub use crate::iter_plane::*;
+pub use crate::mobius::*;
 pub use crate::point_grid::*;
 pub use crate::point_info::*;
 pub use crate::rational_angle::*;
+pub use crate::render_cache::{RenderCache, RenderKey};
 pub use crate::symbolic_dynamics::*;
 pub use crate::traits::*;
 pub use crate::types::*;
@@ -1,5 +1,5 @@
 use crate::symbolic_dynamics::AngleWithDegree;
-use crate::types::{AngleNum, Rational};
+use crate::types::{AngleNum, Rational, Real};
 use derive_more::{From, Into};
 use num_traits::sign::Signed;
 
@@ -59,6 +59,26 @@ impl RationalAngle
         }
     }
 
+    /// The angle $k/d$ nearest to $x$ modulo 1 with $1 \le d \le q$, for snapping approximate
+    /// angles to clean combinatorics. Ties go to the smaller denominator.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn nearest_with_max_denominator(x: Real, max_denom: AngleNum) -> Self
+    {
+        let x = x.rem_euclid(1.);
+        let mut best = Self::ZERO;
+        let mut best_dist = Real::INFINITY;
+        for denom in 1..=max_denom.max(1) {
+            let numer = (x * denom as Real).round() as AngleNum;
+            let dist = (x - numer as Real / denom as Real).abs();
+            if dist < best_dist {
+                best = Self::new(numer, denom).mod_1();
+                best_dist = dist;
+            }
+        }
+        best
+    }
+
     fn mod_1(mut self) -> Self
     {
         self.0 = self.0.fract();
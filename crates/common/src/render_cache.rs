@@ -0,0 +1,127 @@
+use std::collections::{hash_map::DefaultHasher, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use crate::iter_plane::IterPlane;
+use crate::point_grid::PointGrid;
+use crate::point_info::PointInfo;
+use crate::types::IterCount;
+
+/// Default memory budget for cached escape fields, in bytes.
+pub const DEFAULT_RENDER_CACHE_BYTES: usize = 256 * 1024 * 1024;
+
+/// Hash of everything that determines the contents of an `IterPlane`, apart from the
+/// parameter of the plane (caches are expected to be cleared when it changes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RenderKey(u64);
+impl RenderKey
+{
+    #[must_use]
+    pub fn new<T>(profile: T, point_grid: &PointGrid, max_iter: IterCount) -> Self
+    where
+        T: Hash,
+    {
+        let mut hasher = DefaultHasher::new();
+        profile.hash(&mut hasher);
+        point_grid.res_x.hash(&mut hasher);
+        point_grid.res_y.hash(&mut hasher);
+        let bounds = &point_grid.bounds;
+        for x in [bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y] {
+            x.to_bits().hash(&mut hasher);
+        }
+        if let Some(t) = point_grid.view_transform {
+            for coeff in [t.a, t.b, t.c, t.d] {
+                coeff.re.to_bits().hash(&mut hasher);
+                coeff.im.to_bits().hash(&mut hasher);
+            }
+        }
+        max_iter.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// Least-recently-used cache of computed escape fields, bounded by total memory usage.
+#[derive(Clone)]
+pub struct RenderCache<D>
+{
+    entries: VecDeque<(RenderKey, IterPlane<D>)>,
+    max_bytes: usize,
+}
+impl<D> Default for RenderCache<D>
+{
+    fn default() -> Self
+    {
+        Self::new(DEFAULT_RENDER_CACHE_BYTES)
+    }
+}
+impl<D> RenderCache<D>
+{
+    #[must_use]
+    pub const fn new(max_bytes: usize) -> Self
+    {
+        Self {
+            entries: VecDeque::new(),
+            max_bytes,
+        }
+    }
+
+    fn size_of(iter_plane: &IterPlane<D>) -> usize
+    {
+        iter_plane.iter_counts.len() * std::mem::size_of::<PointInfo<D>>()
+    }
+
+    fn total_bytes(&self) -> usize
+    {
+        self.entries
+            .iter()
+            .map(|(_, iter_plane)| Self::size_of(iter_plane))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: RenderKey) -> bool
+    {
+        self.entries.iter().any(|(k, _)| *k == key)
+    }
+
+    /// Look up a cached escape field, marking it as most recently used.
+    pub fn get(&mut self, key: RenderKey) -> Option<&IterPlane<D>>
+    {
+        let idx = self.entries.iter().position(|(k, _)| *k == key)?;
+        let entry = self.entries.remove(idx)?;
+        self.entries.push_back(entry);
+        self.entries.back().map(|(_, iter_plane)| iter_plane)
+    }
+
+    /// Store an escape field, evicting the least recently used entries to stay within budget.
+    /// Fields larger than the whole budget are not cached.
+    pub fn insert(&mut self, key: RenderKey, iter_plane: IterPlane<D>)
+    {
+        self.entries.retain(|(k, _)| *k != key);
+
+        let size = Self::size_of(&iter_plane);
+        if size > self.max_bytes {
+            return;
+        }
+        while !self.entries.is_empty() && self.total_bytes() + size > self.max_bytes {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, iter_plane));
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.entries.clear();
+    }
+}
@@ -143,6 +143,44 @@ impl std::fmt::Display for OrbitSchema
     }
 }
 
+/// Preperiod and period of a finite sequence of points, as found by Brent's cycle detection.
+///
+/// Two points are identified when they differ by at most `tolerance` times $\max(1, |z|)$.
+/// Returns `None` if no cycle closes within the sequence.
+#[must_use]
+pub fn detect_orbit_schema(points: &[Cplx], tolerance: Real) -> Option<OrbitSchema>
+{
+    let close = |i: usize, j: usize| {
+        let (z, w) = (points[i], points[j]);
+        (z - w).norm() <= tolerance * z.norm().max(1.)
+    };
+
+    // Find the period by letting the hare run ahead of a tortoise that teleports to it at
+    // powers of two
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = 0;
+    let mut hare = 1;
+    while hare < points.len() && !close(tortoise, hare) {
+        if power == period {
+            tortoise = hare;
+            power *= 2;
+            period = 0;
+        }
+        hare += 1;
+        period += 1;
+    }
+    if hare >= points.len() {
+        return None;
+    }
+
+    // The first index that matches the point one period later is the preperiod
+    let preperiod = (0..points.len() - period).find(|&i| close(i, i + period))?;
+    Some(OrbitSchema {
+        period: Period::try_from(period).ok()?,
+        preperiod: Period::try_from(preperiod).ok()?,
+    })
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OrbitSchemaWithDegree
@@ -526,6 +564,27 @@ impl std::fmt::Display for DivisionByZeroError
 
 impl std::error::Error for DivisionByZeroError {}
 
+#[derive(Debug)]
+pub struct TooManyDigitsError(usize);
+
+impl std::fmt::Display for TooManyDigitsError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(
+            f,
+            "{} digits after the point, but at most {MAX_DECIMAL_DIGITS} are supported.",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for TooManyDigitsError {}
+
+/// Most digits after the decimal point of an angle, so that the denominators fit in an
+/// [`AngleNum`].
+const MAX_DECIMAL_DIGITS: usize = 15;
+
 #[derive(Debug)]
 pub enum ParseAngleError
 {
@@ -533,6 +592,7 @@ pub enum ParseAngleError
     Fraction(Box<dyn Error>),
     Dyadic(Box<dyn Error>),
     Preperiodic(Box<dyn Error>),
+    Decimal(Box<dyn Error>),
 }
 
 impl std::fmt::Display for ParseAngleError
@@ -540,7 +600,12 @@ impl std::fmt::Display for ParseAngleError
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
         match self {
-            Self::UnrecognizedFormat => write!(f, "Unrecognized angle format."),
+            Self::UnrecognizedFormat => write!(
+                f,
+                "Unrecognized angle format. Expected a fraction such as 1/3, a decimal such as \
+                    0.25 or 0.333..., a binary string such as 0110, or a binary expansion such as 011p10, \
+                    0.01(10) or 0.\\overline{{01}}."
+            ),
             Self::Fraction(cause) => {
                 write!(f, "Error parsing fraction string: {cause}")
             }
@@ -548,6 +613,7 @@ impl std::fmt::Display for ParseAngleError
             Self::Preperiodic(cause) => {
                 write!(f, "Error parsing preperiodic string: {cause}")
             }
+            Self::Decimal(cause) => write!(f, "Error parsing decimal string: {cause}"),
         }
     }
 }
@@ -558,18 +624,22 @@ impl std::error::Error for ParseAngleError
     {
         match self {
             Self::UnrecognizedFormat => None,
-            Self::Fraction(cause) | Self::Dyadic(cause) | Self::Preperiodic(cause) => {
-                Some(&**cause)
-            }
+            Self::Fraction(cause)
+            | Self::Dyadic(cause)
+            | Self::Preperiodic(cause)
+            | Self::Decimal(cause) => Some(&**cause),
         }
     }
 }
 
 pub fn parse_angle(text: &str) -> Result<RationalAngle, ParseAngleError>
 {
+    let text = text.trim();
     parse_fraction(text)
         .or_else(|| parse_dyadic(text))
         .or_else(|| parse_preperiodic(text))
+        .or_else(|| parse_binary_expansion(text))
+        .or_else(|| parse_decimal(text))
         .unwrap_or(Err(ParseAngleError::UnrecognizedFormat))
 }
 
@@ -662,6 +732,79 @@ fn parse_preperiodic(text: &str) -> Option<Result<RationalAngle, ParseAngleError
     None
 }
 
+/// Binary expansion after a point, with an optional repeating block in parentheses or in
+/// `\overline{...}`, e.g. "0.01(10)" or "0.\overline{01}". Since a finite expansion such as
+/// "0.0101" reads as a decimal, expansions without a repeating block need a "0b" prefix.
+#[allow(clippy::unwrap_used)]
+fn parse_binary_expansion(text: &str) -> Option<Result<RationalAngle, ParseAngleError>>
+{
+    lazy_static! {
+        static ref BIN_EXPANSION: Regex =
+            Regex::new(r"^(0b)?0?\.([01]*)(?:\(([01]+)\)|\\overline\{([01]+)\})?$").unwrap();
+    }
+
+    let captures = BIN_EXPANSION.captures(text)?;
+    let prefixed = captures.get(1).is_some();
+    let pre = captures.get(2).map_or("", |m| m.as_str());
+    let Some(per) = captures.get(3).or_else(|| captures.get(4)) else {
+        if !prefixed {
+            return None;
+        }
+        if pre.is_empty() {
+            return Some(Ok(RationalAngle::ZERO));
+        }
+        return parse_dyadic(pre);
+    };
+    parse_preperiodic(&format!("{pre}p{}", per.as_str()))
+}
+
+/// Simplest fraction in the closed interval $[a, b]$, i.e. the one with the smallest
+/// denominator, found by descending the Stern-Brocot tree along the continued fractions.
+fn simplest_between(a: Rational, b: Rational) -> Rational
+{
+    let floor = a.floor();
+    if floor == a {
+        return a;
+    }
+    if floor + 1 <= b {
+        return floor + 1;
+    }
+    let (a, b) = (a - floor, b - floor);
+    floor + simplest_between(b.recip(), a.recip()).recip()
+}
+
+/// Decimal angle, e.g. "0.1", read exactly as $1/10$. With a trailing "...", as in "0.333...",
+/// the decimal is instead read as the simplest fraction that rounds to it at the given number of
+/// digits, here $1/3$.
+#[allow(clippy::unwrap_used)]
+fn parse_decimal(text: &str) -> Option<Result<RationalAngle, ParseAngleError>>
+{
+    lazy_static! {
+        static ref DECIMAL: Regex = Regex::new(r"^(\d*)\.(\d+)(\.\.\.|…)?$").unwrap();
+    }
+
+    let captures = DECIMAL.captures(text)?;
+    let digits = captures.get(2)?.as_str();
+    if digits.len() > MAX_DECIMAL_DIGITS {
+        return Some(Err(ParseAngleError::Decimal(Box::new(TooManyDigitsError(
+            digits.len(),
+        )))));
+    }
+
+    let numer = match digits.parse::<AngleNum>() {
+        Ok(numer) => numer,
+        Err(e) => return Some(Err(ParseAngleError::Decimal(Box::new(e)))),
+    };
+    let scale = AngleNum::pow(10, digits.len() as u32);
+    if captures.get(3).is_none() {
+        return Some(Ok(RationalAngle::new(numer, scale)));
+    }
+    let x = Rational::new(numer, scale);
+    let half_ulp = Rational::new(1, 2 * scale);
+    let simplest = simplest_between(x - half_ulp, x + half_ulp).fract();
+    Some(Ok(RationalAngle::new(*simplest.numer(), *simplest.denom())))
+}
+
 impl FromStr for RationalAngle
 {
     type Err = ParseAngleError;
@@ -669,17 +812,14 @@ impl FromStr for RationalAngle
     /// Parse text representing an angle as done in Wolf Jung's Mandel.
     /// Supports fraction strings, e.g. "17/168",
     /// binary strings for dyadic angles, e.g. "011" -> 3/8,
-    /// and binary representations of (pre)periodic angles,
-    /// e.g. "011p10" -> 3/2^3 + 2/(2^3*(2^2-1)) = 11/24
+    /// binary representations of (pre)periodic angles,
+    /// e.g. "011p10" -> 3/2^3 + 2/(2^3*(2^2-1)) = 11/24,
+    /// binary expansions, e.g. "0.011(10)" or "0.011\overline{10}" -> 11/24 and "0b0.011" -> 3/8,
+    /// and decimals, e.g. "0.25" -> 1/4, or "0.333..." -> 1/3 for the simplest fraction that
+    /// rounds to the digits given.
     fn from_str(text: &str) -> Result<Self, Self::Err>
     {
-        if let Some(result) = parse_fraction(text)
-            .or_else(|| parse_dyadic(text))
-            .or_else(|| parse_preperiodic(text))
-        {
-            return result;
-        }
-        Err(ParseAngleError::UnrecognizedFormat)
+        parse_angle(text)
     }
 }
 
@@ -28,6 +28,29 @@ pub trait MaybeNan
     fn is_nan(&self) -> bool;
 }
 
+/// Whether every component of a value is finite. Parameters fail this at poles of the parameter
+/// map.
+pub trait MaybeFinite
+{
+    fn is_finite(&self) -> bool;
+}
+impl MaybeFinite for Real
+{
+    #[inline]
+    fn is_finite(&self) -> bool
+    {
+        <Self>::is_finite(*self)
+    }
+}
+impl MaybeFinite for Cplx
+{
+    #[inline]
+    fn is_finite(&self) -> bool
+    {
+        <Self>::is_finite(*self)
+    }
+}
+
 pub trait Dist<R>
 {
     fn dist(&self, other: Self) -> R;
@@ -248,7 +271,10 @@ pub trait Variable:
     + Describe
 {
 }
-pub trait Parameter: Clone + Send + Sync + Default + PartialEq + Describe + Summarize {}
+pub trait Parameter:
+    Clone + Send + Sync + Default + PartialEq + MaybeFinite + Describe + Summarize
+{
+}
 pub trait Derivative:
     Polar<Real>
     + Send
@@ -277,7 +303,10 @@ impl<V> Variable for V where
         + Describe
 {
 }
-impl<P> Parameter for P where P: Clone + Send + Sync + Default + PartialEq + Describe + Summarize {}
+impl<P> Parameter for P where
+    P: Clone + Send + Sync + Default + PartialEq + MaybeFinite + Describe + Summarize
+{
+}
 impl<D> Derivative for D where
     D: Polar<Real>
         + Send
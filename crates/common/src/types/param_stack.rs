@@ -1,7 +1,7 @@
 use super::Cplx;
 use crate::{
     prelude::DescriptionConf,
-    traits::{Describe, Summarize},
+    traits::{Describe, MaybeFinite, Summarize},
 };
 use derive_more::Display;
 
@@ -24,6 +24,13 @@ impl Describe for NoParam
     }
 }
 impl Summarize for NoParam {}
+impl MaybeFinite for NoParam
+{
+    fn is_finite(&self) -> bool
+    {
+        true
+    }
+}
 
 pub trait ParamList: Clone
 {
@@ -1,16 +1,16 @@
 use super::{Cplx, Real};
 use crate::consts::ZERO;
 use crate::prelude::{Conj, OMEGA};
-use crate::traits::{Arg, Describe, DescriptionConf, MaybeNan, Norm, Summarize};
+use crate::traits::{Arg, Describe, DescriptionConf, MaybeFinite, MaybeNan, Norm, Summarize};
 use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
 
 pub mod matrix;
-pub use matrix::{Matrix2x2, Point};
+pub use matrix::{CplxMatrix2x2, Matrix2x2, Point};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Copy, Debug, Add, From, PartialEq, Eq, Display)]
+#[derive(Default, Clone, Copy, Debug, Add, Sub, From, PartialEq, Eq, Display)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[display("[ a: {a}, b: {b} ] ")]
 pub struct Pair<T>
@@ -31,10 +31,54 @@ where
     }
 }
 impl<T> Summarize for Pair<T> where T: std::fmt::Display {}
+impl<T> MaybeFinite for Pair<T>
+where
+    T: MaybeFinite + std::fmt::Display,
+{
+    fn is_finite(&self) -> bool
+    {
+        self.a.is_finite() && self.b.is_finite()
+    }
+}
 
 pub type RealPair = Pair<Real>;
 pub type CplxPair = Pair<Cplx>;
 
+/// As a dynamical variable, a pair $(a, b)$ is a point of $\mathbb{C}^2$, drawn in the plane as
+/// its first coordinate $a$.
+impl From<Cplx> for CplxPair
+{
+    fn from(a: Cplx) -> Self
+    {
+        Self { a, b: ZERO }
+    }
+}
+impl From<CplxPair> for Cplx
+{
+    fn from(value: CplxPair) -> Self
+    {
+        value.a
+    }
+}
+impl Norm<Real> for CplxPair
+{
+    fn norm(&self) -> Real
+    {
+        self.norm_sqr().sqrt()
+    }
+    fn norm_sqr(&self) -> Real
+    {
+        self.a.norm_sqr() + self.b.norm_sqr()
+    }
+}
+impl MaybeNan for CplxPair
+{
+    fn is_nan(&self) -> bool
+    {
+        self.a.is_nan() || self.b.is_nan()
+    }
+}
+
 #[derive(Default, Clone, Copy, Debug, Add, From, PartialEq, Display)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[display("[ a: {a}, b: {b}, c: {c}, d: {d} ] ")]
@@ -441,6 +485,14 @@ impl MaybeNan for EisensteinInteger
         false
     }
 }
+impl MaybeFinite for EisensteinInteger
+{
+    #[inline]
+    fn is_finite(&self) -> bool
+    {
+        true
+    }
+}
 
 impl Norm<Real> for EisensteinInteger
 {
@@ -644,6 +696,14 @@ impl MaybeNan for GaussianInteger
         false
     }
 }
+impl MaybeFinite for GaussianInteger
+{
+    #[inline]
+    fn is_finite(&self) -> bool
+    {
+        true
+    }
+}
 
 impl Norm<Real> for GaussianInteger
 {
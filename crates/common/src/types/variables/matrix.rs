@@ -1,6 +1,7 @@
+use crate::consts::{ONE, ZERO};
 use crate::prelude::Conj;
-use crate::traits::{Arg, FloatLike, MaybeNan, Named, Norm};
-use crate::types::{Cplx, Real};
+use crate::traits::{Arg, FloatLike, MaybeFinite, MaybeNan, Named, Norm};
+use crate::types::{Cplx, CplxPair, Real};
 use derive_more::{Add, AddAssign, Display, From, Sub};
 use num_traits::{One, Zero};
 
@@ -66,6 +67,13 @@ impl MaybeNan for Point
         self.x.is_nan() || self.y.is_nan()
     }
 }
+impl MaybeFinite for Point
+{
+    fn is_finite(&self) -> bool
+    {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
 impl From<Cplx> for Point
 {
     fn from(value: Cplx) -> Self
@@ -213,3 +221,142 @@ impl MaybeNan for Matrix2x2
         self.v0.is_nan() || self.v1.is_nan()
     }
 }
+
+/// Complex $2 \times 2$ matrix $\begin{pmatrix} a & b \\ c & d \end{pmatrix}$, the Jacobian of a map
+/// of $\mathbb{C}^2$.
+///
+/// As a multiplier, the matrix is measured by its dominant eigenvalue, which governs whether a
+/// cycle attracts.
+#[derive(Default, Debug, Clone, Copy, Add, Sub, AddAssign, Display, PartialEq)]
+#[display("[[{a}, {b}], [{c}, {d}]]")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CplxMatrix2x2
+{
+    pub a: Cplx,
+    pub b: Cplx,
+    pub c: Cplx,
+    pub d: Cplx,
+}
+impl CplxMatrix2x2
+{
+    #[must_use]
+    pub const fn new(a: Cplx, b: Cplx, c: Cplx, d: Cplx) -> Self
+    {
+        Self { a, b, c, d }
+    }
+    /// Matrix whose first column is `v` and whose second column vanishes, representing the
+    /// derivative of a point of $\mathbb{C}^2$ along a single complex direction.
+    #[must_use]
+    pub const fn column(v: CplxPair) -> Self
+    {
+        Self::new(v.a, ZERO, v.b, ZERO)
+    }
+    #[must_use]
+    pub fn det(&self) -> Cplx
+    {
+        self.a * self.d - self.b * self.c
+    }
+    #[must_use]
+    pub fn trace(&self) -> Cplx
+    {
+        self.a + self.d
+    }
+    /// Eigenvalue of largest modulus.
+    #[must_use]
+    pub fn dominant_eigenvalue(&self) -> Cplx
+    {
+        let half_trace = 0.5 * self.trace();
+        let disc = (half_trace * half_trace - self.det()).sqrt();
+        let (l0, l1) = (half_trace + disc, half_trace - disc);
+        if l0.norm_sqr() >= l1.norm_sqr() {
+            l0
+        } else {
+            l1
+        }
+    }
+    #[must_use]
+    pub fn apply(&self, v: CplxPair) -> CplxPair
+    {
+        CplxPair {
+            a: self.a * v.a + self.b * v.b,
+            b: self.c * v.a + self.d * v.b,
+        }
+    }
+}
+impl From<CplxMatrix2x2> for Cplx
+{
+    fn from(value: CplxMatrix2x2) -> Self
+    {
+        value.dominant_eigenvalue()
+    }
+}
+impl Zero for CplxMatrix2x2
+{
+    fn zero() -> Self
+    {
+        Self::new(ZERO, ZERO, ZERO, ZERO)
+    }
+    fn is_zero(&self) -> bool
+    {
+        self.a.is_zero() && self.b.is_zero() && self.c.is_zero() && self.d.is_zero()
+    }
+}
+impl One for CplxMatrix2x2
+{
+    fn one() -> Self
+    {
+        Self::new(ONE, ZERO, ZERO, ONE)
+    }
+}
+impl std::ops::Mul for CplxMatrix2x2
+{
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Self {
+            a: self.a * rhs.a + self.b * rhs.c,
+            b: self.a * rhs.b + self.b * rhs.d,
+            c: self.c * rhs.a + self.d * rhs.c,
+            d: self.c * rhs.b + self.d * rhs.d,
+        }
+    }
+}
+impl std::ops::MulAssign for CplxMatrix2x2
+{
+    fn mul_assign(&mut self, rhs: Self)
+    {
+        *self = *self * rhs;
+    }
+}
+impl Norm<Real> for CplxMatrix2x2
+{
+    fn norm_sqr(&self) -> Real
+    {
+        self.dominant_eigenvalue().norm_sqr()
+    }
+    fn norm(&self) -> Real
+    {
+        self.dominant_eigenvalue().norm()
+    }
+}
+impl Arg<Real> for CplxMatrix2x2
+{
+    fn arg(self) -> Real
+    {
+        self.dominant_eigenvalue().arg()
+    }
+}
+impl Conj for CplxMatrix2x2
+{
+    fn conj(&self) -> Self
+    {
+        Self::new(self.a.conj(), self.c.conj(), self.b.conj(), self.d.conj())
+    }
+}
+impl MaybeNan for CplxMatrix2x2
+{
+    fn is_nan(&self) -> bool
+    {
+        self.a.is_nan() || self.b.is_nan() || self.c.is_nan() || self.d.is_nan()
+    }
+}
@@ -1,9 +1,13 @@
 use dynamo_color::{Coloring, IncoloringAlgorithm};
 use dynamo_common::math_utils::contour::{Contour, IntegralCurveParams, LevelCurveParams};
-use dynamo_common::math_utils::newton::error::{Error::NanEncountered, NewtonResult};
+use dynamo_common::math_utils::newton::error::NewtonResult;
 use dynamo_common::math_utils::{
     arithmetic::{divisors, gcd, moebius, Integer},
-    newton::{find_root_newton, find_target_newton_err_d},
+    finite_diff_step,
+    newton::{find_root_newton, find_target_newton_iters_d},
+    numeric_derivative,
+    polynomial_roots::solve_polynomial,
+    scaled::ScaledCplx,
 };
 use dynamo_common::prelude::*;
 use dynamo_common::symbolic_dynamics::OrbitSchema;
@@ -11,17 +15,28 @@ use num_traits::{One, Zero};
 
 use ndarray::{Array2, Axis};
 use num_cpus;
-use rayon::iter::{ParallelBridge, ParallelIterator};
+use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+use std::sync::{Arc, Mutex};
 use std::{cell::RefCell, f64::consts::TAU};
 use thread_local::ThreadLocal;
 
+pub mod adaptive;
+pub mod annulus;
+pub mod classification;
+pub mod composition;
 pub mod covering_maps;
 pub mod julia;
 pub mod newton;
+pub mod rays;
+pub mod tuning;
 
 use crate::error::{FindPointError, FindPointResult};
-use crate::orbit::{self, EscapeResult, Orbit, Potential};
+use crate::orbit::{self, CycleDetection, EscapeResult, Orbit, Potential};
+use annulus::EscapeAnnulus;
+use classification::{Classification, OrbitFate};
 use julia::JuliaSet;
+use rays::{group_landings, RayLanding, RayNewtonParams};
+use tuning::Tuning;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -43,20 +58,28 @@ impl PlaneType
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ComputeMode
 {
     #[default]
     SmoothPotential,
     DistanceEstimation,
+    /// Smooth potential together with the external angle of the point, for mapping textures
+    /// onto the escape region.
+    Boettcher,
+    /// Smooth potential together with the accumulated $\log|f'|$ along the orbit, which spreads
+    /// out the exterior colors of maps with very fast escape.
+    LogDerivative,
 }
 impl ComputeMode
 {
     pub fn cycle(&mut self)
     {
         match self {
-            Self::DistanceEstimation => *self = Self::SmoothPotential,
+            Self::DistanceEstimation | Self::Boettcher | Self::LogDerivative => {
+                *self = Self::SmoothPotential;
+            }
             Self::SmoothPotential => *self = Self::DistanceEstimation,
         }
     }
@@ -71,6 +94,98 @@ impl ComputeMode
             Self::DistanceEstimation => {
                 RefCell::new(Box::new(orbit::DistanceEstimation::new(family)))
             }
+            Self::Boettcher => RefCell::new(Box::new(orbit::BoettcherCoordinate::new(family))),
+            Self::LogDerivative => RefCell::new(Box::new(orbit::LogDerivative::new(family))),
+        }
+    }
+}
+
+/// How the pixels of a view are scheduled for computation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RenderStrategy
+{
+    /// Every pixel is iterated in full.
+    #[default]
+    PerPixel,
+    /// Rectangles whose border never settles are flooded with a reduced iteration budget; see
+    /// [`adaptive::compute_mariani_silver`].
+    MarianiSilver,
+}
+
+impl RenderStrategy
+{
+    /// Side of the blocks a Mariani–Silver render starts subdividing from.
+    pub const MARIANI_SILVER_BLOCK_SIZE: usize = 32;
+
+    pub fn toggle(&mut self)
+    {
+        *self = match self {
+            Self::PerPixel => Self::MarianiSilver,
+            Self::MarianiSilver => Self::PerPixel,
+        };
+    }
+}
+
+/// Which critical orbit drives the coloring of a parameter plane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CriticalOrbit
+{
+    /// The orbit of the critical point at this index of `critical_points()`.
+    Index(usize),
+    /// All critical orbits together: a parameter escapes as soon as any of them does.
+    All,
+}
+
+impl Default for CriticalOrbit
+{
+    fn default() -> Self
+    {
+        Self::Index(0)
+    }
+}
+
+impl std::fmt::Display for CriticalOrbit
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Index(index) => write!(f, "{index}"),
+            Self::All => write!(f, "all"),
+        }
+    }
+}
+
+/// Sample points for [`DynamicalFamily::derivative_mismatches`], as offsets from the center of
+/// the view in units of its width.
+const DERIVATIVE_SAMPLES: [Cplx; 3] = [
+    Cplx::new(0.083, 0.041),
+    Cplx::new(-0.127, 0.109),
+    Cplx::new(0.151, -0.062),
+];
+
+/// Relative disagreement beyond which an analytic derivative is reported as wrong.
+const DERIVATIVE_TOLERANCE: Real = 1e-4;
+
+/// An analytic derivative of a family that disagrees with finite differences of its map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DerivativeMismatch
+{
+    /// The multiplier $\partial f / \partial z$ from `map_and_multiplier`.
+    Dynamical,
+    /// The parameter derivative $\partial f / \partial c$ from `gradient`, composed with
+    /// `param_map_d`.
+    Parameter,
+}
+
+impl std::fmt::Display for DerivativeMismatch
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Dynamical => write!(f, "dynamical"),
+            Self::Parameter => write!(f, "parameter"),
         }
     }
 }
@@ -131,6 +246,10 @@ pub trait DynamicalFamily: Sync + Send
         *self.compute_mode_mut() = compute_mode;
     }
 
+    /// Strategy used by orbits of this family to notice that they have landed on a cycle.
+    fn cycle_detection(&self) -> CycleDetection;
+    fn set_cycle_detection(&mut self, cycle_detection: CycleDetection);
+
     fn name(&self) -> String;
     fn long_name(&self) -> String
     {
@@ -145,6 +264,27 @@ pub trait DynamicalFamily: Sync + Send
         String::new()
     }
 
+    /// Symbol used for the plane coordinate in readouts and dialogs.
+    fn param_symbol(&self) -> &str
+    {
+        "c"
+    }
+
+    /// Tuning map onto the small copy of the connectedness locus around a hyperbolic center of
+    /// the given period, if the family supports it.
+    fn tuning(&self, _center: Cplx, _period: Period) -> Option<Tuning>
+    {
+        None
+    }
+
+    /// Internal angle $p/q$ of the satellite component of period $q$ containing `c`, if it is
+    /// attached to the main hyperbolic component and the family supports it. The denominator
+    /// is the number of spokes of the antenna at the tip of the bulb.
+    fn satellite_internal_angle(&self, _c: &Self::Param, _period: Period) -> Option<RationalAngle>
+    {
+        None
+    }
+
     /// The map defining the dynamical system.
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var;
 
@@ -197,6 +337,99 @@ pub trait DynamicalFamily: Sync + Send
         1e12
     }
 
+    /// Whether iterates that would overflow `f64` are continued in extended exponent range.
+    #[inline]
+    fn scaled_iteration(&self) -> bool
+    {
+        false
+    }
+
+    /// Continue orbits in extended exponent range when an iterate overflows before reaching the
+    /// escape radius. Only families implementing `map_scaled` support this.
+    #[inline]
+    fn set_scaled_iteration(&mut self, _scaled_iteration: bool) {}
+
+    /// The map in extended exponent range, used by scaled iteration once an iterate overflows.
+    #[inline]
+    fn map_scaled(&self, _z: ScaledCplx, _c: &Self::Param) -> Option<ScaledCplx>
+    {
+        None
+    }
+
+    /// Whether the map is holomorphic in $z$ and $c$, so that its derivatives can be checked
+    /// against finite differences in any direction.
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        true
+    }
+
+    /// Compare the analytic derivatives of the map against central differences at a few points
+    /// in view, returning those that disagree. Placeholder derivatives silently break distance
+    /// estimation and multiplier coloring, so this is run on loading a profile in debug builds.
+    ///
+    /// Families that are not holomorphic are not checked.
+    fn derivative_mismatches(&self) -> Vec<DerivativeMismatch>
+    {
+        if !self.is_holomorphic() {
+            return vec![];
+        }
+        let h = finite_diff_h();
+        let grid = self.point_grid();
+        let disagree = |analytic: Cplx, numeric: Cplx| {
+            analytic.is_finite()
+                && numeric.is_finite()
+                && (analytic - numeric).norm() > DERIVATIVE_TOLERANCE * numeric.norm().max(1.)
+        };
+
+        let mut mismatches = vec![];
+        for offset in DERIVATIVE_SAMPLES {
+            let point = grid.center() + offset * grid.range_x();
+            let (c, dc_dt) = self.param_map_d(point);
+            let z = Self::Var::from(point);
+
+            let (_, df_dz) = self.map_and_multiplier(z, &c);
+            let numeric_dz =
+                numeric_derivative(|w| self.map(Self::Var::from(w), &c).into(), point, h);
+            if disagree(df_dz.into(), numeric_dz) {
+                mismatches.push(DerivativeMismatch::Dynamical);
+            }
+
+            let (_, _, df_dc) = self.gradient(z, &c);
+            let numeric_dt =
+                numeric_derivative(|t| self.map(z, &self.param_map(t)).into(), point, h);
+            if disagree((df_dc * dc_dt).into(), numeric_dt) {
+                mismatches.push(DerivativeMismatch::Parameter);
+            }
+        }
+        mismatches.sort_by_key(|m| *m as u8);
+        mismatches.dedup();
+        mismatches
+    }
+
+    /// Annulus-based escape criterion replacing the escape radius, for maps of infinite degree.
+    /// The default of `None` uses the plain radius test.
+    #[inline]
+    fn escape_annulus(&self) -> Option<EscapeAnnulus>
+    {
+        None
+    }
+
+    /// Switch between the family's default escape annulus and the plain radius test. Only
+    /// families of infinite degree support this.
+    #[inline]
+    fn set_escape_annulus_enabled(&mut self, _enabled: bool) {}
+
+    /// Region whose translates by its width and height tile the plane, for planes that are
+    /// periodic in one or both directions. Infinite extents mark directions without periodicity.
+    /// When set, only one period of the view is computed, and the rest is filled in by
+    /// translation.
+    #[inline]
+    fn fundamental_domain(&self) -> Option<Bounds>
+    {
+        None
+    }
+
     #[inline]
     fn extra_stop_condition(
         &self,
@@ -307,6 +540,64 @@ pub trait DynamicalFamily: Sync + Send
     #[inline]
     fn set_param(&mut self, _value: <Self::MetaParam as ParamList>::Param) {}
 
+    /// Whether the parameter is locked, in which case `set_param` leaves it unchanged.
+    #[inline]
+    fn param_locked(&self) -> bool
+    {
+        false
+    }
+
+    /// Lock or unlock the parameter. Only planes whose parameter is driven from elsewhere,
+    /// such as Julia sets, support locking.
+    #[inline]
+    fn set_param_locked(&mut self, _locked: bool) {}
+
+    /// Whether bounded points are colored by the attracting critical cycle they converge to.
+    #[inline]
+    fn critical_basins(&self) -> bool
+    {
+        false
+    }
+
+    /// Color bounded points by the attracting critical cycle they converge to, rather than by
+    /// the incoloring algorithm. Only supported on dynamical planes.
+    #[inline]
+    fn set_critical_basins(&mut self, _critical_basins: bool) {}
+
+    /// Critical orbit whose escape colors the parameter plane.
+    #[inline]
+    fn critical_orbit(&self) -> CriticalOrbit
+    {
+        CriticalOrbit::default()
+    }
+
+    /// Choose which critical orbit colors the parameter plane. Only families whose
+    /// `start_point` follows the selection support this.
+    #[inline]
+    fn set_critical_orbit(&mut self, _critical_orbit: CriticalOrbit) {}
+
+    /// A copy of the family following only the critical orbit at `index`, used to combine all
+    /// of them. Returns `None` for families without a choice of critical orbit.
+    #[inline]
+    fn critical_orbit_view(&self, _index: usize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Degree of the map, for families whose degree is a runtime setting rather than fixed by
+    /// the type.
+    #[inline]
+    fn runtime_degree(&self) -> Option<Real>
+    {
+        None
+    }
+
+    /// Change the degree of the map. Only families with a `runtime_degree` support this.
+    #[inline]
+    fn set_runtime_degree(&mut self, _degree: Real) {}
+
     #[inline]
     #[must_use]
     fn with_param(mut self, param: <Self::MetaParam as ParamList>::Param) -> Self
@@ -431,6 +722,110 @@ pub trait DynamicalFamily: Sync + Send
         find_root_newton(diff, start_point).map_err(FindPointError::NewtonError)
     }
 
+    /// Find a Misiurewicz parameter near `start_point`, at which the orbit of the start point
+    /// (usually a critical point) is strictly preperiodic with the given preperiod and period.
+    ///
+    /// The parameter solves $f^{k+n}(z_0) = f^k(z_0)$, with the solutions of lower period and of
+    /// preperiod $k - 1$ divided out, by Newton's method as in
+    /// [`Self::find_nearby_preperiodic_point`].
+    fn find_misiurewicz(
+        &self,
+        start_point: Cplx,
+        orbit_schema: OrbitSchema,
+    ) -> FindPointResult<Cplx>
+    {
+        if orbit_schema.preperiod == 0 {
+            return Err(FindPointError::PreperiodIsZero);
+        }
+        if self.plane_type().is_dynamical() {
+            return Err(FindPointError::NotParameterPlane);
+        }
+        self.find_nearby_preperiodic_point(start_point, orbit_schema)
+    }
+
+    /// Trace the curve of parameters at which a cycle of the given period has multiplier of the
+    /// given modulus. For `modulus = 1`, this is the boundary of a hyperbolic component.
+    ///
+    /// We first find a center of period `period` near `seed`, then solve
+    /// $f^n(z) = z$, $(f^n)'(z) = \lambda$ for $(z, c)$ by continuation in $\lambda$: first along
+    /// the segment $[0, r]$, then around the circle $|\lambda| = r$.
+    ///
+    /// Returns an empty curve if this is not a parameter plane or if continuation fails.
+    fn isoperiodic_curve(&self, seed: Cplx, period: Period, modulus: Real) -> Vec<Cplx>
+    {
+        const RADIAL_STEPS: u32 = 16;
+        const ANGULAR_STEPS: u32 = 256;
+        const NEWTON_STEPS: usize = 8;
+        let h = finite_diff_h();
+
+        if period == 0 || self.plane_type().is_dynamical() {
+            return vec![];
+        }
+        let Ok(mut t) = self.find_nearby_preperiodic_point(
+            seed,
+            OrbitSchema {
+                period,
+                preperiod: 0,
+            },
+        ) else {
+            return vec![];
+        };
+        let mut z: Cplx = self.start_point(t, &self.param_map(t)).into();
+
+        // f^n(z) - z and (f^n)'(z), as functions of the dynamical variable and image point
+        let eval = |z: Cplx, t: Cplx| {
+            let c = self.param_map(t);
+            let mut w = Self::Var::from(z);
+            let mut mult = Self::Deriv::one();
+            for _ in 0..period {
+                let (w1, dw) = self.map_and_multiplier(w, &c);
+                w = w1;
+                mult *= dw;
+            }
+            let w: Cplx = w.into();
+            let mult: Cplx = mult.into();
+            (w - z, mult)
+        };
+
+        // Newton's method on the system, with a finite-difference Jacobian
+        let solve = |z: &mut Cplx, t: &mut Cplx, lambda: Cplx| {
+            for _ in 0..NEWTON_STEPS {
+                let (a, m) = eval(*z, *t);
+                let m = m - lambda;
+                let (hz, ht) = (finite_diff_step(*z, h), finite_diff_step(*t, h));
+                let (a_z0, m_z0) = eval(*z - hz, *t);
+                let (a_z1, m_z1) = eval(*z + hz, *t);
+                let (a_t0, m_t0) = eval(*z, *t - ht);
+                let (a_t1, m_t1) = eval(*z, *t + ht);
+                let (j11, j12) = ((a_z1 - a_z0) / (2. * hz), (a_t1 - a_t0) / (2. * ht));
+                let (j21, j22) = ((m_z1 - m_z0) / (2. * hz), (m_t1 - m_t0) / (2. * ht));
+                let det = j11 * j22 - j12 * j21;
+                *z -= (j22 * a - j12 * m) / det;
+                *t -= (j11 * m - j21 * a) / det;
+            }
+            !(z.is_nan() || t.is_nan())
+        };
+
+        for i in 1..=RADIAL_STEPS {
+            let lambda = Cplx::from(modulus * Real::from(i) / Real::from(RADIAL_STEPS));
+            if !solve(&mut z, &mut t, lambda) {
+                return vec![];
+            }
+        }
+
+        let mut curve = Vec::with_capacity(ANGULAR_STEPS as usize + 1);
+        curve.push(t);
+        for i in 1..=ANGULAR_STEPS {
+            let theta = TAU * Real::from(i) / Real::from(ANGULAR_STEPS);
+            let lambda = Cplx::from_polar(modulus, theta);
+            if !solve(&mut z, &mut t, lambda) {
+                break;
+            }
+            curve.push(t);
+        }
+        curve
+    }
+
     fn run_point(&self, selection: Cplx) -> EscapeResult<Self::Var, Self::Deriv>
     where
         Self: Clone,
@@ -459,6 +854,18 @@ pub trait DynamicalFamily: Sync + Send
         )
     }
 
+    /// At most `len` points of the orbit of `point`, starting from its `skip`-th iterate. The
+    /// orbit is computed lazily, so long orbits can be consumed without collecting them.
+    fn iter_orbit_window(
+        &self,
+        point: Cplx,
+        skip: usize,
+        len: usize,
+    ) -> Box<dyn Iterator<Item = Self::Var> + '_>
+    {
+        Box::new(self.iter_orbit(point).skip(skip).take(len))
+    }
+
     fn get_orbit_vec(&self, point: Cplx) -> Vec<Self::Var>
     {
         let param = self.param_map(point);
@@ -473,6 +880,39 @@ pub trait DynamicalFamily: Sync + Send
         orbit.map(|(z, _s)| z).collect()
     }
 
+    /// Fate of the orbit of the start point at `point`, which in a parameter plane is the
+    /// critical orbit: the iteration at which it escapes, or the preperiod and period of the
+    /// cycle it lands on.
+    ///
+    /// Each iterate is compared with all earlier ones, and the latest within the periodicity
+    /// tolerance determines the schema. Orbits of Misiurewicz parameters land on their cycle
+    /// exactly, while for an orbit attracted to a cycle the preperiod is the number of
+    /// iterations it takes to come within the tolerance.
+    fn orbit_fate(&self, point: Cplx) -> OrbitFate
+    {
+        let tolerance = self.periodicity_tolerance();
+        let c = self.param_map(point);
+        let mut z = self.start_point(point, &c);
+        let mut orbit: Vec<Self::Var> = Vec::new();
+
+        for iter in 0..=self.max_iter() {
+            if let Some(EscapeResult::Escaped { iters, .. }) =
+                self.extra_stop_condition(z, &c, iter)
+            {
+                return OrbitFate::Escapes(iters);
+            }
+            if let Some(j) = orbit.iter().rposition(|&w| (z - w).norm_sqr() <= tolerance) {
+                return OrbitFate::Cycles(OrbitSchema {
+                    preperiod: j as Period,
+                    period: (orbit.len() - j) as Period,
+                });
+            }
+            orbit.push(z);
+            z = self.map(z, &c);
+        }
+        OrbitFate::Undetermined
+    }
+
     /// For some families (e.g. maps with multiple free critical points),
     /// there are many possible starting points. In this case, we can maintain
     /// a plane identifier in `self`, which can by cycled at runtime to switch
@@ -551,6 +991,14 @@ pub trait FamilyDefaults: DynamicalFamily + InfinityFirstReturnMap
         Cplx::default()
     }
 
+    /// Iteration limit to apply when loading the parameter plane. Slow or transcendental maps
+    /// can get by with fewer iterations, while families with deep features need more.
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        1024
+    }
+
     /// Default coloring algorithm to apply when loading the parameter plane.
     fn default_coloring(&self) -> Coloring
     {
@@ -567,6 +1015,15 @@ pub trait FamilyDefaults: DynamicalFamily + InfinityFirstReturnMap
         let bounds = self.default_bounds();
         self.with_bounds(bounds)
     }
+
+    #[must_use]
+    fn with_recommended_max_iter(self) -> Self
+    where
+        Self: Sized,
+    {
+        let max_iter = self.recommended_max_iter();
+        self.with_max_iter(max_iter)
+    }
 }
 
 pub trait HasJulia: DynamicalFamily + InfinityFirstReturnMap
@@ -586,6 +1043,14 @@ pub trait HasJulia: DynamicalFamily + InfinityFirstReturnMap
         Bounds::centered_square(2.2)
     }
 
+    /// Fundamental domain of the dynamical plane with parameter `c`, if the map is periodic.
+    /// See [`DynamicalFamily::fundamental_domain`].
+    #[inline]
+    fn fundamental_domain_child(&self, _c: &Self::Param) -> Option<Bounds>
+    {
+        None
+    }
+
     /// Default coloring algorithm to apply when loading the Julia set.
     fn default_coloring_child(&self) -> Coloring
     {
@@ -609,6 +1074,15 @@ pub trait HasJulia: DynamicalFamily + InfinityFirstReturnMap
     {
         (point.into(), Self::Deriv::one())
     }
+
+    /// All preimages of `w` under the map with parameter `c`, for families whose inverse
+    /// branches are known in closed form. Used to render Julia sets by inverse iteration; the
+    /// default of no branches disables it.
+    #[inline]
+    fn inverse_branches(&self, _w: Self::Var, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        vec![]
+    }
 }
 
 pub trait HasChild<C: DynamicalFamily>: DynamicalFamily
@@ -642,14 +1116,114 @@ pub trait MarkedPoints: DynamicalFamily
         vec![]
     }
 
+    /// Critical values of the map associated to a given parameter, i.e. the images of its
+    /// critical points. For unicritical families these are often more natural markers than the
+    /// critical points themselves.
+    fn critical_values_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        self.critical_points_child(c)
+            .into_iter()
+            .map(|z| self.map(z, c))
+            .collect()
+    }
+
     /// Implementation of `cycles` for Julia sets spawned from this parameter plane.
     /// Used to mark selected periodic points on the dynamical plane.
+    ///
+    /// By default, these are the roots of `cycle_polynomial`.
     #[inline]
-    fn cycles_child(&self, _c: &Self::Param, _period: Period) -> Vec<Self::Var>
+    fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
+    {
+        let coeffs = self.cycle_polynomial(c, period);
+        if coeffs.is_empty() {
+            return vec![];
+        }
+        solve_polynomial(coeffs)
+            .into_iter()
+            .map(Self::Var::from)
+            .collect()
+    }
+
+    /// Coefficients, from the constant term up, of a polynomial whose roots are the points of
+    /// the given period for the map with parameter `c`. Empty if no such polynomial is known.
+    #[inline]
+    fn cycle_polynomial(&self, _c: &Self::Param, _period: Period) -> Vec<Cplx>
     {
         vec![]
     }
 
+    /// Multiplier and classification of each cycle of period up to `max_period` for the map
+    /// with the given parameter, with one entry per cycle.
+    fn cycle_multipliers_child(
+        &self,
+        c: &Self::Param,
+        max_period: Period,
+    ) -> Vec<(Period, Cplx, Classification)>
+    {
+        let mut table = Vec::new();
+        for period in 1..=max_period {
+            let mut seen: Vec<Cplx> = Vec::new();
+            for z in self.cycles_child(c, period) {
+                let z0: Cplx = z.into();
+                let tol = 1e-6 * z0.norm().max(1.);
+                if !z0.is_finite() || seen.iter().any(|w| (w - z0).norm() < tol) {
+                    continue;
+                }
+
+                let mut z = z;
+                let mut multiplier = Self::Deriv::one();
+                for _ in 0..period {
+                    seen.push(z.into());
+                    let (fz, df) = self.map_and_multiplier(z, c);
+                    z = fz;
+                    multiplier *= df;
+                }
+                let multiplier: Cplx = multiplier.into();
+                table.push((period, multiplier, Classification::of(multiplier)));
+            }
+        }
+        table
+    }
+
+    /// Multiplier of the attracting cycle captured by a critical orbit of the map with parameter
+    /// `c`, which characterizes its hyperbolic component. The critical points are tried in turn;
+    /// returns `None` if each of them escapes or fails to settle within `max_iter` iterations.
+    fn critical_orbit_multiplier_child(&self, c: &Self::Param) -> Option<Cplx>
+    {
+        let tolerance = self.periodicity_tolerance();
+        self.critical_points_child(c).into_iter().find_map(|mut z| {
+            for iter in 0..self.max_iter() {
+                match self.extra_stop_condition(z, c, iter) {
+                    Some(EscapeResult::Periodic { info, .. }) => {
+                        return Some(info.multiplier.into());
+                    }
+                    Some(_) => return None,
+                    None => z = self.map(z, c),
+                }
+            }
+
+            // Follow the settled orbit once around its cycle
+            let mut w = z;
+            let mut multiplier = Self::Deriv::one();
+            for _ in 0..self.max_iter() {
+                let (fw, df) = self.map_and_multiplier(w, c);
+                w = fw;
+                multiplier *= df;
+                if w.dist_sqr(z) <= tolerance {
+                    return Some(multiplier.into());
+                }
+            }
+            None
+        })
+    }
+
+    /// Multiplier of the attracting cycle captured by a critical orbit, if the plane is dynamical.
+    #[inline]
+    fn critical_orbit_multiplier(&self) -> Option<Cplx>
+    {
+        None
+    }
+
     /// Implementation of `precycles` for Julia sets spawned from this parameter plane.
     /// Used to mark selected preperiodic points on the dynamical plane.
     #[inline]
@@ -762,6 +1336,14 @@ pub trait InfinityFirstReturnMap: DynamicalFamily
         self.degree_real().try_round().unwrap_or(0)
     }
 
+    /// The degree for acting on external angles, or `None` if it is not a positive whole
+    /// number, in which case external rays are unsupported.
+    #[inline]
+    fn integer_degree(&self) -> Option<AngleNum>
+    {
+        Some(self.degree()).filter(|&degree| degree > 0)
+    }
+
     /// Period of infinity under $f$. Should be set to 0 if infinity is not periodic.
     ///
     /// Used for computing external rays, for which we use an iterate of the map instead of the map
@@ -772,6 +1354,15 @@ pub trait InfinityFirstReturnMap: DynamicalFamily
         1
     }
 
+    /// Whether infinity lies on an attracting cycle of period greater than one, as in rational
+    /// maps with a critical cycle through a pole. Orbits that pass the escape radius then
+    /// converge to that cycle, so their basin is distinct from that of the free critical point.
+    #[inline]
+    fn attracting_infinity(&self) -> bool
+    {
+        self.escaping_period() > 1
+    }
+
     /// For very large values of the parameter, how many iterations before the variable
     /// value is large?
     ///
@@ -824,6 +1415,37 @@ pub trait InfinityFirstReturnMap: DynamicalFamily
         )
     }
 
+    /// Representable stand-in for an iterate beyond the range of `f64`. Steps back whole escaping
+    /// periods, shrinking $\log|z|^2$ as the first return map at infinity would, until the
+    /// iterate fits; the smooth iteration count of the result is that of the original.
+    fn rescale_escaped(
+        &self,
+        iters: IterCount,
+        z: ScaledCplx,
+        c: &Self::Param,
+    ) -> Option<(IterCount, Self::Var)>
+    {
+        const MAX_LN_NORM_SQR: Real = 700.;
+
+        let degree = self.degree_real();
+        if degree.is_nan() || degree <= 1. {
+            return None;
+        }
+        let q = self.escape_coeff(c).norm().ln();
+        let period = IterCount::from(self.escaping_period());
+
+        let mut iters = iters;
+        let mut v = z.norm_sqr_ln();
+        while v > MAX_LN_NORM_SQR {
+            if iters < period || v + q <= 0. {
+                return None;
+            }
+            v = (v + q) / degree - q;
+            iters -= period;
+        }
+        Some((iters, Cplx::from_polar((0.5 * v).exp(), z.arg()).into()))
+    }
+
     /// External Green's function at a point
     fn external_potential_d(&self, t: Cplx) -> Option<(Real, Cplx)>
     {
@@ -842,7 +1464,11 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
 {
     /// Default implementation of external rays. Only valid if the self-return map at infinity is
     /// monic.
-    fn external_ray_helper(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+    fn external_ray_helper(
+        &self,
+        angle: RationalAngle,
+        params: RayNewtonParams,
+    ) -> Option<Vec<Cplx>>
     {
         const R: Real = 16.0;
         let escape_radius_log = R.ln() * self.degree_real().abs();
@@ -853,7 +1479,7 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
         }
 
         let pixel_width = self.point_grid().pixel_width() * 0.03;
-        let error = self.point_grid().res_x as Real * 1e-8;
+        let error = params.error(self.point_grid());
 
         // let base_point = escape_radius * angle.to_circle();
         // Arbitrary starting guess that is likely to escape
@@ -896,7 +1522,13 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
 
             for _j in 0..RAY_SHARPNESS {
                 let target = Cplx::new(u, v).exp();
-                match find_target_newton_err_d(fk_and_dfk, t_curr, target, error) {
+                match find_target_newton_iters_d(
+                    fk_and_dfk,
+                    t_curr,
+                    target,
+                    error,
+                    params.max_iters,
+                ) {
                     Ok((sol, t_k, d_k)) => {
                         t_curr = sol;
 
@@ -911,10 +1543,11 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
                             return Some(t_list);
                         }
                     }
-                    Err(NanEncountered) => {
+                    // Stop at the last point that converged, rather than continuing from a
+                    // bad estimate.
+                    Err(_) => {
                         return Some(t_list);
                     }
-                    _ => {}
                 }
                 u *= factor;
                 u -= target_shift.re;
@@ -933,11 +1566,18 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
     ///
     /// Currently only stable for quadratic polynomials.
     fn external_ray(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+    {
+        self.external_ray_with(angle, RayNewtonParams::default())
+    }
+
+    /// Compute an external ray, with the given settings for the Newton solver.
+    fn external_ray_with(&self, angle: RationalAngle, params: RayNewtonParams)
+        -> Option<Vec<Cplx>>
     {
         // Remove off the end if distance is increasing,
         // as the helper method may return erroneous values near the end.
         // We use l1 norms to preserve precision.
-        if let Some(mut t_list) = self.external_ray_helper(angle) {
+        if let Some(mut t_list) = self.external_ray_helper(angle, params) {
             let t0 = t_list.last()?;
             let mut t1 = t_list.get(t_list.len() - 2)?;
             let mut t2 = t_list.get(t_list.len() - 3)?;
@@ -955,6 +1595,48 @@ pub trait ExternalRays: DynamicalFamily + InfinityFirstReturnMap
             None
         }
     }
+
+    /// Compute the external rays of many angles in parallel. The rays are returned in the order
+    /// of `angles`, with `None` for each ray that could not be computed.
+    fn external_rays(&self, angles: &[RationalAngle]) -> Vec<Option<Vec<Cplx>>>
+    {
+        angles
+            .par_iter()
+            .map(|angle| self.external_ray(*angle))
+            .collect()
+    }
+
+    /// `count` points of the external ray of the given angle, from its outer end to its landing
+    /// end, or `None` if the ray could not be computed. The points are taken at evenly spaced
+    /// indices of the computed ray, so they are not evenly spaced along it.
+    fn external_ray_samples(&self, angle: RationalAngle, count: usize) -> Option<Vec<Cplx>>
+    {
+        let ray = self.external_ray(angle)?;
+        let last = ray.len().checked_sub(1)?;
+        let samples = match count {
+            0 => vec![],
+            1 => vec![ray[last]],
+            _ => (0..count).map(|k| ray[k * last / (count - 1)]).collect(),
+        };
+        Some(samples)
+    }
+
+    /// Combinatorics of the rays whose angles have exact denominator `denominator`: the rays
+    /// are grouped by landing point, taking the end of each computed ray as its landing point,
+    /// and treating points within `tolerance` of each other as the same.
+    fn ray_landings(&self, denominator: AngleNum, tolerance: Real) -> Vec<RayLanding>
+    {
+        let angles: Vec<_> = (1..denominator)
+            .filter(|&k| gcd(k, denominator) == 1)
+            .map(|k| RationalAngle::new(k, denominator))
+            .collect();
+        let landings = angles
+            .iter()
+            .zip(self.external_rays(&angles))
+            .filter_map(|(angle, ray)| Some((*angle, *ray?.last()?)))
+            .collect();
+        group_landings(landings, tolerance)
+    }
 }
 
 pub trait Equipotential: DynamicalFamily
@@ -1118,11 +1800,89 @@ pub trait Computable: DynamicalFamily
 
     fn compute_into(&self, iter_plane: &mut IterPlane<Self::Deriv>);
 
+    /// Compute the escape field on a grid other than the plane's own, tiling by the
+    /// fundamental domain where possible.
+    fn compute_on_grid(&self, point_grid: &PointGrid, iter_plane: &mut IterPlane<Self::Deriv>)
+    {
+        if let Some(domain) = self.fundamental_domain() {
+            if let Some(tile_grid) = point_grid.fundamental_tile(&domain) {
+                let mut tile = IterPlane::create(tile_grid.clone());
+                self.compute_on_grid_direct(&tile_grid, &mut tile);
+                iter_plane.iter_counts = tile.tiled(point_grid.clone(), &domain).iter_counts;
+                return;
+            }
+        }
+        self.compute_on_grid_direct(point_grid, iter_plane);
+    }
+
+    /// Compute the escape field at every pixel of a grid, without exploiting periodicity.
+    fn compute_on_grid_direct(
+        &self,
+        point_grid: &PointGrid,
+        iter_plane: &mut IterPlane<Self::Deriv>,
+    );
+
+    /// Coarse preview at $2^{-level}$ times the full resolution, upscaled to the full grid.
+    fn compute_preview(&self, level: u32) -> IterPlane<Self::Deriv>
+    {
+        if level == 0 {
+            return self.compute();
+        }
+        let coarse_grid = self.point_grid().downscaled(1 << level);
+        let mut coarse = IterPlane::create(coarse_grid.clone());
+        self.compute_on_grid(&coarse_grid, &mut coarse);
+        coarse.upscaled(self.point_grid().clone())
+    }
+
+    /// Escape field of the current view at a width of `width` pixels, with the height
+    /// inferred from the bounds so that the aspect ratio is preserved.
+    fn compute_thumbnail(&self, width: usize) -> IterPlane<Self::Deriv>
+    {
+        let grid = PointGrid::new_by_res_x(width, self.point_grid().bounds.clone());
+        let mut iter_plane = IterPlane::create(grid.clone());
+        self.compute_on_grid(&grid, &mut iter_plane);
+        iter_plane
+    }
+
+    /// Escape field of the current view as ASCII art of `width` by `height` characters, for
+    /// inspecting a plane in a terminal or a test. See [`IterPlane::to_ascii`] for the
+    /// characters used.
+    fn render_ascii(&self, width: usize, height: usize) -> String
+    {
+        let mut grid = self.point_grid().clone();
+        grid.res_x = width;
+        grid.res_y = height;
+        let mut iter_plane = IterPlane::create(grid.clone());
+        self.compute_on_grid(&grid, &mut iter_plane);
+        iter_plane.to_ascii()
+    }
+
+    /// Escape field of the current view by Mariani–Silver subdivision of blocks of
+    /// `block_size` pixels, flooding rectangles whose border never settles. See [`adaptive`]
+    /// for when this agrees with [`compute`](Self::compute).
+    fn compute_mariani_silver(&self, block_size: usize) -> adaptive::AdaptiveRender<Self::Deriv>
+    where
+        Self: Clone;
+
     fn get_orbit_and_info(
         &self,
         point: Cplx,
+    ) -> orbit::OrbitAndInfo<Self::Param, Self::Var, Self::Deriv>
+    {
+        self.get_truncated_orbit_and_info(point, usize::MAX)
+    }
+
+    /// The first `max_len` points of the orbit of `point`, with the escape information of the
+    /// whole orbit. Iterates past the first `max_len` are computed but not stored.
+    fn get_truncated_orbit_and_info(
+        &self,
+        point: Cplx,
+        max_len: usize,
     ) -> orbit::OrbitAndInfo<Self::Param, Self::Var, Self::Deriv>;
 
+    /// Escape information at a single point, computed as for a pixel of the image.
+    fn probe(&self, point: Cplx) -> PointInfo<Self::Deriv>;
+
     fn orbit_summary_conf(&self) -> orbit::OrbitSummaryConf
     {
         orbit::OrbitSummaryConf {
@@ -1138,21 +1898,23 @@ impl<P> Computable for P
 where
     P: DynamicalFamily + EscapeEncoding,
 {
-    fn get_orbit_and_info(
+    fn get_truncated_orbit_and_info(
         &self,
         point: Cplx,
+        max_len: usize,
     ) -> orbit::OrbitAndInfo<Self::Param, Self::Var, Self::Deriv>
     {
         let orbit = orbit::CycleDetected::new(self).init(point);
         let start = orbit.z_fast;
         let param = orbit.param.clone();
         let mut final_state = None;
-        let trajectory: Vec<Self::Var> = orbit
-            .map(|(z, s)| {
-                final_state = s;
-                z
-            })
-            .collect();
+        let mut trajectory: Vec<Self::Var> = vec![];
+        for (z, s) in orbit {
+            if trajectory.len() < max_len {
+                trajectory.push(z);
+            }
+            final_state = s;
+        }
         let result = self.encode_escape_result(final_state.unwrap_or_default(), start, &param);
         orbit::OrbitAndInfo {
             orbit: trajectory,
@@ -1164,36 +1926,159 @@ where
         }
     }
 
+    fn probe(&self, point: Cplx) -> PointInfo<Self::Deriv>
+    {
+        let views = critical_orbit_views(self);
+        let families: Vec<&Self> = views
+            .as_ref()
+            .map_or_else(|| vec![self], |v| v.iter().collect());
+        combine_critical_orbits(families.iter().map(|family| {
+            let mut orbit = family.compute_mode().create_orbit(*family).into_inner();
+            orbit.reset(point);
+            orbit.run_until_complete()
+        }))
+    }
+
     fn compute_into(&self, iter_plane: &mut IterPlane<Self::Deriv>)
     {
-        if self.point_grid().is_nan() {
+        self.compute_on_grid(self.point_grid(), iter_plane);
+    }
+
+    fn compute_mariani_silver(&self, block_size: usize) -> adaptive::AdaptiveRender<Self::Deriv>
+    where
+        Self: Clone,
+    {
+        with_render_pool(|| adaptive::compute_mariani_silver(self, block_size))
+    }
+
+    fn compute_on_grid_direct(
+        &self,
+        point_grid: &PointGrid,
+        iter_plane: &mut IterPlane<Self::Deriv>,
+    )
+    {
+        if point_grid.is_nan() {
             return;
         }
 
+        let views = critical_orbit_views(self);
+        let families: Vec<&Self> = views
+            .as_ref()
+            .map_or_else(|| vec![self], |v| v.iter().collect());
         let orbits = ThreadLocal::new();
 
-        let chunk_size = self.point_grid().res_y / num_cpus::get();
-
-        iter_plane
-            .iter_counts
-            .axis_chunks_iter_mut(Axis(1), chunk_size)
-            .enumerate()
-            .par_bridge()
-            .for_each(|(chunk_idx, mut chunk)| {
-                chunk.indexed_iter_mut().for_each(|((x, local_y), count)| {
-                    let y = chunk_idx * chunk_size + local_y;
-                    let mut orbit = orbits
-                        .get_or(|| self.compute_mode().create_orbit(self))
-                        .borrow_mut();
-
-                    let point = self.point_grid().map_pixel(x, y);
-                    orbit.reset(point);
-                    *count = orbit.run_until_complete();
+        let num_threads = render_threads().unwrap_or_else(num_cpus::get);
+        let chunk_size = (point_grid.res_y / num_threads).max(1);
+
+        with_render_pool(|| {
+            iter_plane
+                .iter_counts
+                .axis_chunks_iter_mut(Axis(1), chunk_size)
+                .enumerate()
+                .par_bridge()
+                .for_each(|(chunk_idx, mut chunk)| {
+                    chunk.indexed_iter_mut().for_each(|((x, local_y), count)| {
+                        let y = chunk_idx * chunk_size + local_y;
+                        let orbits = orbits.get_or(|| {
+                            families
+                                .iter()
+                                .map(|family| family.compute_mode().create_orbit(*family))
+                                .collect::<Vec<_>>()
+                        });
+
+                        let Some(point) = point_grid.sample_point(x, y) else {
+                            *count = PointInfo::Unknown;
+                            return;
+                        };
+                        *count = combine_critical_orbits(orbits.iter().map(|orbit| {
+                            let mut orbit = orbit.borrow_mut();
+                            orbit.reset(point);
+                            orbit.run_until_complete()
+                        }));
+                    });
                 });
-            });
+        });
+    }
+}
+
+/// Pool used for renders with a thread limit, along with its number of threads.
+static RENDER_POOL: Mutex<Option<(usize, Arc<rayon::ThreadPool>)>> = Mutex::new(None);
+
+/// Thread pool with `num_threads` threads, reusing the previous one unless the number of threads
+/// has changed since it was built.
+pub(crate) fn render_pool(
+    num_threads: usize,
+) -> Result<Arc<rayon::ThreadPool>, rayon::ThreadPoolBuildError>
+{
+    let mut cached = RENDER_POOL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some((size, pool)) = cached.as_ref() {
+        if *size == num_threads {
+            return Ok(Arc::clone(pool));
+        }
+    }
+    let pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()?,
+    );
+    *cached = Some((num_threads, Arc::clone(&pool)));
+    Ok(pool)
+}
+
+/// Run a render on a thread pool of the size set with `set_render_threads`, or on rayon's global
+/// pool if no limit is set.
+fn with_render_pool<R, F>(render: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    let Some(num_threads) = render_threads() else {
+        return render();
+    };
+    match render_pool(num_threads) {
+        Ok(pool) => pool.install(render),
+        Err(e) => {
+            println!("Could not create a pool of {num_threads} render threads: {e}");
+            render()
+        }
     }
 }
 
+/// Copies of `family` following each of its critical orbits in turn, if it is set to combine
+/// all of them.
+fn critical_orbit_views<P>(family: &P) -> Option<Vec<P>>
+where
+    P: DynamicalFamily + MarkedPoints,
+{
+    if family.critical_orbit() != CriticalOrbit::All {
+        return None;
+    }
+    let param = family.param_map(family.point_grid().center());
+    let num_critical_points = family.critical_points_child(&param).len();
+    (0..num_critical_points)
+        .map(|index| family.critical_orbit_view(index))
+        .collect()
+}
+
+/// Escape information of a parameter from that of each of its critical orbits. The parameter
+/// escapes as soon as any orbit does; otherwise the first orbit decides.
+fn combine_critical_orbits<D>(infos: impl Iterator<Item = PointInfo<D>>) -> PointInfo<D>
+{
+    infos
+        .reduce(|first, other| match (&first, &other) {
+            (
+                PointInfo::Escaping { potential: a, .. },
+                PointInfo::Escaping { potential: b, .. },
+            ) if b < a => other,
+            (PointInfo::Escaping { .. }, _) => first,
+            (_, PointInfo::Escaping { .. }) => other,
+            _ => first,
+        })
+        .unwrap_or_default()
+}
+
 pub trait Displayable:
     DynamicalFamily + FamilyDefaults + ExternalRays + Equipotential + Computable + MarkedPoints
 {
@@ -0,0 +1,282 @@
+//! Adaptive rendering by Mariani–Silver subdivision, which spends the full iteration budget
+//! only near the boundary of the escape region.
+//!
+//! The image is divided into square blocks, and each block is split in half until the border of
+//! a rectangle agrees on a single escape count, period, or on not settling at all. Pixels inside
+//! a rectangle that escapes or settles on its border still carry their own smooth potential or
+//! multiplier, so they are computed in full. Only rectangles whose border never settles are
+//! flooded: their inside is computed with a small iteration budget, and anything that does not
+//! escape within it is taken to stay bounded. The savings come from interior points that are
+//! slow to settle, which would otherwise run to the full budget.
+//!
+//! Flooding relies on the escape region having no bounded components, as for polynomial
+//! families, so that a rectangle whose border avoids the escape region lies outside it entirely,
+//! up to features thinner than a pixel.
+
+use super::{combine_critical_orbits, critical_orbit_views, DynamicalFamily, EscapeEncoding};
+use crate::orbit::Orbit;
+use dynamo_common::prelude::*;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::cell::RefCell;
+
+/// Ratio of the full iteration budget to the budget spent on the inside of a flooded rectangle.
+const FLOOD_BUDGET_RATIO: IterCount = 16;
+
+type BoxedOrbit<'a, D> = RefCell<Box<dyn Orbit<Outcome = PointInfo<D>> + 'a>>;
+
+/// Escape field of an adaptive render, with a tally of the work it took.
+pub struct AdaptiveRender<D>
+{
+    pub iter_plane: IterPlane<D>,
+    /// Total number of iterations, over all pixels and critical orbits.
+    pub iterations: IterCount,
+    /// Number of pixels inside flooded rectangles, computed with the reduced budget.
+    pub flooded: usize,
+}
+
+/// Whether a pixel computed with the reduced budget may stand in for the full computation.
+const fn is_interior<D>(info: &PointInfo<D>) -> bool
+{
+    !info.is_escaping() && !matches!(info, PointInfo::Unknown | PointInfo::UndefinedParameter)
+}
+
+fn run_orbits<D>(
+    orbits: &[BoxedOrbit<'_, D>],
+    point: Option<Cplx>,
+    iterations: &mut IterCount,
+) -> PointInfo<D>
+{
+    // The pixel is on the pole of the view transform
+    let Some(point) = point else {
+        return PointInfo::Unknown;
+    };
+    combine_critical_orbits(orbits.iter().map(|orbit| {
+        let mut orbit = orbit.borrow_mut();
+        orbit.reset(point);
+        let info = orbit.run_until_complete();
+        *iterations += orbit.iterations();
+        info
+    }))
+}
+
+fn create_orbits<'a, P: EscapeEncoding>(families: &[&'a P]) -> Vec<BoxedOrbit<'a, P::Deriv>>
+{
+    families
+        .iter()
+        .map(|family| family.compute_mode().create_orbit(*family))
+        .collect()
+}
+
+/// What a pixel must share with the rest of the border of a rectangle for the rectangle to be
+/// filled in a Mariani–Silver render.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FillKey
+{
+    EscapeCount(i64, Option<Period>),
+    Period(Period),
+    Bounded,
+}
+
+impl FillKey
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn of<D>(info: &PointInfo<D>) -> Option<Self>
+    {
+        match info {
+            PointInfo::Escaping { potential, phase } => {
+                Some(Self::EscapeCount(potential.floor() as i64, *phase))
+            }
+            PointInfo::Boettcher { potential, .. } | PointInfo::LogDerivative { potential, .. } => {
+                Some(Self::EscapeCount(potential.floor() as i64, None))
+            }
+            PointInfo::Periodic(data) | PointInfo::MarkedPoint { data, .. } => {
+                Some(Self::Period(data.period))
+            }
+            PointInfo::Bounded | PointInfo::Wandering => Some(Self::Bounded),
+            // Distance estimates vary continuously, and pixels without a value never fill
+            _ => None,
+        }
+    }
+}
+
+/// One block of a Mariani–Silver render, with the pixels computed or filled so far.
+struct SubdivisionBlock<'a, P: DynamicalFamily>
+{
+    family: &'a P,
+    orbits: Vec<BoxedOrbit<'a, P::Deriv>>,
+    cheap_orbits: Vec<BoxedOrbit<'a, P::Deriv>>,
+    point_grid: &'a PointGrid,
+    x0: usize,
+    y0: usize,
+    width: usize,
+    pixels: Vec<Option<PointInfo<P::Deriv>>>,
+    iterations: IterCount,
+    filled: usize,
+}
+
+impl<P: DynamicalFamily> SubdivisionBlock<'_, P>
+{
+    const fn index(&self, x: usize, y: usize) -> usize
+    {
+        (y - self.y0) * self.width + (x - self.x0)
+    }
+
+    /// Compute a pixel if it is not already known, and return its fill key.
+    fn key(&mut self, x: usize, y: usize) -> Option<FillKey>
+    {
+        let i = self.index(x, y);
+        let info = self.pixels[i].get_or_insert_with(|| {
+            run_orbits(
+                &self.orbits,
+                self.point_grid.sample_point(x, y),
+                &mut self.iterations,
+            )
+        });
+        FillKey::of(info)
+    }
+
+    /// Compute the rectangle with corners $(x_0, y_0)$ and $(x_1, y_1)$, inclusive. If the border
+    /// and center agree, the inside is computed directly, with the reduced budget if none of
+    /// them settled; otherwise the rectangle is split in half.
+    fn subdivide(&mut self, x0: usize, y0: usize, x1: usize, y1: usize)
+    {
+        if x1 - x0 < 2 || y1 - y0 < 2 {
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    self.key(x, y);
+                }
+            }
+            return;
+        }
+
+        let first = self.key(x0, y0);
+        let mut uniform = first.is_some();
+        for x in x0..=x1 {
+            uniform &= self.key(x, y0) == first;
+            uniform &= self.key(x, y1) == first;
+        }
+        for y in y0 + 1..y1 {
+            uniform &= self.key(x0, y) == first;
+            uniform &= self.key(x1, y) == first;
+        }
+        // A rectangle whose border lies in one escape band may still enclose the slower-escaping
+        // region, if it is large enough to contain all of it
+        uniform = uniform && self.key((x0 + x1) / 2, (y0 + y1) / 2) == first;
+
+        if uniform {
+            let flood = first == Some(FillKey::Bounded);
+            for y in y0 + 1..y1 {
+                for x in x0 + 1..x1 {
+                    let i = self.index(x, y);
+                    if self.pixels[i].is_some() {
+                        continue;
+                    }
+                    if flood {
+                        let info = run_orbits(
+                            &self.cheap_orbits,
+                            self.point_grid.sample_point(x, y),
+                            &mut self.iterations,
+                        );
+                        // A thin filament of the escape region may slip between border pixels
+                        if is_interior(&info) {
+                            self.pixels[i] = Some(info);
+                            self.filled += 1;
+                            continue;
+                        }
+                    }
+                    self.key(x, y);
+                }
+            }
+        } else if x1 - x0 >= y1 - y0 {
+            let mid = (x0 + x1) / 2;
+            self.subdivide(x0, y0, mid, y1);
+            self.subdivide(mid, y0, x1, y1);
+        } else {
+            let mid = (y0 + y1) / 2;
+            self.subdivide(x0, y0, x1, mid);
+            self.subdivide(x0, mid, x1, y1);
+        }
+    }
+}
+
+/// Compute the escape field of `family` on its own grid by Mariani–Silver subdivision, starting
+/// from square blocks of `block_size` pixels. Rectangles whose border pixels all share an
+/// escape count or a period have their inside computed directly, and flooded with the reduced
+/// budget if the border never settles; the others are split in half until they are too thin
+/// to have an inside.
+pub fn compute_mariani_silver<P>(family: &P, block_size: usize) -> AdaptiveRender<P::Deriv>
+where
+    P: DynamicalFamily + EscapeEncoding + Clone,
+{
+    let point_grid = family.point_grid().clone();
+    let mut iter_plane = IterPlane::create(point_grid.clone());
+    if point_grid.is_nan() {
+        return AdaptiveRender {
+            iter_plane,
+            iterations: 0,
+            flooded: 0,
+        };
+    }
+
+    let block_size = block_size.max(3);
+    let mut cheap = family.clone();
+    cheap.set_max_iter((family.max_iter() / FLOOD_BUDGET_RATIO).max(1));
+
+    let full_views = critical_orbit_views(family);
+    let full_families: Vec<&P> = full_views
+        .as_ref()
+        .map_or_else(|| vec![family], |v| v.iter().collect());
+    let cheap_views = critical_orbit_views(&cheap);
+    let cheap_families: Vec<&P> = cheap_views
+        .as_ref()
+        .map_or_else(|| vec![&cheap], |v| v.iter().collect());
+
+    let blocks: Vec<(usize, usize)> = (0..point_grid.res_y)
+        .step_by(block_size)
+        .flat_map(|y0| {
+            (0..point_grid.res_x)
+                .step_by(block_size)
+                .map(move |x0| (x0, y0))
+        })
+        .collect();
+
+    let results: Vec<_> = blocks
+        .par_iter()
+        .map(|&(x0, y0)| {
+            let x1 = (x0 + block_size).min(point_grid.res_x) - 1;
+            let y1 = (y0 + block_size).min(point_grid.res_y) - 1;
+            let width = x1 - x0 + 1;
+            let mut block = SubdivisionBlock {
+                family,
+                orbits: create_orbits(&full_families),
+                cheap_orbits: create_orbits(&cheap_families),
+                point_grid: &point_grid,
+                x0,
+                y0,
+                width,
+                pixels: vec![None; width * (y1 - y0 + 1)],
+                iterations: 0,
+                filled: 0,
+            };
+            block.subdivide(x0, y0, x1, y1);
+            (x0, y0, width, block.pixels, block.iterations, block.filled)
+        })
+        .collect();
+
+    let mut iterations = 0;
+    let mut flooded = 0;
+    for (x0, y0, width, pixels, block_iterations, block_filled) in results {
+        for (i, info) in pixels.into_iter().enumerate() {
+            iter_plane.iter_counts[[x0 + i % width, y0 + i / width]] =
+                info.unwrap_or(PointInfo::Unknown);
+        }
+        iterations += block_iterations;
+        flooded += block_filled;
+    }
+
+    AdaptiveRender {
+        iter_plane,
+        iterations,
+        flooded,
+    }
+}
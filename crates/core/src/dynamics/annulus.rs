@@ -0,0 +1,80 @@
+use dynamo_common::math_utils::slog;
+use dynamo_common::prelude::*;
+
+use crate::orbit::EscapeResult;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Escape criterion for maps of infinite degree, whose orbits can pass close to infinity
+/// without escaping.
+///
+/// Rather than using a single escape radius, an orbit is trapped by the band
+/// $\ln|z| > \text{inner}$: it is considered to have escaped only once a point beyond the
+/// inner edge is mapped past the outer edge, further out than itself. Orbits that graze the
+/// annulus and fall back (e.g. $\Re z \ll 0$ under $\lambda e^z$) are not counted, and escaping
+/// orbits always stop just past the outer edge, which keeps the smoothed escape time stable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EscapeAnnulus
+{
+    /// Lower bound on $\ln|z|$ for a point to be tested.
+    pub inner: Real,
+    /// Lower bound on $\ln|f(z)|$ for a tested point to escape.
+    pub outer: Real,
+}
+impl EscapeAnnulus
+{
+    #[must_use]
+    pub const fn new(inner: Real, outer: Real) -> Self
+    {
+        Self { inner, outer }
+    }
+
+    /// Test whether the orbit through `z` escapes. On escape, the final value is the image of
+    /// `z`, and the iteration count is advanced accordingly, unless the image overflows.
+    #[inline]
+    pub fn escape<D, F>(&self, z: Cplx, map: F, iter: IterCount) -> Option<EscapeResult<Cplx, D>>
+    where
+        F: FnOnce(Cplx) -> Cplx,
+    {
+        if !z.is_finite() {
+            return Some(EscapeResult::Escaped {
+                iters: iter,
+                final_value: z,
+            });
+        }
+
+        let log_z = z.norm().ln();
+        if log_z <= self.inner {
+            return None;
+        }
+
+        let fz = map(z);
+        if !fz.is_finite() {
+            // The image overflowed, so stop at `z` itself, which still has a usable modulus for
+            // smoothing the escape time
+            return Some(EscapeResult::Escaped {
+                iters: iter,
+                final_value: z,
+            });
+        }
+        let log_fz = fz.norm().ln();
+        if log_fz > self.outer && log_fz > log_z {
+            Some(EscapeResult::Escaped {
+                iters: iter + 1,
+                final_value: fz,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Iterated logarithm of the squared outer radius, used as the reference level when
+    /// smoothing escape times. Assumes `outer` is positive.
+    #[must_use]
+    pub fn slog_outer(&self) -> Real
+    {
+        1. + slog(2. * self.outer)
+    }
+}
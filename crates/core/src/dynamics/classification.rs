@@ -0,0 +1,88 @@
+use dynamo_common::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tolerance on $|\lambda| - 1$ below which a cycle is treated as indifferent.
+const INDIFFERENT_TOLERANCE: Real = 1e-8;
+
+/// Largest $q$ for which an indifferent multiplier is tested against $q$-th roots of unity.
+const MAX_PARABOLIC_DENOMINATOR: u32 = 32;
+
+/// Classification of a cycle by its multiplier $\lambda$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Classification
+{
+    /// $|\lambda| < 1$
+    Attracting,
+    /// $|\lambda| > 1$
+    Repelling,
+    /// $\lambda$ is a root of unity
+    Parabolic,
+    /// $|\lambda| = 1$, but not a root of unity (up to numerical precision)
+    Indifferent,
+}
+impl Classification
+{
+    #[must_use]
+    pub fn of(multiplier: Cplx) -> Self
+    {
+        let modulus = multiplier.norm();
+        if modulus < 1. - INDIFFERENT_TOLERANCE {
+            Self::Attracting
+        } else if modulus > 1. + INDIFFERENT_TOLERANCE {
+            Self::Repelling
+        } else if (1..=MAX_PARABOLIC_DENOMINATOR)
+            .any(|q| (multiplier.powu(q) - 1.).norm() < INDIFFERENT_TOLERANCE * Real::from(q))
+        {
+            Self::Parabolic
+        } else {
+            Self::Indifferent
+        }
+    }
+}
+
+impl std::fmt::Display for Classification
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Attracting => write!(f, "attracting"),
+            Self::Repelling => write!(f, "repelling"),
+            Self::Parabolic => write!(f, "parabolic"),
+            Self::Indifferent => write!(f, "indifferent"),
+        }
+    }
+}
+
+/// Fate of the orbit of a single point, as traced by
+/// [`DynamicalFamily::orbit_fate`](super::DynamicalFamily::orbit_fate).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrbitFate
+{
+    /// The orbit escapes at the given iteration.
+    Escapes(IterCount),
+    /// The orbit returns to an earlier point, up to the periodicity tolerance.
+    Cycles(OrbitSchema),
+    /// The orbit neither escapes nor cycles within the iteration limit.
+    Undetermined,
+}
+
+impl std::fmt::Display for OrbitFate
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Escapes(iter) => write!(f, "escapes at iteration {iter}"),
+            Self::Cycles(OrbitSchema { preperiod, period }) => {
+                write!(
+                    f,
+                    "lands on a cycle with preperiod {preperiod}, period {period}"
+                )
+            }
+            Self::Undetermined => write!(f, "undetermined"),
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use super::{
+    DynamicalFamily, EscapeEncoding, ExternalRays, FamilyDefaults, HasJulia,
+    InfinityFirstReturnMap, MarkedPoints,
+};
+use dynamo_common::prelude::*;
+
+/// The composition $g \circ f$ of the maps of two families sharing a parameter. One step of the
+/// composite family is a step of `first` followed by a step of `second`.
+///
+/// The image, parameter map and start point are those of `first`, so that composing a family
+/// with itself gives its second iterate: cycles of even period $2n$ become pairs of cycles of
+/// period $n$, and cycles of odd period keep their period.
+#[derive(Clone, Debug)]
+pub struct Composition<A, B>
+{
+    first: A,
+    second: B,
+}
+
+impl<A, B> Composition<A, B>
+{
+    /// The family $g \circ f$, where $f$ is the map of `first` and $g$ that of `second`.
+    #[must_use]
+    pub const fn new(first: A, second: B) -> Self
+    {
+        Self { first, second }
+    }
+
+    #[must_use]
+    pub const fn first(&self) -> &A
+    {
+        &self.first
+    }
+
+    #[must_use]
+    pub const fn second(&self) -> &B
+    {
+        &self.second
+    }
+}
+
+impl<A, B> DynamicalFamily for Composition<A, B>
+where
+    A: DynamicalFamily,
+    B: DynamicalFamily<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+    type Var = A::Var;
+    type Param = A::Param;
+    type MetaParam = A::MetaParam;
+    type Deriv = A::Deriv;
+
+    #[inline]
+    fn point_grid(&self) -> &PointGrid
+    {
+        self.first.point_grid()
+    }
+
+    #[inline]
+    fn point_grid_mut(&mut self) -> &mut PointGrid
+    {
+        self.first.point_grid_mut()
+    }
+
+    fn with_point_grid(self, point_grid: PointGrid) -> Self
+    {
+        Self {
+            first: self.first.with_point_grid(point_grid),
+            second: self.second,
+        }
+    }
+
+    #[inline]
+    fn max_iter(&self) -> IterCount
+    {
+        self.first.max_iter()
+    }
+
+    #[inline]
+    fn max_iter_mut(&mut self) -> &mut IterCount
+    {
+        self.first.max_iter_mut()
+    }
+
+    #[inline]
+    fn set_max_iter(&mut self, new_max_iter: IterCount)
+    {
+        self.first.set_max_iter(new_max_iter);
+    }
+
+    fn with_max_iter(self, max_iter: IterCount) -> Self
+    {
+        Self {
+            first: self.first.with_max_iter(max_iter),
+            second: self.second,
+        }
+    }
+
+    #[inline]
+    fn compute_mode(&self) -> super::ComputeMode
+    {
+        self.first.compute_mode()
+    }
+
+    #[inline]
+    fn compute_mode_mut(&mut self) -> &mut super::ComputeMode
+    {
+        self.first.compute_mode_mut()
+    }
+
+    #[inline]
+    fn cycle_detection(&self) -> super::CycleDetection
+    {
+        self.first.cycle_detection()
+    }
+
+    #[inline]
+    fn set_cycle_detection(&mut self, cycle_detection: super::CycleDetection)
+    {
+        self.first.set_cycle_detection(cycle_detection);
+    }
+
+    fn name(&self) -> String
+    {
+        format!("({}) ∘ ({})", self.second.name(), self.first.name())
+    }
+
+    fn description(&self) -> String
+    {
+        format!(
+            "The composition $g \\circ f$, where $f$ is the map of {} and $g$ that of {}.",
+            self.first.name(),
+            self.second.name()
+        )
+    }
+
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        self.first.param_symbol()
+    }
+
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        self.first.is_holomorphic() && self.second.is_holomorphic()
+    }
+
+    #[inline]
+    fn min_iter(&self) -> IterCount
+    {
+        self.first.min_iter()
+    }
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        self.first.escape_radius().min(self.second.escape_radius())
+    }
+
+    #[inline]
+    fn periodicity_tolerance(&self) -> Real
+    {
+        self.first.periodicity_tolerance()
+    }
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        self.second.map(self.first.map(z, c), c)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (w, df) = self.first.map_and_multiplier(z, c);
+        let (v, dg) = self.second.map_and_multiplier(w, c);
+        (v, dg * df)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let (w, df_dz, df_dc) = self.first.gradient(z, c);
+        let (v, dg_dw, dg_dc) = self.second.gradient(w, c);
+        (v, dg_dw * df_dz, dg_dw * df_dc + dg_dc)
+    }
+
+    #[inline]
+    fn start_point(&self, point: Cplx, c: &Self::Param) -> Self::Var
+    {
+        self.first.start_point(point, c)
+    }
+
+    #[inline]
+    fn start_point_d(&self, point: Cplx, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        self.first.start_point_d(point, c)
+    }
+
+    #[inline]
+    fn param_map(&self, point: Cplx) -> Self::Param
+    {
+        self.first.param_map(point)
+    }
+
+    #[inline]
+    fn param_map_d(&self, point: Cplx) -> (Self::Param, Self::Deriv)
+    {
+        self.first.param_map_d(point)
+    }
+
+    #[inline]
+    fn get_meta_params(&self) -> Self::MetaParam
+    {
+        self.first.get_meta_params()
+    }
+
+    #[inline]
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.first.get_param()
+    }
+
+    #[inline]
+    fn set_meta_param(&mut self, value: Self::MetaParam)
+    {
+        self.first.set_meta_param(value);
+    }
+
+    #[inline]
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.first.set_param(value);
+    }
+}
+
+impl<A, B> InfinityFirstReturnMap for Composition<A, B>
+where
+    A: InfinityFirstReturnMap,
+    B: InfinityFirstReturnMap<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.first.degree_real() * self.second.degree_real()
+    }
+
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        self.first.degree() * self.second.degree()
+    }
+
+    /// If $f(z) \sim az^d$ and $g(z) \sim bz^e$ near infinity, then $g(f(z)) \sim ba^e z^{de}$.
+    fn escape_coeff_d(&self, c: &Self::Param) -> (Cplx, Cplx)
+    {
+        let (a, da) = self.first.escape_coeff_d(c);
+        let (b, db) = self.second.escape_coeff_d(c);
+        let e = self.second.degree_real();
+        let a_e = a.powf(e);
+        (b * a_e, db * a_e + b * e * a.powf(e - 1.) * da)
+    }
+}
+
+impl<A, B> MarkedPoints for Composition<A, B>
+where
+    A: MarkedPoints + HasJulia,
+    B: MarkedPoints<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+    /// By the chain rule, the critical points of $g \circ f$ are those of $f$ together with the
+    /// preimages under $f$ of those of $g$. The preimages are only found if `first` has inverse
+    /// branches in closed form.
+    fn critical_points_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        let mut critical_points = self.first.critical_points_child(c);
+        critical_points.extend(
+            self.second
+                .critical_points_child(c)
+                .into_iter()
+                .flat_map(|w| self.first.inverse_branches(w, c)),
+        );
+        critical_points
+    }
+}
+
+impl<A, B> EscapeEncoding for Composition<A, B>
+where
+    A: HasJulia + MarkedPoints,
+    B: InfinityFirstReturnMap + MarkedPoints<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+}
+
+impl<A, B> ExternalRays for Composition<A, B>
+where
+    A: InfinityFirstReturnMap,
+    B: InfinityFirstReturnMap<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+}
+
+impl<A, B> FamilyDefaults for Composition<A, B>
+where
+    A: FamilyDefaults,
+    B: InfinityFirstReturnMap<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+    #[inline]
+    fn default_bounds(&self) -> Bounds
+    {
+        self.first.default_bounds()
+    }
+
+    #[inline]
+    fn default_selection(&self) -> Cplx
+    {
+        self.first.default_selection()
+    }
+
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        self.first.recommended_max_iter()
+    }
+}
+
+impl<A, B> HasJulia for Composition<A, B>
+where
+    A: HasJulia,
+    B: InfinityFirstReturnMap<Var = A::Var, Param = A::Param, Deriv = A::Deriv>,
+{
+    #[inline]
+    fn default_max_iter_child(&self) -> IterCount
+    {
+        self.first.default_max_iter_child()
+    }
+
+    #[inline]
+    fn default_bounds_child(&self, point: Cplx, c: &Self::Param) -> Bounds
+    {
+        self.first.default_bounds_child(point, c)
+    }
+}
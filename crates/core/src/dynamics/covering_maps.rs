@@ -107,6 +107,18 @@ where
         self.base_curve.compute_mode_mut()
     }
 
+    #[inline]
+    fn cycle_detection(&self) -> super::CycleDetection
+    {
+        self.base_curve.cycle_detection()
+    }
+
+    #[inline]
+    fn set_cycle_detection(&mut self, cycle_detection: super::CycleDetection)
+    {
+        self.base_curve.set_cycle_detection(cycle_detection);
+    }
+
     fn set_compute_mode(&mut self, compute_mode: super::ComputeMode)
     {
         self.base_curve.set_compute_mode(compute_mode);
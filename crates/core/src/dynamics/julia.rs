@@ -1,15 +1,37 @@
 use super::{ComputeMode, DynamicalFamily, FamilyDefaults, HasJulia, MarkedPoints};
-use crate::macros::basic_plane_impl;
-use crate::orbit::EscapeResult;
+use crate::macros::point_grid_getters;
+use crate::orbit::{CycleDetection, EscapeResult};
 use dynamo_color::{Coloring, IncoloringAlgorithm};
-use dynamo_common::math_utils::newton::error::Error::NanEncountered;
-use dynamo_common::math_utils::newton::find_target_newton_err_d;
+use dynamo_common::math_utils::newton::find_target_newton_iters_d;
+use dynamo_common::math_utils::scaled::ScaledCplx;
 use dynamo_common::prelude::*;
 use dynamo_common::symbolic_dynamics::OrbitSchema;
+use ndarray::Array2;
 use num_traits::{One, Zero};
+use std::sync::OnceLock;
 
+use super::annulus::EscapeAnnulus;
+use super::rays::RayNewtonParams;
 use super::{EscapeEncoding, ExternalRays, InfinityFirstReturnMap, PlaneType};
 
+/// Longest attracting cycle sought when following the critical orbits.
+const CRITICAL_CYCLE_MAX_PERIOD: usize = 256;
+
+/// Seed of the generator choosing inverse branches in [`JuliaSet::ifs_render`].
+const IFS_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Steps of inverse iteration taken before points are recorded, while the backward orbit
+/// settles onto the Julia set.
+const IFS_WARMUP: usize = 32;
+
+/// Step of a xorshift generator, which is plenty for choosing among a few branches.
+const fn next_random(state: u64) -> u64
+{
+    let state = state ^ (state << 13);
+    let state = state ^ (state >> 7);
+    state ^ (state << 17)
+}
+
 #[derive(Clone)]
 pub struct JuliaSet<T>
 where
@@ -23,6 +45,14 @@ where
     pub local_param: T::Param,
     pub parent_selection: Cplx,
     pub compute_mode: ComputeMode,
+    pub cycle_detection: CycleDetection,
+    /// When set, the parameter is frozen and ignores updates from the parent selection.
+    pub param_locked: bool,
+    /// When set, bounded points are colored by the point of an attracting critical cycle that
+    /// their orbit follows, so that each basin component gets its own color.
+    pub critical_basins: bool,
+    /// Attracting cycles reached by the critical orbits, computed on first use.
+    critical_cycles: OnceLock<Vec<Vec<Cplx>>>,
 }
 
 impl<T> JuliaSet<T>
@@ -38,6 +68,7 @@ where
             .new_with_same_height(parent.default_bounds_child(parent_selection, &local_param));
         let min_iter = parent.min_iter();
         let meta_params = parent.get_meta_params();
+        let cycle_detection = parent.cycle_detection();
         Self {
             point_grid,
             max_iter,
@@ -47,9 +78,20 @@ where
             local_param,
             parent_selection,
             compute_mode: ComputeMode::SmoothPotential,
+            cycle_detection,
+            param_locked: false,
+            critical_basins: false,
+            critical_cycles: OnceLock::new(),
         }
     }
 
+    #[must_use]
+    pub fn with_critical_basins(mut self, critical_basins: bool) -> Self
+    {
+        self.set_critical_basins(critical_basins);
+        self
+    }
+
     #[must_use]
     pub fn with_param(mut self, c: T::Param) -> Self
     {
@@ -61,6 +103,40 @@ where
     {
         self.parent.map_and_multiplier(z, &self.local_param)
     }
+
+    /// Render the Julia set by random inverse iteration: a backward orbit that follows a
+    /// randomly chosen inverse branch at each step accumulates on the Julia set, which is much
+    /// faster than escape time for the boundary of a connected Julia set.
+    ///
+    /// Returns the number of visits to each pixel, indexed by image position as given by
+    /// [`PointGrid::locate_point_safe`]. Nothing is visited if the family has no known inverse
+    /// branches.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn ifs_render(&self, iterations: usize) -> Array2<u32>
+    {
+        let grid = &self.point_grid;
+        let mut hits = Array2::zeros(grid.shape());
+        let mut state = IFS_SEED;
+        // Any start point other than the few exceptional points is attracted to the Julia set
+        let mut z = T::Var::from(Cplx::new(1., 1.));
+
+        for step in 0..IFS_WARMUP + iterations {
+            let mut branches = self.parent.inverse_branches(z, &self.local_param);
+            if branches.is_empty() {
+                break;
+            }
+            state = next_random(state);
+            z = branches.swap_remove((state % branches.len() as u64) as usize);
+
+            if step < IFS_WARMUP {
+                continue;
+            }
+            if let Some(pixel) = grid.locate_point_safe(z.into()) {
+                hits[pixel] += 1;
+            }
+        }
+        hits
+    }
 }
 
 impl<T> From<T> for JuliaSet<T>
@@ -83,7 +159,34 @@ where
     type Param = NoParam;
     type MetaParam = ParamStack<T::MetaParam, T::Param>;
     type Deriv = T::Deriv;
-    basic_plane_impl!();
+    point_grid_getters!();
+
+    #[inline]
+    fn max_iter(&self) -> IterCount
+    {
+        self.max_iter
+    }
+
+    #[inline]
+    fn max_iter_mut(&mut self) -> &mut IterCount
+    {
+        self.critical_cycles.take();
+        &mut self.max_iter
+    }
+
+    #[inline]
+    fn set_max_iter(&mut self, new_max_iter: IterCount)
+    {
+        self.max_iter = new_max_iter;
+        self.critical_cycles.take();
+    }
+
+    #[inline]
+    fn with_max_iter(mut self, max_iter: IterCount) -> Self
+    {
+        self.set_max_iter(max_iter);
+        self
+    }
 
     #[inline]
     fn map(&self, z: Self::Var, _c: &Self::Param) -> Self::Var
@@ -110,6 +213,61 @@ where
         self.parent.escape_radius()
     }
 
+    #[inline]
+    fn escape_annulus(&self) -> Option<EscapeAnnulus>
+    {
+        self.parent.escape_annulus()
+    }
+
+    #[inline]
+    fn set_escape_annulus_enabled(&mut self, enabled: bool)
+    {
+        self.parent.set_escape_annulus_enabled(enabled);
+    }
+
+    #[inline]
+    fn scaled_iteration(&self) -> bool
+    {
+        self.parent.scaled_iteration()
+    }
+
+    #[inline]
+    fn set_scaled_iteration(&mut self, scaled_iteration: bool)
+    {
+        self.parent.set_scaled_iteration(scaled_iteration);
+    }
+
+    #[inline]
+    fn runtime_degree(&self) -> Option<Real>
+    {
+        self.parent.runtime_degree()
+    }
+
+    #[inline]
+    fn set_runtime_degree(&mut self, degree: Real)
+    {
+        self.parent.set_runtime_degree(degree);
+        self.critical_cycles.take();
+    }
+
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        self.parent.is_holomorphic()
+    }
+
+    #[inline]
+    fn map_scaled(&self, z: ScaledCplx, _c: &Self::Param) -> Option<ScaledCplx>
+    {
+        self.parent.map_scaled(z, &self.local_param)
+    }
+
+    #[inline]
+    fn fundamental_domain(&self) -> Option<Bounds>
+    {
+        self.parent.fundamental_domain_child(&self.local_param)
+    }
+
     #[inline]
     fn min_iter(&self) -> IterCount
     {
@@ -160,6 +318,7 @@ where
     fn cycle_active_plane(&mut self)
     {
         self.parent.cycle_active_plane();
+        self.critical_cycles.take();
     }
 
     #[inline]
@@ -173,12 +332,40 @@ where
     {
         self.meta_params = meta_params;
         self.local_param = local_param;
+        self.critical_cycles.take();
     }
 
     #[inline]
     fn set_param(&mut self, local_param: T::Param)
     {
-        self.local_param = local_param;
+        if !self.param_locked {
+            self.local_param = local_param;
+            self.critical_cycles.take();
+        }
+    }
+
+    #[inline]
+    fn param_locked(&self) -> bool
+    {
+        self.param_locked
+    }
+
+    #[inline]
+    fn set_param_locked(&mut self, locked: bool)
+    {
+        self.param_locked = locked;
+    }
+
+    #[inline]
+    fn critical_basins(&self) -> bool
+    {
+        self.critical_basins
+    }
+
+    #[inline]
+    fn set_critical_basins(&mut self, critical_basins: bool)
+    {
+        self.critical_basins = critical_basins;
     }
 
     #[inline]
@@ -215,6 +402,12 @@ where
         self.parent.description()
     }
 
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "z"
+    }
+
     #[inline]
     fn periodicity_tolerance(&self) -> Real
     {
@@ -451,6 +644,19 @@ where
         self.parent.critical_points_child(&self.local_param)
     }
 
+    #[inline]
+    fn critical_orbit_multiplier(&self) -> Option<Cplx>
+    {
+        self.parent
+            .critical_orbit_multiplier_child(&self.local_param)
+    }
+
+    #[inline]
+    fn critical_values_child(&self, _param: &Self::Param) -> Vec<Self::Var>
+    {
+        self.parent.critical_values_child(&self.local_param)
+    }
+
     #[inline]
     fn cycles_child(&self, _param: &Self::Param, period: Period) -> Vec<Self::Var>
     {
@@ -463,6 +669,12 @@ where
         self.parent.cycles_child(&self.local_param, period)
     }
 
+    #[inline]
+    fn cycle_polynomial(&self, _param: &Self::Param, period: Period) -> Vec<Cplx>
+    {
+        self.parent.cycle_polynomial(&self.local_param, period)
+    }
+
     #[inline]
     fn precycles(&self, orbit_schema: OrbitSchema) -> Vec<Self::Var>
     {
@@ -486,6 +698,12 @@ where
         self.parent.degree()
     }
 
+    #[inline]
+    fn integer_degree(&self) -> Option<AngleNum>
+    {
+        self.parent.integer_degree()
+    }
+
     #[inline]
     fn escaping_period(&self) -> Period
     {
@@ -506,6 +724,85 @@ where
     }
 }
 
+impl<P: EscapeEncoding + HasJulia> JuliaSet<P>
+{
+    /// Attracting cycles reached by the orbits of the critical points, each listed once.
+    fn critical_cycles(&self) -> &[Vec<Cplx>]
+    {
+        self.critical_cycles.get_or_init(|| {
+            let tolerance = self.periodicity_tolerance();
+            let mut cycles: Vec<Vec<Cplx>> = Vec::new();
+            for z in self.parent.critical_points_child(&self.local_param) {
+                let Some(cycle) = self.attracting_cycle(z) else {
+                    continue;
+                };
+                if !cycles
+                    .iter()
+                    .flatten()
+                    .any(|w| (cycle[0] - w).norm_sqr() < tolerance)
+                {
+                    cycles.push(cycle);
+                }
+            }
+            cycles
+        })
+    }
+
+    /// Follow the orbit of $z$ for `max_iter` steps, and return the cycle that it has settled
+    /// on, if any.
+    fn attracting_cycle(&self, mut z: P::Var) -> Option<Vec<Cplx>>
+    {
+        for iter in 0..self.max_iter {
+            if self.stop_condition(z, &NoParam, iter).is_some() {
+                return None;
+            }
+            z = self.map(z, &NoParam);
+        }
+
+        let tolerance = self.periodicity_tolerance();
+        let start: Cplx = z.into();
+        let mut cycle = vec![start];
+        for _ in 1..CRITICAL_CYCLE_MAX_PERIOD {
+            z = self.map(z, &NoParam);
+            let w: Cplx = z.into();
+            if (w - start).norm_sqr() < tolerance {
+                return Some(cycle);
+            }
+            cycle.push(w);
+        }
+        None
+    }
+
+    /// Identify the point of an attracting critical cycle that $f^n(z_0) = z$ lies next to,
+    /// where $n$ is the preperiod, and mark the starting point $z_0$ by the cycle point that its
+    /// orbit follows. Falls back to the parent's encoding if $z$ is not near any such cycle.
+    fn identify_critical_basin(
+        &self,
+        z: Cplx,
+        info: PointInfoPeriodic<P::Deriv>,
+    ) -> Option<PointInfo<P::Deriv>>
+    {
+        let cycles = self.critical_cycles();
+        let num_point_classes = cycles.iter().map(Vec::len).sum();
+        let tolerance = self.periodicity_tolerance();
+
+        let mut offset = 0;
+        for cycle in cycles {
+            let period = cycle.len();
+            if let Some(index) = cycle.iter().position(|w| (z - w).norm_sqr() < tolerance) {
+                let phase = (index + period - info.preperiod as usize % period) % period;
+                return Some(PointInfo::MarkedPoint {
+                    data: info,
+                    class_id: PointClassId::from(offset + phase),
+                    num_point_classes,
+                });
+            }
+            offset += period;
+        }
+        None
+    }
+}
+
 impl<P: EscapeEncoding + HasJulia> EscapeEncoding for JuliaSet<P>
 {
     fn encode_escape_result(
@@ -515,6 +812,13 @@ impl<P: EscapeEncoding + HasJulia> EscapeEncoding for JuliaSet<P>
         NoParam: &Self::Param,
     ) -> PointInfo<Self::Deriv>
     {
+        if self.critical_basins {
+            if let EscapeResult::Periodic { info, final_value } = &result {
+                if let Some(basin) = self.identify_critical_basin((*final_value).into(), *info) {
+                    return basin;
+                }
+            }
+        }
         self.parent
             .encode_escape_result(result, start, &self.local_param)
     }
@@ -526,7 +830,11 @@ where
 {
     #[allow(clippy::similar_names)]
     #[allow(clippy::many_single_char_names)]
-    fn external_ray_helper(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+    fn external_ray_helper(
+        &self,
+        angle: RationalAngle,
+        params: RayNewtonParams,
+    ) -> Option<Vec<Cplx>>
     {
         const R: Real = 16.0;
         let escape_radius_log2 = R.log2() * self.degree_real().abs();
@@ -537,7 +845,7 @@ where
         }
 
         let pixel_width = self.point_grid().pixel_width() * 0.03;
-        let error = self.point_grid().res_x as Real * 1e-8;
+        let error = params.error(self.point_grid());
 
         // let base_point = escape_radius * angle.to_circle();
         // Arbitrary starting guess that is likely to escape
@@ -591,7 +899,13 @@ where
             };
 
             for target in targets {
-                match find_target_newton_err_d(fk_and_dfk, t_curr, target, error) {
+                match find_target_newton_iters_d(
+                    fk_and_dfk,
+                    t_curr,
+                    target,
+                    error,
+                    params.max_iters,
+                ) {
                     Ok((sol, t_k, d_k)) => {
                         // dbg!(target, sol);
                         t_curr = sol;
@@ -607,10 +921,9 @@ where
                             return Some(t_list);
                         }
                     }
-                    Err(NanEncountered) => {
+                    Err(_) => {
                         return Some(t_list);
                     }
-                    _ => {}
                 }
             }
             // target_angle *= deg;
@@ -0,0 +1,110 @@
+use dynamo_common::prelude::*;
+
+/// Bound on the squared final Newton step used when no tolerance is set, per pixel of
+/// horizontal resolution.
+const DEFAULT_RAY_ERROR_PER_COLUMN: Real = 1e-8;
+
+/// Settings for the Newton solves used to trace external rays.
+///
+/// Each point on a ray is found by solving $f^k(t) = w$ for a target $w$, starting from the
+/// previous point. A solve is accepted once its final step is smaller than `tolerance` pixels,
+/// or by default once its squared step is below $10^{-8}$ times the horizontal resolution; if
+/// it is still moving after `max_iters` steps, the ray is cut off at that point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayNewtonParams
+{
+    /// Maximum number of Newton steps for each point on the ray.
+    pub max_iters: usize,
+    /// Largest accepted final step, in units of the pixel width.
+    pub tolerance: Option<Real>,
+}
+impl Default for RayNewtonParams
+{
+    fn default() -> Self
+    {
+        Self {
+            max_iters: NEWTON_MAX_ITERS,
+            tolerance: None,
+        }
+    }
+}
+impl RayNewtonParams
+{
+    #[must_use]
+    pub fn with_max_iters(mut self, max_iters: usize) -> Self
+    {
+        self.max_iters = max_iters;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: Real) -> Self
+    {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Squared step size below which a solve is accepted on the given grid.
+    #[must_use]
+    pub fn error(&self, point_grid: &PointGrid) -> Real
+    {
+        self.tolerance.map_or(
+            point_grid.res_x as Real * DEFAULT_RAY_ERROR_PER_COLUMN,
+            |tolerance| {
+                let step = tolerance * point_grid.pixel_width();
+                step * step
+            },
+        )
+    }
+}
+
+/// External rays landing at a common point, as found by [`group_landings`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayLanding
+{
+    /// Landing point of the first ray of the group.
+    pub point: Cplx,
+    /// Co-landing angles, in increasing order.
+    pub angles: Vec<RationalAngle>,
+}
+
+/// Group rays whose landing points are within `tolerance` of each other, directly or through
+/// a chain of other rays. Groups are sorted by their smallest angle.
+#[must_use]
+pub fn group_landings(mut landings: Vec<(RationalAngle, Cplx)>, tolerance: Real)
+    -> Vec<RayLanding>
+{
+    landings.sort_by_key(|(angle, _)| *angle);
+
+    // Union-find over the landing points
+    let mut root: Vec<usize> = (0..landings.len()).collect();
+    let find = |root: &[usize], mut i: usize| {
+        while root[i] != i {
+            i = root[i];
+        }
+        i
+    };
+    for i in 0..landings.len() {
+        for j in 0..i {
+            if (landings[i].1 - landings[j].1).norm() < tolerance {
+                let (ri, rj) = (find(&root, i), find(&root, j));
+                root[ri.max(rj)] = ri.min(rj);
+            }
+        }
+    }
+
+    let mut groups: Vec<RayLanding> = Vec::new();
+    let mut group_of_root = vec![usize::MAX; landings.len()];
+    for (i, &(angle, point)) in landings.iter().enumerate() {
+        let r = find(&root, i);
+        if group_of_root[r] == usize::MAX {
+            group_of_root[r] = groups.len();
+            groups.push(RayLanding {
+                point,
+                angles: vec![],
+            });
+        }
+        groups[group_of_root[r]].angles.push(angle);
+    }
+    groups
+}
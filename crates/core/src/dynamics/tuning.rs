@@ -0,0 +1,61 @@
+use dynamo_common::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Affine approximation to the Douady-Hubbard tuning map, sending the base Mandelbrot set
+/// onto the small copy whose main component has the given center and period.
+///
+/// The scale is the usual size estimate $\lambda = 1/(\beta \ell^2)$, where
+/// $\ell = \prod_{k=1}^{p-1} f'(z_k)$ and $\beta = \sum_{k=0}^{p-1} 1/\ell_k$ over partial
+/// products along the critical orbit. Its argument captures the orientation of the copy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tuning
+{
+    pub center: Cplx,
+    pub period: Period,
+    pub scale: Cplx,
+}
+impl Tuning
+{
+    /// Map a point of the base set into the small copy.
+    #[must_use]
+    pub fn tune(&self, c: Cplx) -> Cplx
+    {
+        self.center + self.scale * c
+    }
+
+    /// Map a point of the small copy back to the base set.
+    #[must_use]
+    pub fn untune(&self, c: Cplx) -> Cplx
+    {
+        (c - self.center) / self.scale
+    }
+
+    /// Image of a rectangle in the base set, as an axis-aligned bounding box.
+    #[must_use]
+    pub fn tune_bounds(&self, bounds: &Bounds) -> Bounds
+    {
+        let corners = [
+            Cplx::new(bounds.min_x, bounds.min_y),
+            Cplx::new(bounds.min_x, bounds.max_y),
+            Cplx::new(bounds.max_x, bounds.min_y),
+            Cplx::new(bounds.max_x, bounds.max_y),
+        ]
+        .map(|z| self.tune(z));
+
+        Bounds {
+            min_x: corners.iter().map(|z| z.re).fold(Real::INFINITY, Real::min),
+            max_x: corners
+                .iter()
+                .map(|z| z.re)
+                .fold(Real::NEG_INFINITY, Real::max),
+            min_y: corners.iter().map(|z| z.im).fold(Real::INFINITY, Real::min),
+            max_y: corners
+                .iter()
+                .map(|z| z.im)
+                .fold(Real::NEG_INFINITY, Real::max),
+        }
+    }
+}
@@ -4,6 +4,8 @@ use dynamo_common::math_utils::newton::error::Error as NewtonError;
 pub enum FindPointError<T>
 {
     PeriodIsZero,
+    PreperiodIsZero,
+    NotParameterPlane,
     NewtonError(NewtonError<T>),
 }
 
@@ -87,4 +87,68 @@ mod tests
         assert_eq!(out2, val2);
         assert_eq!(out3, val3);
     }
+
+    #[test]
+    fn parse_angle_formats()
+    {
+        let third = RationalAngle::new(1, 3);
+        assert_eq!("1/3".parse::<RationalAngle>().unwrap(), third);
+        assert_eq!("0.\\overline{01}".parse::<RationalAngle>().unwrap(), third);
+        assert_eq!("0.(01)".parse::<RationalAngle>().unwrap(), third);
+        assert_eq!(
+            "0.011(10)".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(11, 24)
+        );
+        assert_eq!(
+            "0b0.011".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(3, 8)
+        );
+        assert_eq!(
+            " 12345/1000003 ".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(12345, 1_000_003)
+        );
+
+        // Decimals are exact, unless a trailing "..." asks for the simplest fraction that rounds
+        // to them
+        assert_eq!(
+            "0.1".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(1, 10)
+        );
+        assert_eq!(
+            "0.25".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(1, 4)
+        );
+        assert_eq!(
+            "0.333333".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(333_333, 1_000_000)
+        );
+        let decimal = "0.333333...".parse::<RationalAngle>().unwrap();
+        let value = *decimal.numer() as f64 / *decimal.denom() as f64;
+        assert!((value - 0.333333).abs() < 5e-7);
+        assert_eq!(decimal, third);
+        assert_eq!(
+            "0.1...".parse::<RationalAngle>().unwrap(),
+            RationalAngle::new(1, 7)
+        );
+
+        for malformed in ["", "1/0", "0.(12)", "0.3.3", "1/3x", "0.1234567890123456"] {
+            let err = malformed.parse::<RationalAngle>().unwrap_err();
+            assert!(!err.to_string().is_empty(), "{malformed}");
+        }
+    }
+
+    #[test]
+    fn render_pool_reused()
+    {
+        use crate::dynamics::render_pool;
+        use std::sync::Arc;
+
+        let pool = render_pool(2).unwrap();
+        assert_eq!(pool.current_num_threads(), 2);
+        assert!(Arc::ptr_eq(&pool, &render_pool(2).unwrap()));
+
+        let resized = render_pool(3).unwrap();
+        assert_eq!(resized.current_num_threads(), 3);
+        assert!(!Arc::ptr_eq(&pool, &resized));
+    }
 }
@@ -9,6 +9,7 @@ macro_rules! fractal_impl {
                 point_grid,
                 max_iter: 1024,
                 compute_mode: ComputeMode::default(),
+                cycle_detection: CycleDetection::default(),
             }
         }
     };
@@ -21,6 +22,7 @@ macro_rules! fractal_impl {
                 point_grid,
                 max_iter: 1024,
                 compute_mode: ComputeMode::default(),
+                cycle_detection: CycleDetection::default(),
                 $param_name: $param_value,
             }
         }
@@ -34,6 +36,7 @@ macro_rules! fractal_impl {
                 point_grid,
                 max_iter: 1024,
                 compute_mode: ComputeMode::default(),
+                cycle_detection: CycleDetection::default(),
                 $param_name: $param_value,
             }
         }
@@ -79,6 +82,18 @@ macro_rules! point_grid_getters {
         {
             self.compute_mode = compute_mode;
         }
+
+        #[inline]
+        fn cycle_detection(&self) -> CycleDetection
+        {
+            self.cycle_detection
+        }
+
+        #[inline]
+        fn set_cycle_detection(&mut self, cycle_detection: CycleDetection)
+        {
+            self.cycle_detection = cycle_detection;
+        }
     };
 }
 
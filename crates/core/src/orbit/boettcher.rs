@@ -0,0 +1,98 @@
+use super::{CycleDetected, EscapeResult, Orbit};
+use crate::dynamics::{EscapeEncoding, InfinityFirstReturnMap};
+use dynamo_common::prelude::*;
+
+/// An orbit that records the external angle of the selection on escape, in addition to the
+/// smooth potential.
+///
+/// The Böttcher coordinate conjugates the map near infinity to $z \mapsto z^d$, so the argument
+/// of the escaped iterate $z_n$ is $d^n$ times the external angle. Dividing it by $d$ leaves
+/// $d$ choices, and each step back along the orbit picks the one closest to the argument of the
+/// earlier iterate. The potential and the angle are conformal coordinates on the escape region,
+/// in which a texture can be laid out.
+pub struct BoettcherCoordinate<'a, P: EscapeEncoding>
+{
+    family: &'a P,
+    inner: CycleDetected<'a, P>,
+}
+
+impl<'a, P: EscapeEncoding> BoettcherCoordinate<'a, P>
+{
+    pub fn new(family: &'a P) -> Self
+    {
+        Self {
+            family,
+            inner: CycleDetected::new(family).with_arguments(),
+        }
+    }
+}
+
+impl<P: EscapeEncoding> Orbit for BoettcherCoordinate<'_, P>
+{
+    type Outcome = PointInfo<P::Deriv>;
+
+    fn reset(&mut self, selection: Cplx)
+    {
+        self.inner.reset(selection);
+    }
+
+    fn iterations(&self) -> IterCount
+    {
+        self.inner.iter
+    }
+
+    fn run_until_complete(&mut self) -> Self::Outcome
+    {
+        let info = self.inner.run_until_complete();
+        let (
+            PointInfo::Escaping { potential, .. },
+            Some(EscapeResult::Escaped { final_value, .. }),
+        ) = (&info, &self.inner.state)
+        else {
+            return info;
+        };
+        let z: Cplx = (*final_value).into();
+        if !z.is_finite() {
+            return info;
+        }
+        let family = self.family;
+        // Angles are only defined for maps of whole degree fixing infinity
+        let (Some(degree), 1) = (family.integer_degree(), family.escaping_period()) else {
+            return info;
+        };
+        let Some(arguments) = self.inner.arguments.as_deref() else {
+            return info;
+        };
+        // The first iterates of a parameter are not yet large enough to follow its angle
+        let first = (family.escaping_phase() as usize).min(arguments.len());
+        let Some(angle) = external_angle(&arguments[first..], degree) else {
+            return info;
+        };
+        PointInfo::Boettcher {
+            potential: *potential,
+            angle,
+        }
+    }
+}
+
+/// External angle, in turns, of an orbit with iterates of the given arguments under a map of
+/// the given degree near infinity.
+#[allow(clippy::cast_precision_loss)]
+fn external_angle(arguments: &[Real], degree: AngleNum) -> Option<Real>
+{
+    let (&last, earlier) = arguments.split_last()?;
+    let turn_distance = |a: Real, b: Real| {
+        let d = (a - b).rem_euclid(1.);
+        d.min(1. - d)
+    };
+    let angle = earlier
+        .iter()
+        .rev()
+        .fold(last.rem_euclid(1.), |angle, &argument| {
+            (0..degree)
+                .map(|branch| (angle + branch as Real) / degree as Real)
+                .min_by(|a, b| turn_distance(*a, argument).total_cmp(&turn_distance(*b, argument)))
+                .unwrap_or(angle)
+        });
+    Some(angle)
+}
@@ -145,8 +145,17 @@ impl<P: EscapeEncoding> Orbit for DistanceEstimation<'_, P>
         self.iter = 0;
     }
 
+    fn iterations(&self) -> IterCount
+    {
+        self.iter
+    }
+
     fn run_until_complete(&mut self) -> Self::Outcome
     {
+        // The selection is on a pole of the parameter map
+        if !self.param.is_finite() {
+            return PointInfo::UndefinedParameter;
+        }
         if let Some(res) = self.family.early_bailout(self.z_fast, &self.param) {
             return res;
         }
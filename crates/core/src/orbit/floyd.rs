@@ -1,12 +1,30 @@
-use super::{EscapeResult, Orbit};
-use crate::{dynamics::EscapeEncoding, prelude::DynamicalFamily};
+use super::{CycleDetection, EscapeResult, Orbit};
+use crate::{
+    dynamics::{EscapeEncoding, InfinityFirstReturnMap},
+    prelude::DynamicalFamily,
+};
+use dynamo_common::math_utils::scaled::ScaledCplx;
 use dynamo_common::prelude::*;
 use num_traits::One;
 
+/// An orbit that stops once it escapes or lands on a cycle.
+///
+/// Cycles are found by comparing the orbit against a saved iterate, chosen according to the
+/// [`CycleDetection`] strategy.
 pub struct CycleDetected<'a, P: DynamicalFamily>
 {
     family: &'a P,
     periodicity_tolerance: Real,
+    detection: CycleDetection,
+    /// Length of the current search window in Brent's method.
+    power: IterCount,
+    /// Number of iterates compared against the saved one in the current window.
+    lambda: IterCount,
+    /// Sum of $\log|f'|$ along the orbit so far, if it is being tracked.
+    pub log_derivative: Option<Real>,
+    /// Arguments of the iterates so far as fractions of a turn, starting from $z_0$, if they are
+    /// being recorded.
+    pub arguments: Option<Vec<Real>>,
     pub param: P::Param,
     pub z_init: P::Var,
     pub z_slow: P::Var,
@@ -24,6 +42,11 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
             family,
             param: P::Param::default(),
             periodicity_tolerance: family.periodicity_tolerance(),
+            detection: family.cycle_detection(),
+            power: 1,
+            lambda: 0,
+            log_derivative: None,
+            arguments: None,
             z_init: P::Var::default(),
             z_slow: P::Var::default(),
             z_fast: P::Var::default(),
@@ -33,6 +56,31 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
         }
     }
 
+    /// Use a particular cycle detection strategy, rather than the family's own.
+    #[must_use]
+    pub const fn with_cycle_detection(mut self, detection: CycleDetection) -> Self
+    {
+        self.detection = detection;
+        self
+    }
+
+    /// Add up $\log|f'|$ along the orbit as it is iterated, in [`Self::log_derivative`].
+    #[must_use]
+    pub const fn with_log_derivative(mut self) -> Self
+    {
+        self.log_derivative = Some(0.);
+        self
+    }
+
+    /// Record the argument of each iterate in [`Self::arguments`], from which the external angle
+    /// of an escaping orbit can be recovered.
+    #[must_use]
+    pub fn with_arguments(mut self) -> Self
+    {
+        self.arguments = Some(vec![]);
+        self
+    }
+
     /// Initialize an orbit. Should only be called once, before running any computations.
     #[must_use]
     pub fn init(mut self, selection: Cplx) -> Self
@@ -44,9 +92,22 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
         self.z_init = z;
         self.z_slow = z;
         self.z_fast = z;
+        if let Some(arguments) = &mut self.arguments {
+            arguments.clear();
+        }
+        self.record_argument();
         self
     }
 
+    #[inline]
+    fn record_argument(&mut self)
+    {
+        if let Some(arguments) = &mut self.arguments {
+            let z: Cplx = self.z_fast.into();
+            arguments.push(z.arg() / TAU);
+        }
+    }
+
     #[inline]
     fn apply_map_to_slow(&mut self)
     {
@@ -56,7 +117,43 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
     #[inline]
     fn apply_map_to_fast(&mut self)
     {
-        self.z_fast = self.family.map(self.z_fast, &self.param);
+        if let Some(log_derivative) = &mut self.log_derivative {
+            let (z, df_dz) = self.family.map_and_multiplier(self.z_fast, &self.param);
+            *log_derivative += df_dz.norm().ln();
+            self.z_fast = z;
+        } else {
+            self.z_fast = self.family.map(self.z_fast, &self.param);
+        }
+        self.record_argument();
+    }
+
+    /// Advance the orbit by one iteration, checking for escape and periodicity.
+    fn step(&mut self)
+    {
+        self.iter += 1;
+        match self.detection {
+            CycleDetection::Floyd => {
+                if self.iter % 2 == 1 {
+                    self.apply_map_to_slow();
+                    self.apply_map_to_fast();
+                    self.enforce_stop_condition();
+                } else {
+                    self.apply_map_to_fast();
+                    self.check_periodicity();
+                }
+            }
+            CycleDetection::Brent => {
+                self.apply_map_to_fast();
+                self.lambda += 1;
+                self.check_periodicity();
+                // Save the current iterate, and double the length of the next window
+                if self.lambda == self.power {
+                    self.z_slow = self.z_fast;
+                    self.power *= 2;
+                    self.lambda = 0;
+                }
+            }
+        }
     }
 
     #[inline]
@@ -114,25 +211,57 @@ impl<'a, P: DynamicalFamily> CycleDetected<'a, P>
     }
 }
 
+impl<P: EscapeEncoding> CycleDetected<'_, P>
+{
+    /// Continue from the last representable iterate `z` in extended exponent range, after the
+    /// next iterate overflowed.
+    fn escape_scaled(&self, z: P::Var) -> Option<EscapeResult<P::Var, P::Deriv>>
+    {
+        let log_radius = self.family.escape_radius().ln();
+        let mut w = ScaledCplx::from(z.into());
+        for iters in self.iter..=self.family.max_iter() {
+            w = self.family.map_scaled(w, &self.param)?;
+            if w.norm_sqr_ln() > log_radius {
+                let (iters, final_value) = self.family.rescale_escaped(iters, w, &self.param)?;
+                return Some(EscapeResult::Escaped { iters, final_value });
+            }
+        }
+        None
+    }
+
+    /// Whether the orbit escaped to a value that `f64` cannot represent.
+    fn overflowed(&self) -> bool
+    {
+        matches!(
+            &self.state,
+            Some(EscapeResult::Escaped { final_value, .. })
+                if !Into::<Cplx>::into(*final_value).is_finite()
+        )
+    }
+}
+
 impl<P: EscapeEncoding> Orbit for CycleDetected<'_, P>
 {
     type Outcome = PointInfo<P::Deriv>;
 
     fn run_until_complete(&mut self) -> Self::Outcome
     {
+        // The selection is on a pole of the parameter map
+        if !self.param.is_finite() {
+            return PointInfo::UndefinedParameter;
+        }
         if let Some(res) = self.family.early_bailout(self.z_fast, &self.param) {
             return res;
         }
 
+        let scaled = self.family.scaled_iteration();
         while self.state.is_none() {
-            self.iter += 1;
-            if self.iter % 2 == 1 {
-                self.apply_map_to_slow();
-                self.apply_map_to_fast();
-                self.enforce_stop_condition();
-            } else {
-                self.apply_map_to_fast();
-                self.check_periodicity();
+            let z_prev = self.z_fast;
+            self.step();
+            if scaled && self.overflowed() {
+                if let Some(state) = self.escape_scaled(z_prev) {
+                    self.state = Some(state);
+                }
             }
         }
         #[allow(clippy::unwrap_used)]
@@ -151,6 +280,18 @@ impl<P: EscapeEncoding> Orbit for CycleDetected<'_, P>
         self.z_slow = z;
         self.z_fast = z;
         self.iter = 0;
+        self.power = 1;
+        self.lambda = 0;
+        self.log_derivative = self.log_derivative.map(|_| 0.);
+        if let Some(arguments) = &mut self.arguments {
+            arguments.clear();
+        }
+        self.record_argument();
+    }
+
+    fn iterations(&self) -> IterCount
+    {
+        self.iter
     }
 }
 
@@ -162,15 +303,7 @@ impl<P: DynamicalFamily> Iterator for CycleDetected<'_, P>
     {
         if self.state.is_none() {
             let retval = self.z_fast;
-            self.iter += 1;
-            if self.iter % 2 == 1 {
-                self.apply_map_to_slow();
-                self.apply_map_to_fast();
-                self.enforce_stop_condition();
-            } else {
-                self.apply_map_to_fast();
-                self.check_periodicity();
-            }
+            self.step();
             Some((retval, self.state.clone()))
         } else if self.running {
             self.running = false;
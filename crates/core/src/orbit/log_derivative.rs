@@ -0,0 +1,58 @@
+use super::{CycleDetected, EscapeResult, Orbit};
+use crate::dynamics::EscapeEncoding;
+use dynamo_common::prelude::*;
+
+/// An orbit that records the sum of $\log|f'(z_k)|$ over its iterates on escape, in addition to
+/// the smooth potential.
+///
+/// Orbits of transcendental maps escape within a few iterations, so their potentials fall in a
+/// narrow range. The accumulated derivative still varies by many orders of magnitude across the
+/// escape region, which spreads its colors over more bands.
+pub struct LogDerivative<'a, P: EscapeEncoding>
+{
+    inner: CycleDetected<'a, P>,
+}
+
+impl<'a, P: EscapeEncoding> LogDerivative<'a, P>
+{
+    pub fn new(family: &'a P) -> Self
+    {
+        Self {
+            inner: CycleDetected::new(family).with_log_derivative(),
+        }
+    }
+}
+
+impl<P: EscapeEncoding> Orbit for LogDerivative<'_, P>
+{
+    type Outcome = PointInfo<P::Deriv>;
+
+    fn reset(&mut self, selection: Cplx)
+    {
+        self.inner.reset(selection);
+    }
+
+    fn iterations(&self) -> IterCount
+    {
+        self.inner.iter
+    }
+
+    fn run_until_complete(&mut self) -> Self::Outcome
+    {
+        let info = self.inner.run_until_complete();
+        let (PointInfo::Escaping { potential, phase }, Some(EscapeResult::Escaped { .. })) =
+            (&info, &self.inner.state)
+        else {
+            return info;
+        };
+        // The derivative of the last iterates may overflow, which leaves nothing to color by
+        let Some(log_derivative) = self.inner.log_derivative.filter(|x| x.is_finite()) else {
+            return info;
+        };
+        PointInfo::LogDerivative {
+            potential: *potential,
+            log_derivative,
+            phase: *phase,
+        }
+    }
+}
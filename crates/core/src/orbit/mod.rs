@@ -2,19 +2,48 @@ use crate::dynamics::DynamicalFamily;
 use dynamo_common::prelude::*;
 use num_traits::One;
 
+pub mod boettcher;
 pub mod distance_estimation;
 pub mod floyd;
+pub mod log_derivative;
 pub mod potential;
 pub mod simple;
 
+pub use boettcher::BoettcherCoordinate;
 pub use distance_estimation::DistanceEstimation;
 pub use floyd::CycleDetected;
+pub use log_derivative::LogDerivative;
 pub use potential::Potential;
 pub use simple::Simple;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Strategy for noticing that an orbit has landed on a cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CycleDetection
+{
+    /// Compare $z_n$ against $z_{2n}$. Cycles are only noticed at multiples of their period,
+    /// so long cycles with slowly converging orbits may take many iterations to detect.
+    #[default]
+    Floyd,
+    /// Compare $z_n$ against the iterate saved at the last power of two, which catches long
+    /// cycles after slow convergence sooner.
+    Brent,
+}
+
+impl std::fmt::Display for CycleDetection
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Floyd => write!(f, "Floyd"),
+            Self::Brent => write!(f, "Brent"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EscapeResult<V, D>
@@ -95,8 +124,8 @@ where
     pub fn summary(&self, conf: &OrbitSummaryConf) -> String
     {
         use PointInfo::{
-            Bounded, DistanceEstimate, Escaping, MarkedPoint, Periodic, PeriodicKnownPotential,
-            Unknown, Wandering,
+            Boettcher, Bounded, DistanceEstimate, Escaping, LogDerivative, MarkedPoint, Periodic,
+            PeriodicKnownPotential, UndefinedParameter, Unknown, Wandering,
         };
 
         let param_desc = self
@@ -122,6 +151,17 @@ where
             DistanceEstimate { distance, phase } => {
                 format!("Escaped with phase {phase}, est. distance: {distance:.DISPLAY_PREC$}")
             }
+            Boettcher { potential, angle } => format!(
+                "Escaped at angle {angle:.DISPLAY_PREC$}, potential: {potential:.DISPLAY_PREC$}"
+            ),
+            LogDerivative {
+                potential,
+                log_derivative,
+                ..
+            } => format!(
+                "Escaped with log derivative {log_derivative:.DISPLAY_PREC$}, \
+                    potential: {potential:.DISPLAY_PREC$}"
+            ),
             Periodic(data) | MarkedPoint { data, .. } => data.to_string(),
             PeriodicKnownPotential(data) => data.to_string(),
             Bounded => "Bounded (no cycle detected or period too high)".to_owned(),
@@ -129,6 +169,7 @@ where
             Unknown => {
                 "Unknown result, likely due to insufficient floting-point precision".to_owned()
             }
+            UndefinedParameter => "Parameter undefined at this point".to_owned(),
         };
         format!(
             "{start_desc}\
@@ -146,4 +187,7 @@ pub trait Orbit: Send
     fn reset(&mut self, selection: Cplx);
 
     fn run_until_complete(&mut self) -> Self::Outcome;
+
+    /// Number of iterations taken since the last reset.
+    fn iterations(&self) -> IterCount;
 }
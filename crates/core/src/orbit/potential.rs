@@ -2,6 +2,7 @@ use std::f64::consts::LN_2;
 
 use super::{EscapeResult, Orbit};
 use crate::dynamics::InfinityFirstReturnMap;
+use dynamo_common::math_utils::finite_diff_step;
 use dynamo_common::prelude::*;
 use num_traits::One;
 
@@ -182,9 +183,9 @@ impl<'a, P: InfinityFirstReturnMap + ?Sized> Potential<'a, P>
         multiplier: P::Deriv,
     ) -> Option<(Real, Cplx)>
     {
-        const EPS: Real = 1e-6;
+        let eps = finite_diff_step(self.selection, finite_diff_h());
 
-        self.reset(self.selection + EPS);
+        self.reset(self.selection + eps);
 
         for _ in 0..period * 50 {
             self.update_fast_lazy();
@@ -206,7 +207,7 @@ impl<'a, P: InfinityFirstReturnMap + ?Sized> Potential<'a, P>
             mult_delta *= dz;
         }
 
-        let d_mult = (mult_delta - multiplier).into() / EPS;
+        let d_mult = (mult_delta - multiplier).into() / eps;
         Some((
             multiplier.norm_sqr(),
             2. * d_mult.conj() * multiplier.into(),
@@ -283,6 +284,11 @@ impl<P: InfinityFirstReturnMap + ?Sized> Orbit for Potential<'_, P>
         self.iter = 0;
     }
 
+    fn iterations(&self) -> IterCount
+    {
+        self.iter
+    }
+
     fn run_until_complete(&mut self) -> Self::Outcome
     {
         use EscapeResult::{Bounded, Escaped, Periodic, Unknown};
@@ -1,5 +1,10 @@
+pub use crate::dynamics::annulus::EscapeAnnulus;
+pub use crate::dynamics::classification::{Classification, OrbitFate};
+pub use crate::dynamics::composition::Composition;
 pub use crate::dynamics::covering_maps::{CoveringMap, HasDynamicalCovers};
 pub use crate::dynamics::julia::JuliaSet;
+pub use crate::dynamics::rays::{RayLanding, RayNewtonParams};
+pub use crate::dynamics::tuning::Tuning;
 pub use crate::dynamics::*;
 pub use crate::macros::*;
-pub use crate::orbit::{self, EscapeResult, Orbit};
+pub use crate::orbit::{self, CycleDetection, EscapeResult, Orbit};
@@ -1,5 +1,5 @@
 use crate::{marked_points::ContourType, pane::id::PaneSelection};
-use dynamo_color::{IncoloringAlgorithm, Palette};
+use dynamo_color::{AlphaMaskMode, IncoloringAlgorithm, Palette};
 use dynamo_common::types::{IterCountSmooth, Period};
 
 #[cfg(feature = "serde")]
@@ -15,19 +15,40 @@ pub enum Action
     Close,
     NewTab,
     SaveImage(PaneSelection),
+    SaveCleanImage(PaneSelection),
+    SaveContactSheet,
+    SaveAlphaMask(AlphaMaskMode),
+    ExportCyclePolynomial,
+    ExportRayLandings,
+    ExportRayJuliaFrames,
+    ExportOrbitAnimation,
+    CopyViewAsRust,
     SavePalette(PaneSelection),
     LoadPalette(PaneSelection),
+    ExportAnnotations,
+    ImportAnnotations,
+    ExportSampleGrid,
     // Annotation toggles
     ToggleSelectionMarker,
     ToggleCritical,
+    ToggleCriticalValues,
     ToggleMarked(PaneSelection),
     ToggleCycles(PaneSelection, Period),
+    ToggleCycleTable,
+    ToggleSnapToPixels,
+    ToggleProbe,
     // Dynamics
     FindPeriodicPoint,
+    FindMisiurewiczPoint,
     MapSelection,
     EnterCoordinates,
     DrawOrbit,
     ClearOrbit,
+    ToggleOrbitGradient,
+    ToggleOrbitCentroid,
+    AnalyzeOrbitData,
+    PromptMaxOrbitLength,
+    PromptCurveThickness,
     DrawExternalRay
     {
         include_orbit: bool,
@@ -36,25 +57,60 @@ pub enum Action
     DrawRaysOfPeriod,
     DrawContour(ContourType),
     DrawAuxContours,
+    DrawIsoperiodicCurve,
+    ToggleEscapeIsolines,
+    ToggleCriticalBasins,
+    PromptCriticalOrbit,
+    PromptPeriodHues,
+    ToggleScaledIteration,
+    ToggleEscapeAnnulus,
+    ToggleMarianiSilver,
+    ToggleDoubleBuffering,
+    PromptInfinityColor,
     ClearRays,
     ClearEquipotentials,
     ClearCurves,
+    ToggleFreehandDraw,
+    ClearCustomCurves,
+    ClearPins,
+    LabelBulb,
+    ToggleSmoothCurves,
+    TogglePeriodProbe,
+    ToggleDashedEquipotentials,
     StopFollowing,
     ResetSelection,
     ResetView,
+    RoundBounds,
+    ResetColoring,
     // Image controls
     ToggleLiveMode,
+    ToggleJuliaPreview,
+    ToggleChildLock,
+    EstimateBoundaryDimension,
+    EstimateFilledArea,
+    ToggleBrentCycleDetection,
     CycleActivePlane,
+    SwapPanes,
     PromptImageHeight,
     Pan(f64, f64),
     Zoom(f64),
     CenterOnSelection,
+    ToggleTuning,
     ScaleMaxIter(IterCountSmooth),
+    PromptFiniteDiffStep,
+    PromptRenderThreads,
+    PromptDegree,
+    PromptNavigation,
+    TogglePowerOfTwoZoom,
+    PromptViewTransform,
     // Coloring
     RandomizePalette,
+    PromptPaletteSeed,
     SetPalette(Palette),
     SetPaletteWhite,
     SetPaletteBlack,
+    NextPalette,
+    PrevPalette,
     SetColoring(IncoloringAlgorithm),
     SetColoringInternalPotential,
     SetColoringPotentialPeriod,
@@ -62,7 +118,12 @@ pub enum Action
     ScalePalettePeriod(f64),
     ShiftPalettePhase(f64),
     ToggleEscapePhaseColoring,
+    ToggleSplitColoring,
+    ToggleTransparentExport,
+    ToggleSharedLevelRange,
     CycleComputeMode(PaneSelection, ChangeBoolean),
+    ToggleBoettcherTexture(PaneSelection),
+    ToggleLogDerivative(PaneSelection),
 }
 impl Action
 {
@@ -76,24 +137,85 @@ impl Action
             Self::Close => "Close the current tab.".to_owned(),
             Self::NewTab => "Open a new tab.".to_owned(),
             Self::SaveImage(pane_id) => format!("Save the {pane_id} image to a file."),
+            Self::SaveCleanImage(pane_id) => {
+                format!("Save the {pane_id} image to a file, without marked points or curves.")
+            }
+            Self::SaveAlphaMask(mode) => {
+                format!("Save a grayscale {mode} mask of the active image, for compositing.")
+            }
+            Self::SaveContactSheet => {
+                "Save both images side by side, labelled with their names and parameters."
+                    .to_owned()
+            }
+            Self::ExportCyclePolynomial => {
+                "Save the coefficients of the polynomial whose roots are the cycles of a given period."
+                    .to_owned()
+            }
+            Self::ExportRayLandings => {
+                "Save which parameter rays of a given denominator land together.".to_owned()
+            }
+            Self::ExportRayJuliaFrames => {
+                "Save a numbered sequence of Julia sets for parameters along a parameter ray."
+                    .to_owned()
+            }
+            Self::ExportOrbitAnimation => {
+                "Save a numbered sequence of images of the active pane, drawing the orbit of the \
+                    selection one point per frame."
+                    .to_owned()
+            }
+            Self::CopyViewAsRust => {
+                "Copy the bounds and selection of the active image to the clipboard as Rust code."
+                    .to_owned()
+            }
             Self::SavePalette(pane_id) => format!("Save the {pane_id} palette to a file."),
             Self::LoadPalette(pane_id) => format!("Load palette for {pane_id} from file"),
+            Self::ExportAnnotations => {
+                "Save the rays, contours and marked points of the active pane to a JSON file, to \
+                    draw them again on a later render."
+                    .to_owned()
+            }
+            Self::ImportAnnotations => {
+                "Draw the annotations from a JSON file saved with Export Annotations on the active \
+                    pane."
+                    .to_owned()
+            }
+            Self::ExportSampleGrid => {
+                "Write the coordinates sampled by each pixel of the active pane to a CSV file."
+                    .to_owned()
+            }
 
             // Annotation Toggles
             Self::ToggleSelectionMarker => "Toggle selection marker on active image.".to_owned(),
             Self::ToggleCritical => "Toggle critical points on dynamical plane.".to_owned(),
+            Self::ToggleCriticalValues => {
+                "Toggle critical values for the selected parameter on the active plane.".to_owned()
+            }
             Self::ToggleMarked(pane_id) => {
                 format!("Toggle marked points on {pane_id} image.")
             }
             Self::ToggleCycles(pane_id, period) => {
                 format!("Toggle known cycles (or component centers) of period {period} on {pane_id} image.")
             }
+            Self::ToggleCycleTable => {
+                "Toggle a table of cycle multipliers for the selected parameter.".to_owned()
+            }
+            Self::ToggleSnapToPixels => {
+                "Toggle snapping selected points to the nearest pixel.".to_owned()
+            }
+            Self::ToggleProbe => {
+                "Toggle a tooltip showing the coordinate and escape information under the cursor."
+                    .to_owned()
+            }
 
             // Dynamics
             Self::FindPeriodicPoint => {
                 "Find and select a nearby preperiodic/periodic/pcf point on the active image."
                     .to_owned()
             }
+            Self::FindMisiurewiczPoint => {
+                "Find and select a nearby Misiurewicz parameter with a given preperiod and period."
+                    .to_owned()
+            }
             Self::EnterCoordinates => {
                 "Enter coordinates to select a point on active image.".to_owned()
             }
@@ -104,6 +226,23 @@ impl Action
                 "Draw the orbit of currently selected point on dynamical plane.".to_owned()
             }
             Self::ClearOrbit => "Hide orbit from dynamical plane.".to_owned(),
+            Self::ToggleOrbitGradient => {
+                "Toggle coloring the orbit by iteration, from cool to warm.".to_owned()
+            }
+            Self::ToggleOrbitCentroid => {
+                "Toggle marking the centroid of the drawn orbit, the mean of its points.".to_owned()
+            }
+            Self::AnalyzeOrbitData => {
+                "Find the preperiod and period of the drawn orbit from its points, with Brent's \
+                    method."
+                    .to_owned()
+            }
+            Self::PromptMaxOrbitLength => {
+                "Set the maximum number of points of a drawn orbit.".to_owned()
+            }
+            Self::PromptCurveThickness => {
+                "Set the width of drawn curves, fixed on screen or in the plane.".to_owned()
+            }
             Self::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -131,19 +270,106 @@ impl Action
                 }
             },
             Self::DrawAuxContours => "Draw contours for the multiplier map a dynamical variety.".to_owned(),
+            Self::DrawIsoperiodicCurve => {
+                "Draw a curve of constant multiplier modulus for cycles of a given period on the parameter plane.".to_owned()
+            }
+            Self::ToggleEscapeIsolines => {
+                "Toggle curves on which the escape count steps up, on active image.".to_owned()
+            }
             Self::ClearRays => "Clear all external rays on active image.".to_owned(),
             Self::ClearEquipotentials => "Clear all equipotentials on active image.".to_owned(),
             Self::ClearCurves => "Clear all curves on active image.".to_owned(),
+            Self::ToggleFreehandDraw => {
+                "Toggle freehand mode, in which dragging the mouse draws a curve instead of panning."
+                    .to_owned()
+            }
+            Self::ClearCustomCurves => "Clear all freehand curves on active image.".to_owned(),
+            Self::ClearPins => "Clear all labeled pins on active image.".to_owned(),
+            Self::LabelBulb => {
+                "Pin the internal angle of the bulb containing the selection.".to_owned()
+            }
+            Self::ToggleSmoothCurves => {
+                "Toggle drawing rays and contours as smooth splines through their points."
+                    .to_owned()
+            }
+            Self::TogglePeriodProbe => {
+                "Toggle a slider that shows the cycles of one period at a time on the child."
+                    .to_owned()
+            }
+            Self::ToggleDashedEquipotentials => {
+                "Toggle drawing equipotentials dashed, to tell them apart from rays.".to_owned()
+            }
             Self::StopFollowing => "Stop following points around.".to_owned(),
             Self::ResetSelection => "Reset selection to default on active image.".to_owned(),
             Self::ResetView => "Reset bounds and selection to default on active image.".to_owned(),
+            Self::RoundBounds => {
+                "Expand the bounds of the active image outward to round coordinates.".to_owned()
+            }
+            Self::ResetColoring => {
+                "Reset palette and coloring algorithm to default on active image, keeping the view."
+                    .to_owned()
+            }
 
             // Image Controls
             Self::ToggleLiveMode => {
                 "Toggle \"live Julia mode\", in which child plane changes with cursor movement."
                     .to_owned()
             }
+            Self::ToggleJuliaPreview => {
+                "Toggle a thumbnail of the Julia set for the parameter under a resting cursor."
+                    .to_owned()
+            }
+            Self::EstimateBoundaryDimension => {
+                "Estimate the box-counting dimension of the escape set boundary in view.".to_owned()
+            }
+            Self::EstimateFilledArea => {
+                "Estimate the area of the non-escaping set in view, by counting pixels.".to_owned()
+            }
+            Self::ToggleBrentCycleDetection => {
+                "Detect cycles with Brent's method, which catches long cycles of slowly converging \
+                 orbits sooner than the default."
+                    .to_owned()
+            }
+            Self::ToggleCriticalBasins => {
+                "Color the interior by the attracting critical cycle that each point converges to."
+                    .to_owned()
+            }
+            Self::PromptCriticalOrbit => {
+                "Choose which critical orbit colors the parameter plane, or combine them all."
+                    .to_owned()
+            }
+            Self::PromptPeriodHues => {
+                "Set how many periods get distinct colors before the hues repeat.".to_owned()
+            }
+            Self::ToggleScaledIteration => {
+                "Continue orbits that overflow before escaping in extended exponent range."
+                    .to_owned()
+            }
+            Self::ToggleEscapeAnnulus => {
+                "Detect escape of transcendental maps with an annulus rather than a single radius."
+                    .to_owned()
+            }
+            Self::ToggleMarianiSilver => {
+                "Flood rectangles whose border never settles with a reduced iteration budget."
+                    .to_owned()
+            }
+            Self::ToggleDoubleBuffering => {
+                "Keep the previous frame on screen until a recompute finishes, without previews."
+                    .to_owned()
+            }
+            Self::PromptInfinityColor => {
+                "Color the basin of an attracting cycle through infinity with a single color."
+                    .to_owned()
+            }
+            Self::ToggleChildLock => {
+                "Freeze the child plane at its current parameter, independent of the parent selection."
+                    .to_owned()
+            }
             Self::CycleActivePlane => "Cycle through different planes of the fractal.".to_owned(),
+            Self::SwapPanes => {
+                "Exchange the parent and child panes, when both show the same kind of plane."
+                    .to_owned()
+            }
             Self::PromptImageHeight => "Prompt to set the height of the fractal image.".to_owned(),
             Self::Pan(x, y) => {
                 if *x == 0. {
@@ -166,18 +392,48 @@ impl Action
                 format!("Zoom {} (scale: {:.2})", in_or_out(*scale), *scale)
             }
             Self::CenterOnSelection => "Center view on selected point.".to_owned(),
+            Self::ToggleTuning => {
+                "Enter or exit tuning mode for the small copy containing the selection.".to_owned()
+            }
             Self::ScaleMaxIter(scale) => {
                 format!(
                     "{} max iterations on active image (factor: {scale})",
                     inc_or_dec(*scale)
                 )
             }
+            Self::PromptFiniteDiffStep => {
+                "Set the step size used for numerical derivatives.".to_owned()
+            }
+            Self::PromptRenderThreads => {
+                "Limit the number of threads used for rendering.".to_owned()
+            }
+            Self::PromptDegree => {
+                "Set the degree of families whose degree is chosen at runtime.".to_owned()
+            }
+            Self::PromptNavigation => {
+                "Set the pan and zoom sensitivity, and the momentum of dragging.".to_owned()
+            }
+            Self::TogglePowerOfTwoZoom => {
+                "Toggle rounding zooms to powers of two, so that successive views differ by exact factors."
+                    .to_owned()
+            }
+            Self::PromptViewTransform => {
+                "Precompose the active view with a Möbius transformation (az + b)/(cz + d)."
+                    .to_owned()
+            }
 
             // Coloring
             Self::RandomizePalette => "Randomize the color palette.".to_owned(),
+            Self::PromptPaletteSeed => {
+                "Apply the random palette generated from a given seed.".to_owned()
+            }
             Self::SetPalette(_) => "Set the color palette.".to_owned(),
             Self::SetPaletteWhite => "Use black on white palette.".to_owned(),
             Self::SetPaletteBlack => "Use white on black palette.".to_owned(),
+            Self::NextPalette => "Switch to the next palette in the palette library.".to_owned(),
+            Self::PrevPalette => {
+                "Switch to the previous palette in the palette library.".to_owned()
+            }
             Self::SetColoring(algorithm) => {
                 use IncoloringAlgorithm::{InternalPotential, Multiplier, Period, PeriodMultiplier, PotentialAndPeriod, Preperiod, PreperiodPeriod, Solid};
                 let desc = match algorithm {
@@ -214,11 +470,29 @@ impl Action
             Self::ToggleEscapePhaseColoring => {
                 "Toggle coloring based on phase at time of escape.".to_owned()
             }
+            Self::ToggleSplitColoring => {
+                "Split the image diagonally, comparing the interior coloring with internal potential."
+                    .to_owned()
+            }
+            Self::ToggleSharedLevelRange => {
+                "Toggle coloring both images by a shared range of escape levels, using the \
+                parent palette, so that their colors can be compared."
+                    .to_owned()
+            }
+            Self::ToggleTransparentExport => {
+                "Toggle transparency of undecided points in exported images.".to_owned()
+            }
             Self::CycleComputeMode(_, change) => match change {
                 ChangeBoolean::Enable => "Use distance estimation to color escape regions".to_owned(),
                 ChangeBoolean::Disable => "Use Green's function to color escape regions".to_owned(),
                 ChangeBoolean::Toggle => "Cycle between exterior coloring modes (smooth potential and distance estimate).".to_owned(),
             },
+            Self::ToggleBoettcherTexture(_) => {
+                "Toggle a checkerboard texture on the escape region, laid out along rays and equipotentials.".to_owned()
+            }
+            Self::ToggleLogDerivative(_) => {
+                "Color escape regions by the logarithm of the accumulated derivative, which shows more bands for transcendental maps.".to_owned()
+            }
         }
     }
 
@@ -231,21 +505,43 @@ impl Action
             Self::Close => "Close Tab".to_owned(),
             Self::NewTab => "New Tab".to_owned(),
             Self::SaveImage(pane_selection) => format!("Save{pane_selection:#}..."),
+            Self::SaveCleanImage(pane_selection) => format!("Save Clean{pane_selection:#}..."),
+            Self::SaveContactSheet => "Save Contact Sheet...".to_owned(),
+            Self::SaveAlphaMask(AlphaMaskMode::Interior) => "Save Interior Mask...".to_owned(),
+            Self::SaveAlphaMask(AlphaMaskMode::Potential) => "Save Potential Mask...".to_owned(),
+            Self::ExportCyclePolynomial => "Export Cycle Polynomial...".to_owned(),
+            Self::ExportRayLandings => "Export Ray Landings...".to_owned(),
+            Self::ExportRayJuliaFrames => "Export Julia Sets Along Ray...".to_owned(),
+            Self::ExportOrbitAnimation => "Export Orbit Animation".to_owned(),
+            Self::CopyViewAsRust => "Copy View as Rust".to_owned(),
             Self::SavePalette(pane_selection) => format!("Save{pane_selection:#} Palette..."),
             Self::LoadPalette(pane_selection) => format!("Load{pane_selection:#} Palette..."),
+            Self::ExportAnnotations => "Export Annotations...".to_owned(),
+            Self::ImportAnnotations => "Import Annotations...".to_owned(),
+            Self::ExportSampleGrid => "Export Sample Grid...".to_owned(),
 
             // Annotation Toggles
             Self::ToggleSelectionMarker => "Toggle Selection".to_owned(),
             Self::ToggleCritical => "Toggle Critical".to_owned(),
+            Self::ToggleCriticalValues => "Toggle Critical Values".to_owned(),
             Self::ToggleMarked(_) => "Toggle Marked pts".to_owned(),
             Self::ToggleCycles(_, p) => format!("Toggle {p}-cycles"),
+            Self::ToggleCycleTable => "Cycle Multipliers".to_owned(),
+            Self::ToggleSnapToPixels => "Snap to Pixels".to_owned(),
+            Self::ToggleProbe => "Hover Probe".to_owned(),
 
             // Dynamics
             Self::FindPeriodicPoint => "Find Point...".to_owned(),
+            Self::FindMisiurewiczPoint => "Find Misiurewicz Point...".to_owned(),
             Self::EnterCoordinates => "Enter Point...".to_owned(),
             Self::MapSelection => "Map Selection".to_owned(),
             Self::DrawOrbit => "Draw Orbit".to_owned(),
             Self::ClearOrbit => "Clear Orbit".to_owned(),
+            Self::ToggleOrbitGradient => "Orbit Gradient".to_owned(),
+            Self::ToggleOrbitCentroid => "Orbit Centroid".to_owned(),
+            Self::AnalyzeOrbitData => "Orbit Period from Data".to_owned(),
+            Self::PromptMaxOrbitLength => "Orbit Length...".to_owned(),
+            Self::PromptCurveThickness => "Curve Thickness...".to_owned(),
             Self::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -266,27 +562,62 @@ impl Action
                 ContourType::InwardRay => "Inward Ray".to_owned(),
             },
             Self::DrawAuxContours => "Multiplier Contours".to_owned(),
+            Self::DrawIsoperiodicCurve => "Isoperiodic Curve...".to_owned(),
+            Self::ToggleEscapeIsolines => "Escape Isolines".to_owned(),
             Self::ClearRays => "Clear Rays".to_owned(),
             Self::ClearEquipotentials => "Clear Equipotentials".to_owned(),
             Self::ClearCurves => "Clear Curves".to_owned(),
+            Self::ToggleFreehandDraw => "Toggle Freehand Drawing".to_owned(),
+            Self::ClearCustomCurves => "Clear Freehand Curves".to_owned(),
+            Self::ClearPins => "Clear Pins".to_owned(),
+            Self::LabelBulb => "Label Bulb".to_owned(),
+            Self::ToggleSmoothCurves => "Smooth Curves".to_owned(),
+            Self::ToggleDashedEquipotentials => "Dashed Equipotentials".to_owned(),
+            Self::TogglePeriodProbe => "Period Probe".to_owned(),
             Self::StopFollowing => "Stop Following".to_owned(),
             Self::ResetSelection => "Reset Selection".to_owned(),
             Self::ResetView => "Reset View".to_owned(),
+            Self::RoundBounds => "Round Bounds".to_owned(),
+            Self::ResetColoring => "Reset Coloring".to_owned(),
 
             // Image Controls
             Self::ToggleLiveMode => "Toggle Live Mode".to_owned(),
+            Self::ToggleJuliaPreview => "Julia Preview".to_owned(),
+            Self::ToggleCriticalBasins => "Critical Basins".to_owned(),
+            Self::PromptCriticalOrbit => "Critical Orbit...".to_owned(),
+            Self::PromptPeriodHues => "Period Hues...".to_owned(),
+            Self::ToggleScaledIteration => "Scaled Iteration".to_owned(),
+            Self::ToggleEscapeAnnulus => "Escape Annulus".to_owned(),
+            Self::ToggleMarianiSilver => "Mariani-Silver Rendering".to_owned(),
+            Self::ToggleDoubleBuffering => "Double Buffering".to_owned(),
+            Self::PromptInfinityColor => "Infinity Color...".to_owned(),
+            Self::ToggleChildLock => "Lock Child Parameter".to_owned(),
+            Self::EstimateBoundaryDimension => "Boundary Dimension".to_owned(),
+            Self::EstimateFilledArea => "Filled Area".to_owned(),
+            Self::ToggleBrentCycleDetection => "Brent Cycle Detection".to_owned(),
             Self::CycleActivePlane => "Cycle Plane".to_owned(),
+            Self::SwapPanes => "Swap Panes".to_owned(),
             Self::PromptImageHeight => "Set Height".to_owned(),
             Self::Pan(_, _) => "Pan View".to_owned(),
             Self::Zoom(scale) => format!("Zoom {}", in_or_out(*scale)),
             Self::CenterOnSelection => "Center View".to_owned(),
+            Self::ToggleTuning => "Toggle Tuning".to_owned(),
             Self::ScaleMaxIter(scale) => format!("{} iters", inc_or_dec(*scale)),
+            Self::PromptFiniteDiffStep => "Derivative Step".to_owned(),
+            Self::PromptRenderThreads => "Render Threads...".to_owned(),
+            Self::PromptDegree => "Degree...".to_owned(),
+            Self::PromptNavigation => "Navigation".to_owned(),
+            Self::TogglePowerOfTwoZoom => "Power-of-Two Zoom".to_owned(),
+            Self::PromptViewTransform => "View Transform...".to_owned(),
 
             // Coloring
             Self::RandomizePalette => "Random".to_owned(),
+            Self::PromptPaletteSeed => "Random from Seed...".to_owned(),
             Self::SetPalette(_) => "Custom".to_owned(),
             Self::SetPaletteWhite => "White".to_owned(),
             Self::SetPaletteBlack => "Black".to_owned(),
+            Self::NextPalette => "Next Palette".to_owned(),
+            Self::PrevPalette => "Previous Palette".to_owned(),
             Self::SetColoring(algorithm) => {
                 use IncoloringAlgorithm::{
                     InternalPotential, Multiplier, Period, PeriodMultiplier, PotentialAndPeriod,
@@ -310,11 +641,16 @@ impl Action
             Self::ScalePalettePeriod(scale) => format!("{} density", inc_or_dec(1.0 / scale)),
             Self::ShiftPalettePhase(_) => "Adjust Phase".to_owned(),
             Self::ToggleEscapePhaseColoring => "Phase Coloring".to_owned(),
+            Self::ToggleSplitColoring => "Split Comparison".to_owned(),
+            Self::ToggleTransparentExport => "Transparent Export".to_owned(),
+            Self::ToggleSharedLevelRange => "Shared Levels".to_owned(),
             Self::CycleComputeMode(_, change) => match change {
                 ChangeBoolean::Enable => "Distance Estimation".to_owned(),
                 ChangeBoolean::Disable => "External Potential".to_owned(),
                 ChangeBoolean::Toggle => "Cycle Outcoloring".to_owned(),
             },
+            Self::ToggleBoettcherTexture(_) => "Böttcher Texture".to_owned(),
+            Self::ToggleLogDerivative(_) => "Log Derivative".to_owned(),
         }
     }
 }
@@ -0,0 +1,75 @@
+//! Composite images of several panes, laid out in a captioned grid.
+
+use ab_glyph::FontRef;
+use egui::FontFamily;
+use epaint::text::FontDefinitions;
+use image::{imageops, Rgb, RgbImage};
+use imageproc::drawing::draw_text_mut;
+
+/// Height of the caption strip above each tile, in pixels.
+pub const LABEL_HEIGHT: u32 = 32;
+const LABEL_SCALE: f32 = 22.;
+const LABEL_MARGIN: i32 = 6;
+const LABEL_COLOR: Rgb<u8> = Rgb([230, 230, 230]);
+const BACKGROUND_COLOR: Rgb<u8> = Rgb([24, 24, 24]);
+
+/// Lay out labelled images in a grid, filling rows first, with at least as many columns as rows.
+/// Each tile is as wide as the widest image, and each row as tall as its tallest image plus the
+/// caption strip.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn contact_sheet(tiles: &[(String, RgbImage)]) -> RgbImage
+{
+    let cols = (tiles.len() as f64).sqrt().ceil().max(1.) as usize;
+    let tile_width = tiles
+        .iter()
+        .map(|(_, image)| image.width())
+        .max()
+        .unwrap_or(0);
+    let row_heights: Vec<u32> = tiles
+        .chunks(cols)
+        .map(|row| {
+            let image_height = row.iter().map(|(_, image)| image.height()).max();
+            LABEL_HEIGHT + image_height.unwrap_or(0)
+        })
+        .collect();
+
+    let width = tile_width * cols as u32;
+    let height = row_heights.iter().sum();
+    let mut sheet = RgbImage::from_pixel(width, height, BACKGROUND_COLOR);
+
+    let font_definitions = FontDefinitions::default();
+    let font = label_font(&font_definitions);
+
+    let mut y = 0;
+    for (row, row_height) in tiles.chunks(cols).zip(&row_heights) {
+        for (col, (label, image)) in row.iter().enumerate() {
+            let x = col as u32 * tile_width;
+            if let Some(font) = &font {
+                draw_text_mut(
+                    &mut sheet,
+                    LABEL_COLOR,
+                    x as i32 + LABEL_MARGIN,
+                    y as i32 + LABEL_MARGIN,
+                    LABEL_SCALE,
+                    font,
+                    label,
+                );
+            }
+            imageops::replace(&mut sheet, image, i64::from(x), i64::from(y + LABEL_HEIGHT));
+        }
+        y += row_height;
+    }
+    sheet
+}
+
+/// The default proportional font bundled with egui, used for captions.
+fn label_font(font_definitions: &FontDefinitions) -> Option<FontRef<'_>>
+{
+    let name = font_definitions
+        .families
+        .get(&FontFamily::Proportional)?
+        .first()?;
+    let data = font_definitions.font_data.get(name)?;
+    FontRef::try_from_slice(&data.font).ok()
+}
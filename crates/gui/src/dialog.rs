@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 
+use dynamo_color::AlphaMaskMode;
 use dynamo_common::rational_angle::RationalAngle;
 use dynamo_common::symbolic_dynamics::{AngleInfo, OrbitSchemaWithDegree};
 use egui::{self, Key, RichText, WidgetText};
@@ -92,8 +93,23 @@ impl<'a> IntoIterator for &'a mut ToggleMap
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SaveFileType
 {
-    Image,
+    Image
+    {
+        include_annotations: bool,
+    },
+    ContactSheet,
+    AlphaMask(AlphaMaskMode),
+    Palette,
+    Annotations,
+    SampleGrid,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LoadFileType
+{
     Palette,
+    Annotations,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -130,6 +146,7 @@ pub enum Dialog
     {
         pane_selection: PaneSelection,
         file_dialog: FileDialog,
+        file_type: LoadFileType,
     },
     Text(StructuredTextDialog),
     ConfirmRay(ConfirmationDialog<RayParams>),
@@ -166,6 +183,30 @@ pub enum TextInputType
     {
         pane_id: PaneID
     },
+    ViewTransform
+    {
+        pane_id: PaneID
+    },
+    IsoperiodicCurve,
+    FiniteDiffStep,
+    RenderThreads,
+    Navigation,
+    CyclePolynomial,
+    MaxOrbitLength,
+    CurveThickness,
+    Misiurewicz,
+    RayLandings,
+    RayJuliaFrames,
+    CriticalOrbit,
+    PeriodHues,
+    InfinityColor,
+    PaletteSeed,
+    Degree,
+    PinLabel
+    {
+        pane_id: PaneID,
+        id: usize,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -7,15 +7,16 @@ use crate::{
         PaneSelection::{ActivePane, BothPanes, Id},
     },
 };
-use dynamo_color::IncoloringAlgorithm;
+use dynamo_color::{AlphaMaskMode, IncoloringAlgorithm};
 use keyboard_shortcuts::{
-    CTRL_1, CTRL_2, CTRL_3, CTRL_4, CTRL_5, CTRL_6, CTRL_E, CTRL_F, CTRL_K, CTRL_L, CTRL_P, CTRL_Q,
-    CTRL_S, CTRL_SHIFT_1, CTRL_SHIFT_2, CTRL_SHIFT_3, CTRL_SHIFT_4, CTRL_SHIFT_5, CTRL_SHIFT_6,
-    CTRL_T, CTRL_V, CTRL_W, CTRL_X, CTRL_Z, KEY_0, KEY_1, KEY_2, KEY_3, KEY_4, KEY_5, KEY_6, KEY_7,
-    KEY_B, KEY_C, KEY_D, KEY_DOWN, KEY_E, KEY_EQUALS, KEY_ESC, KEY_F, KEY_G, KEY_HOME, KEY_I,
-    KEY_INSERT, KEY_J, KEY_L, KEY_LEFT, KEY_M, KEY_MINUS, KEY_O, KEY_P, KEY_R, KEY_RIGHT,
-    KEY_SPACE, KEY_UP, KEY_V, KEY_W, KEY_Y, KEY_Z, SHIFT_C, SHIFT_DOWN, SHIFT_E, SHIFT_LEFT,
-    SHIFT_M, SHIFT_O, SHIFT_P, SHIFT_R, SHIFT_RIGHT, SHIFT_SPACE, SHIFT_T, SHIFT_UP,
+    CTRL_1, CTRL_2, CTRL_3, CTRL_4, CTRL_5, CTRL_6, CTRL_D, CTRL_E, CTRL_F, CTRL_K, CTRL_L, CTRL_P,
+    CTRL_Q, CTRL_S, CTRL_SHIFT_1, CTRL_SHIFT_2, CTRL_SHIFT_3, CTRL_SHIFT_4, CTRL_SHIFT_5,
+    CTRL_SHIFT_6, CTRL_SHIFT_C, CTRL_T, CTRL_V, CTRL_W, CTRL_X, CTRL_Z, KEY_0, KEY_1, KEY_2, KEY_3,
+    KEY_4, KEY_5, KEY_6, KEY_7, KEY_B, KEY_C, KEY_D, KEY_DOWN, KEY_E, KEY_EQUALS, KEY_ESC, KEY_F,
+    KEY_G, KEY_HOME, KEY_I, KEY_INSERT, KEY_J, KEY_K, KEY_L, KEY_LEFT, KEY_M, KEY_MINUS, KEY_N,
+    KEY_O, KEY_P, KEY_R, KEY_RIGHT, KEY_SPACE, KEY_U, KEY_UP, KEY_V, KEY_W, KEY_Y, KEY_Z, SHIFT_B,
+    SHIFT_C, SHIFT_DOWN, SHIFT_E, SHIFT_F, SHIFT_G, SHIFT_H, SHIFT_I, SHIFT_L, SHIFT_LEFT, SHIFT_M,
+    SHIFT_N, SHIFT_O, SHIFT_P, SHIFT_R, SHIFT_RIGHT, SHIFT_SPACE, SHIFT_T, SHIFT_UP,
 };
 use seq_macro::seq;
 
@@ -97,17 +98,30 @@ impl Hotkey
 }
 
 use Action::{
-    CenterOnSelection, ClearCurves, ClearOrbit, Close, CycleActivePlane, CycleComputeMode,
-    DrawAuxContours, DrawContour, DrawExternalRay, DrawOrbit, DrawRaysOfPeriod, EnterCoordinates,
-    FindPeriodicPoint, LoadPalette, MapSelection, NewTab, Pan, Quit, RandomizePalette,
-    ResetSelection, ResetView, SaveImage, SavePalette, ScaleMaxIter, ScalePalettePeriod,
-    SetColoring, SetColoringInternalPotential, SetColoringPotentialPeriod,
-    SetColoringPreperiodPeriod, SetPaletteBlack, SetPaletteWhite, ShiftPalettePhase, StopFollowing,
-    ToggleCritical, ToggleCycles, ToggleEscapePhaseColoring, ToggleLiveMode, ToggleMarked,
-    ToggleSelectionMarker, Zoom,
+    AnalyzeOrbitData, CenterOnSelection, ClearCurves, ClearCustomCurves, ClearOrbit, ClearPins,
+    Close, CopyViewAsRust, CycleActivePlane, CycleComputeMode, DrawAuxContours, DrawContour,
+    DrawExternalRay, DrawIsoperiodicCurve, DrawOrbit, DrawRaysOfPeriod, EnterCoordinates,
+    EstimateBoundaryDimension, EstimateFilledArea, ExportAnnotations, ExportCyclePolynomial,
+    ExportOrbitAnimation, ExportRayJuliaFrames, ExportRayLandings, ExportSampleGrid,
+    FindMisiurewiczPoint, FindPeriodicPoint, ImportAnnotations, LabelBulb, LoadPalette,
+    MapSelection, NewTab, NextPalette, Pan, PrevPalette, PromptCriticalOrbit, PromptCurveThickness,
+    PromptDegree, PromptFiniteDiffStep, PromptInfinityColor, PromptMaxOrbitLength,
+    PromptNavigation, PromptPaletteSeed, PromptPeriodHues, PromptRenderThreads,
+    PromptViewTransform, Quit, RandomizePalette, ResetColoring, ResetSelection, ResetView,
+    RoundBounds, SaveAlphaMask, SaveCleanImage, SaveContactSheet, SaveImage, SavePalette,
+    ScaleMaxIter, ScalePalettePeriod, SetColoring, SetColoringInternalPotential,
+    SetColoringPotentialPeriod, SetColoringPreperiodPeriod, SetPaletteBlack, SetPaletteWhite,
+    ShiftPalettePhase, StopFollowing, SwapPanes, ToggleBoettcherTexture, ToggleBrentCycleDetection,
+    ToggleChildLock, ToggleCritical, ToggleCriticalBasins, ToggleCriticalValues, ToggleCycleTable,
+    ToggleCycles, ToggleDashedEquipotentials, ToggleDoubleBuffering, ToggleEscapeAnnulus,
+    ToggleEscapeIsolines, ToggleEscapePhaseColoring, ToggleFreehandDraw, ToggleJuliaPreview,
+    ToggleLiveMode, ToggleLogDerivative, ToggleMarianiSilver, ToggleMarked, ToggleOrbitCentroid,
+    ToggleOrbitGradient, TogglePeriodProbe, TogglePowerOfTwoZoom, ToggleProbe,
+    ToggleScaledIteration, ToggleSelectionMarker, ToggleSharedLevelRange, ToggleSmoothCurves,
+    ToggleSnapToPixels, ToggleSplitColoring, ToggleTransparentExport, ToggleTuning, Zoom,
 };
 
-pub const FILE_HOTKEYS: [Hotkey; 6] = [
+pub const FILE_HOTKEYS: [Hotkey; 20] = [
     Hotkey::new(Quit).shortcut(CTRL_Q),
     Hotkey::new(Close).shortcut(CTRL_W),
     Hotkey::new(NewTab).shortcut(CTRL_T),
@@ -116,18 +130,36 @@ pub const FILE_HOTKEYS: [Hotkey; 6] = [
         .hide_in_menu(),
     Hotkey::new(SaveImage(Id(Parent))),
     Hotkey::new(SaveImage(Id(Child))),
+    Hotkey::new(SaveCleanImage(Id(Parent))),
+    Hotkey::new(SaveCleanImage(Id(Child))),
+    Hotkey::new(SaveContactSheet),
+    Hotkey::new(SaveAlphaMask(AlphaMaskMode::Interior)),
+    Hotkey::new(SaveAlphaMask(AlphaMaskMode::Potential)),
+    Hotkey::new(ExportCyclePolynomial),
+    Hotkey::new(ExportRayLandings),
+    Hotkey::new(ExportRayJuliaFrames),
+    Hotkey::new(ExportOrbitAnimation),
+    Hotkey::new(ExportAnnotations),
+    Hotkey::new(ImportAnnotations),
+    Hotkey::new(ExportSampleGrid),
+    Hotkey::new(CopyViewAsRust).shortcut(CTRL_SHIFT_C),
+    Hotkey::new(ToggleTransparentExport),
 ];
 
-pub const PALETTE_HOTKEYS: [Hotkey; 9] = [
+pub const PALETTE_HOTKEYS: [Hotkey; 13] = [
     Hotkey::new(SavePalette(ActivePane)).shortcut(CTRL_K),
     Hotkey::new(LoadPalette(BothPanes)).shortcut(CTRL_L),
     Hotkey::new(SetPaletteBlack).shortcut(KEY_B),
     Hotkey::new(SetPaletteWhite).shortcut(KEY_W),
     Hotkey::new(RandomizePalette).shortcut(KEY_R),
+    Hotkey::new(PromptPaletteSeed),
+    Hotkey::new(NextPalette).shortcut(KEY_N),
+    Hotkey::new(PrevPalette).shortcut(SHIFT_N),
     Hotkey::new(ScalePalettePeriod(1.25)).shortcut(KEY_UP),
     Hotkey::new(ScalePalettePeriod(0.8)).shortcut(KEY_DOWN),
     Hotkey::new(ShiftPalettePhase(-0.02)).shortcut(KEY_LEFT),
     Hotkey::new(ShiftPalettePhase(0.02)).shortcut(KEY_RIGHT),
+    Hotkey::new(ToggleSharedLevelRange),
 ];
 
 seq!(n in 1..=6 {
@@ -139,7 +171,7 @@ pub const CYCLES_HOTKEYS: [Hotkey; 12] = [
 ];
 });
 
-pub const ANNOTATION_HOTKEYS: [Hotkey; 17] = [
+pub const ANNOTATION_HOTKEYS: [Hotkey; 32] = [
     // External ray
     Hotkey::new(DrawExternalRay {
         include_orbit: false,
@@ -173,6 +205,10 @@ pub const ANNOTATION_HOTKEYS: [Hotkey; 17] = [
     Hotkey::new(DrawContour(ContourType::multiplier_auto())).shortcut(KEY_M),
     // Many multiplier contours
     Hotkey::new(DrawAuxContours).shortcut(SHIFT_M),
+    // Hyperbolic component boundaries and internal level curves
+    Hotkey::new(DrawIsoperiodicCurve).shortcut(SHIFT_H),
+    // Integer escape-count level curves
+    Hotkey::new(ToggleEscapeIsolines),
     // Extend Ray
     Hotkey::new(DrawContour(ContourType::ExtendRay)).shortcut(SHIFT_E),
     // Inward Ray
@@ -183,26 +219,43 @@ pub const ANNOTATION_HOTKEYS: [Hotkey; 17] = [
         .shortcut(SHIFT_T)
         .hide_in_menu(),
     Hotkey::new(ToggleCritical).shortcut(KEY_P),
+    Hotkey::new(ToggleCriticalValues),
     Hotkey::new(ToggleMarked(ActivePane))
         .shortcut(SHIFT_P)
         .hide_in_menu(),
     Hotkey::new(DrawOrbit).shortcut(KEY_O),
     Hotkey::new(StopFollowing).shortcut(KEY_ESC).hide_in_menu(),
     Hotkey::new(ClearOrbit).shortcut(KEY_C),
+    Hotkey::new(ToggleOrbitGradient).shortcut(SHIFT_G),
+    Hotkey::new(ToggleOrbitCentroid),
+    Hotkey::new(AnalyzeOrbitData),
+    Hotkey::new(TogglePeriodProbe),
+    Hotkey::new(PromptMaxOrbitLength),
     Hotkey::new(ClearCurves).shortcut(SHIFT_C),
+    Hotkey::new(ToggleFreehandDraw).shortcut(CTRL_D),
+    Hotkey::new(ClearCustomCurves),
+    Hotkey::new(ClearPins),
+    Hotkey::new(LabelBulb),
+    Hotkey::new(ToggleSmoothCurves),
+    Hotkey::new(ToggleDashedEquipotentials),
+    Hotkey::new(PromptCurveThickness),
 ];
 
-pub const SELECTION_HOTKEYS: [Hotkey; 5] = [
+pub const SELECTION_HOTKEYS: [Hotkey; 9] = [
     Hotkey::new(ToggleSelectionMarker).shortcut(KEY_I),
     Hotkey::new(EnterCoordinates).shortcut(KEY_INSERT),
     // Apply map on dynamical plane
     Hotkey::new(MapSelection).shortcut(KEY_F),
     // Find nearby periodic point
     Hotkey::new(FindPeriodicPoint).shortcut(CTRL_F),
+    Hotkey::new(FindMisiurewiczPoint).shortcut(SHIFT_F),
     Hotkey::new(ResetSelection).shortcut(SHIFT_SPACE),
+    Hotkey::new(ToggleCycleTable).shortcut(KEY_K),
+    Hotkey::new(ToggleSnapToPixels).shortcut(SHIFT_I),
+    Hotkey::new(ToggleProbe).shortcut(SHIFT_B),
 ];
 
-pub const IMAGE_HOTKEYS: [Hotkey; 14] = [
+pub const IMAGE_HOTKEYS: [Hotkey; 29] = [
     // Hotkey {
     //     shortcut: Some(KEY_H),
     //     action: PromptImageHeight,
@@ -210,8 +263,19 @@ pub const IMAGE_HOTKEYS: [Hotkey; 14] = [
     //     menu_action_override: None,
     // },
     Hotkey::new(ToggleLiveMode).shortcut(KEY_L),
+    Hotkey::new(ToggleJuliaPreview),
+    Hotkey::new(ToggleChildLock).shortcut(SHIFT_L),
+    Hotkey::new(EstimateBoundaryDimension),
+    Hotkey::new(EstimateFilledArea),
+    Hotkey::new(ToggleBrentCycleDetection),
     Hotkey::new(ScaleMaxIter(2.0)).shortcut(KEY_EQUALS),
     Hotkey::new(ScaleMaxIter(0.5)).shortcut(KEY_MINUS),
+    Hotkey::new(PromptFiniteDiffStep),
+    Hotkey::new(PromptRenderThreads),
+    Hotkey::new(PromptDegree),
+    Hotkey::new(PromptNavigation),
+    Hotkey::new(TogglePowerOfTwoZoom),
+    Hotkey::new(PromptViewTransform),
     Hotkey::new(Pan(-0.01, 0.))
         .shortcut(SHIFT_LEFT)
         .hide_in_menu()
@@ -233,11 +297,15 @@ pub const IMAGE_HOTKEYS: [Hotkey; 14] = [
     Hotkey::new(Zoom(1.25)).shortcut(KEY_V),
     Hotkey::new(Zoom(8.)).shortcut(CTRL_V),
     Hotkey::new(CenterOnSelection).shortcut(KEY_SPACE),
+    Hotkey::new(ToggleTuning).shortcut(KEY_U),
     Hotkey::new(CycleActivePlane).shortcut(CTRL_P),
+    Hotkey::new(SwapPanes),
     Hotkey::new(ResetView).shortcut(KEY_HOME),
+    Hotkey::new(RoundBounds),
+    Hotkey::new(ResetColoring),
 ];
 
-pub const INCOLORING_HOTKEYS: [Hotkey; 8] = [
+pub const INCOLORING_HOTKEYS: [Hotkey; 12] = [
     Hotkey::new(SetColoring(IncoloringAlgorithm::Solid)).shortcut(KEY_0),
     Hotkey::new(SetColoring(IncoloringAlgorithm::Period)).shortcut(KEY_1),
     Hotkey::new(SetColoring(IncoloringAlgorithm::PeriodMultiplier)).shortcut(KEY_2),
@@ -246,9 +314,13 @@ pub const INCOLORING_HOTKEYS: [Hotkey; 8] = [
     Hotkey::new(SetColoringInternalPotential).shortcut(KEY_5),
     Hotkey::new(SetColoringPreperiodPeriod).shortcut(KEY_6),
     Hotkey::new(SetColoringPotentialPeriod).shortcut(KEY_7),
+    Hotkey::new(ToggleSplitColoring),
+    Hotkey::new(ToggleCriticalBasins),
+    Hotkey::new(PromptCriticalOrbit),
+    Hotkey::new(PromptPeriodHues),
 ];
 
-pub const OUTCOLORING_HOTKEYS: [Hotkey; 4] = [
+pub const OUTCOLORING_HOTKEYS: [Hotkey; 11] = [
     Hotkey::new(ToggleEscapePhaseColoring).shortcut(KEY_J),
     Hotkey::new(CycleComputeMode(ActivePane, ChangeBoolean::Toggle))
         .shortcut(KEY_D)
@@ -256,4 +328,11 @@ pub const OUTCOLORING_HOTKEYS: [Hotkey; 4] = [
         .menu_action_override(CycleComputeMode(ActivePane, ChangeBoolean::Enable)),
     Hotkey::new(CycleComputeMode(BothPanes, ChangeBoolean::Disable)),
     Hotkey::new(CycleComputeMode(BothPanes, ChangeBoolean::Enable)),
+    Hotkey::new(ToggleScaledIteration),
+    Hotkey::new(ToggleEscapeAnnulus),
+    Hotkey::new(ToggleMarianiSilver),
+    Hotkey::new(ToggleDoubleBuffering),
+    Hotkey::new(PromptInfinityColor),
+    Hotkey::new(ToggleBoettcherTexture(ActivePane)),
+    Hotkey::new(ToggleLogDerivative(ActivePane)),
 ];
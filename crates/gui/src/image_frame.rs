@@ -1,5 +1,5 @@
 use egui::containers::Frame;
-use egui::{Pos2, Rect, TextureOptions, Ui, Vec2};
+use egui::{Color32, Pos2, Rect, TextureOptions, Ui, Vec2};
 use epaint::{ColorImage, Stroke, TextureHandle};
 
 #[cfg(feature = "serde")]
@@ -39,7 +39,14 @@ impl BorderMode
 
 pub struct ImageFrame
 {
+    /// The image on screen.
     pub image: ColorImage,
+    /// Image that renders are written into, so that the previous frame stays on screen until
+    /// the new one is complete.
+    back_buffer: ColorImage,
+    /// Whether coarse previews are kept off screen, so that the frame only changes once a
+    /// recompute has finished.
+    double_buffered: bool,
     pub region: Rect,
     texture_id: Option<TextureHandle>,
     border: BorderMode,
@@ -48,12 +55,7 @@ impl Default for ImageFrame
 {
     fn default() -> Self
     {
-        Self {
-            image: ColorImage::default(),
-            region: Rect::NOTHING,
-            texture_id: None,
-            border: BorderMode::default(),
-        }
+        Self::new(ColorImage::default())
     }
 }
 impl ImageFrame
@@ -63,6 +65,8 @@ impl ImageFrame
     {
         Self {
             image,
+            back_buffer: ColorImage::default(),
+            double_buffered: true,
             region: Rect::NOTHING,
             texture_id: None,
             border: BorderMode::default(),
@@ -132,6 +136,34 @@ impl ImageFrame
     {
         self.region.min + local_pos
     }
+    #[must_use]
+    pub const fn is_double_buffered(&self) -> bool
+    {
+        self.double_buffered
+    }
+
+    pub fn set_double_buffered(&mut self, double_buffered: bool)
+    {
+        self.double_buffered = double_buffered;
+    }
+
+    /// Back buffer of the given size to render the next frame into. Resizing clears it, but
+    /// leaves the image on screen untouched.
+    pub fn render_target(&mut self, size: [usize; 2]) -> &mut ColorImage
+    {
+        if self.back_buffer.size != size {
+            self.back_buffer = ColorImage::new(size, Color32::BLACK);
+        }
+        &mut self.back_buffer
+    }
+
+    /// Put the frame written to [`Self::render_target`] on screen.
+    pub fn present(&mut self)
+    {
+        std::mem::swap(&mut self.image, &mut self.back_buffer);
+        self.update_texture();
+    }
+
     pub fn update_texture(&mut self)
     {
         if let Some(handle) = self.texture_id.as_mut() {
@@ -1,22 +1,37 @@
-use egui::{Context, CursorIcon, InputState, Ui};
+use egui::{Color32, ColorImage, Context, CursorIcon, InputState, Pos2, Ui};
 use egui_extras::{Column, TableBuilder};
 use egui_file::FileDialog;
+use image::RgbImage;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use dynamo_color::{IncoloringAlgorithm, Palette};
+use dynamo_color::{
+    prelude::FractalImage, AlphaMaskMode, IncoloringAlgorithm, Palette, PaletteLibrary,
+    ToneAdjustment,
+};
 use dynamo_common::prelude::*;
-use dynamo_core::{dynamics::Displayable, prelude::HasChild};
+use dynamo_core::{
+    dynamics::{self, Displayable},
+    prelude::{Classification, HasChild, RayLanding},
+};
 
 use crate::{
     actions::Action,
+    contact_sheet::contact_sheet,
     dialog::{
-        AllActiveRayParams, Dialog, RayParams, SaveFileType, TextDialogBuilder, TextInputType,
-        ToggleKey, ToggleMap,
+        AllActiveRayParams, Dialog, LoadFileType, RayParams, SaveFileType, TextDialogBuilder,
+        TextInputType, ToggleKey, ToggleMap,
     },
     hotkeys::{
         keyboard_shortcuts::shortcut_used, Hotkey, ANNOTATION_HOTKEYS, CYCLES_HOTKEYS,
         FILE_HOTKEYS, IMAGE_HOTKEYS, INCOLORING_HOTKEYS, OUTCOLORING_HOTKEYS, PALETTE_HOTKEYS,
         SELECTION_HOTKEYS,
     },
+    julia_preview::{
+        JuliaPreview, JULIA_PREVIEW_DELAY, JULIA_PREVIEW_MAX_ITER, JULIA_PREVIEW_WIDTH,
+    },
+    marked_points::DEFAULT_MAX_ORBIT_LEN,
+    navigation::Navigation,
     pane::{
         id::{PaneID, PaneSelection},
         tasks::{ChildTask, FollowState, SelectOrFollow},
@@ -27,6 +42,44 @@ use crate::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Zoom applied when recentering on a double-clicked point.
+const DOUBLE_CLICK_ZOOM: Real = 0.8;
+
+/// Width of each pane's image in an exported contact sheet.
+const CONTACT_SHEET_TILE_WIDTH: usize = 1536;
+
+/// Width of each frame of an exported orbit animation.
+const ORBIT_FRAME_WIDTH: usize = 1920;
+
+/// Offset of the hover probe from the cursor, in points.
+const PROBE_OFFSET: egui::Vec2 = egui::vec2(16., 16.);
+
+/// Offset of the bottom left corner of the Julia preview from the cursor, in points, which
+/// keeps it clear of the hover probe.
+const JULIA_PREVIEW_OFFSET: egui::Vec2 = egui::vec2(16., -16.);
+
+/// Distance, in pixels of the parent image, within which rays are taken to co-land.
+const RAY_LANDING_TOLERANCE: Real = 8.;
+
+/// Largest period listed in the cycle multiplier table.
+const CYCLE_TABLE_MAX_PERIOD: Period = 6;
+
+/// Largest period reachable with the period probe slider.
+const PERIOD_PROBE_MAX_PERIOD: Period = 12;
+
+/// An export of Julia sets for parameters along a parameter ray, in progress.
+struct RayJuliaExport
+{
+    dir: PathBuf,
+    /// Parameters of all frames, from the outer end of the ray to its landing end.
+    params: Vec<Cplx>,
+    /// Index of the next frame to render.
+    next: usize,
+    saved: usize,
+    /// Buffer reused by every frame.
+    image: RgbImage,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 /// Represents different types of messages that can be sent within the UI.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -55,6 +108,7 @@ pub trait PanePair
     fn child(&self) -> &dyn Pane;
     fn child_mut(&mut self) -> &mut dyn Pane;
     fn randomize_palette(&mut self);
+    fn apply_palette_seed(&mut self, seed: u64);
     fn set_palette(&mut self, palette: Palette);
     fn set_coloring_algorithm(&mut self, coloring_algorithm: IncoloringAlgorithm);
 
@@ -64,9 +118,15 @@ pub trait PanePair
     fn get_active_pane(&self) -> Option<&dyn Pane>;
     fn get_active_pane_mut(&mut self) -> Option<&mut dyn Pane>;
     fn get_selected_pane_ids(&self, selection: PaneSelection) -> Vec<PaneID>;
-    fn prompt_save_image(&mut self, panes: PaneSelection);
+    /// Prompt for a file to save images to, with or without the marked objects overlaid.
+    fn prompt_save_image(&mut self, panes: PaneSelection, include_annotations: bool);
+    fn prompt_save_contact_sheet(&mut self);
+    fn prompt_save_alpha_mask(&mut self, mode: AlphaMaskMode);
     fn prompt_save_palette(&mut self, panes: PaneSelection);
     fn prompt_load_palette(&mut self, panes: PaneSelection);
+    fn prompt_export_annotations(&mut self);
+    fn prompt_import_annotations(&mut self);
+    fn prompt_export_sample_grid(&mut self);
     fn prompt_text(&mut self, input_type: TextInputType);
 
     /// Updates the state of both the parent and child panes.
@@ -88,8 +148,12 @@ pub trait Interactive
     fn name(&self) -> String;
     fn get_image_height(&self) -> usize;
     fn change_height(&mut self, new_height: usize);
+    fn get_tone(&self) -> ToneAdjustment;
+    fn set_tone(&mut self, tone: ToneAdjustment);
     fn show(&mut self, ui: &mut Ui);
     fn process_action(&mut self, action: &Action);
+    /// Rust code reproducing the view of the active pane: its bounds and selected point.
+    fn view_as_rust(&self) -> Option<String>;
 }
 
 /// The main interface structure that holds the parent and child panes along with UI state.
@@ -104,13 +168,82 @@ where
     image_height: usize,
     active_pane: Option<PaneID>,
     live_mode: bool,
+    /// Whether dragging draws a freehand curve instead of panning.
+    #[cfg_attr(feature = "serde", serde(default))]
+    freehand_mode: bool,
+    /// Whether selected points are snapped to the sample point of the nearest pixel.
+    #[cfg_attr(feature = "serde", serde(default))]
+    snap_to_pixels: bool,
+    /// Whether to show escape information for the point under the cursor.
+    #[cfg_attr(feature = "serde", serde(default))]
+    probe: bool,
+    /// Whether to preview the Julia set of the parameter under a resting cursor.
+    #[cfg_attr(feature = "serde", serde(default))]
+    julia_preview_enabled: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    julia_preview: JuliaPreview<J>,
+    /// Sensitivity and momentum of panning and zooming.
+    #[cfg_attr(feature = "serde", serde(default))]
+    navigation: Navigation,
+    /// Seed of the last random palette, from which it can be regenerated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    palette_seed: Option<u64>,
+    /// Whether both panes color escaping points by a common range of escape levels.
+    #[cfg_attr(feature = "serde", serde(default))]
+    shared_level_range: bool,
     #[cfg_attr(feature = "serde", serde(skip))]
     dialog: Option<Dialog>,
     // save_task: SaveTask,
+    /// Julia sets along a parameter ray that are being exported, one per update.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ray_julia_export: Option<RayJuliaExport>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    palette_library: PaletteLibrary,
+    /// Multipliers of cycles for the child parameter, if the table is shown.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cycle_table: Option<Vec<(Period, Cplx, Classification)>>,
+    /// Multiplier of the cycle attracting a critical orbit of the child, shown with the table.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    critical_multiplier: Option<Cplx>,
+    /// Period whose cycles are shown on the child by the period probe slider, if it is open.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    period_probe: Option<Period>,
+    /// Text waiting to be copied to the clipboard on the next frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clipboard: Option<String>,
     click_used: bool,
     pub message: UiMessage,
 }
 
+/// Parse an angle in any format accepted by [`RationalAngle`], or as `<decimal, q>`, which snaps
+/// the decimal to the nearest angle with denominator at most $q$.
+fn parse_snapped_angle(text: &str) -> Option<RationalAngle>
+{
+    if let Some((x, max_denom)) = text.split_once(',') {
+        let x = x.trim().parse::<Real>().ok()?;
+        let max_denom = max_denom.trim().parse::<AngleNum>().ok()?;
+        return Some(RationalAngle::nearest_with_max_denominator(x, max_denom));
+    }
+    text.trim()
+        .parse::<RationalAngle>()
+        .map_err(|e| println!("Invalid angle {text}: {e}"))
+        .ok()
+}
+
+/// Parse a point, or `<point, step>`, which rounds both coordinates to multiples of the step.
+fn parse_snapped_point(text: &str) -> Option<Cplx>
+{
+    if let Some((point, step)) = text.split_once(',') {
+        let point = point.trim().parse::<Cplx>().ok()?;
+        let step = step.trim().parse::<Real>().ok().filter(|s| *s > 0.)?;
+        return Some(Cplx::new(
+            (point.re / step).round() * step,
+            (point.im / step).round() * step,
+        ));
+    }
+    text.trim().parse::<Cplx>().ok()
+}
+
 impl<P, J> MainInterface<P, J>
 where
     P: Displayable + HasChild<J> + Clone,
@@ -125,18 +258,86 @@ where
             image_height,
             active_pane: Some(PaneID::Parent),
             live_mode: false,
+            freehand_mode: false,
+            snap_to_pixels: false,
+            probe: false,
+            julia_preview_enabled: false,
+            julia_preview: JuliaPreview::default(),
+            navigation: Navigation::default(),
+            palette_seed: None,
+            shared_level_range: false,
             dialog: None,
+            ray_julia_export: None,
+            palette_library: PaletteLibrary::default(),
+            cycle_table: None,
+            critical_multiplier: None,
+            period_probe: None,
+            clipboard: None,
             click_used: false,
             message: UiMessage::default(),
         }
     }
 
+    /// Sets the sensitivity and momentum of panning and zooming.
+    #[must_use]
+    pub fn with_navigation(mut self, navigation: Navigation) -> Self
+    {
+        self.navigation = navigation;
+        self
+    }
+
+    /// Sets the palette library used for quick-switching between palettes.
+    #[must_use]
+    pub fn with_palette_library(mut self, palette_library: PaletteLibrary) -> Self
+    {
+        self.palette_library = palette_library;
+        self
+    }
+
+    /// Exchange the parent and child panes, keeping the same pane active. Only possible when the
+    /// child is a standalone plane of the same type as the parent; returns whether the panes were
+    /// swapped.
+    pub fn swap_panes(&mut self) -> bool
+    {
+        use std::any::Any;
+
+        let Some(parent) = (&mut self.parent as &mut dyn Any).downcast_mut::<WindowPane<J>>()
+        else {
+            println!("Cannot swap panes: the child is not a standalone plane");
+            return false;
+        };
+        std::mem::swap(parent, &mut self.child);
+        self.active_pane = self.active_pane.map(|pane_id| match pane_id {
+            PaneID::Parent => PaneID::Child,
+            PaneID::Child => PaneID::Parent,
+        });
+        self.parent.schedule_redraw();
+        self.child.schedule_redraw();
+        true
+    }
+
     /// Sets a new parameter for the child pane based on the parent pane's parameter.
     fn set_child_param(&mut self, new_param: P::Param)
     {
+        if self.child.plane.param_locked() {
+            return;
+        }
+
         let old_center = self.child.grid().center();
         let old_default_center = self.child.plane.default_bounds().center();
 
+        if self.cycle_table.is_some() {
+            self.cycle_table = Some(
+                self.parent
+                    .plane
+                    .cycle_multipliers_child(&new_param, CYCLE_TABLE_MAX_PERIOD),
+            );
+            self.critical_multiplier = self
+                .parent
+                .plane
+                .critical_orbit_multiplier_child(&new_param);
+        }
+
         if self.child.set_param(P::to_child_param(new_param)) {
             let mut new_bounds = self.child.plane.default_bounds();
 
@@ -144,14 +345,23 @@ where
             let offset = new_bounds.center() - old_default_center;
             let new_center = old_center + offset;
 
-            if offset.is_finite() {
-                new_bounds.zoom(self.child.zoom_factor, new_center);
-                new_bounds.recenter(new_center);
-                self.child.grid_mut().change_bounds(new_bounds);
+            let mut followed = new_bounds.clone();
+            let follow = if offset.is_finite() {
+                followed
+                    .zoom(self.child.zoom_factor, new_center)
+                    .and_then(|()| followed.recenter(new_center))
+                    .and_then(|()| self.child.grid_mut().change_bounds(followed))
+            } else {
+                Err(BoundsError::NonFinite)
+            };
+
+            if follow.is_ok() {
                 self.child.schedule_recompute();
             } else {
                 // Reset child bounds to default
-                self.child.grid_mut().change_bounds(new_bounds);
+                if let Err(e) = self.child.grid_mut().change_bounds(new_bounds) {
+                    println!("Invalid default bounds for {}: {e}", self.child.name());
+                }
                 self.child.grid_mut().resize_y(self.image_height);
                 self.child.schedule_compute();
             }
@@ -172,7 +382,7 @@ where
         file_type: SaveFileType,
     )
     {
-        use SaveFileType::{Image, Palette};
+        use SaveFileType::{AlphaMask, Annotations, ContactSheet, Image, Palette, SampleGrid};
 
         // Ensure file selection was confirmed
         if !file_dialog.selected() {
@@ -186,22 +396,68 @@ where
         let pane_ids = self.get_selected_pane_ids(selection);
 
         match file_type {
-            Image => {
+            Image {
+                include_annotations,
+            } => {
                 let image_width: usize = 4096; // You can make this dynamic as per your requirement
-                pane_ids
+                pane_ids.into_iter().for_each(|pane_id| {
+                    self.get_pane_mut(pane_id)
+                        .save_image(image_width, path, include_annotations);
+                });
+            }
+            ContactSheet => {
+                let tiles: Vec<_> = pane_ids
                     .into_iter()
-                    .for_each(|pane_id| self.get_pane_mut(pane_id).save_image(image_width, path));
+                    .map(|pane_id| {
+                        let pane = self.get_pane_mut(pane_id);
+                        (
+                            pane.long_name(),
+                            pane.render_image(CONTACT_SHEET_TILE_WIDTH),
+                        )
+                    })
+                    .collect();
+                if let Err(e) = contact_sheet(&tiles).save(path) {
+                    println!("Error saving file: {e:?}");
+                } else {
+                    println!("Contact sheet saved to {}", path.to_string_lossy());
+                }
+            }
+            AlphaMask(mode) => {
+                let image_width: usize = 4096;
+                pane_ids.into_iter().for_each(|pane_id| {
+                    self.get_pane_mut(pane_id)
+                        .save_alpha_mask(image_width, path, mode);
+                });
             }
             Palette => {
                 pane_ids
                     .into_iter()
                     .for_each(|pane_id| self.get_pane_mut(pane_id).save_palette(path));
             }
+            Annotations => {
+                pane_ids
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane(pane_id).save_annotations(path));
+            }
+            SampleGrid => {
+                pane_ids.into_iter().for_each(|pane_id| {
+                    if let Err(e) = self.get_pane(pane_id).grid().write_sample_csv(path) {
+                        println!("Error saving sample grid: {e:?}");
+                    } else {
+                        println!("Sample grid saved to {}", path.to_string_lossy());
+                    }
+                });
+            }
         }
         self.set_active_pane(None);
     }
 
-    fn handle_load_dialog(&mut self, file_dialog: &FileDialog, pane_selection: PaneSelection)
+    fn handle_load_dialog(
+        &mut self,
+        file_dialog: &FileDialog,
+        pane_selection: PaneSelection,
+        file_type: LoadFileType,
+    )
     {
         // Ensure file selection was confirmed
         if !file_dialog.selected() {
@@ -215,7 +471,11 @@ where
         self.get_selected_pane_ids(pane_selection)
             .into_iter()
             .for_each(|pane_id| {
-                self.get_pane_mut(pane_id).load_palette(path);
+                let pane = self.get_pane_mut(pane_id);
+                match file_type {
+                    LoadFileType::Palette => pane.load_palette(path),
+                    LoadFileType::Annotations => pane.load_annotations(path),
+                }
             });
 
         self.set_active_pane(None);
@@ -228,14 +488,23 @@ where
         toggle_map: &ToggleMap,
     )
     {
-        use crate::dialog::TextInputType::{ActiveRays, Coordinates, ExternalRay, FindPeriodic};
+        use crate::dialog::TextInputType::{
+            ActiveRays, Coordinates, CriticalOrbit, CurveThickness, CyclePolynomial, Degree,
+            ExternalRay, FindPeriodic, FiniteDiffStep, InfinityColor, IsoperiodicCurve,
+            MaxOrbitLength, Misiurewicz, Navigation, PaletteSeed, PeriodHues, PinLabel,
+            RayJuliaFrames, RayLandings, RenderThreads, ViewTransform,
+        };
         use crate::dialog::ToggleKey::{
             DoChild, DoParent, DrawOrbit, FollowPoint, PrefixAngles, SelectPoint,
         };
         match input_type {
             ExternalRay { .. } => {
-                if let Ok(angle) = text.parse::<RationalAngle>() {
-                    let angle_info = angle.with_degree(self.child.degree()).to_angle_info();
+                if let Some(angle) = parse_snapped_angle(text) {
+                    let Some(degree) = self.child.degree() else {
+                        println!("{} has no external rays", self.child.name());
+                        return;
+                    };
+                    let angle_info = angle.with_degree(degree).to_angle_info();
 
                     let include_orbit = toggle_map.get(DrawOrbit);
 
@@ -262,7 +531,11 @@ where
                 if let Ok(o) = text.parse::<OrbitSchema>() {
                     let include_suffixes = toggle_map.get(PrefixAngles);
 
-                    let degree = self.get_pane(pane_id).degree();
+                    let pane = self.get_pane(pane_id);
+                    let Some(degree) = pane.degree() else {
+                        println!("{} has no external rays", pane.name());
+                        return;
+                    };
                     let od = o.with_degree(degree);
                     let active_angles = od.active_angles(include_suffixes);
 
@@ -279,13 +552,23 @@ where
                 }
             }
             Coordinates { pane_id } => {
-                if let Ok(point) = text.parse::<Cplx>() {
+                if let Some(point) = parse_snapped_point(text) {
                     let pane = self.get_pane_mut(pane_id);
                     pane.select_point(point);
                     pane.stop_following();
                     self.process_child_task();
                 }
             }
+            ViewTransform { pane_id } => {
+                if text.trim().is_empty() {
+                    self.get_pane_mut(pane_id).set_view_transform(None);
+                } else {
+                    match text.parse::<Mobius>() {
+                        Ok(mobius) => self.get_pane_mut(pane_id).set_view_transform(Some(mobius)),
+                        Err(e) => println!("Invalid view transform: {e}"),
+                    }
+                }
+            }
             FindPeriodic { pane_id } => {
                 if let Ok(orbit_schema) = text.parse::<OrbitSchema>() {
                     let follow = toggle_map.get(FollowPoint);
@@ -305,6 +588,151 @@ where
                     }
                 }
             }
+            IsoperiodicCurve => {
+                let mut args = text.split(',').map(str::trim);
+                let period = args.next().and_then(|p| p.parse::<Period>().ok());
+                let modulus = args.next().map_or(Ok(1.), str::parse::<Real>);
+                if let (Some(period), Ok(modulus)) = (period, modulus) {
+                    self.parent_mut().draw_isoperiodic_curve(period, modulus);
+                }
+            }
+            FiniteDiffStep => match text.trim().parse::<Real>() {
+                Ok(h) if h > 0. => {
+                    set_finite_diff_h(h);
+                    self.parent_mut().discard_cached_renders();
+                    self.child_mut().discard_cached_renders();
+                }
+                _ => println!("Invalid step size: {text}"),
+            },
+            RenderThreads => match text.trim().parse::<usize>() {
+                Ok(0) => set_render_threads(None),
+                Ok(num_threads) => set_render_threads(Some(num_threads)),
+                Err(_) => println!("Invalid number of threads: {text}"),
+            },
+            Navigation => {
+                let values: Result<Vec<Real>, _> =
+                    text.split(',').map(|s| s.trim().parse::<Real>()).collect();
+                match values.as_deref() {
+                    Ok(&[pan, zoom, momentum]) if pan > 0. && zoom > 0. => {
+                        self.navigation = self
+                            .navigation
+                            .clone()
+                            .with_pan_sensitivity(pan)
+                            .with_zoom_sensitivity(zoom)
+                            .with_momentum(momentum as f32);
+                    }
+                    _ => println!("Invalid navigation settings: {text}"),
+                }
+            }
+            CyclePolynomial => match text.trim().parse::<Period>() {
+                Ok(period) if period > 0 => self.export_cycle_polynomial(period),
+                _ => println!("Invalid period: {text}"),
+            },
+            MaxOrbitLength => match text.trim().parse::<usize>() {
+                Ok(len) if len > 0 => {
+                    self.parent.marking_mut().set_max_orbit_len(len);
+                    self.child.marking_mut().set_max_orbit_len(len);
+                }
+                _ => println!("Invalid orbit length: {text}"),
+            },
+            CurveThickness => {
+                let mut words = text.split_whitespace();
+                let width = words.next().and_then(|w| w.parse::<f32>().ok());
+                let in_data_space = match words.next() {
+                    None | Some("screen") => Some(false),
+                    Some("data") => Some(true),
+                    Some(_) => None,
+                };
+                match (width, in_data_space) {
+                    (Some(width), Some(in_data_space)) if width > 0. => {
+                        let from_pixels = crate::marked_points::CurveThickness::from_pixels;
+                        let thickness = from_pixels(width, self.parent.grid(), in_data_space);
+                        self.parent.marking_mut().set_curve_thickness(thickness);
+                        let thickness = from_pixels(width, self.child.grid(), in_data_space);
+                        self.child.marking_mut().set_curve_thickness(thickness);
+                    }
+                    _ => println!("Invalid curve thickness: {text}"),
+                }
+            }
+            Misiurewicz => match text.parse::<OrbitSchema>() {
+                Ok(orbit_schema) if orbit_schema.preperiod > 0 => {
+                    match self.parent_mut().select_nearby_misiurewicz(orbit_schema) {
+                        Ok(_) => self.process_child_task(),
+                        Err(e) => println!("Could not find Misiurewicz point: {e:?}"),
+                    }
+                }
+                _ => println!("Invalid orbit schema: {text}"),
+            },
+            RayLandings => match text.trim().parse::<AngleNum>() {
+                Ok(denominator) if denominator > 1 => self.export_ray_landings(denominator),
+                _ => println!("Invalid denominator: {text}"),
+            },
+            RayJuliaFrames => {
+                let mut args = text.split(',').map(str::trim);
+                let angle = args.next().and_then(|a| a.parse::<RationalAngle>().ok());
+                let frames = args.next().and_then(|n| n.parse::<usize>().ok());
+                match (angle, frames) {
+                    (Some(angle), Some(frames)) if frames > 0 => {
+                        self.save_ray_julia_frames(angle, frames);
+                    }
+                    _ => println!("Invalid ray and frame count: {text}"),
+                }
+            }
+            CriticalOrbit => {
+                let text = text.trim();
+                let critical_orbit = if text.eq_ignore_ascii_case("all") {
+                    Some(dynamics::CriticalOrbit::All)
+                } else {
+                    text.parse().ok().map(dynamics::CriticalOrbit::Index)
+                };
+                match critical_orbit {
+                    Some(critical_orbit) => self.parent.set_critical_orbit(critical_orbit),
+                    None => println!("Invalid critical orbit: {text}"),
+                }
+            }
+            PeriodHues => match text.trim().parse::<f32>() {
+                Ok(num_colors) if num_colors >= 1. => {
+                    self.parent.set_period_hues(num_colors);
+                    self.child.set_period_hues(num_colors);
+                }
+                _ => println!("Invalid number of period hues: {text}"),
+            },
+            InfinityColor => {
+                let text = text.trim();
+                if text.is_empty() {
+                    self.set_infinity_color(None);
+                } else {
+                    match Color32::from_hex(text) {
+                        Ok(color) => self.set_infinity_color(Some(color)),
+                        Err(_) => println!("Invalid color: {text}"),
+                    }
+                }
+            }
+            PaletteSeed => match text.trim().parse::<u64>() {
+                Ok(seed) => self.apply_palette_seed(seed),
+                Err(_) => println!("Invalid palette seed: {text}"),
+            },
+            Degree => {
+                if self.parent.runtime_degree().is_none() {
+                    println!("{} has no adjustable degree", self.parent.name());
+                    return;
+                }
+                match text.trim().parse::<Real>() {
+                    Ok(degree) if degree > 1. => {
+                        self.parent.set_runtime_degree(degree);
+                        self.child.set_runtime_degree(degree);
+                    }
+                    _ => println!("Invalid degree: {text}"),
+                }
+            }
+            PinLabel { pane_id, id } => {
+                let label = text.trim();
+                if !label.is_empty() {
+                    self.get_pane_mut(pane_id)
+                        .marking_mut()
+                        .relabel_pin(id, label.to_owned());
+                }
+            }
         }
     }
 
@@ -335,7 +763,15 @@ where
     fn handle_mouse(&mut self, ctx: &Context)
     {
         let clicked = ctx.input(|i| i.pointer.any_click()) && !self.click_used;
+        // Alt-clicking drops a pin instead of selecting a point
+        let pin_clicked = clicked && ctx.input(|i| i.modifiers.alt);
+        let clicked = clicked && !pin_clicked;
+        let double_clicked = ctx.input(|i| {
+            i.pointer
+                .button_double_clicked(egui::PointerButton::Primary)
+        });
         let zoom_factor = ctx.input(InputState::zoom_delta);
+        let zoom_factor = self.navigation.pointer_zoom_scale(zoom_factor.into()) as f32;
 
         self.reset_click();
 
@@ -346,45 +782,271 @@ where
         if ctx.input(|i| i.pointer.is_decidedly_dragging()) {
             if let Some(origin) = ctx.input(|i| i.pointer.press_origin()) {
                 let delta = ctx.input(|i| i.pointer.delta());
-                if self.parent().frame_contains_pixel(origin) {
-                    let offset = self.parent.grid().map_vec2((delta).into());
-                    self.parent.pan(-offset);
+                if self.freehand_mode {
+                    if self.parent().frame_contains_pixel(origin) {
+                        self.parent_mut().draw_freehand(pointer_pos);
+                    } else if self.child().frame_contains_pixel(origin) {
+                        self.child_mut().draw_freehand(pointer_pos);
+                    }
+                } else if self.parent().frame_contains_pixel(origin) {
+                    self.drag_pane(PaneID::Parent, delta.into());
+                    self.navigation.record_drag(PaneID::Parent, delta.into());
                 } else if self.child().frame_contains_pixel(origin) {
-                    let offset = self.child.grid().map_vec2((delta).into());
-                    self.child.pan(-offset);
+                    self.drag_pane(PaneID::Child, delta.into());
+                    self.navigation.record_drag(PaneID::Child, delta.into());
                 }
             }
+        } else if self.freehand_mode {
+            self.parent_mut().end_freehand();
+            self.child_mut().end_freehand();
+        } else if ctx.input(|i| i.pointer.any_pressed()) {
+            self.navigation.stop();
+        } else if let Some((pane_id, delta)) = self.navigation.coast() {
+            self.drag_pane(pane_id, delta);
+            ctx.request_repaint();
         }
 
         if self.parent().frame_contains_pixel(pointer_pos) {
             ctx.set_cursor_icon(CursorIcon::Crosshair);
             self.set_active_pane(Some(PaneID::Parent));
             let reselect_point = self.live_mode || clicked;
-            let pointer_value = self.parent().map_pixel(pointer_pos);
+            let mut pointer_value = self.parent().map_pixel(pointer_pos);
+            if self.snap_to_pixels {
+                pointer_value = self.parent().grid().snap_to_pixel(pointer_value);
+            }
             self.parent_mut()
                 .process_mouse_input(pointer_value, zoom_factor, reselect_point);
             self.process_child_task();
 
+            if self.probe {
+                self.show_probe(ctx, PaneID::Parent, pointer_pos, pointer_value);
+            }
+
+            if self.julia_preview_enabled && !self.live_mode {
+                self.hover_julia_preview(pointer_value, ctx.input(|i| i.time));
+                self.show_julia_preview(ctx, pointer_pos);
+            }
+
+            if double_clicked {
+                self.parent_mut()
+                    .recenter_on(pointer_value, DOUBLE_CLICK_ZOOM);
+            }
+
             if clicked {
                 self.consume_click();
                 self.parent_mut().marking_mut().enable_selection();
             }
+
+            if pin_clicked {
+                self.consume_click();
+                let id = self.parent_mut().place_pin(pointer_value);
+                self.prompt_text(TextInputType::PinLabel {
+                    pane_id: PaneID::Parent,
+                    id,
+                });
+            }
         } else if self.child().frame_contains_pixel(pointer_pos) {
+            self.julia_preview.clear();
             ctx.set_cursor_icon(CursorIcon::Crosshair);
             self.set_active_pane(Some(PaneID::Child));
-            let pointer_value = self.child().map_pixel(pointer_pos);
+            let mut pointer_value = self.child().map_pixel(pointer_pos);
+            if self.snap_to_pixels {
+                pointer_value = self.child().grid().snap_to_pixel(pointer_value);
+            }
             self.child_mut()
                 .process_mouse_input(pointer_value, zoom_factor, clicked);
 
+            if self.probe {
+                self.show_probe(ctx, PaneID::Child, pointer_pos, pointer_value);
+            }
+
+            if double_clicked {
+                self.child_mut()
+                    .recenter_on(pointer_value, DOUBLE_CLICK_ZOOM);
+            }
+
             if clicked {
                 self.consume_click();
                 self.child_mut().marking_mut().enable_selection();
             }
+
+            if pin_clicked {
+                self.consume_click();
+                let id = self.child_mut().place_pin(pointer_value);
+                self.prompt_text(TextInputType::PinLabel {
+                    pane_id: PaneID::Child,
+                    id,
+                });
+            }
         } else {
+            self.julia_preview.clear();
             ctx.set_cursor_icon(CursorIcon::Default);
         }
     }
 
+    /// Save the coefficients of the child's cycle polynomial of the given period to a text file,
+    /// one coefficient per line from the constant term up.
+    fn export_cycle_polynomial(&self, period: Period)
+    {
+        let param = self.child.plane.param_map(self.child.get_selection());
+        let coeffs = self.child.plane.cycle_polynomial(&param, period);
+        if coeffs.is_empty() {
+            println!(
+                "No cycle polynomial of period {period} is known for {}",
+                self.child.name()
+            );
+            return;
+        }
+
+        let Some(dir) = polynomials_dir() else {
+            println!("Could not find a directory to save the polynomial in");
+            return;
+        };
+        let path = dir.join(format!("{}_cycles_period_{period}.txt", self.parent.name()));
+        let text: String = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, a)| format!("{k}\t{}\t{}\n", a.re, a.im))
+            .collect();
+        match std::fs::write(&path, text) {
+            Ok(()) => println!("Saved cycle polynomial to {}", path.display()),
+            Err(e) => println!("Error saving cycle polynomial: {e}"),
+        }
+    }
+
+    /// Save the groups of co-landing parameter rays with the given denominator, one group per
+    /// line, as the landing point followed by the angles.
+    fn export_ray_landings(&self, denominator: AngleNum)
+    {
+        let tolerance = RAY_LANDING_TOLERANCE * self.parent.grid().pixel_width();
+        let landings = self.parent.plane.ray_landings(denominator, tolerance);
+        if landings.is_empty() {
+            println!(
+                "No rays of denominator {denominator} found on {}",
+                self.parent.name()
+            );
+            return;
+        }
+
+        let Some(dir) = combinatorics_dir() else {
+            println!("Could not find a directory to save the ray landings in");
+            return;
+        };
+        let path = dir.join(format!("ray_landings_{denominator}.txt"));
+        let text: String = landings
+            .iter()
+            .map(|RayLanding { point, angles }| {
+                let angles: Vec<String> = angles.iter().map(ToString::to_string).collect();
+                format!("{}\t{}\t{}\n", point.re, point.im, angles.join(", "))
+            })
+            .collect();
+        match std::fs::write(&path, text) {
+            Ok(()) => println!("Saved ray landings to {}", path.display()),
+            Err(e) => println!("Error saving ray landings: {e}"),
+        }
+    }
+
+    /// Start exporting the child Julia set at `frames` parameters sampled along the parameter
+    /// ray of the given angle to `dir`, as numbered images. The frames are rendered one per
+    /// update, each with the child's view and coloring at the time it is rendered.
+    pub fn start_ray_julia_export(&mut self, angle: RationalAngle, frames: usize, dir: PathBuf)
+    {
+        let Some(params) = self.parent.plane.external_ray_samples(angle, frames) else {
+            println!(
+                "Could not compute the ray of angle {angle} on {}",
+                self.parent.name()
+            );
+            return;
+        };
+        self.ray_julia_export = Some(RayJuliaExport {
+            dir,
+            params,
+            next: 0,
+            saved: 0,
+            image: RgbImage::new(0, 0),
+        });
+    }
+
+    /// Whether Julia sets along a parameter ray are still being exported.
+    #[must_use]
+    pub const fn is_exporting_ray_julia_frames(&self) -> bool
+    {
+        self.ray_julia_export.is_some()
+    }
+
+    /// Render and save the next frame of the export along a parameter ray, if there is one.
+    fn export_next_ray_julia_frame(&mut self)
+    {
+        let Some(export) = &mut self.ray_julia_export else {
+            return;
+        };
+        if let Some(&t) = export.params.get(export.next) {
+            let mut julia = self.child.plane.clone();
+            julia.set_param(P::to_child_param(self.parent.plane.param_map(t)));
+            julia
+                .compute()
+                .write_image_into(&mut export.image, &self.child.coloring);
+            let path = export.dir.join(format!("frame_{:04}.png", export.next));
+            match export.image.save(&path) {
+                Ok(()) => export.saved += 1,
+                Err(e) => println!("Error saving frame {}: {e:?}", export.next),
+            }
+            export.next += 1;
+        }
+        if export.next >= export.params.len() {
+            println!("Saved {} frames to {}", export.saved, export.dir.display());
+            self.ray_julia_export = None;
+        }
+    }
+
+    /// Export Julia sets along a parameter ray into a new folder of the images directory.
+    fn save_ray_julia_frames(&mut self, angle: RationalAngle, frames: usize)
+    {
+        let Some(dir) = images_dir() else {
+            println!("Could not find a directory to save the frames in");
+            return;
+        };
+        let dir = dir.join(format!("ray_{}", angle.to_string().replace('/', "_")));
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            println!("Error creating {}: {e}", dir.display());
+            return;
+        }
+        self.start_ray_julia_export(angle, frames, dir);
+    }
+
+    /// Export the orbit of the active pane's selection, one point per frame, into a new folder of
+    /// the images directory. The folder is named by the time of export, so that frames of a
+    /// longer orbit saved earlier are never mixed in.
+    fn save_orbit_animation(&mut self)
+    {
+        let Some(dir) = images_dir() else {
+            println!("Could not find a directory to save the frames in");
+            return;
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        let dir = dir.join(format!("orbit_frames_{timestamp}"));
+        if let Err(e) = std::fs::create_dir(&dir) {
+            println!("Error creating {}: {e}", dir.display());
+            return;
+        }
+        let Some(pane) = self.get_active_pane_mut() else {
+            return;
+        };
+        let saved = pane.save_orbit_frames(ORBIT_FRAME_WIDTH, &dir);
+        println!("Saved {saved} frames to {}", dir.display());
+    }
+
+    /// Pans a pane to follow a drag by `delta` pixels.
+    fn drag_pane(&mut self, pane_id: PaneID, delta: [f32; 2])
+    {
+        let offset = self
+            .navigation
+            .drag_offset(self.get_pane(pane_id).grid(), delta);
+        self.get_pane_mut(pane_id).pan(offset);
+    }
+
     /// Schedules a message to close the current window.
     fn schedule_close(&mut self)
     {
@@ -403,6 +1065,246 @@ where
         self.message = UiMessage::NewTab;
     }
 
+    fn toggle_cycle_table(&mut self)
+    {
+        self.cycle_table = if self.cycle_table.is_some() {
+            None
+        } else {
+            let param = self.parent.plane.param_map(self.parent.get_selection());
+            self.critical_multiplier = self.parent.plane.critical_orbit_multiplier_child(&param);
+            Some(
+                self.parent
+                    .plane
+                    .cycle_multipliers_child(&param, CYCLE_TABLE_MAX_PERIOD),
+            )
+        };
+    }
+
+    fn show_cycle_table(&self, ctx: &Context)
+    {
+        let Some(table) = &self.cycle_table else {
+            return;
+        };
+        egui::Window::new("Cycle Multipliers")
+            .resizable(true)
+            .show(ctx, |ui| {
+                match self.critical_multiplier {
+                    Some(multiplier) => ui.label(format!(
+                        "Critical orbit: multiplier {multiplier:.6}, |λ| = {:.6}",
+                        multiplier.norm()
+                    )),
+                    None => ui.label("Critical orbit: no attracting cycle found"),
+                };
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("cycle_multipliers")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.strong("Period");
+                            ui.strong("Multiplier");
+                            ui.strong("|λ|");
+                            ui.strong("Type");
+                            ui.end_row();
+                            for (period, multiplier, class) in table {
+                                ui.label(period.to_string());
+                                ui.label(format!("{multiplier:.6}"));
+                                ui.label(format!("{:.6}", multiplier.norm()));
+                                ui.label(class.to_string());
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+    }
+
+    /// Show the cycles of a single period on the child, in place of those of the period shown
+    /// before, or close the period probe if `period` is `None`.
+    pub fn set_probed_period(&mut self, period: Option<Period>)
+    {
+        self.period_probe = period;
+        self.child.marking_mut().set_probed_period(period);
+        self.child.schedule_redraw();
+    }
+
+    /// Color the basin of the attracting cycle through infinity on both panes, or by escape
+    /// time if `color` is `None`. Families in which infinity is not attracting are left as is.
+    pub fn set_infinity_color(&mut self, color: Option<Color32>)
+    {
+        if !self.parent.attracting_infinity() {
+            println!(
+                "{} has no attracting cycle through infinity",
+                self.parent.name()
+            );
+            return;
+        }
+        self.parent.set_infinity_color(color);
+        self.child.set_infinity_color(color);
+    }
+
+    fn toggle_period_probe(&mut self)
+    {
+        let period = if self.period_probe.is_some() {
+            None
+        } else {
+            Some(1)
+        };
+        self.set_probed_period(period);
+    }
+
+    fn show_period_probe(&mut self, ctx: &Context)
+    {
+        let Some(mut period) = self.period_probe else {
+            return;
+        };
+        egui::Window::new("Period Probe")
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.add(egui::Slider::new(&mut period, 1..=PERIOD_PROBE_MAX_PERIOD).text("Period"));
+            });
+        if Some(period) != self.period_probe {
+            self.set_probed_period(Some(period));
+        }
+    }
+
+    fn toggle_freehand_mode(&mut self)
+    {
+        self.freehand_mode ^= true;
+        if !self.freehand_mode {
+            self.parent.end_freehand();
+            self.child.end_freehand();
+        }
+    }
+
+    fn toggle_snap_to_pixels(&mut self)
+    {
+        self.snap_to_pixels ^= true;
+    }
+
+    fn toggle_probe(&mut self)
+    {
+        self.probe ^= true;
+    }
+
+    fn toggle_julia_preview(&mut self)
+    {
+        self.julia_preview_enabled ^= true;
+        self.julia_preview.clear();
+    }
+
+    /// The child plane at the parameter of `point` in the parent, in its default view and with
+    /// a reduced iteration limit, for previewing the Julia set there.
+    pub fn julia_preview_plane(&self, point: Cplx) -> J
+    {
+        let mut julia = self.child.plane.clone();
+        julia.set_param(P::to_child_param(self.parent.plane.param_map(point)));
+        let bounds = julia.default_bounds();
+        if let Err(e) = julia.point_grid_mut().change_bounds(bounds) {
+            println!("Invalid default bounds for Julia preview: {e}");
+        }
+        julia.set_max_iter(julia.max_iter().min(JULIA_PREVIEW_MAX_ITER));
+        julia
+    }
+
+    /// Record the point of the parent under the cursor at `time`, in seconds, and render its
+    /// Julia set preview once the cursor has rested there for long enough.
+    pub fn hover_julia_preview(&mut self, point: Cplx, time: f64)
+    {
+        if let Some(point) = self.julia_preview.hover(point, time) {
+            let julia = self.julia_preview_plane(point);
+            let mut image = ColorImage::default();
+            julia
+                .compute_thumbnail(JULIA_PREVIEW_WIDTH)
+                .render_into(&mut image, &self.child.coloring);
+            self.julia_preview.set(julia, image);
+        }
+    }
+
+    /// The plane shown in the Julia preview, if it has been rendered.
+    #[must_use]
+    pub fn julia_preview(&self) -> Option<&J>
+    {
+        self.julia_preview.plane()
+    }
+
+    /// Show the Julia preview next to the cursor, or wake up once it is due if the cursor is
+    /// still resting.
+    fn show_julia_preview(&mut self, ctx: &Context, pointer_pos: Pos2)
+    {
+        if self.julia_preview.is_pending() {
+            ctx.request_repaint_after(Duration::from_secs_f64(JULIA_PREVIEW_DELAY));
+        }
+        let Some(texture) = self.julia_preview.texture(ctx) else {
+            return;
+        };
+        egui::Area::new(egui::Id::new("julia_preview"))
+            .order(egui::Order::Tooltip)
+            .pivot(egui::Align2::LEFT_BOTTOM)
+            .fixed_pos(pointer_pos + JULIA_PREVIEW_OFFSET)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| ui.image(texture));
+            });
+    }
+
+    /// Color both panes by a common range of escape levels with the parent's palette, or go back
+    /// to coloring each by absolute escape level.
+    fn toggle_shared_level_range(&mut self)
+    {
+        self.shared_level_range ^= true;
+        if self.shared_level_range {
+            let palette = *self.parent.get_coloring().get_palette();
+            self.child.change_palette(palette);
+            self.sync_level_ranges();
+        } else {
+            self.parent.set_level_range(None);
+            self.child.set_level_range(None);
+        }
+    }
+
+    /// Give both panes the union of the ranges of escape levels in their images.
+    fn sync_level_ranges(&mut self)
+    {
+        let range = [
+            self.parent.escape_level_range(),
+            self.child.escape_level_range(),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|(lo_0, hi_0), (lo_1, hi_1)| (lo_0.min(lo_1), hi_0.max(hi_1)));
+        self.parent.set_level_range(range);
+        self.child.set_level_range(range);
+    }
+
+    fn copy_view_as_rust(&mut self)
+    {
+        self.clipboard = self.view_as_rust();
+    }
+
+    /// Show the hover probe for a point of a pane next to the cursor.
+    fn show_probe(&self, ctx: &Context, pane_id: PaneID, pointer_pos: Pos2, point: Cplx)
+    {
+        let text = self.get_pane(pane_id).probe(point);
+        egui::Area::new(egui::Id::new("hover_probe"))
+            .order(egui::Order::Tooltip)
+            .fixed_pos(pointer_pos + PROBE_OFFSET)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| ui.label(text));
+            });
+    }
+
+    /// Freeze the child at its current parameter, or release it to follow the parent
+    /// selection again.
+    fn toggle_child_param_lock(&mut self)
+    {
+        let locked = !self.child.plane.param_locked();
+        self.child.plane.set_param_locked(locked);
+        if !locked {
+            let parent_selection = self.parent.get_selection();
+            let new_child_param = self.parent.plane.param_map(parent_selection);
+            self.set_child_param(new_child_param);
+        }
+    }
+
     /// Toggles the live mode state of the interface.
     fn toggle_live_mode(&mut self)
     {
@@ -451,15 +1353,28 @@ where
     /// Randomizes the color palette for both the parent and child panes.
     fn randomize_palette(&mut self)
     {
-        let palette = Palette::new_random(0.45, 0.38);
+        self.apply_palette_seed(Palette::random_seed());
+    }
+
+    /// Applies the random palette generated from `seed` to both panes, and records the seed.
+    fn apply_palette_seed(&mut self, seed: u64)
+    {
+        let palette = Palette::new_seeded(seed, 0.45, 0.38);
         self.parent.change_palette(palette);
         self.child.change_palette(palette);
+        self.palette_seed = Some(seed);
+        println!("Palette seed: {seed}");
     }
 
     /// Prompt for text input for a specified purpose.
     fn prompt_text(&mut self, input_type: TextInputType)
     {
-        use TextInputType::{ActiveRays, Coordinates, ExternalRay, FindPeriodic};
+        use TextInputType::{
+            ActiveRays, Coordinates, CriticalOrbit, CurveThickness, CyclePolynomial, Degree,
+            ExternalRay, FindPeriodic, FiniteDiffStep, InfinityColor, IsoperiodicCurve,
+            MaxOrbitLength, Misiurewicz, Navigation, PaletteSeed, PeriodHues, PinLabel,
+            RayJuliaFrames, RayLandings, ViewTransform,
+        };
         let text_dialog = match input_type {
             ExternalRay {
                 pane_id,
@@ -468,7 +1383,8 @@ where
             } => {
                 let prompt = concat!(
                     "Input an angle to draw a ray\n",
-                    "Example formats: <15/56>, <110>, <p011>, <001p010>",
+                    "Example formats: <15/56>, <110>, <p011>, <001p010>\n",
+                    "Snap to a denominator of at most q: <0.333, q>",
                 );
                 let builder = TextDialogBuilder::new(input_type)
                     .title("External ray angle input")
@@ -534,7 +1450,9 @@ where
             Coordinates { pane_id } => {
                 let pane = self.get_pane(pane_id);
                 let prompt = format!(
-                    "Enter the coordinates of the point to select on {pane_name}",
+                    "Enter the coordinates of the point {symbol} to select on {pane_name}\n\
+                    Snap to a grid of spacing h: <point, h>",
+                    symbol = pane.param_symbol(),
                     pane_name = pane.name()
                 );
                 TextDialogBuilder::new(input_type)
@@ -542,16 +1460,249 @@ where
                     .prompt(prompt)
                     .build()
             }
+            ViewTransform { pane_id } => {
+                let pane = self.get_pane(pane_id);
+                let current = pane
+                    .grid()
+                    .view_transform
+                    .map_or_else(|| "none".to_owned(), |t| t.to_string());
+                let prompt = format!(
+                    "Input the coefficients of a Möbius transformation (az + b)/(cz + d) to apply \
+                    to each point of {pane_name} before iterating, or leave blank to remove it.\n\
+                    Format: <a, b, c, d>\n\
+                    Current value: {current}",
+                    pane_name = pane.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("View transform")
+                    .prompt(prompt)
+                    .build()
+            }
+            IsoperiodicCurve => {
+                let prompt = concat!(
+                    "Input the period and multiplier modulus of the curve to draw.\n",
+                    "Format: <period> or <period, modulus> (default modulus: 1)"
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Draw isoperiodic curve")
+                    .prompt(prompt)
+                    .build()
+            }
+            FiniteDiffStep => {
+                let prompt = format!(
+                    concat!(
+                        "Input the relative step size for numerical derivatives.\n",
+                        "Current value: {h:e} (default: {default:e})"
+                    ),
+                    h = finite_diff_h(),
+                    default = DEFAULT_FINITE_DIFF_H
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Finite difference step")
+                    .prompt(prompt)
+                    .build()
+            }
+            RenderThreads => {
+                let current =
+                    render_threads().map_or_else(|| "no limit".to_owned(), |n| n.to_string());
+                let prompt = format!(
+                    concat!(
+                        "Input the maximum number of threads used for rendering, ",
+                        "or 0 for no limit. Use 1 for sequential renders when profiling.\n",
+                        "Current value: {current}"
+                    ),
+                    current = current
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Render threads")
+                    .prompt(prompt)
+                    .build()
+            }
+            Navigation => {
+                let prompt = format!(
+                    concat!(
+                        "Input the pan sensitivity, zoom sensitivity and drag momentum (0 to 1).\n",
+                        "Format: <pan, zoom, momentum>\n",
+                        "Current values: {pan}, {zoom}, {momentum}"
+                    ),
+                    pan = self.navigation.pan_sensitivity,
+                    zoom = self.navigation.zoom_sensitivity,
+                    momentum = self.navigation.momentum,
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Navigation")
+                    .prompt(prompt)
+                    .build()
+            }
+            CyclePolynomial => {
+                let prompt = format!(
+                    "Input the period of the cycles of {pane_name} to export the polynomial of",
+                    pane_name = self.child.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Export cycle polynomial")
+                    .prompt(prompt)
+                    .build()
+            }
+            MaxOrbitLength => {
+                let prompt = format!(
+                    concat!(
+                        "Input the maximum number of points of a drawn orbit.\n",
+                        "Current value: {len} (default: {default})"
+                    ),
+                    len = self.child.marking().max_orbit_len(),
+                    default = DEFAULT_MAX_ORBIT_LEN
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Orbit length")
+                    .prompt(prompt)
+                    .build()
+            }
+            CurveThickness => {
+                let prompt = format!(
+                    concat!(
+                        "Input the width of drawn curves in pixels, followed by \"data\" to keep ",
+                        "it fixed in the plane, so that curves thicken as the view zooms in.\n",
+                        "Current value: {thickness}"
+                    ),
+                    thickness = self.child.marking().curve_thickness(),
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Curve thickness")
+                    .prompt(prompt)
+                    .build()
+            }
+            Misiurewicz => {
+                let prompt = format!(
+                    concat!(
+                        "Input the preperiod and period of a Misiurewicz point to find near the ",
+                        "selection on {pane_name}.\n",
+                        "Format: <preperiod, period>"
+                    ),
+                    pane_name = self.parent.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Find Misiurewicz point")
+                    .prompt(prompt)
+                    .build()
+            }
+            RayJuliaFrames => {
+                let prompt = format!(
+                    concat!(
+                        "Input the angle of a ray of {pane_name} and the number of Julia sets ",
+                        "to export along it.\n",
+                        "Format: <angle, frames>"
+                    ),
+                    pane_name = self.parent.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Export Julia sets along ray")
+                    .prompt(prompt)
+                    .build()
+            }
+            RayLandings => {
+                let prompt = format!(
+                    "Input the denominator of the rays of {pane_name} to group by landing point",
+                    pane_name = self.parent.name()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Export ray landings")
+                    .prompt(prompt)
+                    .build()
+            }
+            CriticalOrbit => {
+                let prompt = format!(
+                    concat!(
+                        "Input the index of the critical point whose orbit colors {pane_name}, ",
+                        "or \"all\" to combine every critical orbit.\n",
+                        "Current value: {current}"
+                    ),
+                    pane_name = self.parent.name(),
+                    current = self.parent.critical_orbit()
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Critical orbit")
+                    .prompt(prompt)
+                    .build()
+            }
+            PeriodHues => {
+                let prompt = format!(
+                    concat!(
+                        "Input the number of distinct hues used to color periods, ",
+                        "after which the colors repeat.\n",
+                        "Current value: {num_colors}"
+                    ),
+                    num_colors = self.child.get_coloring().get_period_coloring().num_colors
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Period hues")
+                    .prompt(prompt)
+                    .build()
+            }
+            InfinityColor => {
+                let current = self
+                    .parent
+                    .get_coloring()
+                    .infinity_color()
+                    .map_or_else(|| "escape time".to_owned(), |color| color.to_hex());
+                let prompt = format!(
+                    concat!(
+                        "Input a hex color such as #3060c0 for the basin of the attracting cycle ",
+                        "through infinity, or leave empty to color it by escape time.\n",
+                        "Current value: {current}"
+                    ),
+                    current = current
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Infinity color")
+                    .prompt(prompt)
+                    .build()
+            }
+            PaletteSeed => {
+                let last_seed = self
+                    .palette_seed
+                    .map_or_else(|| "none".to_owned(), |seed| seed.to_string());
+                let prompt = format!(
+                    "Input the seed of a random palette to apply.\nLast seed used: {last_seed}"
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Palette seed")
+                    .prompt(prompt)
+                    .build()
+            }
+            Degree => {
+                let prompt = format!(
+                    "Input the degree d > 1 of {pane_name}.\nCurrent value: {current}",
+                    pane_name = self.parent.name(),
+                    current = self
+                        .parent
+                        .runtime_degree()
+                        .map_or_else(|| "fixed".to_owned(), |d| d.to_string())
+                );
+                TextDialogBuilder::new(input_type)
+                    .title("Degree")
+                    .prompt(prompt)
+                    .build()
+            }
+            PinLabel { id, .. } => TextDialogBuilder::new(input_type)
+                .title("Label pin")
+                .prompt(format!("Input a label for pin {}", id + 1))
+                .build(),
         };
         let dialog = Dialog::Text(text_dialog);
         self.dialog = Some(dialog);
     }
 
     /// Open a dialog prompt to save an image.
-    fn prompt_save_image(&mut self, pane_selection: PaneSelection)
+    fn prompt_save_image(&mut self, pane_selection: PaneSelection, include_annotations: bool)
     {
+        let title = if include_annotations {
+            "Save Image"
+        } else {
+            "Save Clean Image"
+        };
         let mut file_dialog = FileDialog::save_file(images_dir())
-            .title("Save Image")
+            .title(title)
             .show_rename(false)
             .show_new_folder(true);
         file_dialog.open();
@@ -559,7 +1710,44 @@ where
         self.dialog = Some(Dialog::Save {
             pane_selection,
             file_dialog,
-            file_type: SaveFileType::Image,
+            file_type: SaveFileType::Image {
+                include_annotations,
+            },
+        });
+    }
+
+    fn prompt_save_contact_sheet(&mut self)
+    {
+        let mut file_dialog = FileDialog::save_file(images_dir())
+            .title("Save Contact Sheet")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog
+            .default_filename(format!("{} (contact sheet).png", self.parent.long_name()));
+        self.dialog = Some(Dialog::Save {
+            pane_selection: PaneSelection::BothPanes,
+            file_dialog,
+            file_type: SaveFileType::ContactSheet,
+        });
+    }
+
+    fn prompt_save_alpha_mask(&mut self, mode: AlphaMaskMode)
+    {
+        let Some(pane) = self.get_active_pane() else {
+            return;
+        };
+        let default_filename = format!("{} ({mode} mask).png", pane.long_name());
+        let mut file_dialog = FileDialog::save_file(images_dir())
+            .title("Save Alpha Mask")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename(default_filename);
+        self.dialog = Some(Dialog::Save {
+            pane_selection: PaneSelection::ActivePane,
+            file_dialog,
+            file_type: SaveFileType::AlphaMask(mode),
         });
     }
 
@@ -588,6 +1776,66 @@ where
         self.dialog = Some(Dialog::Load {
             pane_selection,
             file_dialog,
+            file_type: LoadFileType::Palette,
+        });
+    }
+
+    fn prompt_export_annotations(&mut self)
+    {
+        let Some(pane) = self.get_active_pane() else {
+            return;
+        };
+        let default_filename = format!("{} annotations.json", pane.long_name());
+        let mut file_dialog = FileDialog::save_file(images_dir())
+            .title("Export Annotations")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename(default_filename);
+        self.dialog = Some(Dialog::Save {
+            pane_selection: PaneSelection::ActivePane,
+            file_dialog,
+            file_type: SaveFileType::Annotations,
+        });
+    }
+
+    fn prompt_export_sample_grid(&mut self)
+    {
+        let Some(pane) = self.get_active_pane() else {
+            return;
+        };
+        let num_points = pane.grid().res_x * pane.grid().res_y;
+        if num_points > LARGE_GRID_POINTS {
+            println!("Warning: the sample grid has {num_points} points, one row each");
+        }
+        let default_filename = format!("{} samples.csv", pane.long_name());
+        let mut file_dialog = FileDialog::save_file(images_dir())
+            .title("Export Sample Grid")
+            .show_rename(false)
+            .show_new_folder(true);
+        file_dialog.open();
+        let file_dialog = file_dialog.default_filename(default_filename);
+        self.dialog = Some(Dialog::Save {
+            pane_selection: PaneSelection::ActivePane,
+            file_dialog,
+            file_type: SaveFileType::SampleGrid,
+        });
+    }
+
+    fn prompt_import_annotations(&mut self)
+    {
+        if self.active_pane.is_none() {
+            return;
+        }
+        let mut file_dialog = FileDialog::open_file(images_dir())
+            .title("Import Annotations")
+            .show_rename(false)
+            .show_new_folder(false);
+        file_dialog.open();
+        self.dialog = Some(Dialog::Load {
+            pane_selection: PaneSelection::ActivePane,
+            file_dialog,
+            file_type: LoadFileType::Annotations,
         });
     }
 
@@ -678,8 +1926,19 @@ where
 
     fn update_panes(&mut self)
     {
+        let computing = (
+            self.parent.tasks().compute.is_pending(),
+            self.child.tasks().compute.is_pending(),
+        );
         self.parent.process_tasks();
         self.child.process_tasks();
+        self.export_next_ray_julia_frame();
+        // The escape levels in view only change when a pane finishes computing
+        let finished = (computing.0 && !self.parent.tasks().compute.is_pending())
+            || (computing.1 && !self.child.tasks().compute.is_pending());
+        if self.shared_level_range && finished {
+            self.sync_level_ranges();
+        }
     }
 
     // fn descend(self) -> Box<dyn PanePair>
@@ -705,6 +1964,8 @@ where
             ctx.set_cursor_icon(CursorIcon::Default);
             return;
         }
+        // Don't treat typing into a text field elsewhere, such as a search box, as shortcuts
+        let typing = ctx.wants_keyboard_input();
         for Hotkey {
             shortcut,
             action,
@@ -721,7 +1982,7 @@ where
             .chain(PALETTE_HOTKEYS.iter())
         {
             if let Some(s) = shortcut.as_ref() {
-                if shortcut_used!(ctx, s) {
+                if !typing && shortcut_used!(ctx, s) {
                     self.process_action(action);
                     if let Some(bonus_action) = bonus_action {
                         self.process_action(bonus_action);
@@ -730,6 +1991,9 @@ where
             }
         }
         self.handle_mouse(ctx);
+        if let Some(text) = self.clipboard.take() {
+            ctx.copy_text(text);
+        }
     }
 
     fn show_dialog(&mut self, ctx: &Context)
@@ -748,7 +2012,8 @@ where
                 Dialog::Load {
                     file_dialog,
                     pane_selection,
-                } => self.handle_load_dialog(file_dialog, *pane_selection),
+                    file_type,
+                } => self.handle_load_dialog(file_dialog, *pane_selection, *file_type),
                 Dialog::Text(text_dialog) => {
                     if let crate::dialog::Response::Complete { data } = text_dialog.get_response() {
                         let (text, toggle_map) = data;
@@ -762,13 +2027,8 @@ where
                 }
                 Dialog::ConfirmActiveRays(conf_dialog) => {
                     if let crate::dialog::Response::Complete { data } = conf_dialog.get_response() {
-                        let mut draw_rays = |pane_id| {
-                            let pane = self.get_pane_mut(pane_id);
-                            for angle in &data.active_angles {
-                                pane.marking_mut().enable_ray(*angle);
-                            }
-                            pane.schedule_redraw();
-                        };
+                        let angles: Vec<_> = data.active_angles.iter().copied().collect();
+                        let mut draw_rays = |pane_id| self.get_pane_mut(pane_id).draw_rays(&angles);
 
                         if data.do_child {
                             draw_rays(PaneID::Child);
@@ -813,6 +2073,19 @@ where
         self.parent.name()
     }
 
+    fn view_as_rust(&self) -> Option<String>
+    {
+        let pane = self.get_active_pane()?;
+        let selection = pane.get_selection();
+        Some(format!(
+            "// {}\nlet bounds = {};\nlet selection = Cplx::new({:?}, {:?});\n",
+            pane.name(),
+            pane.grid().bounds.to_rust_code(),
+            selection.re,
+            selection.im
+        ))
+    }
+
     fn get_image_height(&self) -> usize
     {
         self.image_height
@@ -825,10 +2098,28 @@ where
         self.child.change_height(new_height);
     }
 
+    fn get_tone(&self) -> ToneAdjustment
+    {
+        *self.parent.get_coloring().tone()
+    }
+
+    fn set_tone(&mut self, tone: ToneAdjustment)
+    {
+        self.parent.change_tone(tone);
+        self.child.change_tone(tone);
+    }
+
     /// Renders the UI elements of the main interface, which consist of the parent plane, child
     /// plane, plane names, and orbit descriptions. The menus are handled by the parent struct `app::FracalTab`.
     fn show(&mut self, ui: &mut Ui)
     {
+        if !(self.parent.tasks().preview.is_done() && self.child.tasks().preview.is_done())
+            || self.ray_julia_export.is_some()
+            || self.parent.marking().has_pending_rays()
+            || self.child.marking().has_pending_rays()
+        {
+            ui.ctx().request_repaint();
+        }
         TableBuilder::new(ui)
             .column(Column::exact(self.parent.get_image_frame().width() as f32))
             .column(Column::remainder())
@@ -864,6 +2155,8 @@ where
                     });
                 });
             });
+        self.show_cycle_table(ui.ctx());
+        self.show_period_probe(ui.ctx());
     }
 
     #[allow(clippy::too_many_lines)]
@@ -874,9 +2167,28 @@ where
             Action::Quit => self.schedule_quit(),
             Action::Close => self.schedule_close(),
             Action::NewTab => self.schedule_new_tab(),
-            Action::SaveImage(panes) => self.prompt_save_image(*panes),
+            Action::SaveImage(panes) => self.prompt_save_image(*panes, true),
+            Action::SaveCleanImage(panes) => self.prompt_save_image(*panes, false),
+            Action::SaveContactSheet => self.prompt_save_contact_sheet(),
+            Action::SaveAlphaMask(mode) => self.prompt_save_alpha_mask(*mode),
+            Action::CopyViewAsRust => self.copy_view_as_rust(),
+            Action::ExportCyclePolynomial => {
+                // Only some families (so far, the Mandelbrot set) know their cycle polynomials
+                let param = self.child.plane.param_map(self.child.get_selection());
+                if self.child.plane.cycle_polynomial(&param, 1).is_empty() {
+                    println!("No cycle polynomials are known for {}", self.child.name());
+                } else {
+                    self.prompt_text(TextInputType::CyclePolynomial);
+                }
+            }
+            Action::ExportRayLandings => self.prompt_text(TextInputType::RayLandings),
+            Action::ExportRayJuliaFrames => self.prompt_text(TextInputType::RayJuliaFrames),
+            Action::ExportOrbitAnimation => self.save_orbit_animation(),
             Action::SavePalette(panes) => self.prompt_save_palette(*panes),
             Action::LoadPalette(panes) => self.prompt_load_palette(*panes),
+            Action::ExportAnnotations => self.prompt_export_annotations(),
+            Action::ImportAnnotations => self.prompt_import_annotations(),
+            Action::ExportSampleGrid => self.prompt_export_sample_grid(),
             Action::ToggleSelectionMarker => {
                 if let Some(pane) = self.get_active_pane_mut() {
                     pane.marking_mut().toggle_selection();
@@ -888,6 +2200,12 @@ where
                 pane.marking_mut().toggle_critical();
                 pane.schedule_redraw();
             }
+            Action::ToggleCriticalValues => {
+                if let Some(pane) = self.get_active_pane_mut() {
+                    pane.marking_mut().toggle_critical_values();
+                    pane.schedule_redraw();
+                }
+            }
             Action::ToggleMarked(selection) => {
                 self.get_selected_pane_ids(*selection)
                     .into_iter()
@@ -906,12 +2224,18 @@ where
                         pane.schedule_redraw();
                     });
             }
+            Action::FindMisiurewiczPoint => self.prompt_text(TextInputType::Misiurewicz),
             Action::FindPeriodicPoint => {
                 if let Some(pane_id) = self.active_pane {
                     let input_type = TextInputType::FindPeriodic { pane_id };
                     self.prompt_text(input_type);
                 }
             }
+            Action::PromptViewTransform => {
+                if let Some(pane_id) = self.active_pane {
+                    self.prompt_text(TextInputType::ViewTransform { pane_id });
+                }
+            }
             Action::EnterCoordinates => {
                 if let Some(pane_id) = self.active_pane {
                     let input_type = TextInputType::Coordinates { pane_id };
@@ -933,6 +2257,21 @@ where
             Action::ClearOrbit => {
                 self.child_mut().clear_marked_orbit();
             }
+            Action::ToggleOrbitGradient => {
+                self.child_mut().toggle_orbit_gradient();
+            }
+            Action::ToggleOrbitCentroid => {
+                self.child_mut().toggle_orbit_centroid();
+            }
+            Action::AnalyzeOrbitData => {
+                self.child_mut().analyze_orbit_data();
+            }
+            Action::PromptCurveThickness => {
+                self.prompt_text(TextInputType::CurveThickness);
+            }
+            Action::PromptMaxOrbitLength => {
+                self.prompt_text(TextInputType::MaxOrbitLength);
+            }
             Action::DrawExternalRay {
                 include_orbit,
                 select_landing_point,
@@ -957,9 +2296,51 @@ where
                     p.draw_contour(*contour_type);
                 }
             }
+            Action::DrawIsoperiodicCurve => {
+                self.prompt_text(TextInputType::IsoperiodicCurve);
+            }
+            Action::PromptRenderThreads => {
+                self.prompt_text(TextInputType::RenderThreads);
+            }
+            Action::PromptFiniteDiffStep => {
+                self.prompt_text(TextInputType::FiniteDiffStep);
+            }
+            Action::PromptDegree => {
+                self.prompt_text(TextInputType::Degree);
+            }
+            Action::PromptNavigation => {
+                self.prompt_text(TextInputType::Navigation);
+            }
             Action::DrawAuxContours => {
                 self.get_active_pane_mut().map(Pane::draw_aux_contours);
             }
+            Action::ToggleEscapeIsolines => {
+                self.get_active_pane_mut().map(Pane::toggle_escape_isolines);
+            }
+            Action::ToggleCriticalBasins => {
+                self.get_active_pane_mut().map(Pane::toggle_critical_basins);
+            }
+            Action::PromptCriticalOrbit => {
+                self.prompt_text(TextInputType::CriticalOrbit);
+            }
+            Action::PromptPeriodHues => {
+                self.prompt_text(TextInputType::PeriodHues);
+            }
+            Action::ToggleScaledIteration => {
+                self.get_active_pane_mut()
+                    .map(Pane::toggle_scaled_iteration);
+            }
+            Action::ToggleEscapeAnnulus => {
+                self.get_active_pane_mut().map(Pane::toggle_escape_annulus);
+            }
+            Action::PromptInfinityColor => self.prompt_text(TextInputType::InfinityColor),
+            Action::ToggleMarianiSilver => {
+                self.get_active_pane_mut().map(Pane::toggle_render_strategy);
+            }
+            Action::ToggleDoubleBuffering => {
+                self.get_active_pane_mut()
+                    .map(Pane::toggle_double_buffering);
+            }
             Action::ClearRays => {
                 self.get_active_pane_mut().map(Pane::clear_marked_rays);
             }
@@ -969,6 +2350,34 @@ where
             Action::ClearCurves => {
                 self.get_active_pane_mut().map(Pane::clear_curves);
             }
+            Action::ToggleFreehandDraw => self.toggle_freehand_mode(),
+            Action::ToggleSnapToPixels => self.toggle_snap_to_pixels(),
+            Action::ToggleProbe => self.toggle_probe(),
+            Action::TogglePowerOfTwoZoom => self.navigation.toggle_power_of_two_zoom(),
+            Action::ToggleJuliaPreview => self.toggle_julia_preview(),
+            Action::ToggleCycleTable => self.toggle_cycle_table(),
+            Action::TogglePeriodProbe => self.toggle_period_probe(),
+            Action::ClearCustomCurves => {
+                self.get_active_pane_mut().map(Pane::clear_custom_curves);
+            }
+            Action::ClearPins => {
+                self.get_active_pane_mut().map(Pane::clear_pins);
+            }
+            Action::LabelBulb => {
+                self.get_active_pane_mut().map(Pane::label_bulb);
+            }
+            Action::ToggleSmoothCurves => {
+                self.parent_mut().toggle_smooth_curves();
+                self.child_mut().toggle_smooth_curves();
+            }
+            Action::ToggleDashedEquipotentials => {
+                self.parent_mut()
+                    .marking_mut()
+                    .toggle_dashed_equipotentials();
+                self.child_mut()
+                    .marking_mut()
+                    .toggle_dashed_equipotentials();
+            }
             Action::ResetSelection => match self.active_pane {
                 Some(PaneID::Parent) => self.parent.reset_selection(),
                 Some(PaneID::Child) => {
@@ -979,7 +2388,27 @@ where
             Action::ResetView => {
                 self.get_active_pane_mut().map(Pane::reset);
             }
+            Action::RoundBounds => {
+                self.get_active_pane_mut().map(Pane::round_bounds);
+            }
+            Action::ResetColoring => {
+                self.get_active_pane_mut().map(Pane::reset_coloring);
+            }
             Action::ToggleLiveMode => self.toggle_live_mode(),
+            Action::ToggleChildLock => self.toggle_child_param_lock(),
+            Action::EstimateBoundaryDimension => {
+                self.get_active_pane_mut()
+                    .map(Pane::estimate_boundary_dimension);
+            }
+            Action::EstimateFilledArea => {
+                self.get_active_pane_mut().map(Pane::estimate_filled_area);
+            }
+            Action::ToggleBrentCycleDetection => {
+                self.get_active_pane_mut().map(Pane::toggle_cycle_detection);
+            }
+            Action::SwapPanes => {
+                self.swap_panes();
+            }
             Action::CycleActivePlane => {
                 self.parent_mut().cycle_active_plane();
                 self.child_mut().cycle_active_plane();
@@ -988,20 +2417,29 @@ where
                 // TODO: Fill in with actual handling
             }
             Action::Pan(x, y) => {
+                let (x, y) = self.navigation.pan_fraction(*x, *y);
                 if let Some(p) = self.get_active_pane_mut() {
-                    p.pan_relative(*x, *y);
+                    p.pan_relative(x, y);
                 }
             }
             Action::Zoom(scale) => {
+                let scale = self.navigation.zoom_scale(*scale);
                 if let Some(p) = self.get_active_pane_mut() {
-                    p.zoom(*scale, p.get_selection());
+                    p.zoom(scale, p.get_selection());
                 }
             }
             Action::CenterOnSelection => {
                 if let Some(pane) = self.get_active_pane_mut() {
                     let selection = pane.get_selection();
-                    pane.grid_mut().recenter(selection);
-                    pane.schedule_recompute();
+                    match pane.grid_mut().recenter(selection) {
+                        Ok(()) => pane.schedule_recompute(),
+                        Err(e) => println!("Could not center on selection: {e}"),
+                    }
+                }
+            }
+            Action::ToggleTuning => {
+                if let Some(pane) = self.get_active_pane_mut() {
+                    pane.toggle_tuning();
                 }
             }
             Action::ScaleMaxIter(factor) => {
@@ -1010,6 +2448,9 @@ where
                 }
             }
             Action::RandomizePalette => self.randomize_palette(),
+            Action::PromptPaletteSeed => {
+                self.prompt_text(TextInputType::PaletteSeed);
+            }
             Action::SetPalette(palette) => {
                 self.set_palette(*palette);
             }
@@ -1021,6 +2462,16 @@ where
                 let black_palette = Palette::black(16.);
                 self.set_palette(black_palette);
             }
+            Action::NextPalette => {
+                if let Some(palette) = self.palette_library.next_palette() {
+                    self.set_palette(palette);
+                }
+            }
+            Action::PrevPalette => {
+                if let Some(palette) = self.palette_library.prev_palette() {
+                    self.set_palette(palette);
+                }
+            }
             Action::SetColoring(algorithm) => {
                 if let Some(p) = self.get_active_pane_mut() {
                     p.set_coloring_algorithm(algorithm.clone());
@@ -1038,6 +2489,9 @@ where
                 self.get_active_pane_mut()
                     .map(Pane::select_preperiod_period_smooth_coloring);
             }
+            Action::ToggleSplitColoring => {
+                self.get_active_pane_mut().map(Pane::toggle_split_coloring);
+            }
             Action::ScalePalettePeriod(factor) => {
                 if let Some(p) = self.get_active_pane_mut() {
                     p.scale_palette(*factor);
@@ -1054,6 +2508,12 @@ where
                     p.schedule_redraw();
                 }
             }
+            Action::ToggleSharedLevelRange => self.toggle_shared_level_range(),
+            Action::ToggleTransparentExport => {
+                if let Some(p) = self.get_active_pane_mut() {
+                    p.get_coloring_mut().toggle_transparent_bounded();
+                }
+            }
             Action::CycleComputeMode(selection, change) => {
                 self.get_selected_pane_ids(*selection)
                     .into_iter()
@@ -1062,6 +2522,16 @@ where
                         pane.change_compute_mode(*change);
                     });
             }
+            Action::ToggleBoettcherTexture(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_boettcher_texture());
+            }
+            Action::ToggleLogDerivative(selection) => {
+                self.get_selected_pane_ids(*selection)
+                    .into_iter()
+                    .for_each(|pane_id| self.get_pane_mut(pane_id).toggle_log_derivative());
+            }
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::interface::{Interactive, Interface, UiMessage};
+use dynamo_color::ToneAdjustment;
 use egui::{Context, Ui};
 use libloading::Library;
 
@@ -61,10 +62,22 @@ impl Interactive for InterfaceHolder<'_>
     {
         self.interface.get_image_height()
     }
+    fn get_tone(&self) -> ToneAdjustment
+    {
+        self.interface.get_tone()
+    }
+    fn set_tone(&mut self, tone: ToneAdjustment)
+    {
+        self.interface.set_tone(tone);
+    }
     fn process_action(&mut self, action: &crate::actions::Action)
     {
         self.interface.process_action(action);
     }
+    fn view_as_rust(&self) -> Option<String>
+    {
+        self.interface.view_as_rust()
+    }
 }
 
 impl Interface for InterfaceHolder<'_>
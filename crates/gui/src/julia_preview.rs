@@ -0,0 +1,94 @@
+//! Thumbnail of the Julia set for the parameter under the cursor, shown once the cursor rests
+//! on the parameter plane.
+
+use epaint::{ColorImage, TextureHandle};
+
+use dynamo_common::prelude::*;
+
+/// Width in pixels of a Julia set preview.
+pub const JULIA_PREVIEW_WIDTH: usize = 128;
+
+/// Time in seconds the cursor must rest on a point before its Julia set is previewed.
+pub const JULIA_PREVIEW_DELAY: f64 = 0.25;
+
+/// Iteration limit of a preview, which only needs to show the rough shape of the Julia set.
+pub const JULIA_PREVIEW_MAX_ITER: IterCount = 256;
+
+pub struct JuliaPreview<J>
+{
+    /// Hovered point of the parameter plane, and the time at which the cursor came to rest on it.
+    hovered: Option<(Cplx, f64)>,
+    /// The previewed plane and its thumbnail, once rendered.
+    preview: Option<(J, ColorImage)>,
+    texture: Option<TextureHandle>,
+}
+
+impl<J> Default for JuliaPreview<J>
+{
+    fn default() -> Self
+    {
+        Self {
+            hovered: None,
+            preview: None,
+            texture: None,
+        }
+    }
+}
+
+impl<J> JuliaPreview<J>
+{
+    /// Record the point under the cursor at `time`, in seconds. Moving to another point
+    /// discards the preview and restarts the delay. Returns the point if its preview is due to
+    /// be rendered.
+    pub fn hover(&mut self, point: Cplx, time: f64) -> Option<Cplx>
+    {
+        match self.hovered {
+            Some((hovered, _)) if hovered == point => {}
+            _ => {
+                self.clear();
+                self.hovered = Some((point, time));
+            }
+        }
+        let (_, since) = self.hovered?;
+        (self.preview.is_none() && time - since >= JULIA_PREVIEW_DELAY).then_some(point)
+    }
+
+    /// Whether the cursor is resting on a point whose preview has not been rendered yet.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool
+    {
+        self.hovered.is_some() && self.preview.is_none()
+    }
+
+    pub fn set(&mut self, plane: J, image: ColorImage)
+    {
+        self.preview = Some((plane, image));
+        self.texture = None;
+    }
+
+    pub fn clear(&mut self)
+    {
+        self.hovered = None;
+        self.preview = None;
+        self.texture = None;
+    }
+
+    #[must_use]
+    pub fn plane(&self) -> Option<&J>
+    {
+        self.preview.as_ref().map(|(plane, _)| plane)
+    }
+
+    /// Texture of the rendered thumbnail, uploaded on first use.
+    pub fn texture(&mut self, ctx: &egui::Context) -> Option<&TextureHandle>
+    {
+        let (_, image) = self.preview.as_ref()?;
+        Some(self.texture.get_or_insert_with(|| {
+            ctx.load_texture(
+                "julia_preview",
+                image.clone(),
+                egui::TextureOptions::default(),
+            )
+        }))
+    }
+}
@@ -1,12 +1,350 @@
 #![allow(dead_code)]
 pub mod actions;
 pub mod colors;
+pub mod contact_sheet;
 pub mod dialog;
 pub mod hotkeys;
 pub mod image_frame;
 pub mod interface;
+pub mod julia_preview;
 pub mod marked_points;
+pub mod navigation;
 pub mod pane;
 
 #[cfg(feature = "scripting")]
 pub mod interface_holder;
+
+#[cfg(test)]
+mod tests
+{
+    use crate::marked_points::{Colored, PathCache};
+    use dynamo_common::prelude::RationalAngle;
+    use egui::{Color32, Pos2};
+
+    #[test]
+    fn path_cache_dirty_ray()
+    {
+        let rays: Vec<_> = (1..4).map(|k| RationalAngle::new(k, 7)).collect();
+        let mut cache = PathCache::default();
+        for &ray in &rays {
+            let path = Colored {
+                object: vec![Pos2::ZERO],
+                color: Color32::RED,
+            };
+            cache.insert(ray, path);
+        }
+        cache.set_fresh();
+        assert!(cache.is_fresh());
+
+        cache.mark_dirty(rays[0]);
+        assert!(cache.is_dirty(&rays[0]));
+        assert!(!cache.is_dirty(&rays[1]));
+        assert!(!cache.is_dirty(&rays[2]));
+        assert!(!cache.is_stale());
+        assert_eq!(cache.take_dirty(), vec![rays[0]]);
+
+        // Changing many curves at once falls back to a full rebuild
+        for k in 0..64 {
+            cache.mark_dirty(RationalAngle::new(k, 127));
+        }
+        assert!(cache.is_stale());
+    }
+
+    #[test]
+    fn orbit_gradient_endpoints()
+    {
+        use crate::marked_points::{orbit_gradient, ORBIT_END_COLOR, ORBIT_START_COLOR};
+
+        let colors = orbit_gradient(10);
+        assert_eq!(colors.len(), 10);
+        assert_eq!(colors[0], ORBIT_START_COLOR);
+        assert_eq!(colors[9], ORBIT_END_COLOR);
+
+        // Early iterates are cool and late ones warm
+        for (c0, c1) in colors.iter().zip(&colors[1..]) {
+            assert!(c0.r() <= c1.r());
+            assert!(c0.b() >= c1.b());
+        }
+    }
+
+    #[test]
+    fn contact_sheet_layout()
+    {
+        use crate::contact_sheet::{contact_sheet, LABEL_HEIGHT};
+        use image::{Rgb, RgbImage};
+
+        let parent = RgbImage::from_pixel(120, 80, Rgb([255, 0, 0]));
+        let child = RgbImage::from_pixel(120, 100, Rgb([0, 0, 255]));
+        let tiles = [("Parent".to_owned(), parent), ("Child".to_owned(), child)];
+        let sheet = contact_sheet(&tiles);
+
+        // Side by side, below a caption strip
+        assert_eq!(sheet.width(), 240);
+        assert_eq!(sheet.height(), LABEL_HEIGHT + 100);
+        assert_eq!(sheet.pixels().count(), 240 * (LABEL_HEIGHT as usize + 100));
+
+        assert_eq!(sheet[(10, LABEL_HEIGHT + 10)], Rgb([255, 0, 0]));
+        assert_eq!(sheet[(130, LABEL_HEIGHT + 90)], Rgb([0, 0, 255]));
+    }
+
+    #[test]
+    fn pan_sensitivity_scales_drag()
+    {
+        use crate::navigation::Navigation;
+        use crate::pane::id::PaneID;
+        use dynamo_common::prelude::{Bounds, PointGrid};
+
+        let grid = PointGrid::new_by_res_y(400, Bounds::centered_square(2.));
+        let delta = [12., -5.];
+        let base = Navigation::default().drag_offset(&grid, delta);
+        let doubled = Navigation::default()
+            .with_pan_sensitivity(2.)
+            .drag_offset(&grid, delta);
+        assert!(base.norm() > 0.);
+        assert!((doubled - 2. * base).norm() < 1e-12);
+
+        // Coasting decays by the momentum on each frame, then stops
+        let mut navigation = Navigation::default().with_momentum(0.5);
+        navigation.record_drag(PaneID::Parent, [8., 0.]);
+        assert_eq!(navigation.coast(), Some((PaneID::Parent, [4., 0.])));
+        assert_eq!(navigation.coast(), Some((PaneID::Parent, [2., 0.])));
+        assert!(std::iter::from_fn(|| navigation.coast()).count() < 4);
+    }
+
+    #[test]
+    fn long_orbit_truncated()
+    {
+        use dynamo_common::prelude::Cplx;
+
+        use crate::marked_points::{Marking, DEFAULT_MAX_ORBIT_LEN};
+
+        let orbit: Vec<Cplx> = (0..5000).map(|k| Cplx::new(f64::from(k), 0.)).collect();
+        let mut marking = Marking::default();
+        marking.mark_orbit_manually(orbit.clone(), Color32::GREEN);
+
+        let drawn = marking.orbit().unwrap();
+        assert_eq!(drawn.len(), DEFAULT_MAX_ORBIT_LEN);
+        assert_eq!(drawn, &orbit[..DEFAULT_MAX_ORBIT_LEN]);
+
+        marking.set_max_orbit_len(100);
+        assert_eq!(marking.orbit().unwrap(), &orbit[..100]);
+    }
+
+    #[test]
+    fn pins_survive_zoom_and_pan()
+    {
+        use dynamo_common::prelude::{Bounds, Cplx, PointGrid};
+
+        use crate::marked_points::Marking;
+
+        let mut marking = Marking::default();
+        let a = Cplx::new(0.5, 0.25);
+        let b = Cplx::new(-1., 1.);
+        marking.add_pin(marking.next_pin_id(), a, "a".to_owned());
+        marking.add_pin(marking.next_pin_id(), b, "b".to_owned());
+        assert_eq!(marking.next_pin_id(), 2);
+
+        let mut grid = PointGrid::new_by_res_y(400, Bounds::centered_square(2.));
+        grid.zoom(0.25, a).unwrap();
+        grid.recenter(b).unwrap();
+
+        // Pins are stored in the plane, so they land wherever the new view puts their points
+        let pins: Vec<_> = marking.pins().collect();
+        assert_eq!(pins, vec![(0, a, "a"), (1, b, "b")]);
+        let scale = 400. / grid.range_x();
+        let expected = [[200. + 1.5 * scale, 199. + 0.75 * scale], [200., 199.]];
+        for ((_, point, _), [ex, ey]) in pins.into_iter().zip(expected) {
+            let [x, y] = grid.locate_point(point);
+            assert!((f64::from(x) - ex).abs() < 1e-3 && (f64::from(y) - ey).abs() < 1e-3);
+        }
+
+        marking.relabel_pin(1, "cusp".to_owned());
+        assert_eq!(marking.pins().nth(1).unwrap().2, "cusp");
+
+        marking.clear_pins();
+        assert_eq!(marking.pins().count(), 0);
+        assert_eq!(marking.iter_points().count(), 0);
+    }
+
+    #[test]
+    fn spline_through_curve_points()
+    {
+        use dynamo_common::prelude::Cplx;
+
+        use crate::marked_points::catmull_rom;
+
+        let curve: Vec<Cplx> = (0..6)
+            .map(|k| Cplx::from_polar(1., f64::from(k) * 0.4))
+            .collect();
+        let spline = catmull_rom(&curve, 4);
+        assert_eq!(spline.len(), (curve.len() - 1) * 4 + 1);
+        assert!(spline.len() > curve.len());
+
+        // Every computed point is a vertex of the spline
+        for (k, z) in curve.iter().enumerate() {
+            assert_eq!(spline[4 * k], *z);
+        }
+        // Interpolated vertices stay close to the arc they approximate
+        for z in &spline {
+            assert!((z.norm() - 1.).abs() < 0.02, "{z}");
+        }
+    }
+
+    #[test]
+    fn clean_export_has_no_annotations()
+    {
+        use dynamo_color::{fractal_image::FractalImage, Coloring};
+        use dynamo_common::prelude::{Bounds, Cplx, IterPlane, PointGrid, PointInfo};
+
+        use crate::marked_points::Marking;
+
+        let grid = PointGrid::new(64, 64, Bounds::centered_square(2.));
+        let mut plane: IterPlane<Cplx> = IterPlane::create(grid.clone());
+        for ((x, y), info) in plane.iter_counts.indexed_iter_mut() {
+            *info = PointInfo::Escaping {
+                potential: 4. * grid.map_pixel(x, y).norm(),
+                phase: None,
+            };
+        }
+        let coloring = Coloring::default();
+        let raw = plane.write_image(&coloring);
+
+        let mut marking = Marking::default();
+        marking.mark_custom_curve(0, vec![Cplx::new(-1.5, -1.5), Cplx::new(1.5, 1.5)]);
+        marking.add_pin(0, Cplx::new(0.5, -0.5), "pin".to_owned());
+
+        let mut clean = raw.clone();
+        marking.mark_export(&grid, &mut clean, false);
+        assert_eq!(clean, raw);
+
+        let mut full = raw.clone();
+        marking.mark_export(&grid, &mut full, true);
+        assert_ne!(full, raw);
+    }
+
+    #[test]
+    fn centroid_of_symmetric_orbit()
+    {
+        use dynamo_common::prelude::Cplx;
+
+        use crate::marked_points::Marking;
+
+        let mut marking = Marking::default();
+        assert_eq!(marking.orbit_centroid(), None);
+        marking.toggle_orbit_centroid();
+        assert_eq!(marking.iter_points().count(), 0);
+
+        let orbit = vec![
+            Cplx::new(1., 0.),
+            Cplx::new(0., 1.),
+            Cplx::new(-1., 0.),
+            Cplx::new(0., -1.),
+        ];
+        marking.mark_orbit_manually(orbit, Color32::GREEN);
+        let centroid = marking.orbit_centroid().unwrap();
+        assert!(centroid.norm() < 1e-12, "{centroid}");
+
+        let marked: Vec<_> = marking.iter_points().map(|p| p.point).collect();
+        assert_eq!(marked, vec![centroid]);
+
+        marking.disable_orbit();
+        assert_eq!(marking.orbit_centroid(), None);
+        assert_eq!(marking.iter_points().count(), 0);
+    }
+
+    #[test]
+    fn orbit_frame_shows_prefix()
+    {
+        use dynamo_common::prelude::{Bounds, Cplx, PointGrid};
+        use image::{Rgb, RgbImage};
+
+        use crate::marked_points::draw_orbit_prefix;
+
+        let grid = PointGrid::new(64, 64, Bounds::centered_square(2.));
+        let orbit = vec![
+            Cplx::new(-1., -1.),
+            Cplx::new(1., -1.),
+            Cplx::new(1., 1.),
+            Cplx::new(-1., 1.),
+            Cplx::new(0., 0.),
+        ];
+        let background = RgbImage::new(64, 64);
+
+        for k in 0..=orbit.len() {
+            let mut frame = background.clone();
+            draw_orbit_prefix(&grid, &mut frame, &orbit, k);
+            let drawn: Vec<bool> = orbit
+                .iter()
+                .map(|&z| {
+                    let [x, y] = grid.locate_point(z);
+                    *frame.get_pixel(x as u32, y as u32) != Rgb([0, 0, 0])
+                })
+                .collect();
+            assert_eq!(drawn.iter().filter(|&&d| d).count(), k);
+            assert!(drawn[..k].iter().all(|&d| d));
+        }
+    }
+
+    #[test]
+    fn double_buffered_frame_swaps_on_present()
+    {
+        use crate::image_frame::ImageFrame;
+        use epaint::ColorImage;
+
+        let old = ColorImage::new([4, 3], Color32::RED);
+        let mut frame = ImageFrame::new(old.clone());
+        assert!(frame.is_double_buffered());
+
+        let target = frame.render_target([4, 3]);
+        assert_eq!(target.size, [4, 3]);
+        target.pixels.fill(Color32::BLUE);
+        assert_eq!(frame.image, old);
+
+        frame.present();
+        assert!(frame.image.pixels.iter().all(|&c| c == Color32::BLUE));
+
+        // Resizing clears the back buffer, not the image on screen
+        let shown = frame.image.clone();
+        frame.render_target([2, 2]).pixels[0] = Color32::GREEN;
+        assert_eq!(frame.image, shown);
+        frame.present();
+        assert_eq!(frame.image.size, [2, 2]);
+        assert_eq!(frame.image.pixels[0], Color32::GREEN);
+    }
+
+    #[test]
+    fn data_space_curves_scale_with_zoom()
+    {
+        use crate::marked_points::{CurveThickness, Marking};
+        use dynamo_common::prelude::{Bounds, Cplx, PointGrid};
+        use image::RgbImage;
+
+        let grid = PointGrid::new(256, 256, Bounds::centered_square(2.));
+        let mut zoomed = grid.clone();
+        zoomed.change_bounds(Bounds::centered_square(1.)).unwrap();
+
+        assert!((CurveThickness::default().pixels(&grid, 1.) - 1.).abs() < 1e-6);
+
+        let screen = CurveThickness::from_pixels(4., &grid, false);
+        assert!((screen.pixels(&zoomed, 1.) - 4.).abs() < 1e-6);
+
+        let data = CurveThickness::from_pixels(4., &grid, true);
+        assert!((data.pixels(&grid, 1.) - 4.).abs() < 1e-4);
+        assert!((data.pixels(&zoomed, 1.) - 8.).abs() < 1e-4);
+
+        // Count the pixels covered by a horizontal curve in the middle column of the image
+        let mut marking = Marking::default();
+        marking.mark_custom_curve(0, vec![Cplx::new(-3., 0.), Cplx::new(3., 0.)]);
+        marking.set_curve_thickness(data);
+        let width = |grid: &PointGrid| {
+            let mut image = RgbImage::new(256, 256);
+            marking.mark_image(grid, &mut image);
+            (0..256)
+                .filter(|&y| image.get_pixel(128, y).0 != [0, 0, 0])
+                .count()
+        };
+        let (thin, thick) = (width(&grid), width(&zoomed));
+        assert!((3..=6).contains(&thin), "{thin}");
+        assert!(thick.abs_diff(2 * thin) <= 2, "{thin} -> {thick}");
+    }
+}
@@ -1,9 +1,10 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use egui::{Color32, Painter};
-use epaint::{CircleShape, PathShape, Pos2, Stroke};
-use image::{ImageBuffer, Rgb};
+use egui::{Align2, Color32, FontId, Painter};
+use epaint::{CircleShape, PathShape, Pos2, Shape, Stroke};
+use image::{ImageBuffer, Pixel};
 use imageproc::drawing::{
     draw_antialiased_line_segment_mut, draw_filled_circle_mut, draw_polygon_mut,
 };
@@ -11,6 +12,8 @@ use imageproc::pixelops::interpolate;
 use itertools::Itertools;
 
 use dynamo_color::palette::DiscretePalette;
+use dynamo_color::types::FromColor32;
+use dynamo_common::math_utils::centroid;
 use dynamo_common::prelude::*;
 use dynamo_core::dynamics::Displayable;
 
@@ -22,7 +25,34 @@ use self::hashing::HashedReal;
 use serde::{self, Deserialize, Serialize};
 
 const POINT_RADIUS: f32 = 3.5;
+/// Width of curves in exported images of a 768-pixel view, relative to their width on screen.
 const CURVE_THICKNESS: f32 = 1.4;
+const CUSTOM_CURVE_COLOR: Color32 = Color32::from_rgb(0, 200, 255);
+const PIN_COLOR: Color32 = Color32::from_rgb(255, 230, 0);
+const PIN_LABEL_SIZE: f32 = 13.;
+const CENTROID_COLOR: Color32 = Color32::from_rgb(160, 255, 120);
+
+/// Colors of the first and last points of an orbit drawn with a gradient.
+pub const ORBIT_START_COLOR: Color32 = Color32::from_rgb(40, 120, 255);
+pub const ORBIT_END_COLOR: Color32 = Color32::from_rgb(255, 70, 30);
+
+/// Number of segments each span between computed points is divided into when curves are
+/// smoothed.
+const SPLINE_SUBDIVISIONS: usize = 8;
+
+/// Lengths of the dashes and gaps of a dashed curve, in multiples of its width.
+const DASH_LENGTH: f32 = 5.;
+const DASH_GAP: f32 = 3.;
+
+/// Number of changed curves beyond which the whole path cache is rebuilt.
+const MAX_DIRTY_PATHS: usize = 16;
+
+/// Number of queued rays computed together, in parallel, on each update.
+const RAY_BATCH_SIZE: usize = 16;
+
+/// Number of points of an orbit drawn by default. Longer orbits are truncated, since slowly
+/// converging orbits can otherwise make for huge polylines.
+pub const DEFAULT_MAX_ORBIT_LEN: usize = 512;
 
 type Curve = Vec<Cplx>;
 
@@ -38,6 +68,23 @@ pub trait ObjectKey: Clone + std::hash::Hash + std::cmp::Eq + std::fmt::Debug
     type Object;
     fn color_with(&self, palette: &DiscretePalette, degree: AngleNum) -> Color32;
     fn compute<P: Displayable>(&self, plane: &P, selection: Cplx) -> Self::Object;
+
+    /// Compute the object, stopping early if it is made of more than `max_len` points.
+    fn compute_bounded<P: Displayable>(
+        &self,
+        plane: &P,
+        selection: Cplx,
+        _max_len: usize,
+    ) -> Self::Object
+    {
+        self.compute(plane, selection)
+    }
+
+    /// Whether the object can be recomputed from the plane, as opposed to being user input.
+    fn is_computed(&self) -> bool
+    {
+        true
+    }
 }
 
 /// Keys of point-set objects in the data store. Each key may be toggled by the API.
@@ -49,9 +96,14 @@ pub enum PointSetKey
     #[default]
     SelectedPoint,
     CriticalPoints,
+    CriticalValues,
     MiscMarkedPoints,
     PeriodicPoints(Period),
     PreperiodicPoints(OrbitSchema),
+    /// A labeled point placed by the user. Its label is kept in the [`Marking`].
+    UserPin(usize),
+    /// Mean of the points of the drawn orbit, kept in sync with the orbit by the [`Marking`].
+    OrbitCentroid,
 }
 impl ObjectKey for PointSetKey
 {
@@ -61,9 +113,12 @@ impl ObjectKey for PointSetKey
         match self {
             Self::SelectedPoint => Color32::WHITE,
             Self::CriticalPoints => Color32::RED,
+            Self::CriticalValues => Color32::from_rgb(255, 128, 0),
             Self::MiscMarkedPoints => Color32::from_rgb(255, 0, 64),
             Self::PeriodicPoints(period) => palette.map(*period as f32, 1.),
             Self::PreperiodicPoints(o) => palette.map_preperiodic(*o),
+            Self::UserPin(_) => PIN_COLOR,
+            Self::OrbitCentroid => CENTROID_COLOR,
         }
     }
 
@@ -76,13 +131,24 @@ impl ObjectKey for PointSetKey
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            Self::CriticalValues => plane
+                .critical_values_child(&plane.param_map(selection))
+                .into_iter()
+                .map(Into::into)
+                .collect(),
             Self::MiscMarkedPoints => plane.other_marked_points(),
             Self::PeriodicPoints(period) => {
                 plane.cycles(*period).into_iter().map(Into::into).collect()
             }
             Self::PreperiodicPoints(o) => plane.precycles(*o).into_iter().map(Into::into).collect(),
+            Self::UserPin(_) | Self::OrbitCentroid => Vec::new(),
         }
     }
+
+    fn is_computed(&self) -> bool
+    {
+        !matches!(self, Self::UserPin(_) | Self::OrbitCentroid)
+    }
 }
 
 #[non_exhaustive]
@@ -129,6 +195,19 @@ enum CurveKey
     Orbit,
     Ray(RationalAngle),
     Contour(ContourType, hashing::HashedCplx),
+    Isoperiodic
+    {
+        period: Period,
+        modulus: HashedReal,
+        seed: hashing::HashedCplx,
+    },
+    Custom(usize),
+    /// One component of the escape-count isoline at `level`.
+    Isoline
+    {
+        level: IterCount,
+        index: usize,
+    },
 }
 impl ObjectKey for CurveKey
 {
@@ -142,6 +221,9 @@ impl ObjectKey for CurveKey
                 palette.map_preperiodic(o)
             }
             Self::Contour(ctype, _) => ctype.color(),
+            Self::Isoperiodic { period, .. } => palette.map(*period as f32, 1.),
+            Self::Custom(_) => CUSTOM_CURVE_COLOR,
+            Self::Isoline { level, .. } => palette.map(*level as f32, 1.),
         }
     }
 
@@ -162,8 +244,31 @@ impl ObjectKey for CurveKey
                 ContourType::ExtendRay => plane.extend_ray(Cplx::from(*point)).compute(),
                 ContourType::InwardRay => plane.inward_ray(Cplx::from(*point)).compute(),
             },
+            Self::Isoperiodic {
+                period,
+                modulus,
+                seed,
+            } => plane.isoperiodic_curve(Cplx::from(*seed), *period, Real::from(*modulus)),
+            Self::Custom(_) | Self::Isoline { .. } => Vec::new(),
+        }
+    }
+
+    /// Orbits are iterated only as far as they are drawn, rather than up to the iteration limit.
+    fn compute_bounded<P: Displayable>(&self, plane: &P, selection: Cplx, max_len: usize) -> Curve
+    {
+        match self {
+            Self::Orbit => plane
+                .iter_orbit_window(selection, 0, max_len)
+                .map(Into::into)
+                .collect(),
+            _ => self.compute(plane, selection),
         }
     }
+
+    fn is_computed(&self) -> bool
+    {
+        !matches!(self, Self::Custom(_) | Self::Isoline { .. })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -210,6 +315,8 @@ struct EnvironmentInfo<'plane, 'palette, P: Displayable>
     plane: &'plane P,
     selection: Cplx,
     palette: &'palette DiscretePalette,
+    /// Number of points beyond which computed objects are cut off.
+    max_len: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -262,6 +369,34 @@ where
         self.tasks.push_back(MarkingTask::Recolor(key));
     }
 
+    /// Schedule `new` to take the place of `old`, dropping any computation of `old` still
+    /// pending, so that a quick succession of replacements only computes the last one.
+    pub fn sched_replace(&mut self, old: Option<K>, new: Option<K>)
+    {
+        if let Some(old) = old {
+            self.tasks.retain(|task| {
+                !matches!(task, MarkingTask::Enable(key) | MarkingTask::Recompute(key) if *key == old)
+            });
+            self.tasks.push_back(MarkingTask::Disable(old));
+        }
+        if let Some(new) = new {
+            self.tasks.push_back(MarkingTask::Enable(new));
+        }
+    }
+
+    /// Whether `key` will be marked once the pending tasks have run.
+    fn marked_after_tasks(&self, key: &K) -> bool
+    {
+        self.tasks
+            .iter()
+            .fold(self.objects.contains_key(key), |marked, task| match task {
+                MarkingTask::Enable(k) if k == key => true,
+                MarkingTask::Disable(k) if k == key => false,
+                MarkingTask::Toggle(k) if k == key => !marked,
+                _ => marked,
+            })
+    }
+
     pub fn sched_recompute_all(&mut self)
     {
         self.tasks.push_back(MarkingTask::RecomputeAll);
@@ -288,8 +423,11 @@ where
                 }
             }
             MarkingTask::Recompute(key) => {
+                if !key.is_computed() {
+                    return;
+                }
                 if let Some(col_obj) = self.objects.get_mut(&key) {
-                    col_obj.object = key.compute(e.plane, e.selection);
+                    col_obj.object = key.compute_bounded(e.plane, e.selection, e.max_len);
                     col_obj.color = key.color_with(e.palette, self.degree);
                 }
             }
@@ -299,7 +437,7 @@ where
                 }
             }
             MarkingTask::RecomputeAll => {
-                self.recompute_all(e.plane, e.selection);
+                self.recompute_all(e.plane, e.selection, e.max_len);
             }
             MarkingTask::RecolorAll => {
                 self.recolor_all(e.palette);
@@ -310,13 +448,24 @@ where
     fn enable<P: Displayable>(&mut self, key: K, e: &EnvironmentInfo<P>)
     {
         let col_obj = ColoredMaybeHidden {
-            object: key.compute(e.plane, e.selection),
+            object: key.compute_bounded(e.plane, e.selection, e.max_len),
             color: key.color_with(e.palette, self.degree),
             visible: true,
         };
         self.objects.insert(key, col_obj);
     }
 
+    /// Insert a precomputed object, replacing any object with the same key.
+    fn insert_computed(&mut self, key: K, object: O, palette: &DiscretePalette)
+    {
+        let col_obj = ColoredMaybeHidden {
+            object,
+            color: key.color_with(palette, self.degree),
+            visible: true,
+        };
+        self.objects.insert(key, col_obj);
+    }
+
     fn disable(&mut self, key: &K)
     {
         self.objects.remove(key);
@@ -329,19 +478,25 @@ where
         });
     }
 
-    pub fn recompute_all<P: Displayable>(&mut self, plane: &P, selection: Cplx)
+    pub fn recompute_all<P: Displayable>(&mut self, plane: &P, selection: Cplx, max_len: usize)
     {
-        self.objects.iter_mut().for_each(|(key, col_obj)| {
-            col_obj.object = key.compute(plane, selection);
-        });
+        self.objects
+            .iter_mut()
+            .filter(|(key, _)| key.is_computed())
+            .for_each(|(key, col_obj)| {
+                col_obj.object = key.compute_bounded(plane, selection, max_len);
+            });
     }
 
+    /// Process all scheduled tasks, returning them so that dependent caches can be invalidated.
     fn process_all_tasks<P: Displayable>(&mut self, env: &EnvironmentInfo<P>)
+        -> Vec<MarkingTask<K>>
     {
         let tasks: Vec<_> = self.tasks.drain(..).collect();
         for task in &tasks {
             self.process_task(task.clone(), env);
         }
+        tasks
     }
 
     pub fn clear_all_tasks(&mut self)
@@ -355,15 +510,259 @@ where
     }
 }
 
-#[derive(Default, Clone)]
+/// Width of drawn curves, kept constant either on screen or in the plane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CurveThickness
+{
+    /// Width in screen pixels. Exported images draw it 1.4 times wider, scaled by their width
+    /// relative to a 768-pixel view.
+    Screen(f32),
+    /// Width in the plane, so that curves thicken as the view zooms in.
+    Data(Real),
+}
+
+impl Default for CurveThickness
+{
+    fn default() -> Self
+    {
+        Self::Screen(1.)
+    }
+}
+
+impl std::fmt::Display for CurveThickness
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Screen(width) => write!(f, "{width} pixels"),
+            Self::Data(width) => write!(f, "{width:.3e} in the plane"),
+        }
+    }
+}
+
+/// Whether a curve is drawn as a continuous line or in dashes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DashStyle
+{
+    #[default]
+    Solid,
+    Dashed,
+}
+
+impl DashStyle
+{
+    pub fn toggle(&mut self)
+    {
+        *self = match self {
+            Self::Solid => Self::Dashed,
+            Self::Dashed => Self::Solid,
+        };
+    }
+
+    /// Lengths in pixels of the dashes and gaps of a curve `thickness` pixels wide, if it is
+    /// dashed. Hairlines are dashed as if they were a pixel and a half wide, so that their gaps
+    /// stay visible.
+    fn pattern(self, thickness: f32) -> Option<(f32, f32)>
+    {
+        let unit = thickness.max(1.5);
+        match self {
+            Self::Solid => None,
+            Self::Dashed => Some((DASH_LENGTH * unit, DASH_GAP * unit)),
+        }
+    }
+}
+
+/// Dash style of each kind of curve, so that overlapping annotations can be told apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DashStyles
+{
+    pub rays: DashStyle,
+    pub equipotentials: DashStyle,
+    /// Every other curve, including the orbit and hand-drawn curves.
+    pub other: DashStyle,
+}
+
+impl Default for DashStyles
+{
+    fn default() -> Self
+    {
+        Self {
+            rays: DashStyle::Solid,
+            equipotentials: DashStyle::Dashed,
+            other: DashStyle::Solid,
+        }
+    }
+}
+
+impl DashStyles
+{
+    const fn of(&self, key: &CurveKey) -> DashStyle
+    {
+        match key {
+            CurveKey::Ray(_) => self.rays,
+            CurveKey::Contour(ContourType::Equipotential, _) => self.equipotentials,
+            _ => self.other,
+        }
+    }
+}
+
+impl CurveThickness
+{
+    /// A thickness of `width` pixels on the current view of `grid`, kept constant in the plane
+    /// rather than on screen if `in_data_space` is set.
+    #[must_use]
+    pub fn from_pixels(width: f32, grid: &PointGrid, in_data_space: bool) -> Self
+    {
+        if in_data_space {
+            Self::Data(Real::from(width) * grid.pixel_width())
+        } else {
+            Self::Screen(width)
+        }
+    }
+
+    /// Width in pixels of a curve drawn over `grid`, where `scale` is the size of a screen pixel
+    /// in pixels of the image.
+    #[must_use]
+    pub fn pixels(&self, grid: &PointGrid, scale: f32) -> f32
+    {
+        match self {
+            Self::Screen(width) => width * scale,
+            Self::Data(width) => (width / grid.pixel_width()) as f32,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_data_space(&self) -> bool
+    {
+        matches!(self, Self::Data(_))
+    }
+}
+
+#[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Marking
 {
     point_sets: MarkedObjectStore<PointSetKey, Vec<Cplx>>,
     curves: MarkedObjectStore<CurveKey, Curve>,
     #[cfg_attr(feature = "serde", serde(skip))]
-    path_cache: RefCell<PathCache>,
+    path_cache: RefCell<PathCache<CurveKey>>,
+    /// Whether the orbit is colored by iteration index rather than in a single color.
+    #[cfg_attr(feature = "serde", serde(default))]
+    orbit_gradient: bool,
+    /// Maximum number of points of a drawn orbit.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_orbit_len"))]
+    max_orbit_len: usize,
+    /// Whether curves other than the orbit are drawn as splines through their points.
+    #[cfg_attr(feature = "serde", serde(default))]
+    smooth_curves: bool,
+    /// Labels of the user pins, by id.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pin_labels: HashMap<usize, String>,
+    /// Whether the centroid of the drawn orbit is marked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    show_orbit_centroid: bool,
+    /// Width of drawn curves, both on screen and in exported images.
+    #[cfg_attr(feature = "serde", serde(default))]
+    curve_thickness: CurveThickness,
+    /// Period of the cycles shown by the period probe, which replaces them as it moves, and
+    /// whether the probe marked them itself. Cycles that were already marked are left in place
+    /// when the probe moves on.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    probed_period: Option<(Period, bool)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    dash_styles: DashStyles,
+    /// Angles of the rays waiting to be drawn, computed a batch at a time.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_rays: VecDeque<RationalAngle>,
+}
+
+#[cfg(feature = "serde")]
+const fn default_max_orbit_len() -> usize
+{
+    DEFAULT_MAX_ORBIT_LEN
+}
+
+/// The enabled annotations of a [`Marking`] that are recomputed from the plane, such as rays
+/// by angle, contours by base point and periodic points by period. Saved on its own, this can
+/// be applied again after a re-render or to another view of the same plane.
+///
+/// The drawn orbit, pins and hand-drawn curves are not included.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnnotationOverlay
+{
+    point_sets: HashSet<PointSetKey>,
+    curves: HashSet<CurveKey>,
+}
+
+impl AnnotationOverlay
+{
+    #[must_use]
+    pub fn is_empty(&self) -> bool
+    {
+        self.point_sets.is_empty() && self.curves.is_empty()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn save_to_file<P>(&self, filename: P) -> Result<(), Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(filename, json)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn load_from_file<P>(filename: P) -> Result<Self, Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let json = std::fs::read_to_string(filename)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    #[cfg(not(feature = "serde"))]
+    pub fn save_to_file<P>(&self, _filename: P) -> Result<(), Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Err("annotations can only be saved with the `serde` feature".into())
+    }
+
+    #[cfg(not(feature = "serde"))]
+    pub fn load_from_file<P>(_filename: P) -> Result<Self, Box<dyn std::error::Error>>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Err("annotations can only be loaded with the `serde` feature".into())
+    }
+}
+
+impl Default for Marking
+{
+    fn default() -> Self
+    {
+        Self {
+            point_sets: MarkedObjectStore::default(),
+            curves: MarkedObjectStore::default(),
+            path_cache: RefCell::default(),
+            orbit_gradient: false,
+            max_orbit_len: DEFAULT_MAX_ORBIT_LEN,
+            smooth_curves: false,
+            pin_labels: HashMap::new(),
+            show_orbit_centroid: false,
+            curve_thickness: CurveThickness::default(),
+            probed_period: None,
+            dash_styles: DashStyles::default(),
+            pending_rays: VecDeque::new(),
+        }
+    }
 }
+
 impl Marking
 {
     #[must_use]
@@ -401,6 +800,11 @@ impl Marking
         self.point_sets.sched_toggle(PointSetKey::CriticalPoints);
     }
 
+    pub fn toggle_critical_values(&mut self)
+    {
+        self.point_sets.sched_toggle(PointSetKey::CriticalValues);
+    }
+
     pub fn toggle_misc_marked(&mut self)
     {
         self.point_sets.sched_toggle(PointSetKey::MiscMarkedPoints);
@@ -408,27 +812,99 @@ impl Marking
 
     pub fn toggle_cycles_of_period(&mut self, period: Period)
     {
+        // Cycles toggled by hand are no longer the probe's to remove
+        if let Some((probed, marked_by_probe)) = &mut self.probed_period {
+            if *probed == period {
+                *marked_by_probe = false;
+            }
+        }
         self.point_sets
             .sched_toggle(PointSetKey::PeriodicPoints(period));
     }
 
+    #[must_use]
+    pub fn probed_period(&self) -> Option<Period>
+    {
+        self.probed_period.map(|(period, _)| period)
+    }
+
+    /// Show the cycles of `period` in place of those of the previously probed period, or stop
+    /// probing if `period` is `None`. Only cycles marked by the probe itself are removed, so
+    /// those marked beforehand stay visible.
+    pub fn set_probed_period(&mut self, period: Option<Period>)
+    {
+        if period == self.probed_period() {
+            return;
+        }
+        let old = self
+            .probed_period
+            .and_then(|(old, marked_by_probe)| marked_by_probe.then_some(old))
+            .map(PointSetKey::PeriodicPoints);
+        let new = period
+            .map(PointSetKey::PeriodicPoints)
+            .filter(|key| !self.point_sets.marked_after_tasks(key));
+        self.probed_period = period.map(|period| (period, new.is_some()));
+        self.point_sets.sched_replace(old, new);
+    }
+
     pub fn toggle_ray(&mut self, angle: RationalAngle)
     {
         self.curves.sched_toggle(CurveKey::Ray(angle));
-        self.path_cache.borrow_mut().set_stale();
     }
 
     pub fn enable_ray(&mut self, angle: RationalAngle)
     {
         self.curves.sched_enable(CurveKey::Ray(angle));
-        self.path_cache.borrow_mut().set_stale();
+    }
+
+    /// Queue the rays of many angles to be drawn. Rather than one per scheduled task, they are
+    /// computed in parallel batches, one batch per update.
+    pub fn enable_rays(&mut self, angles: &[RationalAngle])
+    {
+        self.pending_rays.extend(angles);
+    }
+
+    /// Whether rays queued by [`Self::enable_rays`] are still waiting to be drawn.
+    #[must_use]
+    pub fn has_pending_rays(&self) -> bool
+    {
+        !self.pending_rays.is_empty()
+    }
+
+    /// Compute and draw the next batch of queued rays, reporting those that could not be
+    /// computed.
+    fn enable_next_rays<P: Displayable>(&mut self, plane: &P, palette: &DiscretePalette)
+    {
+        let count = self.pending_rays.len().min(RAY_BATCH_SIZE);
+        if count == 0 {
+            return;
+        }
+        let angles: Vec<_> = self.pending_rays.drain(..count).collect();
+        let mut path_cache = self.path_cache.borrow_mut();
+        for (angle, ray) in angles.iter().zip(plane.external_rays(&angles)) {
+            let Some(ray) = ray else {
+                println!("Could not compute the ray of angle {angle}");
+                continue;
+            };
+            let key = CurveKey::Ray(*angle);
+            self.curves.insert_computed(key, ray, palette);
+            path_cache.mark_dirty(key);
+        }
     }
 
     pub fn toggle_contour(&mut self, contour_type: ContourType, base_point: Cplx)
     {
         self.curves
             .sched_toggle(CurveKey::Contour(contour_type, base_point.into()));
-        self.path_cache.borrow_mut().set_stale();
+    }
+
+    pub fn toggle_isoperiodic(&mut self, period: Period, modulus: Real, seed: Cplx)
+    {
+        self.curves.sched_toggle(CurveKey::Isoperiodic {
+            period,
+            modulus: modulus.into(),
+            seed: seed.into(),
+        });
     }
 
     pub fn sched_recompute_all(&mut self)
@@ -451,10 +927,46 @@ impl Marking
     pub fn remove_all_annotations(&mut self)
     {
         self.point_sets.disable_all();
+        self.pin_labels.clear();
+        self.show_orbit_centroid = false;
         self.curves.disable_all();
+        self.pending_rays.clear();
         self.path_cache.borrow_mut().set_stale();
     }
 
+    /// Keys of the enabled annotations that can be recomputed on another render.
+    #[must_use]
+    pub fn annotation_overlay(&self) -> AnnotationOverlay
+    {
+        AnnotationOverlay {
+            point_sets: self
+                .point_sets
+                .objects
+                .keys()
+                .filter(|key| key.is_computed())
+                .copied()
+                .collect(),
+            curves: self
+                .curves
+                .objects
+                .keys()
+                .filter(|key| key.is_computed() && **key != CurveKey::Orbit)
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Schedule every annotation of the overlay to be enabled, keeping those already present.
+    pub fn apply_annotation_overlay(&mut self, overlay: &AnnotationOverlay)
+    {
+        for key in &overlay.point_sets {
+            self.point_sets.sched_enable(*key);
+        }
+        for key in &overlay.curves {
+            self.curves.sched_enable(*key);
+        }
+    }
+
     pub fn process_all_tasks<P: Displayable>(
         &mut self,
         plane: &P,
@@ -466,26 +978,282 @@ impl Marking
             plane,
             selection,
             palette,
+            max_len: self.max_orbit_len,
         };
         self.point_sets.process_all_tasks(&env);
-        self.curves.process_all_tasks(&env);
+
+        let mut path_cache = self.path_cache.borrow_mut();
+        for task in self.curves.process_all_tasks(&env) {
+            match task {
+                MarkingTask::Enable(key)
+                | MarkingTask::Disable(key)
+                | MarkingTask::Toggle(key)
+                | MarkingTask::Recompute(key)
+                | MarkingTask::Recolor(key) => path_cache.mark_dirty(key),
+                MarkingTask::RecomputeAll | MarkingTask::RecolorAll => path_cache.set_stale(),
+            }
+        }
+        drop(path_cache);
+        self.enable_next_rays(plane, palette);
+        self.sync_orbit_centroid();
     }
 
-    pub fn mark_orbit_manually(&mut self, orbit: Curve, color: Color32)
+    pub fn mark_orbit_manually(&mut self, mut orbit: Curve, color: Color32)
     {
+        orbit.truncate(self.max_orbit_len);
         let col_obj = ColoredMaybeHidden {
             object: orbit,
             color,
             visible: true,
         };
         self.curves.objects.insert(CurveKey::Orbit, col_obj);
+        self.path_cache.borrow_mut().mark_dirty(CurveKey::Orbit);
+        self.sync_orbit_centroid();
+    }
+
+    /// One more than the largest custom curve id, or 0 if there are no custom curves.
+    #[must_use]
+    pub fn next_custom_curve_id(&self) -> usize
+    {
+        self.curves
+            .objects
+            .keys()
+            .filter_map(|k| match k {
+                CurveKey::Custom(id) => Some(id + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn mark_custom_curve(&mut self, id: usize, curve: Curve)
+    {
+        let col_obj = ColoredMaybeHidden {
+            object: curve,
+            color: CUSTOM_CURVE_COLOR,
+            visible: true,
+        };
+        self.curves.objects.insert(CurveKey::Custom(id), col_obj);
+        self.path_cache
+            .borrow_mut()
+            .mark_dirty(CurveKey::Custom(id));
+    }
+
+    pub fn disable_custom_curves(&mut self)
+    {
+        let to_remove: Vec<_> = self
+            .curves
+            .objects
+            .keys()
+            .filter(|k| matches!(k, CurveKey::Custom(_)))
+            .copied()
+            .collect();
+        self.remove_curves(to_remove);
+    }
+
+    /// One more than the largest id used by any pin, so new pins never reuse an id.
+    #[must_use]
+    pub fn next_pin_id(&self) -> usize
+    {
+        self.pin_labels.keys().map(|id| id + 1).max().unwrap_or(0)
+    }
+
+    /// Place a labeled pin at `point`, replacing any pin with the same id.
+    pub fn add_pin(&mut self, id: usize, point: Cplx, label: String)
+    {
+        let col_obj = ColoredMaybeHidden {
+            object: vec![point],
+            color: PIN_COLOR,
+            visible: true,
+        };
+        self.point_sets
+            .objects
+            .insert(PointSetKey::UserPin(id), col_obj);
+        self.pin_labels.insert(id, label);
+    }
+
+    /// Change the label of an existing pin.
+    pub fn relabel_pin(&mut self, id: usize, label: String)
+    {
+        if let Some(old_label) = self.pin_labels.get_mut(&id) {
+            *old_label = label;
+        }
+    }
+
+    /// Location and label of each pin, ordered by id.
+    pub fn pins(&self) -> impl Iterator<Item = (usize, Cplx, &str)> + '_
+    {
+        self.pin_labels
+            .iter()
+            .sorted_by_key(|(id, _)| **id)
+            .filter_map(|(id, label)| {
+                let col_obj = self.point_sets.objects.get(&PointSetKey::UserPin(*id))?;
+                let point = *col_obj.object.first()?;
+                Some((*id, point, label.as_str()))
+            })
+    }
+
+    pub fn clear_pins(&mut self)
+    {
+        self.point_sets
+            .objects
+            .retain(|k, _| !matches!(k, PointSetKey::UserPin(_)));
+        self.pin_labels.clear();
+    }
+
+    /// Replace the escape-count isolines with new ones, grouped by level.
+    pub fn mark_isolines(
+        &mut self,
+        isolines: Vec<(IterCount, Vec<Curve>)>,
+        palette: &DiscretePalette,
+    )
+    {
+        self.disable_isolines();
+        let mut path_cache = self.path_cache.borrow_mut();
+        for (level, lines) in isolines {
+            for (index, line) in lines.into_iter().enumerate() {
+                let key = CurveKey::Isoline { level, index };
+                let col_obj = ColoredMaybeHidden {
+                    object: line,
+                    color: key.color_with(palette, self.curves.degree),
+                    visible: true,
+                };
+                self.curves.objects.insert(key, col_obj);
+                path_cache.mark_dirty(key);
+            }
+        }
+    }
+
+    pub fn disable_isolines(&mut self)
+    {
+        let to_remove: Vec<_> = self
+            .curves
+            .objects
+            .keys()
+            .filter(|k| matches!(k, CurveKey::Isoline { .. }))
+            .copied()
+            .collect();
+        self.remove_curves(to_remove);
+    }
+
+    pub fn toggle_orbit_gradient(&mut self)
+    {
+        self.orbit_gradient ^= true;
+        self.path_cache.borrow_mut().mark_dirty(CurveKey::Orbit);
+    }
+
+    pub fn toggle_smooth_curves(&mut self)
+    {
+        self.smooth_curves ^= true;
         self.path_cache.borrow_mut().set_stale();
     }
 
+    /// Vertices of a curve as drawn, interpolated by a spline if smoothing is enabled. Orbits
+    /// are always drawn as polylines, since only their vertices are meaningful.
+    fn drawn_curve<'a>(&self, key: &CurveKey, curve: &'a Curve) -> Cow<'a, Curve>
+    {
+        if self.smooth_curves && *key != CurveKey::Orbit {
+            Cow::Owned(catmull_rom(curve, SPLINE_SUBDIVISIONS))
+        } else {
+            Cow::Borrowed(curve)
+        }
+    }
+
+    #[must_use]
+    pub const fn curve_thickness(&self) -> CurveThickness
+    {
+        self.curve_thickness
+    }
+
+    pub fn set_curve_thickness(&mut self, curve_thickness: CurveThickness)
+    {
+        self.curve_thickness = curve_thickness;
+    }
+
+    #[must_use]
+    pub const fn dash_styles(&self) -> DashStyles
+    {
+        self.dash_styles
+    }
+
+    pub fn set_dash_styles(&mut self, dash_styles: DashStyles)
+    {
+        self.dash_styles = dash_styles;
+    }
+
+    pub fn toggle_dashed_equipotentials(&mut self)
+    {
+        self.dash_styles.equipotentials.toggle();
+    }
+
+    #[must_use]
+    pub const fn max_orbit_len(&self) -> usize
+    {
+        self.max_orbit_len
+    }
+
+    /// Set the maximum number of points of a drawn orbit, truncating the current one.
+    pub fn set_max_orbit_len(&mut self, max_orbit_len: usize)
+    {
+        self.max_orbit_len = max_orbit_len;
+        if let Some(orbit) = self.curves.objects.get_mut(&CurveKey::Orbit) {
+            if orbit.object.len() > max_orbit_len {
+                orbit.object.truncate(max_orbit_len);
+                self.path_cache.borrow_mut().mark_dirty(CurveKey::Orbit);
+            }
+        }
+        self.sync_orbit_centroid();
+    }
+
+    /// Points of the drawn orbit, if any.
+    #[must_use]
+    pub fn orbit(&self) -> Option<&[Cplx]>
+    {
+        self.curves
+            .objects
+            .get(&CurveKey::Orbit)
+            .map(|orbit| orbit.object.as_slice())
+    }
+
     pub fn disable_orbit(&mut self)
     {
         self.curves.disable(&CurveKey::Orbit);
-        self.path_cache.borrow_mut().set_stale();
+        self.path_cache.borrow_mut().mark_dirty(CurveKey::Orbit);
+        self.sync_orbit_centroid();
+    }
+
+    /// Mean of the points of the drawn orbit, or `None` if no orbit is drawn.
+    #[must_use]
+    pub fn orbit_centroid(&self) -> Option<Cplx>
+    {
+        centroid(self.orbit()?.iter().copied())
+    }
+
+    pub fn toggle_orbit_centroid(&mut self)
+    {
+        self.show_orbit_centroid ^= true;
+        self.sync_orbit_centroid();
+    }
+
+    /// Move the centroid marker to the centroid of the current orbit, removing it if it is
+    /// hidden or no orbit is drawn.
+    fn sync_orbit_centroid(&mut self)
+    {
+        match self.orbit_centroid().filter(|_| self.show_orbit_centroid) {
+            Some(centroid) => {
+                let col_obj = ColoredMaybeHidden {
+                    object: vec![centroid],
+                    color: CENTROID_COLOR,
+                    visible: true,
+                };
+                self.point_sets
+                    .objects
+                    .insert(PointSetKey::OrbitCentroid, col_obj);
+            }
+            None => {
+                self.point_sets.objects.remove(&PointSetKey::OrbitCentroid);
+            }
+        }
     }
 
     pub fn disable_all_contours(&mut self)
@@ -497,14 +1265,12 @@ impl Marking
             .filter(|k| matches!(k, CurveKey::Contour(..)))
             .copied()
             .collect();
-        for key in &to_remove {
-            self.curves.objects.remove(key);
-        }
-        self.path_cache.borrow_mut().set_stale();
+        self.remove_curves(to_remove);
     }
 
     pub fn disable_all_rays(&mut self)
     {
+        self.pending_rays.clear();
         let to_remove: Vec<_> = self
             .curves
             .objects
@@ -512,21 +1278,30 @@ impl Marking
             .filter(|k| matches!(k, CurveKey::Ray(_)))
             .copied()
             .collect();
-        for key in &to_remove {
-            self.curves.objects.remove(key);
+        self.remove_curves(to_remove);
+    }
+
+    fn remove_curves(&mut self, keys: Vec<CurveKey>)
+    {
+        let mut path_cache = self.path_cache.borrow_mut();
+        for key in keys {
+            self.curves.objects.remove(&key);
+            path_cache.mark_dirty(key);
         }
-        self.path_cache.borrow_mut().set_stale();
     }
 
     pub fn disable_all_points(&mut self)
     {
         self.point_sets.disable_all();
+        self.pin_labels.clear();
+        self.show_orbit_centroid = false;
     }
 
     pub fn disable_all_curves(&mut self)
     {
         self.curves.disable_all();
         self.path_cache.borrow_mut().set_stale();
+        self.sync_orbit_centroid();
     }
 
     pub fn iter_points(&self) -> impl Iterator<Item = ColoredPoint> + '_
@@ -549,42 +1324,43 @@ impl Marking
             )
     }
 
-    fn iter_visible_curves(&self) -> impl Iterator<Item = ColoredMaybeHidden<Curve>> + '_
-    {
-        self.curves.objects.values().filter(|o| o.visible).cloned()
-    }
-
     pub fn ray_landing_point(&self, angle: RationalAngle) -> Option<Cplx>
     {
         let col_ray = self.curves.objects.get(&CurveKey::Ray(angle))?;
         col_ray.object.last().copied()
     }
 
+    /// Bring the screen-space paths up to date, rebuilding only those of changed curves unless
+    /// the whole cache is stale.
     fn update_cache(&self, grid: &PointGrid, frame: &ImageFrame)
     {
-        self.path_cache.borrow_mut().paths.clear();
-        self.path_cache
-            .borrow_mut()
-            .paths
-            .extend(self.iter_visible_curves().map(
-                |ColoredMaybeHidden {
-                     object: zs, color, ..
-                 }| {
-                    let points = zs
-                        .iter()
-                        .map(|z| {
-                            let pt = grid.locate_point(*z);
-                            frame.to_global_coords(pt.into())
-                        })
-                        .collect();
-                    Colored {
-                        object: points,
-                        color,
-                    }
-                },
-            ));
+        let to_screen = |key: &CurveKey, col_obj: &ColoredMaybeHidden<Curve>| Colored {
+            object: self
+                .drawn_curve(key, &col_obj.object)
+                .iter()
+                .map(|z| {
+                    let pt = grid.locate_point(*z);
+                    frame.to_global_coords(pt.into())
+                })
+                .collect(),
+            color: col_obj.color,
+        };
 
-        self.path_cache.borrow_mut().set_fresh();
+        let mut path_cache = self.path_cache.borrow_mut();
+        if path_cache.is_stale() {
+            path_cache.clear();
+            for (key, col_obj) in self.curves.objects.iter().filter(|(_, o)| o.visible) {
+                path_cache.insert(*key, to_screen(key, col_obj));
+            }
+        } else {
+            for key in path_cache.take_dirty() {
+                match self.curves.objects.get(&key).filter(|o| o.visible) {
+                    Some(col_obj) => path_cache.insert(key, to_screen(&key, col_obj)),
+                    None => path_cache.remove(&key),
+                }
+            }
+        }
+        path_cache.set_fresh();
     }
 
     pub fn draw_points(&self, painter: &Painter, grid: &PointGrid, frame: &ImageFrame)
@@ -594,64 +1370,120 @@ impl Marking
             let patch = CircleShape::filled(point, POINT_RADIUS, color);
             painter.add(patch);
         }
+        for (_, z, label) in self.pins() {
+            let point = frame.to_global_coords(grid.locate_point(z).into());
+            let offset = egui::vec2(POINT_RADIUS + 2., -POINT_RADIUS - 2.);
+            painter.text(
+                point + offset,
+                Align2::LEFT_BOTTOM,
+                label,
+                FontId::proportional(PIN_LABEL_SIZE),
+                PIN_COLOR,
+            );
+        }
     }
 
     pub fn draw_curves(&self, painter: &Painter, grid: &PointGrid, frame: &ImageFrame)
     {
-        if self.path_cache.borrow().is_stale() {
+        if !self.path_cache.borrow().is_fresh() {
             self.update_cache(grid, frame);
         }
-        self.path_cache.borrow().paths.iter().for_each(
-            |Colored {
-                 object: path,
-                 color,
-             }| {
-                let stroke = Stroke::new(1.0, *color);
-                let path = PathShape::line(path.clone(), stroke);
-                painter.add(path);
-            },
-        );
-    }
-
-    fn draw_curves_to_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
-    {
-        let thickness = CURVE_THICKNESS * (image.width() as f32) / 768.;
-
-        self.iter_visible_curves().for_each(
-            |ColoredMaybeHidden {
-                 object: curve,
-                 color,
-                 ..
-             }| {
-                let (r, g, b, _a) = color.to_tuple();
-                let color = Rgb([r, g, b]);
-                CurveDrawJob {
-                    curve: &curve,
-                    color,
-                    thickness,
-                    grid,
+        let thickness = self.curve_thickness.pixels(grid, 1.);
+        for (key, col_path) in self.path_cache.borrow().iter() {
+            let path = &col_path.object;
+            if self.orbit_gradient && *key == CurveKey::Orbit {
+                let colors = orbit_gradient(path.len());
+                for (i, (p0, p1)) in path.iter().tuple_windows().enumerate() {
+                    let stroke = Stroke::new(thickness, lerp_color(colors[i], colors[i + 1], 0.5));
+                    painter.line_segment([*p0, *p1], stroke);
                 }
-                .draw_to(image);
-            },
-        );
+            } else {
+                let stroke = Stroke::new(thickness, col_path.color);
+                match self.dash_styles.of(key).pattern(thickness) {
+                    None => {
+                        painter.add(PathShape::line(path.clone(), stroke));
+                    }
+                    Some((dash, gap)) => {
+                        painter.extend(Shape::dashed_line(path, stroke, dash, gap));
+                    }
+                }
+            }
+        }
     }
-    fn draw_points_to_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+
+    fn draw_curves_to_image<Px>(&self, grid: &PointGrid, image: &mut ImageBuffer<Px, Vec<u8>>)
+    where
+        Px: Pixel<Subpixel = u8> + FromColor32 + 'static,
+    {
+        let thickness = self
+            .curve_thickness
+            .pixels(grid, CURVE_THICKNESS * (image.width() as f32) / 768.);
+
+        for (key, col_obj) in self.curves.objects.iter().filter(|(_, o)| o.visible) {
+            let curve = &col_obj.object;
+            if self.orbit_gradient && *key == CurveKey::Orbit {
+                let colors = orbit_gradient(curve.len());
+                for (i, (z0, z1)) in curve.iter().copied().tuple_windows().enumerate() {
+                    CurveDrawJob {
+                        curve: &vec![z0, z1],
+                        color: Px::from_color32(lerp_color(colors[i], colors[i + 1], 0.5)),
+                        thickness,
+                        grid,
+                    }
+                    .draw_to(image);
+                }
+            } else {
+                let curve = self.drawn_curve(key, curve);
+                let mut draw = |curve: &Curve| {
+                    CurveDrawJob {
+                        curve,
+                        color: Px::from_color32(col_obj.color),
+                        thickness,
+                        grid,
+                    }
+                    .draw_to(image);
+                };
+                match self.dash_styles.of(key).pattern(thickness) {
+                    None => draw(&curve),
+                    Some((dash, gap)) => dash_curve(&curve, grid, dash, gap).iter().for_each(draw),
+                }
+            }
+        }
+    }
+    fn draw_points_to_image<Px>(&self, grid: &PointGrid, image: &mut ImageBuffer<Px, Vec<u8>>)
+    where
+        Px: Pixel<Subpixel = u8> + FromColor32 + 'static,
     {
         let radius = POINT_RADIUS * (image.width() as f32) / 768.;
         self.iter_points()
             .for_each(|ColoredPoint { point, color }| {
-                let (red, grn, blu, _alp) = color.to_tuple();
-                let color = Rgb([red, grn, blu]);
+                let color = Px::from_color32(color);
                 let [x, y] = grid.locate_point(point);
                 let center = (x as i32, y as i32);
                 draw_filled_circle_mut(image, center, radius as i32, color);
             });
     }
-    pub fn mark_image(&self, grid: &PointGrid, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+    pub fn mark_image<Px>(&self, grid: &PointGrid, image: &mut ImageBuffer<Px, Vec<u8>>)
+    where
+        Px: Pixel<Subpixel = u8> + FromColor32 + 'static,
     {
         self.draw_curves_to_image(grid, image);
         self.draw_points_to_image(grid, image);
     }
+
+    /// Overlay the marked objects on an exported image, unless a clean image is requested.
+    pub fn mark_export<Px>(
+        &self,
+        grid: &PointGrid,
+        image: &mut ImageBuffer<Px, Vec<u8>>,
+        include_annotations: bool,
+    ) where
+        Px: Pixel<Subpixel = u8> + FromColor32 + 'static,
+    {
+        if include_annotations {
+            self.mark_image(grid, image);
+        }
+    }
 }
 
 mod hashing
@@ -712,55 +1544,226 @@ mod hashing
     }
 }
 
+/// Screen-space paths of the visible curves, keyed by curve. Individual paths can be marked
+/// dirty when their curves change; the whole cache is rebuilt when it is stale, e.g. after the
+/// view moves or too many curves change at once.
 #[derive(Clone)]
-pub struct PathCache
+pub struct PathCache<K>
 {
-    paths: Vec<Colored<Vec<Pos2>>>,
+    paths: HashMap<K, Colored<Vec<Pos2>>>,
+    dirty: HashSet<K>,
     needs_refresh: bool,
 }
-impl Default for PathCache
+impl<K> Default for PathCache<K>
 {
     fn default() -> Self
     {
         Self {
-            paths: Vec::new(),
+            paths: HashMap::new(),
+            dirty: HashSet::new(),
             needs_refresh: true,
         }
     }
 }
 
-impl PathCache
+impl<K> PathCache<K>
+where
+    K: std::hash::Hash + Eq,
 {
     pub fn set_fresh(&mut self)
     {
         self.needs_refresh = false;
+        self.dirty.clear();
     }
     #[must_use]
-    pub const fn is_fresh(&self) -> bool
+    pub fn is_fresh(&self) -> bool
     {
-        !self.needs_refresh
+        !self.needs_refresh && self.dirty.is_empty()
     }
     pub fn set_stale(&mut self)
     {
         self.needs_refresh = true;
+        self.dirty.clear();
     }
     #[must_use]
     pub const fn is_stale(&self) -> bool
     {
         self.needs_refresh
     }
+
+    /// Mark the path of a single curve as needing a rebuild, falling back to a full rebuild if
+    /// many curves have changed.
+    pub fn mark_dirty(&mut self, key: K)
+    {
+        if self.needs_refresh {
+            return;
+        }
+        self.dirty.insert(key);
+        if self.dirty.len() > MAX_DIRTY_PATHS {
+            self.set_stale();
+        }
+    }
+    #[must_use]
+    pub fn is_dirty(&self, key: &K) -> bool
+    {
+        self.needs_refresh || self.dirty.contains(key)
+    }
+    pub fn take_dirty(&mut self) -> Vec<K>
+    {
+        self.dirty.drain().collect()
+    }
+
+    pub fn insert(&mut self, key: K, path: Colored<Vec<Pos2>>)
+    {
+        self.paths.insert(key, path);
+    }
+    pub fn remove(&mut self, key: &K)
+    {
+        self.paths.remove(key);
+    }
+    pub fn clear(&mut self)
+    {
+        self.paths.clear();
+    }
+    pub fn paths(&self) -> impl Iterator<Item = &Colored<Vec<Pos2>>>
+    {
+        self.paths.values()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Colored<Vec<Pos2>>)>
+    {
+        self.paths.iter()
+    }
+}
+
+/// Per-vertex colors for an orbit of `len` points, running from `ORBIT_START_COLOR` at the
+/// first iterate to `ORBIT_END_COLOR` at the last.
+#[must_use]
+pub fn orbit_gradient(len: usize) -> Vec<Color32>
+{
+    let last = len.saturating_sub(1).max(1) as f32;
+    (0..len)
+        .map(|i| lerp_color(ORBIT_START_COLOR, ORBIT_END_COLOR, i as f32 / last))
+        .collect()
+}
+
+/// Draw the first `count` points of `orbit` onto an image, as one frame of an animation of the
+/// orbit. Colors follow the gradient of the whole orbit, so each point keeps its color from one
+/// frame to the next.
+pub fn draw_orbit_prefix<Px>(
+    grid: &PointGrid,
+    image: &mut ImageBuffer<Px, Vec<u8>>,
+    orbit: &[Cplx],
+    count: usize,
+) where
+    Px: Pixel<Subpixel = u8> + FromColor32 + 'static,
+{
+    let radius = POINT_RADIUS * (image.width() as f32) / 768.;
+    let colors = orbit_gradient(orbit.len());
+    for (&point, &color) in orbit.iter().zip(&colors).take(count) {
+        let [x, y] = grid.locate_point(point);
+        let center = (x as i32, y as i32);
+        draw_filled_circle_mut(image, center, radius as i32, Px::from_color32(color));
+    }
+}
+
+/// Uniform Catmull-Rom spline through the points of a curve, with each span divided into
+/// `subdivisions` segments. The spline passes through every original point, and the end points
+/// are repeated to define the tangents there.
+#[must_use]
+pub fn catmull_rom(curve: &[Cplx], subdivisions: usize) -> Curve
+{
+    if curve.len() < 3 || subdivisions < 2 {
+        return curve.to_vec();
+    }
+    let n = curve.len();
+    let mut spline = Vec::with_capacity((n - 1) * subdivisions + 1);
+    for i in 0..n - 1 {
+        let p0 = curve[i.saturating_sub(1)];
+        let p1 = curve[i];
+        let p2 = curve[i + 1];
+        let p3 = curve[(i + 2).min(n - 1)];
+        spline.push(p1);
+        for k in 1..subdivisions {
+            let t = k as Real / subdivisions as Real;
+            let a = 2. * p1;
+            let b = p2 - p0;
+            let c = 2. * p0 - 5. * p1 + 4. * p2 - p3;
+            let d = 3. * (p1 - p2) + p3 - p0;
+            spline.push(0.5 * (a + t * (b + t * (c + t * d))));
+        }
+    }
+    spline.push(curve[n - 1]);
+    spline
+}
+
+/// Split a curve into the dashes of a pattern with the given lengths, measured in pixels of
+/// `grid`. A segment through the point a view transform sends to infinity has no length in
+/// pixels, and ends the current dash.
+fn dash_curve(curve: &[Cplx], grid: &PointGrid, dash: f32, gap: f32) -> Vec<Curve>
+{
+    let mut dashes = Vec::new();
+    let mut current = Vec::new();
+    let mut drawing = true;
+    let mut remaining = dash;
+    for (z0, z1) in curve.iter().copied().tuple_windows() {
+        let [x0, y0] = grid.locate_point(z0);
+        let [x1, y1] = grid.locate_point(z1);
+        let length = (x1 - x0).hypot(y1 - y0);
+        if !length.is_finite() {
+            if current.len() > 1 {
+                dashes.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            continue;
+        }
+        if drawing && current.is_empty() {
+            current.push(z0);
+        }
+        let mut pos = 0.;
+        while length - pos > remaining {
+            pos += remaining;
+            current.push(z0 + (z1 - z0) * Real::from(pos / length));
+            if drawing {
+                dashes.push(std::mem::take(&mut current));
+                remaining = gap;
+            } else {
+                remaining = dash;
+            }
+            drawing = !drawing;
+        }
+        remaining -= length - pos;
+        if drawing {
+            current.push(z1);
+        }
+    }
+    if current.len() > 1 {
+        dashes.push(current);
+    }
+    dashes
+}
+
+fn lerp_color(start: Color32, end: Color32, t: f32) -> Color32
+{
+    let lerp = |a: u8, b: u8| f32::from(a).mul_add(1. - t, f32::from(b) * t).round() as u8;
+    Color32::from_rgb(
+        lerp(start.r(), end.r()),
+        lerp(start.g(), end.g()),
+        lerp(start.b(), end.b()),
+    )
 }
 
-struct CurveDrawJob<'a>
+struct CurveDrawJob<'a, Px>
 {
     curve: &'a Curve,
-    color: Rgb<u8>,
+    color: Px,
     thickness: f32,
     grid: &'a PointGrid,
 }
-impl CurveDrawJob<'_>
+impl<Px> CurveDrawJob<'_, Px>
+where
+    Px: Pixel<Subpixel = u8> + 'static,
 {
-    pub fn draw_thick(self, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+    pub fn draw_thick(self, image: &mut ImageBuffer<Px, Vec<u8>>)
     {
         self.curve
             .iter()
@@ -805,7 +1808,7 @@ impl CurveDrawJob<'_>
             });
     }
 
-    fn draw_thin(self, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+    fn draw_thin(self, image: &mut ImageBuffer<Px, Vec<u8>>)
     {
         self.curve
             .iter()
@@ -818,7 +1821,7 @@ impl CurveDrawJob<'_>
             });
     }
 
-    pub fn draw_to(self, image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>)
+    pub fn draw_to(self, image: &mut ImageBuffer<Px, Vec<u8>>)
     {
         if self.thickness <= 1.0 {
             self.draw_thin(image);
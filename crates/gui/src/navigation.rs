@@ -0,0 +1,159 @@
+//! Sensitivity and inertia of panning and zooming.
+
+use dynamo_common::prelude::*;
+
+use crate::pane::id::PaneID;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Drag speed, in pixels per frame, below which coasting stops.
+const MIN_COAST_SPEED: f32 = 0.5;
+
+/// Scales pan and zoom inputs, and keeps panning for a while after a drag is released if
+/// momentum is enabled.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Navigation
+{
+    /// Multiplier on the distance panned, both by dragging and by keyboard.
+    pub pan_sensitivity: Real,
+    /// Exponent applied to zoom scales, so that 2 zooms twice as far per input.
+    pub zoom_sensitivity: Real,
+    /// Fraction of the drag velocity kept on each frame after release. 0 disables coasting.
+    pub momentum: f32,
+    /// Whether zooms are rounded to powers of two, so that successive views differ by exact
+    /// factors. Panning is unaffected.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub power_of_two_zoom: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    velocity: Option<(PaneID, [f32; 2])>,
+    /// Pointer zoom not yet applied while zooms are rounded, as a power of two.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_zoom: Real,
+}
+
+impl Default for Navigation
+{
+    fn default() -> Self
+    {
+        Self {
+            pan_sensitivity: 1.,
+            zoom_sensitivity: 1.,
+            momentum: 0.,
+            power_of_two_zoom: false,
+            velocity: None,
+            pending_zoom: 0.,
+        }
+    }
+}
+
+impl Navigation
+{
+    #[must_use]
+    pub const fn with_pan_sensitivity(mut self, pan_sensitivity: Real) -> Self
+    {
+        self.pan_sensitivity = pan_sensitivity;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_zoom_sensitivity(mut self, zoom_sensitivity: Real) -> Self
+    {
+        self.zoom_sensitivity = zoom_sensitivity;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_power_of_two_zoom(mut self, power_of_two_zoom: bool) -> Self
+    {
+        self.power_of_two_zoom = power_of_two_zoom;
+        self
+    }
+
+    #[must_use]
+    pub fn with_momentum(mut self, momentum: f32) -> Self
+    {
+        self.momentum = momentum.clamp(0., 1.);
+        self
+    }
+
+    /// Translation of the view for a drag by `delta` pixels, so that the image follows the
+    /// pointer at unit sensitivity.
+    #[must_use]
+    pub fn drag_offset(&self, grid: &PointGrid, delta: [f32; 2]) -> Cplx
+    {
+        -grid.map_vec2(delta) * self.pan_sensitivity
+    }
+
+    /// Scale the relative offsets of a keyboard pan.
+    #[must_use]
+    pub fn pan_fraction(&self, x: Real, y: Real) -> (Real, Real)
+    {
+        (x * self.pan_sensitivity, y * self.pan_sensitivity)
+    }
+
+    /// Scale the zoom factor of a keyboard action. If zooms are rounded to powers of two, any
+    /// zoom moves by at least a factor of two.
+    #[must_use]
+    pub fn zoom_scale(&self, scale: Real) -> Real
+    {
+        let scale = scale.powf(self.zoom_sensitivity);
+        if !self.power_of_two_zoom || scale == 1. {
+            return scale;
+        }
+        let log = scale.log2();
+        let exponent = if log.abs() < 1. {
+            log.signum()
+        } else {
+            log.round()
+        };
+        exponent.exp2()
+    }
+
+    /// Scale the zoom factor of pointer input on one frame. If zooms are rounded to powers of
+    /// two, the input is saved up until it amounts to a factor of two, and the zoom is 1 until
+    /// then.
+    pub fn pointer_zoom_scale(&mut self, scale: Real) -> Real
+    {
+        let scale = scale.powf(self.zoom_sensitivity);
+        if !self.power_of_two_zoom {
+            return scale;
+        }
+        self.pending_zoom += scale.log2();
+        let exponent = self.pending_zoom.trunc();
+        self.pending_zoom -= exponent;
+        exponent.exp2()
+    }
+
+    pub fn toggle_power_of_two_zoom(&mut self)
+    {
+        self.power_of_two_zoom ^= true;
+        self.pending_zoom = 0.;
+    }
+
+    /// Remember the latest drag, to keep it going once the pointer is released.
+    pub fn record_drag(&mut self, pane_id: PaneID, delta: [f32; 2])
+    {
+        self.velocity = (self.momentum > 0.).then_some((pane_id, delta));
+    }
+
+    /// Stop coasting, e.g. when a new drag begins.
+    pub fn stop(&mut self)
+    {
+        self.velocity = None;
+    }
+
+    /// Drag to apply on this frame while coasting, decaying by the momentum each frame.
+    pub fn coast(&mut self) -> Option<(PaneID, [f32; 2])>
+    {
+        let (pane_id, [dx, dy]) = self.velocity?;
+        let delta = [dx * self.momentum, dy * self.momentum];
+        if delta[0].hypot(delta[1]) < MIN_COAST_SPEED {
+            self.velocity = None;
+            return None;
+        }
+        self.velocity = Some((pane_id, delta));
+        Some((pane_id, delta))
+    }
+}
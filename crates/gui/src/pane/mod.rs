@@ -1,8 +1,9 @@
 use egui::{Color32, Pos2, Ui};
+use image::RgbImage;
 use std::path::Path;
 
 use crate::actions::ChangeBoolean;
-use crate::marked_points::ContourType;
+use crate::marked_points::{draw_orbit_prefix, AnnotationOverlay, ContourType};
 
 use super::image_frame::ImageFrame;
 use super::marked_points::Marking;
@@ -18,6 +19,19 @@ pub mod id;
 pub mod tasks;
 use tasks::{ChildTask, FollowState, PaneTasks, RepeatableTask};
 
+/// Number of levels in the multi-resolution preview shown while zooming.
+const PREVIEW_LEVELS: u32 = 4;
+
+/// Iteration cap for thumbnails, which favour speed over detail.
+const THUMBNAIL_MAX_ITER: IterCount = 1024;
+
+/// Number of escape-count isolines drawn when the overlay is enabled.
+const ESCAPE_ISOLINE_COUNT: IterCount = 8;
+
+/// Relative distance below which two points of a drawn orbit count as the same point when
+/// looking for a cycle in the orbit data.
+const ORBIT_DATA_TOLERANCE: Real = 1e-9;
+
 pub trait Pane
 {
     fn tasks(&self) -> &PaneTasks;
@@ -32,14 +46,55 @@ pub trait Pane
     fn get_selection(&self) -> Cplx;
     fn reset_selection(&mut self);
     fn reset(&mut self);
+    /// Expand the view outward to round coordinates, for figures.
+    fn round_bounds(&mut self);
+    /// Restore the default palette and coloring algorithm of the plane, leaving the view as is.
+    fn reset_coloring(&mut self);
     fn select_nearby_point(&mut self, orbit_schema: OrbitSchema) -> FindPointResult<Cplx>;
+    fn select_nearby_misiurewicz(&mut self, orbit_schema: OrbitSchema) -> FindPointResult<Cplx>;
     fn map_selection(&mut self);
     fn stop_following(&mut self);
     fn set_follow_state(&mut self, follow_state: FollowState);
-    fn degree(&self) -> AngleNum;
+    /// Degree acting on external angles, if the plane supports external rays.
+    fn degree(&self) -> Option<AngleNum>;
 
     fn draw_contour(&mut self, contour_type: ContourType);
     fn draw_aux_contours(&mut self);
+    fn draw_isoperiodic_curve(&mut self, period: Period, modulus: Real);
+    fn toggle_tuning(&mut self);
+    /// Pin the internal angle $p/q$ of the satellite bulb containing the selection.
+    fn label_bulb(&mut self);
+
+    /// Extend the freehand curve being drawn, starting a new one if necessary.
+    fn draw_freehand(&mut self, pointer_pos: Pos2);
+    fn end_freehand(&mut self);
+    fn clear_custom_curves(&mut self);
+    /// Drop a pin at `point`, labeled by its number until renamed, returning its id.
+    fn place_pin(&mut self, point: Cplx) -> usize;
+    fn clear_pins(&mut self);
+    fn toggle_orbit_gradient(&mut self);
+    fn toggle_orbit_centroid(&mut self);
+    /// Find the preperiod and period of the drawn orbit from its points, with Brent's method.
+    fn analyze_orbit_data(&mut self);
+    fn toggle_smooth_curves(&mut self);
+    fn toggle_escape_isolines(&mut self);
+    fn toggle_critical_basins(&mut self);
+    fn toggle_scaled_iteration(&mut self);
+    fn toggle_escape_annulus(&mut self);
+    fn toggle_cycle_detection(&mut self);
+    /// Switch between iterating every pixel and Mariani–Silver subdivision.
+    fn toggle_render_strategy(&mut self);
+    /// Switch between showing coarse previews while zooming and keeping the previous frame
+    /// until the recompute has finished.
+    fn toggle_double_buffering(&mut self);
+    /// Recompute the image from scratch, after a setting that the render cache does not track
+    /// has changed.
+    fn discard_cached_renders(&mut self);
+    fn critical_orbit(&self) -> CriticalOrbit;
+    fn set_critical_orbit(&mut self, critical_orbit: CriticalOrbit);
+    fn runtime_degree(&self) -> Option<Real>;
+    fn attracting_infinity(&self) -> bool;
+    fn set_runtime_degree(&mut self, degree: Real);
 
     fn get_image_frame(&self) -> &ImageFrame;
     fn get_image_frame_mut(&mut self) -> &mut ImageFrame;
@@ -47,6 +102,8 @@ pub trait Pane
     fn clear_marked_points(&mut self);
     fn clear_marked_orbit(&mut self);
     fn clear_marked_rays(&mut self);
+    /// Draw the rays of many angles, computed in parallel batches over the next updates.
+    fn draw_rays(&mut self, angles: &[RationalAngle]);
     fn clear_equipotentials(&mut self);
     fn clear_curves(&mut self);
     fn put_marked_points(&self, ui: &mut Ui);
@@ -54,8 +111,12 @@ pub trait Pane
 
     fn plane_type(&self) -> PlaneType;
     fn name(&self) -> String;
+    fn param_symbol(&self) -> &str;
     fn long_name(&self) -> String;
 
+    /// Coordinate and escape information at a point, as shown by the hover probe.
+    fn probe(&self, point: Cplx) -> String;
+
     fn grid(&self) -> &PointGrid;
 
     fn grid_mut(&mut self) -> &mut PointGrid;
@@ -103,6 +164,21 @@ pub trait Pane
         self.schedule_redraw();
     }
 
+    /// Color escaping points relative to `level_range`, or by absolute level if `None`.
+    fn set_level_range(&mut self, level_range: Option<(Real, Real)>)
+    {
+        if self.get_coloring().level_range() != level_range {
+            self.get_coloring_mut().set_level_range(level_range);
+            self.schedule_redraw();
+        }
+    }
+
+    fn change_tone(&mut self, tone: ToneAdjustment)
+    {
+        self.get_coloring_mut().set_tone(tone);
+        self.schedule_redraw();
+    }
+
     fn scale_palette(&mut self, scale_factor: f64)
     {
         self.get_coloring_mut().scale_period(scale_factor);
@@ -116,6 +192,21 @@ pub trait Pane
         self.schedule_redraw();
     }
 
+    /// Set the number of hues of the period coloring before they repeat.
+    fn set_period_hues(&mut self, num_colors: f32)
+    {
+        self.get_coloring_mut().get_period_coloring_mut().num_colors = num_colors;
+        self.marking_mut().sched_recolor_all();
+        self.schedule_redraw();
+    }
+
+    /// Color the basin of the attracting cycle through infinity, or by escape time if `None`.
+    fn set_infinity_color(&mut self, color: Option<Color32>)
+    {
+        self.get_coloring_mut().set_infinity_color(color);
+        self.schedule_redraw();
+    }
+
     fn shift_palette(&mut self, shift: f64)
     {
         self.get_coloring_mut().adjust_phase(shift);
@@ -124,10 +215,23 @@ pub trait Pane
 
     fn zoom(&mut self, scale: Real, base_point: Cplx);
 
+    /// Recenter the view on a point, then zoom about it by the given scale.
+    fn recenter_on(&mut self, point: Cplx, scale: Real)
+    {
+        if let Err(e) = self.grid_mut().recenter(point) {
+            println!("Could not recenter view: {e}");
+            return;
+        }
+        self.zoom(scale, point);
+    }
+
     #[inline]
     fn pan(&mut self, offset_vector: Cplx)
     {
-        self.grid_mut().translate(offset_vector);
+        if let Err(e) = self.grid_mut().translate(offset_vector) {
+            println!("Could not pan view: {e}");
+            return;
+        }
         self.schedule_recompute();
         self.schedule_redraw();
     }
@@ -170,10 +274,23 @@ pub trait Pane
         self.grid().map_pos(relative_pos.into())
     }
 
+    /// Precompose the view with a Möbius transformation, or remove it with `None`.
+    fn set_view_transform(&mut self, view_transform: Option<Mobius>)
+    {
+        self.grid_mut().view_transform = view_transform;
+        self.schedule_recompute();
+        self.schedule_redraw();
+    }
+
     fn process_mouse_input(&mut self, pointer_value: Cplx, zoom_factor: f32, reselect_point: bool)
     {
         if (zoom_factor - 1.0).abs() > f32::EPSILON {
-            self.zoom((1. / zoom_factor).into(), pointer_value);
+            // Zooming acts on the view, before the view transform
+            let base_point = self
+                .grid()
+                .untransform_point(pointer_value)
+                .unwrap_or_else(|| self.grid().center());
+            self.zoom((1. / zoom_factor).into(), base_point);
         }
 
         if reselect_point {
@@ -184,19 +301,60 @@ pub trait Pane
     fn select_preperiod_smooth_coloring(&mut self);
     fn select_preperiod_period_smooth_coloring(&mut self);
     fn select_preperiod_coloring(&mut self);
+    /// Split the image along its diagonal, keeping the interior coloring above it and coloring
+    /// below it by internal potential, or by multiplier if the interior coloring is already the
+    /// internal potential. Toggling again removes the split.
+    fn toggle_split_coloring(&mut self);
 
     fn marking(&self) -> &Marking;
     fn marking_mut(&mut self) -> &mut Marking;
 
     fn cycle_active_plane(&mut self);
     fn change_compute_mode(&mut self, change: ChangeBoolean);
+    /// Switch texture coloring via Böttcher coordinates on, or back to the compute mode it
+    /// replaced.
+    fn toggle_boettcher_texture(&mut self);
+    /// Switch between plain smooth potential and coloring by the accumulated derivative.
+    fn toggle_log_derivative(&mut self);
 
     fn scale_max_iter(&mut self, factor: f64);
 
-    fn save_image(&mut self, img_width: usize, filename: &Path);
+    fn save_image(&mut self, img_width: usize, filename: &Path, include_annotations: bool);
+    fn render_image(&mut self, img_width: usize) -> RgbImage;
+    fn save_alpha_mask(&mut self, img_width: usize, filename: &Path, mode: AlphaMaskMode);
+    /// Save one frame per point of the selection's orbit to `dir`, where frame $k$ shows the
+    /// first $k$ points over the unannotated image. Returns the number of frames saved.
+    fn save_orbit_frames(&mut self, img_width: usize, dir: &Path) -> usize;
+    fn render_thumbnail(&mut self, width: usize) -> RgbImage;
+    fn estimate_boundary_dimension(&mut self);
+    fn estimate_filled_area(&mut self);
+    /// Smallest and largest escape levels in the current image.
+    fn escape_level_range(&self) -> Option<(Real, Real)>;
     fn save_palette(&mut self, filename: &Path);
     fn load_palette(&mut self, filename: &Path);
 
+    /// Save the annotations that can be recomputed, such as rays and contours, to a JSON file.
+    fn save_annotations(&self, filename: &Path)
+    {
+        if let Err(e) = self.marking().annotation_overlay().save_to_file(filename) {
+            println!("Error saving annotations: {e:?}");
+        } else {
+            println!("Annotations saved to {}", filename.to_string_lossy());
+        }
+    }
+
+    /// Draw the annotations saved with [`Self::save_annotations`] on this pane.
+    fn load_annotations(&mut self, filename: &Path)
+    {
+        match AnnotationOverlay::load_from_file(filename) {
+            Ok(overlay) => {
+                self.marking_mut().apply_annotation_overlay(&overlay);
+                self.schedule_redraw();
+            }
+            Err(e) => println!("Error loading annotations: {e:?}"),
+        }
+    }
+
     fn change_height(&mut self, new_height: usize);
 
     fn state_info(&self) -> String;
@@ -224,13 +382,40 @@ where
     selection: Cplx,
     #[cfg_attr(feature = "serde", serde(skip))]
     orbit_info: Option<orbit::Info<P::Param, P::Var, P::Deriv>>,
+    /// Fate of the critical orbit at the selection, if this is a parameter plane.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    orbit_fate: Option<OrbitFate>,
     pub marking: Marking,
     pub zoom_factor: Real,
     pub child_task: ChildTask,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    render_cache: RenderCache<P::Deriv>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tuning: Option<(Tuning, Bounds)>,
+    /// Id and pixel positions of the freehand curve being drawn.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    freehand: Option<(usize, Vec<[f32; 2]>)>,
+    /// Box-counting dimension of the escape set boundary, if estimated for the current view.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    boundary_dimension: Option<Real>,
+    /// Area of the filled-in set, if estimated for the current view.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    filled_area: Option<Real>,
+    /// Preperiod and period found in the points of the drawn orbit, if analyzed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    orbit_data_schema: Option<OrbitSchema>,
+    /// Whether integer escape-count isolines are drawn over the image.
+    #[cfg_attr(feature = "serde", serde(default))]
+    escape_isolines: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    render_strategy: RenderStrategy,
+    /// Compute mode to return to when the Böttcher texture is switched off.
+    #[cfg_attr(feature = "serde", serde(default))]
+    untextured_compute_mode: ComputeMode,
 }
 impl<P> WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     /// Change the meta-parameter for the plane. Returns true if the new value is distinct from the
     /// old one.
@@ -253,6 +438,7 @@ where
             false
         } else {
             self.plane.set_param(new_param);
+            self.render_cache.clear();
             if matches!(self.tasks().follow, FollowState::Idle) {
                 self.select_point(self.plane.default_selection());
             }
@@ -290,6 +476,14 @@ where
             marking.toggle_critical();
         }
 
+        #[cfg(debug_assertions)]
+        for mismatch in plane.derivative_mismatches() {
+            println!(
+                "Warning: the {mismatch} derivative of {} disagrees with finite differences",
+                plane.name()
+            );
+        }
+
         Self {
             plane,
             coloring,
@@ -298,9 +492,19 @@ where
             tasks: PaneTasks::init_tasks(),
             selection,
             orbit_info: None,
+            orbit_fate: None,
             marking,
             zoom_factor: 1.,
             child_task: ChildTask::Idle,
+            render_cache: RenderCache::default(),
+            tuning: None,
+            freehand: None,
+            boundary_dimension: None,
+            filled_area: None,
+            orbit_data_schema: None,
+            escape_isolines: false,
+            render_strategy: RenderStrategy::default(),
+            untextured_compute_mode: ComputeMode::default(),
         }
     }
 
@@ -334,6 +538,7 @@ where
     fn del_orbit_info(&mut self)
     {
         self.orbit_info = None;
+        self.orbit_fate = None;
     }
 
     #[inline]
@@ -355,7 +560,50 @@ where
         self.selection
             .describe(&conf.selection_conf())
             .map_or_else(String::new, |description| {
-                format!("Selection: {description}")
+                let symbol = self.plane.param_symbol();
+                let mut result = format!("Selection: {symbol} = {description}");
+                if let Some((tuning, _)) = &self.tuning {
+                    let base = tuning.untune(self.selection);
+                    result.push_str(&format!(
+                        "\nBase coordinate (period {period} tuning): {symbol} = {base:.DISPLAY_PREC$}",
+                        period = tuning.period
+                    ));
+                }
+                result
+            })
+    }
+
+    fn describe_boundary_dimension(&self) -> String
+    {
+        self.boundary_dimension
+            .filter(|dim| dim.is_finite())
+            .map_or_else(String::new, |dim| format!("Boundary dimension ≈ {dim:.3}"))
+    }
+
+    fn describe_filled_area(&self) -> String
+    {
+        self.filled_area
+            .map_or_else(String::new, |area| format!("Filled area ≈ {area:.5}"))
+    }
+
+    fn describe_orbit_data_schema(&self) -> String
+    {
+        self.orbit_data_schema
+            .map_or_else(String::new, |o| format!("Orbit data: {o}"))
+    }
+
+    fn describe_orbit_fate(&self) -> String
+    {
+        self.orbit_fate
+            .map_or_else(String::new, |fate| format!("Critical orbit {fate}"))
+    }
+
+    fn describe_orbit_centroid(&self) -> String
+    {
+        self.marking
+            .orbit_centroid()
+            .map_or_else(String::new, |centroid| {
+                format!("Orbit centroid: {centroid:.DISPLAY_PREC$}")
             })
     }
 
@@ -377,38 +625,111 @@ where
 
     fn draw(&mut self)
     {
-        let image = self.iter_plane.render(self.get_coloring());
-        let image_frame = self.frame_mut();
-        image_frame.image = image;
-        image_frame.update_texture();
+        let grid = self.iter_plane.point_grid();
+        let target = self.image_frame.render_target([grid.res_x, grid.res_y]);
+        self.iter_plane.render_into(target, &self.coloring);
+        self.image_frame.present();
     }
 
     fn redraw(&mut self)
     {
-        let coloring = self.coloring.clone();
-        self.iter_plane
-            .render_into(&mut self.image_frame.image, &coloring);
-        self.image_frame.update_texture();
+        self.draw();
+    }
+
+    fn render_key(&self) -> RenderKey
+    {
+        RenderKey::new(
+            (
+                self.plane.name(),
+                self.plane.compute_mode(),
+                self.render_strategy,
+            ),
+            self.plane.point_grid(),
+            self.plane.max_iter(),
+        )
     }
 
-    #[inline]
     fn compute(&mut self)
     {
-        self.iter_plane = self.plane.compute();
+        self.boundary_dimension = None;
+        self.filled_area = None;
+        let key = self.render_key();
+        if let Some(cached) = self.render_cache.get(key) {
+            self.iter_plane = cached.clone();
+            return;
+        }
+        self.iter_plane = match self.render_strategy {
+            RenderStrategy::PerPixel => self.plane.compute(),
+            RenderStrategy::MarianiSilver => {
+                self.plane
+                    .compute_mariani_silver(RenderStrategy::MARIANI_SILVER_BLOCK_SIZE)
+                    .iter_plane
+            }
+        };
+        self.render_cache.insert(key, self.iter_plane.clone());
     }
 
-    #[inline]
     fn recompute(&mut self)
     {
-        self.plane.compute_into(&mut self.iter_plane);
+        self.boundary_dimension = None;
+        self.filled_area = None;
+        let key = self.render_key();
+        if let Some(cached) = self.render_cache.get(key) {
+            self.iter_plane.clone_from(cached);
+            return;
+        }
+        match self.render_strategy {
+            RenderStrategy::PerPixel => self.plane.compute_into(&mut self.iter_plane),
+            RenderStrategy::MarianiSilver => {
+                self.iter_plane = self
+                    .plane
+                    .compute_mariani_silver(RenderStrategy::MARIANI_SILVER_BLOCK_SIZE)
+                    .iter_plane;
+            }
+        }
+        self.render_cache.insert(key, self.iter_plane.clone());
+    }
+
+    /// Redraw the escape-count isolines for the current field, starting just above the
+    /// smallest escape count in view.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn update_isolines(&mut self)
+    {
+        if !self.escape_isolines {
+            return;
+        }
+        let min_count = self
+            .iter_plane
+            .iter_counts
+            .iter()
+            .filter_map(|info| match info {
+                PointInfo::Escaping { potential, .. }
+                | PointInfo::Boettcher { potential, .. }
+                | PointInfo::LogDerivative { potential, .. } => Some(*potential as IterCount),
+                _ => None,
+            })
+            .min();
+        let Some(min_count) = min_count else {
+            self.marking.disable_isolines();
+            return;
+        };
+        let levels: Vec<_> = (min_count + 1..=min_count + ESCAPE_ISOLINE_COUNT).collect();
+        let isolines = self.iter_plane.escape_isolines(&levels);
+        let palette = self.coloring.get_period_coloring();
+        self.marking.mark_isolines(isolines, palette);
     }
 
     fn mark_orbit_and_info(&mut self, pointer_value: Cplx)
     {
-        let orbit::OrbitAndInfo { orbit, info } = self.plane.get_orbit_and_info(pointer_value);
-        let orbit_pts = orbit.iter().map(|x| (*x).into()).collect();
+        let orbit::OrbitAndInfo { orbit, info } = self
+            .plane
+            .get_truncated_orbit_and_info(pointer_value, self.marking.max_orbit_len());
+        let orbit_pts = orbit.into_iter().map(Into::into).collect();
         self.mark_orbit(orbit_pts, Color32::GREEN);
+        self.orbit_data_schema = None;
         self.set_orbit_info(info);
+        self.orbit_fate =
+            (!self.plane.plane_type().is_dynamical()).then(|| self.plane.orbit_fate(pointer_value));
     }
 
     fn mark_orbit(&mut self, zs: ComplexVec, color: Color32)
@@ -426,7 +747,7 @@ where
 
 impl<P> From<P> for WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     fn from(plane: P) -> Self
     {
@@ -440,7 +761,7 @@ where
 /// handling tasks, zooming, panning, and managing selections and markings.
 impl<P> Pane for WindowPane<P>
 where
-    P: Displayable + 'static,
+    P: Displayable + Clone + 'static,
 {
     #[inline]
     fn tasks(&self) -> &PaneTasks
@@ -517,13 +838,32 @@ where
     #[inline]
     fn reset(&mut self)
     {
+        self.tuning = None;
         let bounds = self.plane.default_bounds();
-        self.grid_mut().change_bounds(bounds);
+        if let Err(e) = self.grid_mut().change_bounds(bounds) {
+            println!("Could not reset view: {e}");
+        }
         self.zoom_factor = 1.;
         self.reset_selection();
         self.clear_marked_orbit();
         self.schedule_recompute();
     }
+    fn round_bounds(&mut self)
+    {
+        let bounds = self.grid().bounds.round_to_nice();
+        if let Err(e) = self.grid_mut().change_bounds(bounds) {
+            println!("Could not round view: {e}");
+            return;
+        }
+        self.schedule_recompute();
+    }
+    #[inline]
+    fn reset_coloring(&mut self)
+    {
+        self.coloring = self.plane.default_coloring();
+        self.marking.sched_recolor_all();
+        self.schedule_redraw();
+    }
     #[inline]
     fn stop_following(&mut self)
     {
@@ -536,9 +876,9 @@ where
     }
 
     #[inline]
-    fn degree(&self) -> AngleNum
+    fn degree(&self) -> Option<AngleNum>
     {
-        self.plane().degree()
+        self.plane().integer_degree()
     }
 
     #[inline]
@@ -548,6 +888,94 @@ where
 
         self.marking_mut().toggle_contour(contour_type, selection);
     }
+    #[inline]
+    fn draw_isoperiodic_curve(&mut self, period: Period, modulus: Real)
+    {
+        let selection = self.get_selection();
+
+        self.marking_mut()
+            .toggle_isoperiodic(period, modulus, selection);
+    }
+
+    fn toggle_tuning(&mut self)
+    {
+        if let Some((_, old_bounds)) = self.tuning.take() {
+            if let Err(e) = self.grid_mut().change_bounds(old_bounds) {
+                println!("Could not restore view: {e}");
+            }
+            self.schedule_recompute();
+            return;
+        }
+
+        if self.plane_type().is_dynamical() {
+            return;
+        }
+
+        let period = match self.plane.get_orbit_and_info(self.selection).info.result {
+            PointInfo::Periodic(data) => data.period,
+            PointInfo::PeriodicKnownPotential(data) => data.period,
+            _ => {
+                println!("Select a point in a hyperbolic component to enter tuning mode");
+                return;
+            }
+        };
+
+        let orbit_schema = OrbitSchema {
+            period,
+            preperiod: 0,
+        };
+        let center = match self
+            .plane
+            .find_nearby_preperiodic_point(self.selection, orbit_schema)
+        {
+            Ok(center) => center,
+            Err(e) => {
+                println!("Could not find center of period {period} component: {e:?}");
+                return;
+            }
+        };
+
+        let Some(tuning) = self.plane.tuning(center, period) else {
+            println!("Tuning is not available for this component");
+            return;
+        };
+
+        let old_bounds = self.grid().bounds.clone();
+        let bounds = tuning.tune_bounds(&self.plane.default_bounds());
+        if let Err(e) = self.grid_mut().change_bounds(bounds) {
+            println!("Could not enter tuning mode: {e}");
+            return;
+        }
+        self.tuning = Some((tuning, old_bounds));
+        self.select_point(center);
+        self.schedule_recompute();
+    }
+
+    fn label_bulb(&mut self)
+    {
+        if self.plane_type().is_dynamical() {
+            return;
+        }
+
+        let period = match self.plane.get_orbit_and_info(self.selection).info.result {
+            PointInfo::Periodic(data) => data.period,
+            PointInfo::PeriodicKnownPotential(data) => data.period,
+            _ => {
+                println!("Select a point in a hyperbolic component to label its bulb");
+                return;
+            }
+        };
+
+        let c = self.plane.param_map(self.selection);
+        let Some(angle) = self.plane.satellite_internal_angle(&c, period) else {
+            println!("Selection is not in a bulb attached to the main component");
+            return;
+        };
+
+        let id = self.marking.next_pin_id();
+        self.marking.add_pin(id, self.selection, angle.to_string());
+    }
+
     fn draw_aux_contours(&mut self)
     {
         let selection = self.get_selection();
@@ -587,6 +1015,15 @@ where
             })
     }
 
+    fn select_nearby_misiurewicz(&mut self, o: OrbitSchema) -> FindPointResult<Cplx>
+    {
+        self.plane
+            .find_misiurewicz(self.selection, o)
+            .inspect(|&pt| {
+                self.select_point(pt);
+            })
+    }
+
     fn map_selection(&mut self)
     {
         if self.plane_type().is_dynamical() {
@@ -621,6 +1058,30 @@ where
         self.schedule_recompute();
     }
 
+    fn toggle_boettcher_texture(&mut self)
+    {
+        let current = self.plane.compute_mode();
+        let mode = if current == ComputeMode::Boettcher {
+            self.untextured_compute_mode
+        } else {
+            self.untextured_compute_mode = current;
+            ComputeMode::Boettcher
+        };
+        self.plane.set_compute_mode(mode);
+        self.schedule_recompute();
+    }
+
+    fn toggle_log_derivative(&mut self)
+    {
+        let mode = if self.plane.compute_mode() == ComputeMode::LogDerivative {
+            ComputeMode::SmoothPotential
+        } else {
+            ComputeMode::LogDerivative
+        };
+        self.plane.set_compute_mode(mode);
+        self.schedule_recompute();
+    }
+
     #[allow(clippy::cast_sign_loss)]
     fn scale_max_iter(&mut self, factor: f64)
     {
@@ -643,9 +1104,17 @@ where
     #[inline]
     fn zoom(&mut self, scale: Real, base_point: Cplx)
     {
+        if let Err(e) = self.grid_mut().zoom(scale, base_point) {
+            println!("Could not zoom: {e}");
+            return;
+        }
         self.zoom_factor *= scale;
-        self.grid_mut().zoom(scale, base_point);
         self.schedule_recompute_keep_old_annotations();
+        // A double-buffered frame keeps the previous image until the full recompute is done
+        if !self.render_cache.contains(self.render_key()) && !self.image_frame.is_double_buffered()
+        {
+            self.tasks_mut().preview = MipmapLevels::new(PREVIEW_LEVELS);
+        }
     }
 
     fn process_tasks(&mut self)
@@ -677,16 +1146,25 @@ where
         if self.tasks_mut().orbit.pop() {
             self.mark_orbit_and_info(self.selection);
         } else {
-            self.orbit_info = None;
+            self.del_orbit_info();
+        }
+
+        // Show coarse previews first, leaving the full computation and draw for later frames
+        if let Some(level) = self.tasks_mut().preview.pop().filter(|&level| level > 0) {
+            self.iter_plane = self.plane.compute_preview(level);
+            self.redraw();
+            return;
         }
 
         match self.tasks_mut().compute.pop() {
             RepeatableTask::Rerun => {
                 self.recompute();
+                self.update_isolines();
             }
             RepeatableTask::DoNothing => {}
             RepeatableTask::InitRun => {
                 self.compute();
+                self.update_isolines();
             }
         }
         match self.tasks_mut().draw.pop() {
@@ -706,6 +1184,22 @@ where
         self.set_coloring_algorithm(coloring_algorithm);
     }
 
+    fn toggle_split_coloring(&mut self)
+    {
+        let split_algorithm = if self.coloring.split_algorithm().is_some() {
+            None
+        } else if matches!(
+            self.coloring.get_algorithm(),
+            IncoloringAlgorithm::InternalPotential { .. }
+        ) {
+            Some(IncoloringAlgorithm::Multiplier)
+        } else {
+            Some(self.plane.internal_potential_coloring())
+        };
+        self.coloring.set_split_algorithm(split_algorithm);
+        self.schedule_redraw();
+    }
+
     fn select_preperiod_period_smooth_coloring(&mut self)
     {
         let coloring_algorithm = self.plane.potential_and_period_coloring();
@@ -718,25 +1212,120 @@ where
         self.set_coloring_algorithm(coloring_algorithm);
     }
 
-    fn save_image(&mut self, img_width: usize, filename: &Path)
+    fn save_image(&mut self, img_width: usize, filename: &Path, include_annotations: bool)
     {
         let old_res_x = self.plane.point_grid().res_x;
         self.plane.point_grid_mut().resize_x(img_width);
         let iter_plane = self.plane.compute();
         // iter_plane.save(self.get_coloring(), filename.to_owned());
 
+        let result = if self.coloring.transparent_bounded() {
+            let mut image = iter_plane.write_image_rgba(self.get_coloring());
+            self.marking
+                .mark_export(self.grid(), &mut image, include_annotations);
+            image.save(filename)
+        } else {
+            let mut image = iter_plane.write_image(self.get_coloring());
+            self.marking
+                .mark_export(self.grid(), &mut image, include_annotations);
+            image.save(filename)
+        };
+
+        if let Err(e) = result {
+            println!("Error saving file: {e:?}");
+        } else {
+            println!("Image saved to {}", filename.to_string_lossy());
+        }
+
+        self.plane.point_grid_mut().resize_x(old_res_x);
+    }
+
+    fn save_orbit_frames(&mut self, img_width: usize, dir: &Path) -> usize
+    {
+        let orbit: Vec<Cplx> = self
+            .plane
+            .iter_orbit_window(self.selection, 0, self.marking.max_orbit_len())
+            .map(Into::into)
+            .collect();
+
+        let old_res_x = self.plane.point_grid().res_x;
+        self.plane.point_grid_mut().resize_x(img_width);
+        let background = self.plane.compute().write_image(self.get_coloring());
+        let grid = self.grid().clone();
+        self.plane.point_grid_mut().resize_x(old_res_x);
+
+        let mut saved = 0;
+        for k in 1..=orbit.len() {
+            let mut frame = background.clone();
+            draw_orbit_prefix(&grid, &mut frame, &orbit, k);
+            let path = dir.join(format!("orbit_{k:04}.png"));
+            match frame.save(&path) {
+                Ok(()) => saved += 1,
+                Err(e) => println!("Error saving frame {k}: {e:?}"),
+            }
+        }
+        saved
+    }
+
+    fn render_image(&mut self, img_width: usize) -> RgbImage
+    {
+        let old_res_x = self.plane.point_grid().res_x;
+        self.plane.point_grid_mut().resize_x(img_width);
+        let iter_plane = self.plane.compute();
+
         let mut image = iter_plane.write_image(self.get_coloring());
         self.marking.mark_image(self.grid(), &mut image);
 
-        if let Err(e) = image.save(filename) {
+        self.plane.point_grid_mut().resize_x(old_res_x);
+        image
+    }
+
+    fn save_alpha_mask(&mut self, img_width: usize, filename: &Path, mode: AlphaMaskMode)
+    {
+        let old_res_x = self.plane.point_grid().res_x;
+        self.plane.point_grid_mut().resize_x(img_width);
+        let iter_plane = self.plane.compute();
+
+        if let Err(e) = iter_plane.render_alpha_mask(mode).save(filename) {
             println!("Error saving file: {e:?}");
         } else {
-            println!("Image saved to {}", filename.to_string_lossy());
+            println!("Mask saved to {}", filename.to_string_lossy());
         }
 
         self.plane.point_grid_mut().resize_x(old_res_x);
     }
 
+    fn render_thumbnail(&mut self, width: usize) -> RgbImage
+    {
+        let old_max_iter = self.plane.max_iter();
+        self.plane
+            .set_max_iter(old_max_iter.min(THUMBNAIL_MAX_ITER));
+        let iter_plane = self.plane.compute_thumbnail(width);
+        self.plane.set_max_iter(old_max_iter);
+
+        let mut image = iter_plane.write_image(self.get_coloring());
+        self.marking.mark_image(iter_plane.point_grid(), &mut image);
+        image
+    }
+
+    fn estimate_boundary_dimension(&mut self)
+    {
+        self.boundary_dimension = Some(self.iter_plane.boundary_box_dimension());
+    }
+
+    fn escape_level_range(&self) -> Option<(Real, Real)>
+    {
+        self.iter_plane.escape_level_range()
+    }
+
+    fn estimate_filled_area(&mut self)
+    {
+        if !self.iter_plane.contains_filled_set() {
+            println!("The view does not contain the whole filled set; the area is underestimated");
+        }
+        self.filled_area = Some(self.iter_plane.filled_area_estimate());
+    }
+
     fn save_palette(&mut self, filename: &Path)
     {
         if let Err(e) = self.coloring.save_to_file(filename) {
@@ -758,6 +1347,7 @@ where
     fn clear_marked_orbit(&mut self)
     {
         self.marking.disable_orbit();
+        self.orbit_data_schema = None;
         self.tasks_mut().orbit.disable();
     }
 
@@ -767,11 +1357,171 @@ where
         self.marking.disable_all_rays();
     }
 
+    fn draw_rays(&mut self, angles: &[RationalAngle])
+    {
+        self.marking.enable_rays(angles);
+    }
+
     fn clear_equipotentials(&mut self)
     {
         self.marking.disable_all_contours();
     }
 
+    fn draw_freehand(&mut self, pointer_pos: Pos2)
+    {
+        let relative_pos: [f32; 2] = self.frame().to_local_coords(pointer_pos).into();
+        let next_id = self.marking.next_custom_curve_id();
+        let (id, stroke) = self.freehand.get_or_insert_with(|| (next_id, Vec::new()));
+        stroke.push(relative_pos);
+        let curve = self.plane.point_grid().map_path(stroke.iter().copied());
+        self.marking.mark_custom_curve(*id, curve);
+    }
+
+    fn end_freehand(&mut self)
+    {
+        self.freehand = None;
+    }
+
+    fn toggle_orbit_gradient(&mut self)
+    {
+        self.marking.toggle_orbit_gradient();
+    }
+
+    fn toggle_orbit_centroid(&mut self)
+    {
+        self.marking.toggle_orbit_centroid();
+    }
+
+    fn analyze_orbit_data(&mut self)
+    {
+        let Some(orbit) = self.marking.orbit() else {
+            println!("No orbit is drawn");
+            return;
+        };
+        self.orbit_data_schema = detect_orbit_schema(orbit, ORBIT_DATA_TOLERANCE);
+        if self.orbit_data_schema.is_none() {
+            println!("No cycle found in the {} points of the orbit", orbit.len());
+        }
+    }
+
+    fn toggle_smooth_curves(&mut self)
+    {
+        self.marking.toggle_smooth_curves();
+    }
+
+    fn toggle_escape_isolines(&mut self)
+    {
+        self.escape_isolines ^= true;
+        if self.escape_isolines {
+            self.update_isolines();
+        } else {
+            self.marking.disable_isolines();
+        }
+    }
+
+    fn toggle_critical_basins(&mut self)
+    {
+        let enabled = !self.plane.critical_basins();
+        self.plane.set_critical_basins(enabled);
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn toggle_scaled_iteration(&mut self)
+    {
+        let enabled = !self.plane.scaled_iteration();
+        self.plane.set_scaled_iteration(enabled);
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn toggle_escape_annulus(&mut self)
+    {
+        let enabled = self.plane.escape_annulus().is_none();
+        self.plane.set_escape_annulus_enabled(enabled);
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn toggle_cycle_detection(&mut self)
+    {
+        let detection = match self.plane.cycle_detection() {
+            CycleDetection::Floyd => CycleDetection::Brent,
+            CycleDetection::Brent => CycleDetection::Floyd,
+        };
+        self.plane.set_cycle_detection(detection);
+        println!("Cycle detection: {detection}");
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn toggle_render_strategy(&mut self)
+    {
+        self.render_strategy.toggle();
+        self.schedule_recompute();
+    }
+
+    fn toggle_double_buffering(&mut self)
+    {
+        let double_buffered = !self.image_frame.is_double_buffered();
+        self.image_frame.set_double_buffered(double_buffered);
+        println!("Double buffering: {double_buffered}");
+    }
+
+    fn discard_cached_renders(&mut self)
+    {
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn critical_orbit(&self) -> CriticalOrbit
+    {
+        self.plane.critical_orbit()
+    }
+
+    fn set_critical_orbit(&mut self, critical_orbit: CriticalOrbit)
+    {
+        self.plane.set_critical_orbit(critical_orbit);
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn runtime_degree(&self) -> Option<Real>
+    {
+        self.plane.runtime_degree()
+    }
+
+    fn attracting_infinity(&self) -> bool
+    {
+        self.plane.attracting_infinity()
+    }
+
+    fn set_runtime_degree(&mut self, degree: Real)
+    {
+        self.plane.set_runtime_degree(degree);
+        self.marking.degree = self.plane.integer_degree().unwrap_or(2);
+        self.render_cache.clear();
+        self.schedule_recompute();
+    }
+
+    fn clear_custom_curves(&mut self)
+    {
+        self.freehand = None;
+        self.marking.disable_custom_curves();
+    }
+
+    fn place_pin(&mut self, point: Cplx) -> usize
+    {
+        let id = self.marking.next_pin_id();
+        self.marking.add_pin(id, point, (id + 1).to_string());
+        id
+    }
+
+    fn clear_pins(&mut self)
+    {
+        self.marking.clear_pins();
+    }
+
     fn clear_curves(&mut self)
     {
         self.marking.disable_all_curves();
@@ -804,10 +1554,19 @@ where
     fn state_info(&self) -> String
     {
         format!(
-            "{iters_info}\n{selection_info}\n{orbit_info}\n\n{follow_state}",
+            concat!(
+                "{iters_info}\n{selection_info}\n{orbit_info}\n{data_info}\n{fate_info}\n",
+                "{centroid_info}\n",
+                "{dimension_info}\n{area_info}\n\n{follow_state}"
+            ),
             iters_info = self.describe_max_iter(),
+            dimension_info = self.describe_boundary_dimension(),
+            area_info = self.describe_filled_area(),
             selection_info = self.describe_selection(),
             orbit_info = self.describe_orbit_info(),
+            data_info = self.describe_orbit_data_schema(),
+            fate_info = self.describe_orbit_fate(),
+            centroid_info = self.describe_orbit_centroid(),
             follow_state = self.tasks().follow,
         )
     }
@@ -829,6 +1588,20 @@ where
         self.plane.name()
     }
 
+    fn probe(&self, point: Cplx) -> String
+    {
+        format!(
+            "{symbol} = {point:.DISPLAY_PREC$}\n{summary}",
+            symbol = self.plane.param_symbol(),
+            summary = self.plane.probe(point).summary()
+        )
+    }
+
+    fn param_symbol(&self) -> &str
+    {
+        self.plane.param_symbol()
+    }
+
     fn long_name(&self) -> String
     {
         self.plane.name()
@@ -36,6 +36,11 @@ impl RepeatableTask
     {
         *self = Self::DoNothing;
     }
+    #[must_use]
+    pub const fn is_pending(&self) -> bool
+    {
+        !matches!(self, Self::DoNothing)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -46,6 +51,7 @@ pub struct PaneTasks
     pub draw: RepeatableTask,
     pub orbit: OrbitTask,
     pub follow: FollowState,
+    pub preview: MipmapLevels,
 }
 
 impl PaneTasks
@@ -59,6 +65,7 @@ impl PaneTasks
             draw: task,
             orbit: OrbitTask::Disabled,
             follow: FollowState::Idle,
+            preview: MipmapLevels::new(0),
         }
     }
 }
@@ -224,8 +231,12 @@ impl SelectOrFollow
     pub fn run_on<P: Pane + ?Sized>(&self, pane: &mut P, ray_params: &RayParams)
     {
         let angle_info = &ray_params.angle_info;
+        // Without a whole degree there are no external rays to toggle
+        let Some(degree) = pane.degree() else {
+            return;
+        };
         if ray_params.include_orbit {
-            for t in angle_info.orbit(pane.degree()) {
+            for t in angle_info.orbit(degree) {
                 pane.marking_mut().toggle_ray(t);
             }
         } else {
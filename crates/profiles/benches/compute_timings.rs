@@ -122,7 +122,7 @@ fn rulkov(b: &mut Bencher)
             max_y: 10.011_947_411_300_476,
         };
 
-        plane.point_grid_mut().change_bounds(bounds);
+        plane.point_grid_mut().change_bounds(bounds).unwrap();
         plane.compute();
     });
 }
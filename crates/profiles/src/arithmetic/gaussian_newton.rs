@@ -0,0 +1,296 @@
+use crate::macros::{degree_impl, profile_imports};
+use dynamo_color::prelude::*;
+profile_imports!();
+
+/// Newton's method for $p(z) - c$, where $p$ is the monic polynomial whose roots are the
+/// Gaussian integers $a + bi$ with $|a|, |b| \le N$.
+///
+/// At $c = 0$ every lattice point is a superattracting fixed point, and the dynamical plane
+/// shows which lattice point each starting point converges to. The other critical points of the
+/// Newton map are the roots of $p''$, which do not depend on $c$; the parameter plane follows
+/// one of them, selected with `set_critical_orbit`.
+#[derive(Clone, Debug)]
+pub struct GaussianNewton<const N: i64>
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    roots: Vec<Cplx>,
+    free_critical_points: Vec<Cplx>,
+    critical_orbit: CriticalOrbit,
+}
+
+impl<const N: i64> GaussianNewton<N>
+{
+    /// The Gaussian integers $a + bi$ with $|a|, |b| \le N$, row by row.
+    #[must_use]
+    pub fn lattice_points() -> Vec<Cplx>
+    {
+        (-N..=N)
+            .flat_map(|b| (-N..=N).map(move |a| Cplx::new(a as Real, b as Real)))
+            .collect()
+    }
+
+    /// Coefficients of $p$, from the constant term up.
+    fn coefficients(roots: &[Cplx]) -> Vec<Cplx>
+    {
+        let mut coeffs = vec![ONE];
+        for r in roots {
+            coeffs.insert(0, ZERO);
+            for k in 0..coeffs.len() - 1 {
+                let next = coeffs[k + 1];
+                coeffs[k] -= r * next;
+            }
+        }
+        coeffs
+    }
+
+    /// Roots of $p''$ that are not themselves lattice points.
+    ///
+    /// The roots found from the coefficients are only roughly accurate, and those near a multiple
+    /// root of $p''$ (such as $0$) can land well away from it, so they are polished with Newton's
+    /// method before discarding the lattice points.
+    fn inflection_points(roots: &[Cplx]) -> Vec<Cplx>
+    {
+        let coeffs = Self::coefficients(roots);
+        let degree = coeffs.len() - 1;
+        if degree < 3 {
+            return vec![];
+        }
+        let leading = (degree * (degree - 1)) as Real;
+        let second_derivative: Vec<Cplx> = coeffs
+            .iter()
+            .enumerate()
+            .skip(2)
+            .map(|(k, a)| a * (k * (k - 1)) as Real / leading)
+            .collect();
+        solve_polynomial(second_derivative)
+            .into_iter()
+            .map(|w| Self::polish_inflection_point(roots, w))
+            .filter(|w| roots.iter().all(|r| (w - r).norm() > 1e-3))
+            .collect()
+    }
+
+    /// Refine an approximate root of $p''$ by Newton's method, evaluating $p''$ and $p'''$ one
+    /// root at a time rather than from the coefficients.
+    fn polish_inflection_point(roots: &[Cplx], mut w: Cplx) -> Cplx
+    {
+        for _ in 0..16 {
+            let (mut p, mut dp, mut d2p, mut d3p) = (ONE, ZERO, ZERO, ZERO);
+            for r in roots {
+                let u = w - r;
+                d3p = d3p * u + 3. * d2p;
+                d2p = d2p * u + 2. * dp;
+                dp = dp * u + p;
+                p *= u;
+            }
+            let step = d2p / d3p;
+            if !step.is_finite() {
+                break;
+            }
+            w -= step;
+        }
+        w
+    }
+
+    /// The free critical points of the Newton map, i.e. the roots of $p''$ other than the
+    /// lattice points.
+    #[must_use]
+    pub fn free_critical_points(&self) -> &[Cplx]
+    {
+        &self.free_critical_points
+    }
+
+    /// $p(z)$ and its first two derivatives, accumulated one root at a time so that they stay
+    /// accurate near the roots.
+    #[inline]
+    fn eval(&self, z: Cplx) -> (Cplx, Cplx, Cplx)
+    {
+        let (mut p, mut dp, mut d2p) = (ONE, ZERO, ZERO);
+        for r in &self.roots {
+            let u = z - r;
+            d2p = d2p * u + 2. * dp;
+            dp = dp * u + p;
+            p *= u;
+        }
+        (p, dp, d2p)
+    }
+
+    /// Index of the lattice point nearest to `z`.
+    fn nearest_root_index(z: Cplx) -> usize
+    {
+        let side = 2 * N + 1;
+        let a = (z.re.round() as i64).clamp(-N, N) + N;
+        let b = (z.im.round() as i64).clamp(-N, N) + N;
+        (b * side + a) as usize
+    }
+}
+
+impl<const N: i64> Default for GaussianNewton<N>
+{
+    fn default() -> Self
+    {
+        let roots = Self::lattice_points();
+        let free_critical_points = Self::inflection_points(&roots);
+        let mut plane = Self {
+            point_grid: PointGrid::default(),
+            compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
+            max_iter: 1024,
+            roots,
+            free_critical_points,
+            critical_orbit: CriticalOrbit::default(),
+        };
+        plane.point_grid = PointGrid::new_by_res_y(1024, plane.default_bounds());
+        plane
+    }
+}
+
+impl<const N: i64> DynamicalFamily for GaussianNewton<N>
+{
+    parameter_plane_impl!();
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        let (p, dp, _) = self.eval(z);
+        z - (p - c) / dp
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (p, dp, d2p) = self.eval(z);
+        let u = (p - c) / dp;
+        (z - u, u * d2p / dp)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let (p, dp, d2p) = self.eval(z);
+        let u = (p - c) / dp;
+        (z - u, u * d2p / dp, dp.inv())
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        let index = match self.critical_orbit {
+            CriticalOrbit::Index(index) => index,
+            CriticalOrbit::All => 0,
+        };
+        self.free_critical_points
+            .get(index)
+            .copied()
+            .unwrap_or(ZERO)
+    }
+
+    #[inline]
+    fn start_point_d(&self, t: Cplx, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        (self.start_point(t, c), ZERO, ZERO)
+    }
+
+    fn critical_orbit(&self) -> CriticalOrbit
+    {
+        self.critical_orbit
+    }
+
+    fn set_critical_orbit(&mut self, critical_orbit: CriticalOrbit)
+    {
+        self.critical_orbit = critical_orbit;
+    }
+
+    fn critical_orbit_view(&self, index: usize) -> Option<Self>
+    {
+        Some(Self {
+            critical_orbit: CriticalOrbit::Index(index),
+            ..self.clone()
+        })
+    }
+
+    fn name(&self) -> String
+    {
+        format!("Gaussian Integer Newton, extent {N}")
+    }
+}
+
+impl<const N: i64> FamilyDefaults for GaussianNewton<N>
+{
+    /// Large enough to contain the critical values $p(w)$ of the free critical points.
+    fn default_bounds(&self) -> Bounds
+    {
+        let radius = self
+            .free_critical_points
+            .iter()
+            .map(|&w| self.eval(w).0.norm())
+            .fold(1., Real::max);
+        Bounds::centered_square(1.5 * radius)
+    }
+
+    fn default_coloring(&self) -> Coloring
+    {
+        Coloring::default().with_interior_algorithm(IncoloringAlgorithm::Period)
+    }
+}
+
+impl<const N: i64> HasJulia for GaussianNewton<N>
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(N as Real + 1.)
+    }
+}
+
+impl<const N: i64> MarkedPoints for GaussianNewton<N>
+{
+    /// The derivative of the Newton map vanishes at the roots of $p - c$, which are fixed, and
+    /// at the roots of $p''$, which are returned here.
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        self.free_critical_points.clone()
+    }
+
+    fn get_marked_points(&self, _c: &Self::Param) -> Vec<(Cplx, PointClassId)>
+    {
+        self.roots
+            .iter()
+            .enumerate()
+            .map(|(i, &z)| (z, PointClassId::from(i)))
+            .collect()
+    }
+
+    /// Fixed points are the roots of $p - c$, and each is labeled by the nearest lattice point,
+    /// which is exactly its root when $c = 0$.
+    fn identify_marked_points(
+        &self,
+        z: Self::Var,
+        _c: &Self::Param,
+        info: PointInfoPeriodic<Self::Deriv>,
+    ) -> PointInfo<Self::Deriv>
+    {
+        if info.period != 1 || !z.is_finite() {
+            return PointInfo::Periodic(info);
+        }
+        PointInfo::MarkedPoint {
+            data: info,
+            class_id: PointClassId::from(Self::nearest_root_index(z)),
+            num_point_classes: self.roots.len(),
+        }
+    }
+}
+
+impl<const N: i64> InfinityFirstReturnMap for GaussianNewton<N>
+{
+    degree_impl!(1);
+    #[inline]
+    fn escaping_phase(&self) -> Period
+    {
+        1
+    }
+}
+
+impl<const N: i64> EscapeEncoding for GaussianNewton<N> {}
+impl<const N: i64> ExternalRays for GaussianNewton<N> {}
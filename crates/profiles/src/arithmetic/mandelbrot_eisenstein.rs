@@ -10,6 +10,7 @@ pub struct EisensteinMandel<const A: i64, const B: i64>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     cache: Cache<(EInt, EInt), PointInfo<EInt>>,
 }
@@ -28,6 +29,7 @@ impl<const A: i64, const B: i64> Default for EisensteinMandel<A, B>
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             cache: Cache::new(),
         }
@@ -48,6 +50,12 @@ impl<const A: i64, const B: i64> DynamicalFamily for EisensteinMandel<A, B>
         self.cache.get(&(start, *c))
     }
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
         (z * z + *c) % Self::MOD
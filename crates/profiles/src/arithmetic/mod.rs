@@ -1,5 +1,8 @@
 pub mod mandelbrot_eisenstein;
 pub use mandelbrot_eisenstein::EisensteinMandel;
 
+pub mod gaussian_newton;
+pub use gaussian_newton::GaussianNewton;
+
 pub mod mandelbrot_gaussian;
 pub use mandelbrot_gaussian::GaussianMandel;
@@ -25,6 +25,60 @@ mod tests
     use dynamo_common::prelude::*;
     use dynamo_core::prelude::*;
 
+    /// A family given by a plain map and start point, with a placeholder multiplier of 1 and a
+    /// periodicity tolerance of $10^{-6}$.
+    #[derive(Clone)]
+    struct FixtureFamily
+    {
+        point_grid: PointGrid,
+        compute_mode: ComputeMode,
+        cycle_detection: CycleDetection,
+        max_iter: IterCount,
+        map: fn(Cplx, &Cplx) -> Cplx,
+        start: Cplx,
+    }
+
+    impl FixtureFamily
+    {
+        fn new(map: fn(Cplx, &Cplx) -> Cplx, start: Cplx) -> Self
+        {
+            Self {
+                point_grid: PointGrid::default(),
+                compute_mode: ComputeMode::default(),
+                cycle_detection: CycleDetection::default(),
+                max_iter: IterCount::default(),
+                map,
+                start,
+            }
+        }
+    }
+
+    impl DynamicalFamily for FixtureFamily
+    {
+        crate::macros::parameter_plane_impl!();
+
+        fn map(&self, z: Cplx, c: &Cplx) -> Cplx
+        {
+            (self.map)(z, c)
+        }
+        fn map_and_multiplier(&self, z: Cplx, c: &Cplx) -> (Cplx, Cplx)
+        {
+            (self.map(z, c), ONE)
+        }
+        fn start_point(&self, _point: Cplx, _c: &Cplx) -> Cplx
+        {
+            self.start
+        }
+        fn periodicity_tolerance(&self) -> Real
+        {
+            1e-6
+        }
+        fn name(&self) -> String
+        {
+            "Fixture".to_owned()
+        }
+    }
+
     #[test]
     fn test_horner()
     {
@@ -204,4 +258,1438 @@ mod tests
         let q = plane.escape_coeff(&c);
         assert!((q - 0.119_960_462_401_084).norm_sqr() < 1e-12);
     }
+
+    #[test]
+    fn mcmullen_critical_symmetry()
+    {
+        type Plane = McMullenFamily<2, 2>;
+        let plane = Plane::default();
+        let c = Cplx::new(0.01, 0.02);
+
+        let num_crit = plane.critical_points_child(&c).len();
+        let distinct = plane.distinct_critical_orbits(&c);
+        assert!(Plane::has_critical_symmetry());
+        assert!(distinct.len() < num_crit);
+
+        // Critical points sharing an orbit have equal critical values
+        let v0 = plane.map(distinct[0], &c);
+        let v2 = plane.map(plane.critical_points_child(&c)[2], &c);
+        assert!(v0.dist_sqr(v2) < 1e-12);
+
+        // Each shared orbit contributes a single critical value
+        let values = plane.critical_values_child(&c);
+        assert_eq!(values.len(), distinct.len());
+        assert!(values[0].dist_sqr(v0) < 1e-12);
+    }
+
+    #[test]
+    fn isoperiodic_main_cardioid()
+    {
+        let param_plane = Mandelbrot::default();
+        let curve = param_plane.isoperiodic_curve(Cplx::new(0.1, 0.1), 1, 1.0);
+        assert!(curve.len() > 200);
+
+        // On the main cardioid, the fixed point has multiplier 1 - sqrt(1 - 4c) of modulus 1
+        for c in curve {
+            let mult = ONE - (ONE - 4. * c).sqrt();
+            assert!((mult.norm() - 1.).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn param_symbols()
+    {
+        assert_eq!(Mandelbrot::default().param_symbol(), "c");
+        assert_eq!(QuadRatPer2LambdaParam::default().param_symbol(), "λ");
+        assert_eq!(Exponential::default().param_symbol(), "λ");
+    }
+
+    #[test]
+    fn render_cache_hit()
+    {
+        let plane = Mandelbrot::default().with_res_y(64);
+        let key = RenderKey::new(plane.name(), plane.point_grid(), plane.max_iter());
+        let mut cache = RenderCache::default();
+        assert!(cache.get(key).is_none());
+
+        let first = plane.compute();
+        cache.insert(key, first.clone());
+
+        let key_again = RenderKey::new(plane.name(), plane.point_grid(), plane.max_iter());
+        let cached = cache
+            .get(key_again)
+            .expect("second render should hit the cache");
+        assert_eq!(cached.iter_counts, first.iter_counts);
+        assert_eq!(cached.iter_counts, plane.compute().iter_counts);
+
+        let other = plane.clone().with_max_iter(plane.max_iter() * 2);
+        let other_key = RenderKey::new(other.name(), other.point_grid(), other.max_iter());
+        assert!(cache.get(other_key).is_none());
+    }
+
+    #[test]
+    fn tuning_period_two()
+    {
+        let plane = Mandelbrot::default();
+        let center = plane
+            .find_nearby_preperiodic_point(
+                Cplx::new(-1.05, 0.02),
+                OrbitSchema {
+                    period: 2,
+                    preperiod: 0,
+                },
+            )
+            .expect("should find the center of the period 2 component");
+        let tuning = plane.tuning(center, 2).unwrap();
+
+        // Images of the main cardioid under tuning should have attracting 2-cycles
+        for k in 0..32 {
+            let mu = Cplx::from_polar(0.5, Real::from(k) * TAU / 32.);
+            let c_base = 0.5 * mu - 0.25 * mu * mu;
+            let c = tuning.tune(c_base);
+            let multiplier = 4. * (c + 1.);
+            assert!(multiplier.norm() < 1.);
+            assert!((tuning.untune(c) - c_base).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn mipmap_preview_order()
+    {
+        let plane = Mandelbrot::default().with_res_y(64);
+        let mut schedule = MipmapLevels::new(4);
+        let levels: Vec<u32> = std::iter::from_fn(|| schedule.pop()).collect();
+        assert_eq!(levels, vec![3, 2, 1, 0]);
+        assert!(schedule.is_done());
+
+        // The coarsest level is upscaled to the full grid in blocks of 8x8 pixels
+        let coarsest = plane.compute_preview(levels[0]);
+        assert_eq!(coarsest.point_grid, *plane.point_grid());
+        assert_eq!(coarsest.iter_counts[(0, 0)], coarsest.iter_counts[(7, 7)]);
+
+        let finest = plane.compute_preview(levels[3]);
+        assert_eq!(finest.iter_counts, plane.compute().iter_counts);
+    }
+
+    #[test]
+    fn mandelbrot_critical_value()
+    {
+        let plane = Mandelbrot::default();
+        let c = Cplx::new(-0.12, 0.75);
+        assert_eq!(plane.critical_values_child(&c), vec![c]);
+
+        let julia = JuliaSet::from(plane).with_param(c);
+        assert_eq!(julia.critical_values_child(&NoParam), vec![c]);
+    }
+
+    #[test]
+    fn exponential_annulus_escape()
+    {
+        use orbit::Orbit;
+
+        // Total variation of the slope of the escape potential along the segment $[1, 3)$,
+        // where the critical orbit $0 \mapsto \lambda \mapsto \lambda e^\lambda \mapsto \dots$
+        // escapes since $\lambda > 1/e$; bands are smoother when this is smaller.
+        fn roughness(plane: &Exponential) -> Real
+        {
+            let potentials: Vec<Real> = (0..256)
+                .map(|k| {
+                    let lambda = Cplx::new(1. + Real::from(k) / 128., 0.);
+                    let mut orbit = orbit::CycleDetected::new(plane).init(lambda);
+                    match orbit.run_until_complete() {
+                        PointInfo::Escaping { potential, .. } => potential,
+                        result => panic!("{lambda} should escape, got {result:?}"),
+                    }
+                })
+                .collect();
+            potentials
+                .windows(3)
+                .map(|w| (w[2] - 2. * w[1] + w[0]).abs())
+                .sum()
+        }
+
+        let radius = Exponential::default();
+        let annulus =
+            Exponential::default().with_escape_annulus(Some(Exponential::DEFAULT_ESCAPE_ANNULUS));
+        assert!(roughness(&annulus) < roughness(&radius));
+    }
+
+    #[test]
+    fn main_cardioid_cycle_multipliers()
+    {
+        let plane = Mandelbrot::default();
+        let table = plane.cycle_multipliers_child(&Cplx::new(0.1, 0.1), 2);
+
+        let period_one: Vec<_> = table.iter().filter(|(p, ..)| *p == 1).collect();
+        assert_eq!(period_one.len(), 2);
+        assert!(period_one
+            .iter()
+            .any(|(_, _, class)| *class == Classification::Attracting));
+        assert!(period_one
+            .iter()
+            .any(|(_, _, class)| *class == Classification::Repelling));
+
+        // The two fixed-point multipliers of z^2 + c sum to 2
+        let sum: Cplx = period_one.iter().map(|(_, m, _)| m).sum();
+        assert!((sum - 2.).norm() < 1e-10);
+
+        // Period 2 is repelling outside the period-doubling component
+        assert!(table
+            .iter()
+            .filter(|(p, ..)| *p == 2)
+            .all(|(_, _, class)| *class == Classification::Repelling));
+    }
+
+    #[test]
+    fn burning_ship_julia()
+    {
+        use orbit::Orbit;
+
+        let c = Cplx::new(-0.4, -0.6);
+        let ship = JuliaSet::from(BurningShip::<2>::default()).with_param(c);
+        let quadratic = JuliaSet::from(Mandelbrot::default()).with_param(c);
+
+        let escapes = |info: PointInfo<Cplx>| matches!(info, PointInfo::Escaping { .. });
+        let mut differs = false;
+        for j in -16..=16 {
+            for k in -16..=16 {
+                let z = Cplx::new(Real::from(j), Real::from(k)) / 8.;
+                let ship_z = orbit::CycleDetected::new(&ship)
+                    .init(z)
+                    .run_until_complete();
+                let ship_conj = orbit::CycleDetected::new(&ship)
+                    .init(z.conj())
+                    .run_until_complete();
+                let quad_z = orbit::CycleDetected::new(&quadratic)
+                    .init(z)
+                    .run_until_complete();
+
+                // The fold makes the map invariant under reflection in either axis, unlike the
+                // holomorphic quadratic map with a non-real parameter.
+                assert_eq!(escapes(ship_z.clone()), escapes(ship_conj));
+                differs |= escapes(ship_z) != escapes(quad_z);
+            }
+        }
+        assert!(differs);
+
+        // Fixed points account for the fold on each quadrant
+        let plane = BurningShip::<2>::default();
+        let fixed_points = plane.cycles_child(&c, 1);
+        assert_eq!(fixed_points.len(), 4);
+        for z in fixed_points {
+            assert!((plane.map(z, &c) - z).norm() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn tricorn_marked_cycle_covers()
+    {
+        let tricorn = Tricorne::<2>::default();
+        let samples = (-8..=8)
+            .flat_map(|j| (-8..=8).map(move |k| Cplx::new(Real::from(j), Real::from(k)) / 4.))
+            .filter(|t| t.norm() > 1e-3);
+
+        let period_1 = tricorn.clone().marked_cycle_curve(1);
+        for t in samples.clone() {
+            let c = period_1.param_map(t);
+            let z = t + 0.5;
+            let (fz, df) = tricorn.map_and_multiplier(z, &c);
+            assert!((fz - z).norm() < 1e-10);
+
+            // The first return map is antiholomorphic, so the multiplier is that of its square
+            let (mult, _) = period_1.auxiliary_value(t).unwrap();
+            assert!((mult - df.norm_sqr()).norm() < 1e-10);
+        }
+
+        let period_2 = tricorn.clone().marked_cycle_curve(2);
+        for d in samples {
+            let c = period_2.param_map(d);
+            let z1 = -0.5 * (d.conj() / d + d);
+            let (z2, df1) = tricorn.map_and_multiplier(z1, &c);
+            let (z3, df2) = tricorn.map_and_multiplier(z2, &c);
+            assert!((z2 - z1 - d).norm() < 1e-10);
+            assert!((z3 - z1).norm() < 1e-10);
+
+            let (mult, _) = period_2.auxiliary_value(d).unwrap();
+            assert!((mult - df2 * df1.conj()).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn thumbnail_dimensions()
+    {
+        let plane = Mandelbrot::default();
+        let bounds = plane.point_grid().bounds.clone();
+        let thumbnail = plane.compute_thumbnail(96);
+
+        assert_eq!(thumbnail.point_grid.res_x, 96);
+        assert_eq!(
+            thumbnail.point_grid.res_y,
+            PointGrid::infer_height(96, &bounds)
+        );
+        assert_eq!(
+            thumbnail.iter_counts.dim(),
+            (thumbnail.point_grid.res_x, thumbnail.point_grid.res_y)
+        );
+        assert_eq!(thumbnail.point_grid.bounds, bounds);
+    }
+
+    #[test]
+    fn locked_child_param()
+    {
+        let parent = Mandelbrot::default();
+        let mut child = JuliaSet::from(parent.clone()).with_param(Cplx::new(-0.12, 0.75));
+        child.set_param_locked(true);
+
+        let locked_param = child.get_param();
+        for selection in [Cplx::new(0.3, 0.), Cplx::new(-1., 0.2)] {
+            child.set_param(parent.param_map(selection));
+            assert_eq!(child.get_param(), locked_param);
+        }
+
+        child.set_param_locked(false);
+        child.set_param(parent.param_map(Cplx::new(0.3, 0.)));
+        assert_eq!(child.get_param(), Cplx::new(0.3, 0.));
+    }
+
+    #[test]
+    fn ray_newton_tolerance()
+    {
+        let plane = Mandelbrot::default();
+        let angle = RationalAngle::new(1, 6);
+        let landing_point = Cplx::new(0., 1.);
+
+        let lands = |params: RayNewtonParams| {
+            plane
+                .external_ray_with(angle, params)
+                .and_then(|ray| ray.last().copied())
+                .is_some_and(|z| (z - landing_point).norm() < 1e-2)
+        };
+
+        assert!(plane
+            .external_ray(angle)
+            .is_some_and(|ray| (ray.last().unwrap() - landing_point).norm() < 1e-2));
+
+        // A loose tolerance lands with fewer Newton steps per point than a tight one, which is
+        // cut off early when held to the same budget
+        let loose = RayNewtonParams::default().with_tolerance(1.0);
+        let tight = RayNewtonParams::default().with_tolerance(1e-3);
+        assert!(lands(loose.with_max_iters(3)));
+        assert!(!lands(tight.with_max_iters(3)));
+        assert!(lands(tight.with_max_iters(4)));
+    }
+
+    #[test]
+    fn generalized_collatz_default()
+    {
+        let plane = GeneralizedCollatz::default();
+        let m = plane.default_selection();
+
+        for n in 1..50 {
+            let z = Cplx::from(Real::from(n));
+            let expected = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+            assert!((plane.map(z, &m) - Real::from(expected)).norm() < 1e-9);
+        }
+
+        // Smooth Collatz map, $\frac{1}{4}(2 + 7z - (2 + 5z)\cos(\pi z))$
+        let z = Cplx::new(0.3, -0.7);
+        let smooth = 0.25 * (2. + 7. * z - (2. + 5. * z) * (PI * z).cos());
+        assert!((plane.map(z, &m) - smooth).norm() < 1e-12);
+    }
+
+    #[test]
+    fn exponential_periodic_tiling()
+    {
+        let mut julia = JuliaSet::new(Exponential::default(), Cplx::from(0.3), 256);
+        // Three periods of $2\pi i$, at a whole number of pixels per period
+        julia.point_grid = PointGrid::new(
+            96,
+            3 * 64,
+            Bounds {
+                min_x: -2.,
+                max_x: 4.,
+                min_y: -3. * PI,
+                max_y: 3. * PI,
+            },
+        );
+        let grid = julia.point_grid().clone();
+        assert!(grid
+            .fundamental_tile(&julia.fundamental_domain().unwrap())
+            .is_some());
+
+        let tiled = julia.compute();
+        let mut direct = IterPlane::create(grid);
+        julia.compute_on_grid_direct(&julia.point_grid, &mut direct);
+
+        let matching = tiled
+            .iter_counts
+            .iter()
+            .zip(direct.iter_counts.iter())
+            .filter(|(a, b)| std::mem::discriminant(*a) == std::mem::discriminant(*b))
+            .count();
+        assert!(matching * 100 >= tiled.iter_counts.len() * 99);
+    }
+
+    #[test]
+    fn chebyshev_critical_basins()
+    {
+        use orbit::Orbit;
+
+        // All critical orbits fall into the superattracting-like cycle $0 \mapsto c \mapsto 0$
+        let c = Cplx::from(-0.39);
+        let julia = JuliaSet::from(Chebyshev::<4>::default())
+            .with_param(c)
+            .with_critical_basins(true);
+        assert_eq!(julia.critical_points().len(), 7);
+
+        let class = |z: Cplx| match orbit::CycleDetected::new(&julia)
+            .init(z)
+            .run_until_complete()
+        {
+            PointInfo::MarkedPoint {
+                class_id,
+                num_point_classes,
+                ..
+            } => (class_id, num_point_classes),
+            info => panic!("expected a marked basin, got {info:?}"),
+        };
+
+        let (near_zero, num_classes) = class(Cplx::from(0.05));
+        let (near_c, _) = class(c + 0.02);
+        assert_eq!(num_classes, 2);
+        assert_ne!(near_zero, near_c);
+        assert_eq!(class(Cplx::from(-0.05)).0, near_zero);
+
+        let plain = JuliaSet::from(Chebyshev::<4>::default()).with_param(c);
+        assert!(matches!(
+            orbit::CycleDetected::new(&plain)
+                .init(Cplx::from(0.05))
+                .run_until_complete(),
+            PointInfo::Periodic(_)
+        ));
+    }
+
+    #[test]
+    fn mandelbrot_cycle_polynomial()
+    {
+        use dynamo_common::math_utils::polynomial_roots::solve_polynomial;
+
+        let plane = Mandelbrot::default();
+        let c = Cplx::new(-0.12, 0.75);
+        // The root finder starts from random points and is only accurate to about $10^{-4}$ on
+        // the clustered roots of high periods, so roots are polished by Newton's method on
+        // $f^n(z) - z$, then matched up to a tolerance and in any order. Low periods are solved in
+        // closed form, but the polynomials have the same roots.
+        for period in 1..=6 {
+            let polish = |mut z: Cplx| {
+                for _ in 0..8 {
+                    let (mut w, mut dw) = (z, ONE);
+                    for _ in 0..period {
+                        let (w1, d) = plane.map_and_multiplier(w, &c);
+                        w = w1;
+                        dw *= d;
+                    }
+                    z -= (w - z) / (dw - 1.);
+                }
+                z
+            };
+            let roots: Vec<Cplx> = solve_polynomial(plane.cycle_polynomial(&c, period))
+                .into_iter()
+                .map(polish)
+                .collect();
+            let cycles = plane.cycles_child(&c, period);
+            assert_eq!(roots.len(), cycles.len());
+            for z in cycles.into_iter().map(polish) {
+                assert!(
+                    roots.iter().any(|w| (w - z).norm() < 1e-10),
+                    "period {period}: {z} is not a root"
+                );
+            }
+        }
+
+        let julia = JuliaSet::from(plane.clone()).with_param(c);
+        assert_eq!(
+            julia.cycle_polynomial(&NoParam, 4),
+            plane.cycle_polynomial(&c, 4)
+        );
+        assert!(plane.cycle_polynomial(&c, 7).is_empty());
+    }
+
+    #[test]
+    fn probe_escaping_point()
+    {
+        let plane = Mandelbrot::default();
+        let info = plane.probe(ONE);
+        let PointInfo::Escaping { potential, .. } = info else {
+            panic!("expected c = 1 to escape, got {info:?}");
+        };
+        // The orbit 0, 1, 2, 5, 26, 677, ... outgrows any reasonable escape radius quickly
+        assert!((2. ..12.).contains(&potential));
+        assert!(info.summary().starts_with("Escaping after"));
+
+        // Inside the main cardioid
+        let info = plane.probe(Cplx::new(-0.1, 0.1));
+        assert!(!info.is_escaping());
+        assert_eq!(info.summary(), "Periodic, period 1");
+    }
+
+    #[test]
+    fn nova_newton_slice()
+    {
+        let nova = NovaMandelbrot::default();
+        let newton = NewtonCubic::default();
+        assert!((nova.critical_point() - ONE).norm() < 1e-12);
+
+        // With no added constant, the map is Newton's method for $z^3 - 1$
+        for j in -4..=4 {
+            for k in -4..=4 {
+                let z = Cplx::new(Real::from(j), Real::from(k)) / 3. + 0.1;
+                assert!((nova.map(z, &ZERO) - newton.map(z, &ZERO)).norm() < 1e-12);
+                let (_, df) = nova.map_and_multiplier(z, &ZERO);
+                let (_, df_newton) = newton.map_and_multiplier(z, &ZERO);
+                assert!((df - df_newton).norm() < 1e-10);
+            }
+        }
+
+        // The critical orbit sits on the root, so the slice is fully converging
+        let info = nova.probe(ZERO);
+        assert!(matches!(info, PointInfo::Periodic(data) if data.period == 1));
+    }
+
+    #[test]
+    fn misiurewicz_chebyshev_point()
+    {
+        let mandelbrot = Mandelbrot::default();
+        let orbit_schema = OrbitSchema {
+            preperiod: 2,
+            period: 1,
+        };
+        let c = mandelbrot
+            .find_misiurewicz(Cplx::new(-1.9, 0.05), orbit_schema)
+            .unwrap();
+        assert!((c + 2.).norm() < 1e-10, "{c}");
+
+        let center = OrbitSchema {
+            preperiod: 0,
+            period: 1,
+        };
+        assert!(mandelbrot.find_misiurewicz(ZERO, center).is_err());
+        assert!(JuliaSet::from(mandelbrot)
+            .find_misiurewicz(Cplx::new(-1.9, 0.), orbit_schema)
+            .is_err());
+    }
+
+    #[test]
+    fn mandelbrot_ray_landings()
+    {
+        let mandelbrot = Mandelbrot::default();
+        let landings = mandelbrot.ray_landings(7, 0.1);
+        let groups: Vec<Vec<RationalAngle>> =
+            landings.iter().map(|group| group.angles.clone()).collect();
+
+        // Each pair lands at the root of a period 3 component: the two satellite bulbs of the
+        // main cardioid, and the airplane component
+        let pair = |j, k| vec![RationalAngle::new(j, 7), RationalAngle::new(k, 7)];
+        assert_eq!(groups, vec![pair(1, 2), pair(3, 4), pair(5, 6)]);
+        assert!(
+            (landings[1].point + 1.75).norm() < 0.05,
+            "{}",
+            landings[1].point
+        );
+    }
+
+    #[test]
+    fn critical_orbit_selection()
+    {
+        let image = |critical_orbit| {
+            let mut plane = NovaMandelbrot::default().with_res_y(48);
+            plane.set_critical_orbit(critical_orbit);
+            plane.compute().iter_counts
+        };
+        // The orbit of $\omega z_0$ at $c$ is conjugate to that of $z_0$ at $\bar\omega c$, so
+        // the images of different critical points are rotated copies of each other
+        assert_ne!(
+            image(CriticalOrbit::Index(0)),
+            image(CriticalOrbit::Index(1))
+        );
+
+        let mut cheb = Chebyshev::<3>::default().with_res_y(48);
+        let c = Cplx::new(0.3, 0.2);
+        let orbit = |cheb: &mut Chebyshev<3>, critical_orbit| {
+            cheb.set_critical_orbit(critical_orbit);
+            cheb.get_orbit_and_info(c)
+        };
+        let from_zero = orbit(&mut cheb, CriticalOrbit::Index(0));
+        let from_sqrt_3 = orbit(&mut cheb, CriticalOrbit::Index(1));
+        let from_one = orbit(&mut cheb, CriticalOrbit::Index(3));
+        assert_eq!(from_sqrt_3.info.start, Cplx::from(SQRT_3));
+        assert_eq!(from_one.info.start, ONE);
+        // The critical points $0, \pm\sqrt 3$ map to $c$, and $\pm 1$ map to $-c$
+        assert!((from_zero.orbit[1] - c).norm() < 1e-12);
+        assert!((from_sqrt_3.orbit[1] - c).norm() < 1e-12);
+        assert!((from_one.orbit[1] + c).norm() < 1e-12);
+
+        // The map is even, so every critical orbit meets the others after two steps, and each
+        // choice of critical point, or all of them, gives the same image
+        let image = |cheb: &mut Chebyshev<3>, critical_orbit| {
+            cheb.set_critical_orbit(critical_orbit);
+            cheb.compute().iter_counts
+        };
+        let single = image(&mut cheb, CriticalOrbit::Index(0));
+        for critical_orbit in (1..5).map(CriticalOrbit::Index).chain([CriticalOrbit::All]) {
+            for (a, b) in image(&mut cheb, critical_orbit).iter().zip(single.iter()) {
+                assert_eq!(std::mem::discriminant(a), std::mem::discriminant(b));
+                if let (Some(x), Some(y)) = (a.escape_level(), b.escape_level()) {
+                    assert!((x - y).abs() < 1e-9, "{critical_orbit}: {x} != {y}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trig_wander_matches_sine_wander()
+    {
+        let general = TrigWander::default()
+            .with_trig(TrigKind::Sin)
+            .with_param(TrigWander::WANDER_COEFFICIENT);
+        let sine = SineWander::default();
+
+        let c = Cplx::new(0.3, -0.1);
+        assert!((general.start_point(ZERO, &c) - sine.start_point(ZERO, &c)).norm() < 1e-12);
+        for z in [ZERO, Cplx::new(1.5, 0.5), Cplx::new(-2., 3.)] {
+            let (f0, df0, dc0) = general.gradient(z, &c);
+            let (f1, df1, dc1) = sine.gradient(z, &c);
+            assert!((f0 - f1).norm() < 1e-12);
+            assert!((df0 - df1).norm() < 1e-12);
+            assert!((dc0 - dc1).norm() < 1e-12);
+        }
+
+        // The start point is critical for every kind
+        for trig in [TrigKind::Sin, TrigKind::Cos, TrigKind::Tan] {
+            let plane = TrigWander::default()
+                .with_trig(trig)
+                .with_param(Cplx::new(0.5, 0.2));
+            let (_, multiplier) = plane.map_and_multiplier(plane.start_point(ZERO, &c), &c);
+            assert!(multiplier.norm() < 1e-9, "{trig}: {multiplier}");
+        }
+    }
+
+    #[test]
+    fn scaled_iteration_escapes_cleanly()
+    {
+        use orbit::Orbit;
+
+        // $z_1 = c$ lies inside the escape radius, but $z_2 = c(1 + c/128)^{128}$ is far beyond
+        // the range of `f64`
+        let c = Cplx::new(5e5, 0.);
+        let run = |scaled| {
+            let mut plane = Unicritical::<128>::default();
+            plane.set_scaled_iteration(scaled);
+            let mut orbit = orbit::CycleDetected::new(&plane).init(c);
+            orbit.run_until_complete();
+            orbit.state
+        };
+
+        match run(false) {
+            Some(EscapeResult::Escaped { final_value, .. }) => assert!(!final_value.is_finite()),
+            state => panic!("expected overflow, got {state:?}"),
+        }
+
+        let Some(EscapeResult::Escaped { iters, final_value }) = run(true) else {
+            panic!("expected escape in scaled mode");
+        };
+        assert!(final_value.is_finite());
+
+        // Stepping back along the first return map at infinity preserves the smooth iteration
+        // count: $(\log|z_n|^2 + q) d^{2 - n} = \log|z_2|^2 + q$, with $q = \log|c/d^d|$
+        let degree: Real = 128.;
+        let q = c.norm().ln() - degree * degree.ln();
+        let v = 2. * c.norm().ln() + 2. * degree * (1. + c / degree).norm().ln();
+        let steps = 2 - i32::try_from(iters).unwrap();
+        let pulled_back = (final_value.norm_sqr().ln() + q) * degree.powi(steps);
+        assert!(((pulled_back - (v + q)) / (v + q)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn basilica_filled_area()
+    {
+        let area_at = |res_y| {
+            let julia = JuliaSet::from(Mandelbrot::default())
+                .with_param(Cplx::new(-1., 0.))
+                .with_bounds(Bounds::centered_square(2.5))
+                .with_res_y(res_y);
+            let iter_plane = julia.compute();
+            assert!(iter_plane.contains_filled_set());
+            iter_plane.filled_area_estimate()
+        };
+        let coarse = area_at(128);
+        let fine = area_at(256);
+        assert!(fine > 0.5, "{fine}");
+        assert!((coarse - fine).abs() < 0.1 * fine, "{coarse} vs {fine}");
+    }
+
+    #[test]
+    fn derivative_self_check()
+    {
+        use dynamo_core::dynamics::DerivativeMismatch;
+
+        // The quadratic family with a placeholder multiplier
+        let placeholder_multiplier = FixtureFamily::new(|z, c| z * z + c, ZERO);
+        assert_eq!(
+            placeholder_multiplier.derivative_mismatches(),
+            vec![DerivativeMismatch::Dynamical]
+        );
+        assert!(Mandelbrot::default().derivative_mismatches().is_empty());
+        assert!(JuliaSet::from(Mandelbrot::default())
+            .with_param(Cplx::new(-0.12, 0.75))
+            .derivative_mismatches()
+            .is_empty());
+        // Non-holomorphic families are not checked
+        assert!(BurningShip::<2>::default()
+            .derivative_mismatches()
+            .is_empty());
+    }
+
+    #[test]
+    fn unicritical_dyn_matches_const_degree()
+    {
+        let fixed = Unicritical::<3>::default().with_res_y(64);
+        let runtime = UnicriticalDyn::default()
+            .with_degree(3.)
+            .with_point_grid(fixed.point_grid().clone());
+        let c = Cplx::new(-1.1, 0.4);
+        for z in [ZERO, Cplx::new(0.3, -1.2), Cplx::new(-2.5, 0.7)] {
+            let (w, dw) = runtime.map_and_multiplier(z, &c);
+            let (w_fixed, dw_fixed) = fixed.map_and_multiplier(z, &c);
+            assert!((runtime.map(z, &c) - fixed.map(z, &c)).norm() < 1e-12);
+            assert!((w - w_fixed).norm() < 1e-12);
+            assert!((dw - dw_fixed).norm() < 1e-12);
+        }
+        let runtime_field = runtime.compute().iter_counts;
+        let fixed_field = fixed.compute().iter_counts;
+        for (a, b) in runtime_field.iter().zip(fixed_field.iter()) {
+            assert_eq!(std::mem::discriminant(a), std::mem::discriminant(b));
+            if let (Some(x), Some(y)) = (a.escape_level(), b.escape_level()) {
+                assert!((x - y).abs() < 1e-9, "{x} != {y}");
+            }
+        }
+        assert_eq!(runtime.integer_degree(), Some(3));
+
+        let fractional = UnicriticalDyn::default().with_degree(2.5);
+        assert_eq!(fractional.integer_degree(), None);
+        assert!(fractional.derivative_mismatches().is_empty());
+    }
+
+    #[test]
+    fn unicritical_dyn_rays_need_integer_degree()
+    {
+        let angle = RationalAngle::new(1, 4);
+        let cubic = UnicriticalDyn::default().with_degree(3.);
+        assert!(cubic.external_ray(angle).is_some());
+        let fractional = UnicriticalDyn::default().with_degree(2.5);
+        assert!(fractional.external_ray(angle).is_none());
+    }
+
+    #[test]
+    fn interior_alpha_mask()
+    {
+        use dynamo_color::prelude::{AlphaMaskMode, FractalImage};
+
+        let mandel = Mandelbrot::default()
+            .with_bounds(Bounds::centered_square(2.))
+            .with_res_y(64);
+        let iter_plane = mandel.compute();
+        let grid = &iter_plane.point_grid;
+        assert_eq!(grid.map_pixel(32, 32), ZERO);
+
+        let mask = iter_plane.render_alpha_mask(AlphaMaskMode::Interior);
+        // Image rows run from the top, grid rows from the bottom
+        assert_eq!(mask.get_pixel(32, 31).0, [255]);
+        assert_eq!(mask.get_pixel(0, 63).0, [0]);
+        for (x, y, pixel) in mask.enumerate_pixels() {
+            let point_info = &iter_plane.iter_counts[(x as usize, 63 - y as usize)];
+            let interior = !point_info.is_escaping() && !matches!(point_info, PointInfo::Unknown);
+            let expected = if interior { 255 } else { 0 };
+            assert_eq!(pixel.0, [expected], "{point_info:?}");
+        }
+
+        let potential = iter_plane.render_alpha_mask(AlphaMaskMode::Potential);
+        assert_eq!(potential.get_pixel(32, 31).0, [255]);
+        let exterior: Vec<u8> = potential
+            .enumerate_pixels()
+            .filter(|(x, y, _)| {
+                iter_plane.iter_counts[(*x as usize, 63 - *y as usize)].is_escaping()
+            })
+            .map(|(_, _, pixel)| pixel.0[0])
+            .collect();
+        assert_eq!(exterior.iter().min(), Some(&0));
+        assert_eq!(exterior.iter().max(), Some(&255));
+    }
+
+    #[test]
+    fn brent_detects_long_slow_cycle()
+    {
+        const PERIOD: Period = 24;
+        const CONTRACTION: Real = 0.99;
+
+        // Rotation by $1/24$ of a turn, with $\log|z|$ shrinking slowly towards zero, so every
+        // orbit converges to a cycle of period 24 on the unit circle
+        let slow_rotation = FixtureFamily::new(
+            |z, _c| {
+                let rotation = Cplx::from_polar(1., TAU / Real::from(PERIOD));
+                rotation * z * z.norm().powf(CONTRACTION - 1.)
+            },
+            Cplx::new(0.5_f64.exp(), 0.),
+        );
+
+        let outcome = |detection| {
+            let mut family = slow_rotation.clone().with_max_iter(1000);
+            family.set_cycle_detection(detection);
+            orbit::CycleDetected::new(&family)
+                .init(ZERO)
+                .find_map(|(_, state)| state)
+        };
+
+        // Floyd's method only compares iterates $n$ and $2n$ with $n$ a multiple of 24, by which
+        // time the iteration budget has run out
+        assert!(matches!(
+            outcome(CycleDetection::Floyd),
+            Some(EscapeResult::Bounded(_))
+        ));
+        match outcome(CycleDetection::Brent) {
+            Some(EscapeResult::Periodic { info, .. }) => assert_eq!(info.period, PERIOD),
+            other => panic!("No cycle detected: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn phoenix_p_zero_is_mandelbrot()
+    {
+        let mandel = Mandelbrot::default()
+            .with_bounds(Bounds::centered_square(2.))
+            .with_res_y(48);
+        let phoenix = PhoenixMandelbrot::default()
+            .with_fixed(ZERO)
+            .with_point_grid(mandel.point_grid().clone());
+
+        let c = Cplx::new(-0.7, 0.3);
+        let z = CplxPair {
+            a: Cplx::new(0.4, -0.2),
+            b: Cplx::new(1.5, 0.9),
+        };
+        let param = phoenix.param_map(c);
+        assert_eq!(phoenix.map(z, &param).a, mandel.map(z.a, &c));
+        let (_, jacobian) = phoenix.map_and_multiplier(z, &param);
+        let multiplier = mandel.map_and_multiplier(z.a, &c).1;
+        assert!((Cplx::from(jacobian) - multiplier).norm() < 1e-12);
+
+        // The Mandelbrot plane skips its largest components, so compare the escape set
+        let mandel_plane = mandel.compute();
+        let phoenix_plane = phoenix.compute();
+        for (m, p) in mandel_plane
+            .iter_counts
+            .iter()
+            .zip(phoenix_plane.iter_counts.iter())
+        {
+            assert_eq!(m.is_escaping(), p.is_escaping());
+            if let (
+                PointInfo::Escaping { potential: pm, .. },
+                PointInfo::Escaping { potential: pp, .. },
+            ) = (m, p)
+            {
+                assert!((pm - pp).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_rays_match_sequential()
+    {
+        let mandelbrot = Mandelbrot::default().with_res_y(128);
+        let angles: Vec<_> = (1..=10).map(|k| RationalAngle::new(k, 11)).collect();
+
+        let sequential: Vec<_> = angles
+            .iter()
+            .map(|angle| mandelbrot.external_ray(*angle))
+            .collect();
+        let parallel = mandelbrot.external_rays(&angles);
+        assert_eq!(parallel.len(), angles.len());
+        assert!(parallel.iter().all(Option::is_some));
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn ifs_render_traces_boundary()
+    {
+        let julia = JuliaSet::from(Mandelbrot::default())
+            .with_param(ZERO)
+            .with_res_y(96);
+        let hits = julia.ifs_render(20_000);
+        let escape = julia.compute().iter_counts;
+        let (res_x, res_y) = julia.point_grid().shape();
+
+        // Pixels, indexed from the top as in the IFS render, where the escape-time image changes
+        // between escaping and bounded
+        let escaping = |x: usize, row: usize| escape[(x, res_y - 1 - row)].is_escaping();
+        let is_boundary = |x: usize, row: usize| {
+            (x + 1 < res_x && escaping(x, row) != escaping(x + 1, row))
+                || (row + 1 < res_y && escaping(x, row) != escaping(x, row + 1))
+        };
+
+        let mut visited = 0;
+        for ((x, row), &count) in hits.indexed_iter() {
+            if count == 0 {
+                continue;
+            }
+            visited += 1;
+            let near_boundary = (x.saturating_sub(2)..=(x + 2).min(res_x - 1)).any(|i| {
+                (row.saturating_sub(2)..=(row + 2).min(res_y - 1)).any(|j| is_boundary(i, j))
+            });
+            assert!(near_boundary, "pixel ({x}, {row}) is far from the boundary");
+        }
+        assert!(visited > 100);
+    }
+
+    #[test]
+    fn tangent_small_lambda_bounded()
+    {
+        let tangent = Tangent::default();
+
+        // For $|\lambda| < 1$ the origin is an attracting fixed point, which captures the
+        // asymptotic value
+        let info = tangent.probe(Cplx::new(0.5, 0.2));
+        assert!(matches!(info, PointInfo::Periodic(data) if data.period == 1));
+        let lambda = Cplx::new(0.5, 0.2);
+        let mut z = Cplx::new(0.3, -0.1);
+        for _ in 0..200 {
+            z = tangent.map(z, &lambda);
+        }
+        assert!(z.norm() < 1e-12);
+
+        // The pole guard makes orbits through a pole escape, rather than become `NaN`
+        let (w, dw) = tangent.map_and_multiplier(Cplx::new(0.5 * PI, 0.), &ONE);
+        assert!(!w.is_nan() && !dw.is_nan());
+        let far = tangent.map(Cplx::new(0.3, 400.), &ONE);
+        assert!((far - Cplx::new(0., 1.)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn critical_orbit_multiplier_main_cardioid()
+    {
+        let c = Cplx::new(-0.2, 0.1);
+        let julia = JuliaSet::from(Mandelbrot::default()).with_param(c);
+        let multiplier = julia
+            .critical_orbit_multiplier()
+            .expect("the critical orbit converges to the attracting fixed point");
+
+        // The attracting fixed point is $\alpha = (1 - \sqrt{1 - 4c}) / 2$, with multiplier $2\alpha$
+        let fixed_multiplier = ONE - (ONE - 4. * c).sqrt();
+        assert!(multiplier.norm() < 1.);
+        assert!((multiplier - fixed_multiplier).norm() < 1e-6);
+
+        let basilica = JuliaSet::from(Mandelbrot::default()).with_param(Cplx::new(-1., 0.));
+        assert!(basilica.critical_orbit_multiplier().unwrap().norm() < 1e-6);
+        let escaping = JuliaSet::from(Mandelbrot::default()).with_param(ONE);
+        assert_eq!(escaping.critical_orbit_multiplier(), None);
+    }
+
+    #[test]
+    fn covering_map_derivatives()
+    {
+        fn check(name: &str, f: impl Fn(Cplx) -> (Cplx, Cplx))
+        {
+            let h = 1e-5;
+            for t in [
+                Cplx::new(0.31, 0.17),
+                Cplx::new(-0.22, 0.38),
+                Cplx::new(0.45, -0.29),
+            ] {
+                let (_, analytic) = f(t);
+                let numeric = (f(t + h).0 - f(t - h).0) / (2. * h);
+                let error = (analytic - numeric).norm() / analytic.norm().max(1.);
+                assert!(
+                    error < 1e-4,
+                    "{name} at {t}: analytic {analytic}, numeric {numeric}"
+                );
+            }
+        }
+
+        let preper_2_1 = QuadRatPreper21::default().marked_cycle_curve(4);
+        check("QuadRatPreper21", |t| preper_2_1.param_map_d(t));
+
+        let per_3 = QuadRatPer3::default().marked_cycle_curve(4);
+        check("QuadRatPer3", |t| {
+            let (c, dc) = per_3.param_map_d(t);
+            (c.into(), dc)
+        });
+
+        let per_4 = QuadRatPer4::default().marked_cycle_curve(3);
+        check("QuadRatPer4", |t| per_4.param_map_d(t));
+
+        let cubic_marked = CubicMarked2Cycle::default();
+        let mis_1_1 = cubic_marked.clone().misiurewicz_curve(1, 1);
+        check("CubicMarked2Cycle (1, 1)", |t| mis_1_1.param_map_d(t));
+        let mis_1_2 = cubic_marked.misiurewicz_curve(1, 2);
+        check("CubicMarked2Cycle (1, 2)", |t| mis_1_2.param_map_d(t));
+
+        let odd_cubic = OddCubic::default().misiurewicz_curve(1, 2);
+        check("OddCubic", |t| odd_cubic.param_map_d(t));
+
+        let per_2_crit = CubicPer2CritMarked::default().marked_cycle_curve(1);
+        check("CubicPer2CritMarked", |t| per_2_crit.param_map_d(t));
+    }
+
+    #[test]
+    fn composition_doubles_mandelbrot()
+    {
+        let mandelbrot = Mandelbrot::default();
+        let square = Composition::new(Mandelbrot::default(), Mandelbrot::default());
+
+        let (z, c) = (Cplx::new(0.3, -0.2), Cplx::new(-0.7, 0.25));
+        let (w, dw) = square.map_and_multiplier(z, &c);
+        let (w1, d1) = mandelbrot.map_and_multiplier(z, &c);
+        let (w2, d2) = mandelbrot.map_and_multiplier(w1, &c);
+        assert!((w - w2).norm() < 1e-14);
+        assert!((dw - d2 * d1).norm() < 1e-14);
+        assert_eq!(square.degree(), 4);
+
+        // The critical point $0$ of the first map, and its two preimages $\pm\sqrt{-c}$ that the
+        // first map sends to the critical point of the second
+        let critical_points = square.critical_points_child(&c);
+        assert_eq!(critical_points.len(), 3);
+        for z in critical_points {
+            assert!(square.map_and_multiplier(z, &c).1.norm() < 1e-12, "{z}");
+        }
+
+        let cycle = |info: PointInfo<Cplx>| match info {
+            PointInfo::Periodic(data) => (data.period, data.multiplier),
+            PointInfo::PeriodicKnownPotential(data) => (data.period, data.multiplier),
+            other => panic!("expected a periodic point, got {other:?}"),
+        };
+
+        // Cycles of even period $2n$ split into two cycles of period $n$ with the same multiplier,
+        // while those of odd period keep their period and square their multiplier
+        for c in [
+            Cplx::new(-0.9, 0.),
+            Cplx::new(-0.12, 0.75),
+            Cplx::new(-1.31, 0.),
+        ] {
+            let (period, multiplier) = cycle(mandelbrot.probe(c));
+            let (period_sq, multiplier_sq) = cycle(square.probe(c));
+            if period % 2 == 0 {
+                assert_eq!(period_sq, period / 2);
+                assert!((multiplier_sq - multiplier).norm() < 1e-3);
+            } else {
+                assert_eq!(period_sq, period);
+                assert!((multiplier_sq - multiplier * multiplier).norm() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn critical_orbit_fate()
+    {
+        let mandelbrot = Mandelbrot::default();
+        let schema = |preperiod, period| OrbitFate::Cycles(OrbitSchema { preperiod, period });
+
+        // $0 \mapsto i \mapsto -1 + i \mapsto -i \mapsto -1 + i$
+        assert_eq!(mandelbrot.orbit_fate(Cplx::new(0., 1.)), schema(2, 2));
+
+        let orbit_schema = OrbitSchema {
+            preperiod: 3,
+            period: 1,
+        };
+        let c = mandelbrot
+            .find_misiurewicz(Cplx::new(-1.55, 0.), orbit_schema)
+            .unwrap();
+        assert_eq!(mandelbrot.orbit_fate(c), OrbitFate::Cycles(orbit_schema));
+
+        assert_eq!(mandelbrot.orbit_fate(Cplx::new(-1., 0.)), schema(0, 2));
+        assert!(matches!(
+            mandelbrot.orbit_fate(Cplx::new(0.5, 0.5)),
+            OrbitFate::Escapes(_)
+        ));
+    }
+
+    #[test]
+    fn gaussian_newton_basins()
+    {
+        type Plane = GaussianNewton<1>;
+        let roots = Plane::lattice_points();
+        assert_eq!(roots.len(), 9);
+
+        let plane = Plane::default();
+        for &w in plane.free_critical_points() {
+            let (_, df) = plane.map_and_multiplier(w, &ZERO);
+            assert!(df.norm() < 1e-8, "{w} is not critical");
+        }
+
+        // Marked points are colored by their class alone, so points sharing a class share the
+        // basin color
+        let julia = JuliaSet::from(plane).with_param(ZERO);
+        let basin = |z: Cplx| match julia.probe(z) {
+            PointInfo::MarkedPoint {
+                class_id,
+                num_point_classes,
+                ..
+            } => (class_id, num_point_classes),
+            info => panic!("expected a root's basin, got {info:?}"),
+        };
+
+        for (i, &r) in roots.iter().enumerate() {
+            assert_eq!(
+                basin(r + Cplx::new(1e-3, -2e-3)),
+                (PointClassId::from(i), roots.len())
+            );
+            assert_eq!(basin(r - Cplx::new(2e-3, 1e-3)).0, PointClassId::from(i));
+        }
+    }
+
+    #[test]
+    fn render_independent_of_thread_count()
+    {
+        let plane = Mandelbrot::default().with_res_y(96);
+        let render = |num_threads| {
+            set_render_threads(num_threads);
+            plane.compute().iter_counts
+        };
+        let default = render(None);
+        for num_threads in [1, 2, 5] {
+            assert_eq!(render(Some(num_threads)), default, "{num_threads} threads");
+        }
+        set_render_threads(None);
+    }
+
+    #[test]
+    fn param_map_pole_renders_undefined()
+    {
+        use dynamo_color::prelude::{Coloring, FractalImage};
+        let plane = QuadRatPer4::default()
+            .with_point_grid(PointGrid::new_by_res_y(16, Bounds::centered_square(1.)));
+        let res = plane.point_grid().res_y;
+        let (x, y) = (res / 2, res / 2);
+        assert_eq!(plane.point_grid().map_pixel(x, y), ZERO);
+        assert!(plane.param_map(ZERO).is_nan());
+        assert!(!plane.param_map(ZERO).is_finite());
+
+        let iter_plane = plane.compute();
+        let undefined: Vec<_> = iter_plane
+            .iter_counts
+            .indexed_iter()
+            .filter(|(_, info)| matches!(info, PointInfo::UndefinedParameter))
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(undefined, vec![(x, y)]);
+
+        let coloring = Coloring::default();
+        let image = iter_plane.render(&coloring);
+        let row = res - y - 1;
+        assert_eq!(
+            image.pixels[x + row * res],
+            coloring.get_palette().undefined_color
+        );
+    }
+
+    #[test]
+    fn streaming_orbit_centroid()
+    {
+        use dynamo_common::math_utils::centroid;
+
+        let plane = Mandelbrot::default();
+        let rabbit = Cplx::new(-0.12, 0.75);
+        let collected = plane.get_orbit_vec(rabbit);
+        assert!(collected.len() > 60);
+
+        let mean = collected.iter().sum::<Cplx>() / collected.len() as Real;
+        let streamed = centroid(plane.iter_orbit(rabbit)).unwrap();
+        assert!((streamed - mean).norm() < 1e-12, "{streamed} != {mean}");
+
+        let window: Vec<_> = plane.iter_orbit_window(rabbit, 10, 50).collect();
+        assert_eq!(window, collected[10..60]);
+        assert_eq!(
+            centroid(plane.iter_orbit_window(rabbit, 10, 50)),
+            centroid(collected[10..60].iter().copied())
+        );
+        assert_eq!(centroid(plane.iter_orbit_window(rabbit, 0, 0)), None);
+
+        // Truncating the marked orbit keeps its prefix and the fate of the whole orbit
+        let full = plane.get_orbit_and_info(rabbit);
+        let truncated = plane.get_truncated_orbit_and_info(rabbit, 20);
+        assert_eq!(truncated.orbit, full.orbit[..20]);
+        assert_eq!(truncated.info.result, full.info.result);
+    }
+
+    #[test]
+    fn view_transform_inversion()
+    {
+        let grid = PointGrid::new(16, 16, Bounds::centered_square(4.));
+        let plane = Mandelbrot::default().with_point_grid(grid.clone());
+        let plain = plane.compute();
+
+        let identity = Mandelbrot::default()
+            .with_point_grid(grid.clone().with_view_transform(Some(Mobius::IDENTITY)));
+        // Superattracting centers have NaN potentials, which never compare equal, so compare the
+        // printed fields instead
+        assert_eq!(
+            format!("{:?}", identity.compute().iter_counts),
+            format!("{:?}", plain.iter_counts)
+        );
+
+        let inverted = Mandelbrot::default()
+            .with_point_grid(grid.with_view_transform(Some(Mobius::INVERSION)))
+            .compute();
+        // The center is the pole of $1/z$
+        assert_eq!(inverted.iter_counts[(8, 8)], PointInfo::Unknown);
+        // $0.5i$ is in the main cardioid, and $-2i$ escapes
+        assert!(!plain.iter_counts[(8, 9)].is_escaping());
+        assert!(inverted.iter_counts[(8, 9)].is_escaping());
+        // Far from the origin, the corner now shows the neighborhood of $0$
+        assert!(plain.iter_counts[(0, 0)].is_escaping());
+        assert!(!inverted.iter_counts[(0, 0)].is_escaping());
+    }
+
+    #[test]
+    fn mandelbrot_ascii_render()
+    {
+        let plane = Mandelbrot::default().with_point_grid(PointGrid::new(
+            64,
+            64,
+            Bounds::square(1.5, Cplx::new(-0.5, 0.)),
+        ));
+        let text = plane.render_ascii(31, 15);
+        let rows: Vec<&str> = text.lines().collect();
+        assert_eq!(rows.len(), 15);
+        assert!(rows.iter().all(|row| row.chars().count() == 31));
+
+        // The main cardioid fills the middle of the view, and the corners escape
+        assert_eq!(&rows[7][14..17], "@@@");
+        assert!(!rows[0].starts_with('@'));
+    }
+
+    #[test]
+    fn mariani_silver_matches_per_pixel()
+    {
+        use dynamo_color::prelude::{Coloring, FractalImage};
+        use dynamo_core::orbit::{CycleDetected, Orbit};
+
+        let coloring = Coloring::default();
+        let plane = Mandelbrot::default().with_res_y(64);
+        let per_pixel = plane.compute().render(&coloring);
+        let subdivided = plane.compute_mariani_silver(16);
+        assert!(subdivided.iter_plane.render(&coloring).pixels == per_pixel.pixels);
+
+        // Around the period 3 bulb, whose interior is slow to settle near its boundary, so that
+        // with a modest budget some rectangles never settle and are flooded
+        let bulb = Mandelbrot::default()
+            .with_bounds(Bounds {
+                min_x: -0.35,
+                max_x: 0.1,
+                min_y: 0.5,
+                max_y: 0.95,
+            })
+            .with_res_y(96)
+            .with_max_iter(256);
+        let mut per_pixel_iterations = 0;
+        for y in 0..bulb.point_grid().res_y {
+            for x in 0..bulb.point_grid().res_x {
+                let mut orbit = CycleDetected::new(&bulb).init(bulb.point_grid().map_pixel(x, y));
+                orbit.run_until_complete();
+                per_pixel_iterations += orbit.iterations();
+            }
+        }
+        let subdivided = bulb.compute_mariani_silver(16);
+        assert!(
+            subdivided.iter_plane.render(&coloring).pixels
+                == bulb.compute().render(&coloring).pixels
+        );
+        assert!(subdivided.flooded > 0);
+        assert!(subdivided.iterations < per_pixel_iterations);
+    }
+
+    #[test]
+    fn log_derivative_spreads_exponential_exterior()
+    {
+        // Range of escape levels over the exterior, in units of the palette scale
+        let level_span = |compute_mode| {
+            let mut julia =
+                JuliaSet::new(Exponential::default(), Cplx::new(0.3, 0.), 256).with_res_y(64);
+            julia.set_compute_mode(compute_mode);
+            let levels: Vec<Real> = julia
+                .compute()
+                .iter_counts
+                .iter()
+                .filter_map(PointInfo::escape_level)
+                .filter(|level| level.is_finite())
+                .collect();
+            assert!(!levels.is_empty());
+            let min = levels.iter().copied().fold(Real::INFINITY, Real::min);
+            let max = levels.iter().copied().fold(Real::NEG_INFINITY, Real::max);
+            max - min
+        };
+        let potential_span = level_span(ComputeMode::SmoothPotential);
+        let log_derivative_span = level_span(ComputeMode::LogDerivative);
+        assert!(log_derivative_span > 1.5 * potential_span);
+    }
+
+    #[test]
+    fn log_derivative_accumulated_during_iteration()
+    {
+        use dynamo_core::orbit::{CycleDetected, LogDerivative, Orbit};
+
+        let family = JuliaSet::new(Exponential::default(), Cplx::new(0.3, 0.), 256);
+        let point = Cplx::new(4., 0.);
+
+        let mut plain = CycleDetected::new(&family);
+        plain.reset(point);
+        let info = plain.run_until_complete();
+
+        let mut orbit = LogDerivative::new(&family);
+        orbit.reset(point);
+        let PointInfo::LogDerivative {
+            potential,
+            log_derivative,
+            ..
+        } = orbit.run_until_complete()
+        else {
+            panic!("{point} should escape");
+        };
+        assert_eq!(info.escape_level(), Some(potential.ln()));
+        assert_eq!(orbit.iterations(), plain.iterations());
+
+        // Same sum as iterating the orbit again from its start
+        let c = family.param_map(point);
+        let mut z = family.start_point(point, &c);
+        let mut expected = 0.;
+        for _ in 0..plain.iterations() {
+            let (fz, df_dz) = family.map_and_multiplier(z, &c);
+            expected += df_dz.norm().ln();
+            z = fz;
+        }
+        assert!((log_derivative - expected).abs() < 1e-9 * expected.abs().max(1.));
+    }
+
+    #[test]
+    fn satellite_internal_angles()
+    {
+        let plane = Mandelbrot::default();
+        let label = |c: Cplx, period| {
+            plane
+                .satellite_internal_angle(&c, period)
+                .map(|angle| angle.to_string())
+        };
+        assert_eq!(label(Cplx::new(-1., 0.), 2).as_deref(), Some("1/2"));
+        assert_eq!(label(Cplx::new(-0.1226, 0.7449), 3).as_deref(), Some("1/3"));
+        assert_eq!(label(Cplx::new(0.2822, 0.5301), 4).as_deref(), Some("1/4"));
+        assert_eq!(label(Cplx::new(-0.5043, 0.5627), 5).as_deref(), Some("2/5"));
+
+        // The airplane has period 3, and its $\alpha$ multiplier points towards $2/3$, but it is
+        // primitive
+        assert_eq!(label(Cplx::new(-1.7549, 0.), 3), None);
+        // A satellite of the basilica bulb
+        assert_eq!(label(Cplx::new(-1.31, 0.), 4), None);
+    }
+
+    #[test]
+    fn boettcher_angles_follow_rays()
+    {
+        let turn_distance = |a: Real, b: Real| {
+            let d = (a - b).rem_euclid(1.);
+            d.min(1. - d)
+        };
+        let angle = |info: PointInfo<Cplx>| match info {
+            PointInfo::Boettcher { angle, .. } => angle,
+            other => panic!("expected a Böttcher coordinate, got {other:?}"),
+        };
+
+        let mut mandelbrot = Mandelbrot::default();
+        mandelbrot.set_compute_mode(ComputeMode::Boettcher);
+        assert!(turn_distance(angle(mandelbrot.probe(Cplx::new(2., 0.))), 0.) < 1e-6);
+        assert!(turn_distance(angle(mandelbrot.probe(Cplx::new(-3., 0.))), 0.5) < 1e-6);
+
+        // Points along a computed ray have the angle of the ray, not $2^n$ times it
+        for (numer, denom) in [(1, 3), (2, 3), (1, 7), (3, 7), (5, 12)] {
+            let theta = numer as Real / denom as Real;
+            let samples = mandelbrot
+                .external_ray_samples(RationalAngle::new(numer, denom), 8)
+                .expect("ray should be computed");
+            for point in &samples[..4] {
+                let found = angle(mandelbrot.probe(*point));
+                assert!(
+                    turn_distance(found, theta) < 1e-3,
+                    "{numer}/{denom} at {point}: {found}"
+                );
+            }
+        }
+
+        // The Böttcher coordinate of $z^2$ is the identity
+        let mut julia = JuliaSet::from(Mandelbrot::default()).with_param(ZERO);
+        julia.set_compute_mode(ComputeMode::Boettcher);
+        for theta in [0.1, 0.35, 0.8] {
+            let found = angle(julia.probe(Cplx::from_polar(1.5, TAU * theta)));
+            assert!(turn_distance(found, theta) < 1e-9, "{theta}: {found}");
+        }
+    }
+
+    #[test]
+    fn parabolic_basin_colors_smoothly()
+    {
+        use dynamo_color::prelude::{Coloring, FractalImage, IncoloringAlgorithm};
+
+        // The Julia set inherits its periodicity tolerance from the parent's view, which is
+        // widened so that orbits converging to the parabolic fixed point of $z^2 + 1/4$ are
+        // detected within the iteration budget
+        let parent = Mandelbrot::default().with_bounds(Bounds::centered_square(500.));
+        let julia = JuliaSet::new(parent, Cplx::new(0.25, 0.), 1 << 14).with_res_y(64);
+        let tol = julia.periodicity_tolerance();
+        let iter_plane = julia.compute();
+        let counts = &iter_plane.iter_counts;
+
+        let multipliers: Vec<Cplx> = counts
+            .iter()
+            .filter_map(|info| match info {
+                PointInfo::Periodic(data) => Some(data.multiplier),
+                _ => None,
+            })
+            .collect();
+        assert!(multipliers.len() > 500);
+        for multiplier in multipliers {
+            assert!(
+                IncoloringAlgorithm::parabolic_weight(multiplier, tol) >= 1.,
+                "multiplier {multiplier}"
+            );
+        }
+
+        // No interior pixel stands out from all of its neighbors
+        let (width, height) = (julia.point_grid().res_x, julia.point_grid().res_y);
+        let is_periodic = |x: usize, y: usize| matches!(counts[(x, y)], PointInfo::Periodic(_));
+        for algorithm in [
+            julia.internal_potential_coloring(),
+            julia.potential_and_period_coloring(),
+        ] {
+            let coloring = Coloring::default().with_interior_algorithm(algorithm);
+            let image = iter_plane.render(&coloring);
+            let pixel = |x: usize, y: usize| image.pixels[x + (height - y - 1) * width];
+            for y in 1..height - 1 {
+                for x in 1..width - 1 {
+                    let neighbors = [(x, y + 1), (x + 1, y), (x, y - 1), (x - 1, y)];
+                    if !is_periodic(x, y) || !neighbors.iter().all(|&(u, v)| is_periodic(u, v)) {
+                        continue;
+                    }
+                    let color = pixel(x, y).to_array();
+                    let smallest_jump = neighbors
+                        .iter()
+                        .map(|&(u, v)| {
+                            let other = pixel(u, v).to_array();
+                            (0..3)
+                                .map(|i| color[i].abs_diff(other[i]))
+                                .max()
+                                .unwrap_or(0)
+                        })
+                        .min()
+                        .unwrap_or(0);
+                    assert!(smallest_jump <= 2, "speckle at ({x}, {y})");
+                }
+            }
+        }
+    }
 }
@@ -168,8 +168,12 @@ macro_rules! degree_impl_transcendental {
                         phase: None,
                     };
                 }
-                let u = slog(self.escape_radius());
-                let v = slog(z.norm_sqr());
+                let u = self
+                    .escape_annulus()
+                    .map_or_else(|| slog(self.escape_radius()), |a| a.slog_outer());
+                // $\operatorname{slog}|z|^2 = 1 + \operatorname{slog}(2\ln|z|)$, which stays
+                // finite when $|z|^2$ would overflow
+                let v = 1. + slog(2. * z.norm().ln());
                 let residual = v - u;
                 let potential = (iters as f64) - (residual as IterCountSmooth);
                 PointInfo::Escaping {
@@ -208,11 +212,13 @@ macro_rules! cplx_arr {
 #[allow(unused_macros)]
 macro_rules! ext_ray_impl_nonmonic_conj {
     () => {
-        fn external_ray_helper(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+        fn external_ray_helper(
+            &self,
+            angle: RationalAngle,
+            params: RayNewtonParams,
+        ) -> Option<Vec<Cplx>>
         {
-            use dynamo_common::math_utils::newton::{
-                error::Error::NanEncountered, find_target_newton_err_d,
-            };
+            use dynamo_common::math_utils::newton::find_target_newton_iters_d;
 
             const R: Real = 16.0;
             let escape_radius_log2 = R.log2() * self.degree_real().abs();
@@ -224,7 +230,7 @@ macro_rules! ext_ray_impl_nonmonic_conj {
             let deg_log2 = deg_real.log2();
 
             let pixel_width = self.point_grid().pixel_width() * 0.03;
-            let error = self.point_grid().res_x as Real * 1e-8;
+            let error = params.error(self.point_grid());
 
             // let base_point = escape_radius * angle.to_circle();
             // Arbitrary starting guess that is likely to escape
@@ -295,7 +301,13 @@ macro_rules! ext_ray_impl_nonmonic_conj {
                 };
 
                 for target in targets {
-                    match find_target_newton_err_d(fk_and_dfk, t_curr, target, error) {
+                    match find_target_newton_iters_d(
+                        fk_and_dfk,
+                        t_curr,
+                        target,
+                        error,
+                        params.max_iters,
+                    ) {
                         Ok((sol, t_k, d_k)) => {
                             t_curr = sol;
 
@@ -310,10 +322,9 @@ macro_rules! ext_ray_impl_nonmonic_conj {
                                 return Some(t_list);
                             }
                         }
-                        Err(NanEncountered) => {
+                        Err(_) => {
                             return Some(t_list);
                         }
-                        _ => {}
                     }
                 }
                 target_angle *= deg;
@@ -333,7 +344,11 @@ macro_rules! ext_ray_impl_nonmonic_conj {
 #[allow(unused_macros)]
 macro_rules! ext_ray_impl_rk {
     ($step: literal, $esc: expr) => {
-        fn external_ray_helper(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+        fn external_ray_helper(
+            &self,
+            angle: RationalAngle,
+            _params: RayNewtonParams,
+        ) -> Option<Vec<Cplx>>
         {
             use dynamo_common::math_utils::contour::{Contour, IntegralCurveParams};
             const R: Real = $esc;
@@ -366,20 +381,22 @@ macro_rules! ext_ray_impl_rk {
 
 macro_rules! ext_ray_impl_nonmonic {
     () => {
-        fn external_ray_helper(&self, angle: RationalAngle) -> Option<Vec<Cplx>>
+        fn external_ray_helper(
+            &self,
+            angle: RationalAngle,
+            params: RayNewtonParams,
+        ) -> Option<Vec<Cplx>>
         {
-            use dynamo_common::math_utils::newton::{
-                error::Error::NanEncountered, find_target_newton_err_d,
-            };
+            use dynamo_common::math_utils::newton::find_target_newton_iters_d;
             const R: Real = 256.0;
             let escape_radius_log = R.ln() * self.degree_real().abs();
 
             let deg_real = self.degree_real().abs();
-            if deg_real.is_nan() {
+            if deg_real.is_nan() || self.integer_degree().is_none() {
                 return None;
             }
             // let pixel_width = self.point_grid().pixel_width() * 0.08;
-            let error = self.point_grid().res_x as Real * 1e-8;
+            let error = params.error(self.point_grid());
 
             // let base_point = escape_radius * angle.to_circle();
             // Arbitrary starting guess that is likely to escape
@@ -428,7 +445,13 @@ macro_rules! ext_ray_impl_nonmonic {
                     u.re *= factor;
 
                     let target = u.exp();
-                    match find_target_newton_err_d(fk_and_dfk, t_curr, target, error) {
+                    match find_target_newton_iters_d(
+                        fk_and_dfk,
+                        t_curr,
+                        target,
+                        error,
+                        params.max_iters,
+                    ) {
                         Ok((sol, _t_k, _d_k)) => {
                             if _j == 0 {
                                 // println!("{}", (sol / t_curr).arg());
@@ -447,13 +470,10 @@ macro_rules! ext_ray_impl_nonmonic {
                             //     return Some(t_list);
                             // }
                         }
-                        Err(NanEncountered) => {
+                        Err(_) => {
                             // panic!("k = {k}, j = {}, t = {}", _j, t_curr);
                             return Some(t_list);
                         }
-                        _ => {
-                            // dbg!(_j, k, target, t_curr);
-                        }
                     }
                 }
             }
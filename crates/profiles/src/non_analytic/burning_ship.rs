@@ -8,6 +8,8 @@ pub struct BurningShip<const N: Period>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -24,6 +26,51 @@ impl<const N: Period> BurningShip<N>
         },
         _ => Bounds::centered_square(1.5),
     };
+
+    /// Fixed points of the quadratic map $z \mapsto (|x| + i|y|)^2 + c$.
+    ///
+    /// The fold acts as $\pm z$ on the quadrants where $xy \ge 0$, so fixed points there solve
+    /// $z^2 + c = z$. Elsewhere it acts as $\pm\bar z$, and $\bar z^2 + c = z$ reduces to the
+    /// real quartic $(x^2 - x + a)(1 + 2x)^2 = b^2$ with $y = b / (1 + 2x)$, where $c = a + ib$.
+    fn fixed_points(c: Cplx) -> Vec<Cplx>
+    {
+        use dynamo_common::math_utils::polynomial_roots::solve_polynomial;
+
+        let disc = (1. - 4. * c).sqrt();
+        let holomorphic = [0.5 * (1. - disc), 0.5 * (1. + disc)]
+            .into_iter()
+            .filter(|z| z.re * z.im >= 0.);
+
+        let (a, b) = (c.re, c.im);
+        let quartic = vec![
+            Cplx::from(0.25 * b.mul_add(-b, a)),
+            Cplx::from(a - 0.25),
+            Cplx::from(a - 0.75),
+            ZERO,
+            ONE,
+        ];
+        // The solver starts from random seeds, so polish the real roots before using them
+        let polish = |mut x: Real| {
+            for _ in 0..4 {
+                let p = x.powi(4) + (a - 0.75) * x * x + (a - 0.25) * x + 0.25 * b.mul_add(-b, a);
+                let dp = 4. * x.powi(3) + 2. * (a - 0.75) * x + a - 0.25;
+                if dp == 0. {
+                    break;
+                }
+                x -= p / dp;
+            }
+            x
+        };
+        let antiholomorphic = solve_polynomial(quartic)
+            .into_iter()
+            .filter(|x| x.im.abs() < 1e-6)
+            .map(|x| polish(x.re))
+            .filter(|x| 2f64.mul_add(*x, 1.).abs() > 1e-12)
+            .map(|x| Cplx::new(x, b / 2f64.mul_add(x, 1.)))
+            .filter(|z| z.re * z.im < 0.);
+
+        holomorphic.chain(antiholomorphic).collect()
+    }
 }
 impl<const N: Period> Default for BurningShip<N>
 {
@@ -40,6 +87,12 @@ impl<const N: Period> DynamicalFamily for BurningShip<N>
     basic_plane_impl!();
     default_name!();
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
     {
@@ -110,10 +163,7 @@ impl<const N: Period> MarkedPoints for BurningShip<N>
     fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
     {
         match period {
-            1 => {
-                let disc = (1. - 4. * c).sqrt();
-                vec![0.5 * (1. - disc), 0.5 * (1. + disc)]
-            }
+            1 if N == 2 => Self::fixed_points(*c),
             _ => vec![],
         }
     }
@@ -125,6 +175,8 @@ pub struct Sailboat<const N: Period>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     shift: Cplx,
 }
@@ -153,6 +205,12 @@ impl<const N: Period> DynamicalFamily for Sailboat<N>
     type Deriv = Cplx;
     basic_plane_impl!();
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     #[inline]
     fn map(&self, z: Cplx, c: &Cplx) -> Cplx
     {
@@ -44,6 +44,7 @@ pub struct Rulkov
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 impl Rulkov
@@ -78,6 +79,12 @@ impl DynamicalFamily for Rulkov
         }
     }
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
@@ -7,6 +7,8 @@ pub struct Tricorne<const N: Period>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -35,6 +37,12 @@ impl<const N: Period> DynamicalFamily for Tricorne<N>
     parameter_plane_impl!();
     default_name!();
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
@@ -63,7 +71,17 @@ impl<const N: Period> FamilyDefaults for Tricorne<N>
     default_bounds!();
 }
 
-impl<const N: Period> HasJulia for Tricorne<N> {}
+impl<const N: Period> HasJulia for Tricorne<N>
+{
+    #[inline]
+    fn inverse_branches(&self, w: Cplx, c: &Cplx) -> Vec<Cplx>
+    {
+        let z = (w - c).conj().powf(1. / Self::N_FLOAT);
+        (0..N)
+            .map(|k| z * Cplx::from_polar(1., TAU * Real::from(k) / Self::N_FLOAT))
+            .collect()
+    }
+}
 
 impl<const N: Period> MarkedPoints for Tricorne<N>
 {
@@ -79,3 +97,64 @@ impl<const N: Period> InfinityFirstReturnMap for Tricorne<N>
 }
 impl<const N: Period> EscapeEncoding for Tricorne<N> {}
 impl<const N: Period> ExternalRays for Tricorne<N> {}
+
+/// Covers for the quadratic tricorn.
+///
+/// Since $f_c(z) = \bar z^2 + c$ is antiholomorphic, these covers are only real-analytic, and
+/// the derivatives reported are the holomorphic parts $\partial/\partial t$. Multipliers are
+/// those of the holomorphic return map: for a cycle of odd period $n$ this is $f_c^{2n}$, so
+/// a marked fixed point $z$ has multiplier $|2z|^2$ rather than $2\bar z$.
+impl HasDynamicalCovers for Tricorne<2>
+{
+    fn marked_cycle_curve(self, period: Period) -> CoveringMap<Self>
+    {
+        match period {
+            1 => {
+                // Fixed point $z = t + 1/2$, so that $c = z - \bar z^2$.
+                let param_map = |t: Cplx| {
+                    let s = t.conj();
+                    (0.25 + t - s - s * s, ONE)
+                };
+                let mult = |t: Cplx| {
+                    let z = t + 0.5;
+                    (Cplx::from(4. * z.norm_sqr()), 4. * z.conj())
+                };
+                let bounds = Bounds::centered_square(2.2);
+                CoveringMap::new(self, param_map)
+                    .with_orig_bounds(bounds)
+                    .with_multiplier_map(mult)
+            }
+            2 => {
+                // For a 2-cycle $z_1 \leftrightarrow z_2$ with $d = z_2 - z_1$, the sum
+                // $\sigma = z_1 + z_2$ is forced to be $-\bar d / d$. The parabolic fixed points
+                // these cycles bifurcate from lie over $d = 0$. Note that
+                // $\partial \bar z_1 / \partial d = \partial \bar z_2 / \partial d = -1/(2\bar d)$.
+                let param_map = |d: Cplx| {
+                    let (z1, z2) = tricorn_2_cycle(d);
+                    let dz2 = 0.5 * (d.conj() / (d * d) + 1.);
+                    let z1_conj = z1.conj();
+                    (z2 - z1_conj * z1_conj, dz2 + z1_conj / d.conj())
+                };
+                let mult = |d: Cplx| {
+                    let (z1, z2) = tricorn_2_cycle(d);
+                    let dz1 = 0.5 * (d.conj() / (d * d) - 1.);
+                    let dz2_conj = -0.5 * d.conj().inv();
+                    (4. * z1 * z2.conj(), 4. * (dz1 * z2.conj() + z1 * dz2_conj))
+                };
+                let bounds = Bounds::centered_square(2.4);
+                CoveringMap::new(self, param_map)
+                    .with_orig_bounds(bounds)
+                    .with_multiplier_map(mult)
+            }
+            _ => CoveringMap::from(self),
+        }
+    }
+}
+
+/// The marked 2-cycle $(z_1, z_2)$ of the quadratic tricorn with $z_2 - z_1 = d$.
+#[inline]
+fn tricorn_2_cycle(d: Cplx) -> (Cplx, Cplx)
+{
+    let sigma = -d.conj() / d;
+    (0.5 * (sigma - d), 0.5 * (sigma + d))
+}
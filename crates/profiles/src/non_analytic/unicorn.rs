@@ -8,6 +8,8 @@ pub struct Unicorn<const D: i32>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -29,6 +31,12 @@ impl<const D: i32> DynamicalFamily for Unicorn<D>
 {
     parameter_plane_impl!();
 
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
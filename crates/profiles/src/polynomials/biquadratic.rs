@@ -8,6 +8,8 @@ pub struct Biquadratic
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
 }
@@ -115,6 +117,8 @@ pub struct BiquadraticMult
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
     starting_plane: PlaneID,
@@ -140,6 +144,7 @@ impl Default for BiquadraticMult
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             multiplier,
             starting_plane: PlaneID::ZPlane,
@@ -407,6 +412,8 @@ pub struct BiquadraticMultParam
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     starting_plane: PlaneID,
 }
@@ -429,6 +436,7 @@ impl Default for BiquadraticMultParam
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             starting_plane: PlaneID::ZPlane,
         }
@@ -558,6 +566,7 @@ impl From<BiquadraticMultParam> for BiquadraticMult
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
             multiplier: param.a * param.b,
             starting_plane: parent.starting_plane,
@@ -572,6 +581,8 @@ pub struct BiquadraticMultSecondIterate
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
 }
@@ -704,6 +715,8 @@ pub struct BiquadraticMultSection
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     starting_plane: PlaneID,
 }
@@ -727,6 +740,7 @@ impl Default for BiquadraticMultSection
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             starting_plane: PlaneID::ZPlane,
         }
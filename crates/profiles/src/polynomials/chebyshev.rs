@@ -69,9 +69,13 @@ pub struct Chebyshev<const D: Period>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     coeffs: Vec<Real>,
     coeffs_d: Vec<Real>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    critical_orbit: CriticalOrbit,
 }
 
 impl<const D: Period> Default for Chebyshev<D>
@@ -97,9 +101,11 @@ impl<const D: Period> Default for Chebyshev<D>
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             coeffs,
             coeffs_d,
+            critical_orbit: CriticalOrbit::default(),
         }
     }
 }
@@ -195,9 +201,34 @@ impl<const D: Period> DynamicalFamily for Chebyshev<D>
         (c * zval, c * dval * w, zval)
     }
 
-    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
     {
-        ZERO
+        match self.critical_orbit {
+            CriticalOrbit::Index(index) => self
+                .critical_points_child(c)
+                .get(index)
+                .copied()
+                .unwrap_or(ZERO),
+            CriticalOrbit::All => ZERO,
+        }
+    }
+
+    fn critical_orbit(&self) -> CriticalOrbit
+    {
+        self.critical_orbit
+    }
+
+    fn set_critical_orbit(&mut self, critical_orbit: CriticalOrbit)
+    {
+        self.critical_orbit = critical_orbit;
+    }
+
+    fn critical_orbit_view(&self, index: usize) -> Option<Self>
+    {
+        Some(Self {
+            critical_orbit: CriticalOrbit::Index(index),
+            ..self.clone()
+        })
     }
 
     fn name(&self) -> String
@@ -228,7 +259,10 @@ impl<const D: Period> MarkedPoints for Chebyshev<D>
             }
             4 => CHEBYSHEV_4_CRIT.map(std::convert::Into::into).to_vec(),
             5 => CHEBYSHEV_5_CRIT.map(std::convert::Into::into).to_vec(),
-            _ => vec![ZERO],
+            // Critical points of T_{2D}(z/2) are 2cos(k pi / 2D) for 0 < k < 2D
+            _ => (1..2 * D)
+                .map(|k| (2. * (Real::from(k) * PI / Real::from(2 * D)).cos()).into())
+                .collect(),
         }
     }
 }
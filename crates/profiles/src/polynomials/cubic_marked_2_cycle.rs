@@ -9,6 +9,8 @@ pub struct CubicMarked2Cycle
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -80,6 +82,12 @@ impl DynamicalFamily for CubicMarked2Cycle
 impl FamilyDefaults for CubicMarked2Cycle
 {
     default_bounds!();
+
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        4096
+    }
 }
 
 impl HasJulia for CubicMarked2Cycle
@@ -235,22 +243,36 @@ impl HasDynamicalCovers for CubicMarked2Cycle
             (1, 1) => {
                 param_map = |t| {
                     let (mut x, mut y) = weierstrass_p(MIS_1_1_G2, MIS_1_1_G3, t + 0.123, 0.01);
+                    // p'' = 6p^2 - g2/2
+                    let mut dx = y;
+                    let mut dy = 6. * x * x - 0.5 * MIS_1_1_G2;
 
                     x *= 4.;
+                    dx *= 4.;
                     y *= 4.;
+                    dy *= 4.;
 
                     x = FRAC_5_12 - x;
+                    dx = -dx;
                     y += (x - 1.) / 2.;
+                    dy += dx / 2.;
 
                     y /= x;
+                    dy = (dy - y * dx) / x;
 
                     let z = x - 1.;
-                    y = z / (y - 1.);
+                    let dz = dx;
+                    let y1 = y - 1.;
+                    y = z / y1;
+                    dy = (dz - y * dy) / y1;
                     x /= z;
+                    dx = (dx - x * dz) / z;
 
+                    dy = horner!(x, -1., 2., 3. * y) * dx + x * x * x * dy;
                     y = horner!(x, -1., -1., 1., y);
 
-                    (y / x, ONE)
+                    let w = y / x;
+                    (w, (dy - w * dx) / x)
                 };
                 bounds = Bounds {
                     min_x: -2.5,
@@ -263,8 +285,11 @@ impl HasDynamicalCovers for CubicMarked2Cycle
                 param_map = |t| {
                     let l = t + 0.5;
                     let numer = horner_monic!(l, OMEGA + 1., 1. - 3. * OMEGA, -3., OMEGA);
+                    let dnumer = horner!(l, 1. - 3. * OMEGA, -6., 3. * OMEGA, 4.);
                     let denom = l * (1. - l) * (OMEGA + l);
-                    (numer / denom, ONE) //TODO
+                    let ddenom = horner!(l, OMEGA, 2. * (1. - OMEGA), -3.);
+                    let w = numer / denom;
+                    (w, (dnumer - w * ddenom) / denom)
                 };
                 bounds = Bounds {
                     min_x: -3.0,
@@ -18,6 +18,7 @@ pub struct CubicPer1Lambda
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
     starting_crit: PlaneID,
@@ -37,6 +38,7 @@ impl Default for CubicPer1Lambda
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             multiplier: ZERO,
             starting_crit: PlaneID::ZPlane,
@@ -326,6 +328,7 @@ pub struct CubicPer1LambdaParam
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     starting_crit: PlaneID,
 }
@@ -354,6 +357,7 @@ impl Default for CubicPer1LambdaParam
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             starting_crit: PlaneID::ZPlane,
         }
@@ -482,6 +486,7 @@ impl From<CubicPer1LambdaParam> for CubicPer1Lambda
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
             multiplier: param,
             starting_crit: parent.starting_crit,
@@ -494,6 +499,7 @@ pub struct CubicPer1_1
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -682,6 +688,8 @@ pub struct CubicPer1_0
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -1298,6 +1306,8 @@ pub struct CubicPer1LambdaModuli
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
     starting_crit: PlaneID,
@@ -1316,6 +1326,7 @@ impl Default for CubicPer1LambdaModuli
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             multiplier: ZERO,
             starting_crit: PlaneID::ZPlane,
@@ -1504,6 +1515,7 @@ impl From<CubicPer1LambdaParam> for CubicPer1LambdaModuli
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
             multiplier: param,
             starting_crit: PlaneID::ZPlane,
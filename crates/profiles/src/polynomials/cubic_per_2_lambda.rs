@@ -8,6 +8,8 @@ pub struct CubicPer2Lambda
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     multiplier: Cplx,
     starting_crit: PlaneID,
@@ -26,6 +28,7 @@ impl Default for CubicPer2Lambda
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             multiplier: ZERO,
             starting_crit: PlaneID::ZPlane,
@@ -105,6 +108,12 @@ impl DynamicalFamily for CubicPer2Lambda
 impl FamilyDefaults for CubicPer2Lambda
 {
     default_bounds!();
+
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        4096
+    }
 }
 
 impl HasJulia for CubicPer2Lambda
@@ -189,6 +198,8 @@ pub struct CubicPer2LambdaParam
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     starting_crit: PlaneID,
 }
@@ -202,6 +213,7 @@ impl Default for CubicPer2LambdaParam
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
             starting_crit: PlaneID::ZPlane,
         }
@@ -328,6 +340,7 @@ impl From<CubicPer2LambdaParam> for CubicPer2Lambda
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
             multiplier: param,
             starting_crit: parent.starting_crit,
@@ -341,6 +354,7 @@ pub struct CubicPer2CritMarked
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -494,10 +508,18 @@ impl HasDynamicalCovers for CubicPer2CritMarked
                 param_map = |t| {
                     let g2 = 0.5.into();
                     let g3 = Cplx::new(-0.0625, 0.);
-                    let (mut x, y) = weierstrass_p(g2, g3, t, 0.01);
-
-                    x += x;
-                    (x * (x - 1.) / (y + y - x + 0.5), ONE)
+                    let (p, dp) = weierstrass_p(g2, g3, t, 0.01);
+                    // p'' = 6p^2 - g2/2
+                    let ddp = 6. * p * p - 0.5 * g2;
+
+                    let x = p + p;
+                    let dx = dp + dp;
+                    let numer = x * (x - 1.);
+                    let dnumer = (2. * x - 1.) * dx;
+                    let denom = dp + dp - x + 0.5;
+                    let ddenom = ddp + ddp - dx;
+                    let w = numer / denom;
+                    (w, (dnumer - w * ddenom) / denom)
                 };
                 bounds = Bounds {
                     min_x: -3.5,
@@ -9,6 +9,7 @@ pub struct CubicPer3_0
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -86,6 +87,12 @@ impl FamilyDefaults for CubicPer3_0
 {
     default_bounds!();
 
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        4096
+    }
+
     fn default_selection(&self) -> Cplx
     {
         // ComplexNum::new(-3.34447065821736, 0.) // center of a capture component; c1 -2> c0=0 -2> 1 -> a+b+1 -> 0
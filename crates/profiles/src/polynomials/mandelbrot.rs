@@ -10,6 +10,7 @@ pub struct Mandelbrot
     point_grid: PointGrid,
     max_iter: IterCount,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
 }
 
 impl Mandelbrot
@@ -41,6 +42,56 @@ impl DynamicalFamily for Mandelbrot
         ZERO
     }
 
+    fn tuning(&self, center: Cplx, period: Period) -> Option<Tuning>
+    {
+        if period < 2 {
+            return None;
+        }
+
+        let mut z = ZERO;
+        let mut l = ONE;
+        let mut b = ONE;
+        for _ in 1..period {
+            z = z * z + center;
+            l *= 2. * z;
+            b += l.inv();
+        }
+        let scale = (b * l * l).inv();
+
+        scale.is_finite().then_some(Tuning {
+            center,
+            period,
+            scale,
+        })
+    }
+
+    /// At the root of the $p/q$-bulb, the $\alpha$ fixed point has multiplier
+    /// $\lambda = 1 - \sqrt{1 - 4c} = e^{2\pi i p/q}$. Inside the bulb $\alpha$ is repelling,
+    /// but its multiplier stays close enough to that direction to recover $p$ once the period
+    /// $q$ of the attracting cycle is known. Components of the same period elsewhere in the set
+    /// are rejected by requiring `c` to lie within a bulb diameter of the root
+    /// $\lambda/2 - \lambda^2/4$, using the estimate $\sin(\pi p/q)/q^2$ for the radius of the
+    /// $p/q$-bulb.
+    fn satellite_internal_angle(&self, c: &Self::Param, period: Period) -> Option<RationalAngle>
+    {
+        if period < 2 {
+            return None;
+        }
+        let multiplier = 1. - (1. - 4. * c).sqrt();
+        let denom = AngleNum::from(period);
+        let numer = (multiplier.arg() / TAU * denom as Real).round() as AngleNum;
+        let angle = RationalAngle::new(numer.rem_euclid(denom), denom);
+        if *angle.denom() != denom {
+            return None;
+        }
+
+        let theta = Real::from(angle);
+        let root_multiplier = Cplx::from_polar(1., TAU * theta);
+        let root = 0.5 * root_multiplier - 0.25 * root_multiplier * root_multiplier;
+        let radius = (PI * theta).sin() / (denom * denom) as Real;
+        ((c - root).norm() < 2.2 * radius).then_some(angle)
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
@@ -116,6 +167,13 @@ impl HasJulia for Mandelbrot
     {
         Bounds::centered_square(2.2)
     }
+
+    #[inline]
+    fn inverse_branches(&self, w: Cplx, c: &Cplx) -> Vec<Cplx>
+    {
+        let z = (w - c).sqrt();
+        vec![z, -z]
+    }
 }
 
 impl HasDynamicalCovers for Mandelbrot
@@ -281,6 +339,12 @@ impl MarkedPoints for Mandelbrot
         vec![Cplx::new(0., 0.)]
     }
 
+    #[inline]
+    fn critical_values_child(&self, c: &Cplx) -> ComplexVec
+    {
+        vec![*c]
+    }
+
     fn cycles(&self, period: Period) -> Vec<Self::Var>
     {
         match period {
@@ -300,7 +364,6 @@ impl MarkedPoints for Mandelbrot
         }
     }
 
-    #[allow(clippy::too_many_lines)]
     fn cycles_child(&self, c: &Cplx, period: Period) -> ComplexVec
     {
         use dynamo_common::math_utils::polynomial_roots::solve_polynomial;
@@ -313,9 +376,25 @@ impl MarkedPoints for Mandelbrot
                 let u = (-3. - 4. * c).sqrt();
                 vec![0.5 * (-1. + u), -0.5 * (1. + u)]
             }
+            _ => {
+                let coeffs = self.cycle_polynomial(c, period);
+                if coeffs.is_empty() {
+                    return vec![];
+                }
+                solve_polynomial(coeffs)
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn cycle_polynomial(&self, c: &Cplx, period: Period) -> ComplexVec
+    {
+        match period {
+            1 => vec![*c, -ONE, ONE],
+            2 => vec![c + 1., ONE, ONE],
             3 => {
                 let c2 = c * c;
-                let coeffs = vec![
+                let coeffs = [
                     1. + c + (2. + c) * c2,
                     1. + c + c + c2,
                     1. + 3. * (c + c2),
@@ -324,11 +403,11 @@ impl MarkedPoints for Mandelbrot
                     ONE,
                     ONE,
                 ];
-                solve_polynomial(coeffs)
+                coeffs.to_vec()
             }
             4 => {
                 let c2 = c * c;
-                let coeffs = vec![
+                let coeffs = [
                     1. + c2 * horner_monic!(c, 2., 3., 3., 3.),
                     c * horner_monic!(c, 2., 1., 2.),
                     c * horner!(c, 1., 5., 6., 12., 6.),
@@ -343,7 +422,7 @@ impl MarkedPoints for Mandelbrot
                     ZERO,
                     ONE,
                 ];
-                solve_polynomial(coeffs)
+                coeffs.to_vec()
             }
             5 => {
                 let v = horner_monic!(
@@ -405,7 +484,7 @@ impl MarkedPoints for Mandelbrot
                     ONE,
                     ONE,
                 ];
-                solve_polynomial(coeffs)
+                coeffs.to_vec()
             }
             6 => {
                 let c2 = c * c;
@@ -1006,7 +1085,7 @@ impl MarkedPoints for Mandelbrot
                     -ONE,
                     ONE,
                 ];
-                solve_polynomial(coeffs)
+                coeffs.to_vec()
             }
             _ => vec![],
         }
@@ -25,6 +25,12 @@ pub use cubic_marked_2_cycle::CubicMarked2Cycle;
 pub mod unicritical;
 pub use unicritical::Unicritical;
 
+pub mod unicritical_dyn;
+pub use unicritical_dyn::UnicriticalDyn;
+
+pub mod phoenix;
+pub use phoenix::{PhoenixMandelbrot, PhoenixSlice};
+
 pub mod chebyshev;
 pub use chebyshev::Chebyshev;
 
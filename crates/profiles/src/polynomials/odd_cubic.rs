@@ -7,6 +7,7 @@ pub struct OddCubic
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -245,28 +246,50 @@ impl HasDynamicalCovers for OddCubic
                     let g2 = ONE_NINTH.into();
                     let g3 = ZERO;
                     let (mut x, mut y) = weierstrass_p(g2, g3, t, 0.01);
+                    // p'' = 6p^2 - g2/2
+                    let mut dx = 3. * y;
+                    let mut dy = 6. * (6. * x * x - 0.5 * g2);
 
                     x *= 3.;
                     y *= 6.;
 
                     x = x.inv();
+                    dx *= -x * x;
+                    dy = dy * x + y * dx;
                     y *= x;
 
                     let y2 = y * y;
+                    let dy2 = 2. * y * dy;
                     let x2 = x * x;
+                    let dx2 = 2. * x * dx;
                     let x4 = x2 * x2;
+                    let dx4 = 2. * x2 * dx2;
 
                     let u0 = 3. / y;
+                    let du0 = -u0 * dy / y;
                     let u2 = (x2 * x + 3. * y2).inv();
+                    let du2 = -3. * u2 * u2 * (x2 * dx + dy2);
                     let u3 = x4 * u0 * u2 - x * u0;
+                    let du3 = (dx4 * u2 + x4 * du2 - dx) * u0 + (x4 * u2 - x) * du0;
                     let u4 = 3. / y2;
+                    let du4 = -u4 * dy2 / y2;
                     let u5 = x4 * x * u2 * u4 - x2 * u4;
+                    let du5 = (dx4 * x + x4 * dx) * u2 * u4 + x4 * x * (du2 * u4 + u2 * du4)
+                        - dx2 * u4
+                        - x2 * du4;
 
                     let u3_2 = u3 * u3;
+                    let du3_2 = 2. * u3 * du3;
                     let u5_2 = u5 * u5;
-                    let v = u3_2 * u3_2 / (u5 * u5_2) + 3. * u3_2 / u5_2;
+                    let du5_2 = 2. * u5 * du5;
+                    let a = u3_2 * u3_2 / (u5 * u5_2);
+                    let da = a * (2. * du3_2 / u3_2 - 3. * du5 / u5);
+                    let b = 3. * u3_2 / u5_2;
+                    let db = b * (du3_2 / u3_2 - du5_2 / u5_2);
+                    let v = a + b;
 
-                    (v.inv(), ONE)
+                    let w = v.inv();
+                    (w, -(da + db) * w * w)
                 };
                 bounds = Bounds {
                     min_x: -2.5,
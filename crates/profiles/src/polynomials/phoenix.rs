@@ -0,0 +1,231 @@
+use crate::macros::{default_bounds, degree_impl, profile_imports};
+use dynamo_common::types::{CplxMatrix2x2, CplxPair};
+
+profile_imports!();
+
+/// Coordinate of the Phoenix family that varies across a [`PhoenixMandelbrot`] image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PhoenixSlice
+{
+    /// The image is the $c$-plane, with $p$ fixed.
+    #[default]
+    C,
+    /// The image is the $p$-plane, with $c$ fixed.
+    P,
+}
+
+impl std::fmt::Display for PhoenixSlice
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::C => write!(f, "c"),
+            Self::P => write!(f, "p"),
+        }
+    }
+}
+
+impl PhoenixSlice
+{
+    /// The coordinate held fixed.
+    #[must_use]
+    pub const fn fixed(self) -> Self
+    {
+        match self {
+            Self::C => Self::P,
+            Self::P => Self::C,
+        }
+    }
+
+    /// Value of the fixed coordinate of the classic Phoenix fractal, $c = 0.5667$, $p = -0.5$.
+    #[must_use]
+    pub const fn default_fixed(self) -> Cplx
+    {
+        match self {
+            Self::C => Cplx::new(-0.5, 0.),
+            Self::P => Cplx::new(0.5667, 0.),
+        }
+    }
+}
+
+#[inline]
+fn phoenix(z: CplxPair, &CplxPair { a: c, b: p }: &CplxPair) -> CplxPair
+{
+    CplxPair {
+        a: z.a * z.a + c + p * z.b,
+        b: z.a,
+    }
+}
+
+#[inline]
+fn jacobian(z: CplxPair, &CplxPair { b: p, .. }: &CplxPair) -> CplxMatrix2x2
+{
+    CplxMatrix2x2::new(2. * z.a, p, ONE, ZERO)
+}
+
+/// Parameter plane of the Phoenix maps $(z, y) \mapsto (z^2 + c + py, z)$, which realize the
+/// second-order recurrence $z_{n+1} = z_n^2 + c + p z_{n-1}$ as a map of $\mathbb{C}^2$.
+///
+/// One of $c$ and $p$ varies across the image, and the other is the meta-parameter. Each map is
+/// started at the critical point $(0, 0)$. With $p = 0$ the $c$-plane is the Mandelbrot set.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PhoenixMandelbrot
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    slice: PhoenixSlice,
+    fixed: Cplx,
+}
+
+impl PhoenixMandelbrot
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(2.);
+
+    /// Vary the given coordinate, fixing the other at its classic value.
+    #[must_use]
+    pub const fn with_slice(mut self, slice: PhoenixSlice) -> Self
+    {
+        self.slice = slice;
+        self.fixed = slice.default_fixed();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_fixed(mut self, fixed: Cplx) -> Self
+    {
+        self.fixed = fixed;
+        self
+    }
+
+    #[must_use]
+    pub const fn slice(&self) -> PhoenixSlice
+    {
+        self.slice
+    }
+}
+
+impl Default for PhoenixMandelbrot
+{
+    fn default() -> Self
+    {
+        let slice = PhoenixSlice::default();
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, Self::DEFAULT_BOUNDS),
+            compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
+            max_iter: 1024,
+            slice,
+            fixed: slice.default_fixed(),
+        }
+    }
+}
+
+impl DynamicalFamily for PhoenixMandelbrot
+{
+    parameter_plane_impl!(CplxPair, CplxPair, CplxMatrix2x2, Cplx);
+
+    #[inline]
+    fn escape_radius(&self) -> Real
+    {
+        1e26
+    }
+
+    // The derivative is a Jacobian matrix, which finite differences of the drawn coordinate
+    // cannot check.
+    #[inline]
+    fn is_holomorphic(&self) -> bool
+    {
+        false
+    }
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        phoenix(z, c)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        (phoenix(z, c), jacobian(z, c))
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let df_dt = match self.slice {
+            PhoenixSlice::C => CplxPair { a: ONE, b: ZERO },
+            PhoenixSlice::P => CplxPair { a: z.b, b: ZERO },
+        };
+        (phoenix(z, c), jacobian(z, c), CplxMatrix2x2::column(df_dt))
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        match self.slice {
+            PhoenixSlice::C => CplxPair {
+                a: t,
+                b: self.fixed,
+            },
+            PhoenixSlice::P => CplxPair {
+                a: self.fixed,
+                b: t,
+            },
+        }
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        CplxPair { a: ZERO, b: ZERO }
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.fixed
+    }
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.fixed = value;
+    }
+
+    fn name(&self) -> String
+    {
+        format!("Phoenix, {} = {}", self.slice.fixed(), self.fixed)
+    }
+
+    fn description(&self) -> String
+    {
+        "The Phoenix maps $(z, y) \\mapsto (z^2 + c + py, z)$, \
+            a two-dimensional extension of the quadratic family. \
+            One of $c$ and $p$ varies across the image while the other is fixed, \
+            and each parameter is colored according to the activity \
+            of the critical point $(0, 0)$."
+            .to_owned()
+    }
+}
+
+impl FamilyDefaults for PhoenixMandelbrot
+{
+    default_bounds!();
+}
+
+impl HasJulia for PhoenixMandelbrot
+{
+    #[inline]
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(2.)
+    }
+}
+
+impl MarkedPoints for PhoenixMandelbrot {}
+
+degree_impl!(PhoenixMandelbrot, 2);
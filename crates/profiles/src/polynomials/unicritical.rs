@@ -1,5 +1,8 @@
 use crate::macros::{degree_impl, ext_ray_impl_nonmonic, horner_monic, profile_imports};
-use dynamo_common::{horner, math_utils::roots_of_unity};
+use dynamo_common::{
+    horner,
+    math_utils::{roots_of_unity, scaled::ScaledCplx},
+};
 profile_imports!();
 
 #[derive(Clone, Debug)]
@@ -8,7 +11,11 @@ pub struct Unicritical<const D: i32>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
+    #[cfg_attr(feature = "serde", serde(default))]
+    scaled_iteration: bool,
 }
 
 impl<const D: i32> Unicritical<D>
@@ -21,7 +28,7 @@ impl<const D: i32> Unicritical<D>
 
 impl<const D: i32> Default for Unicritical<D>
 {
-    fractal_impl!();
+    fractal_impl!(scaled_iteration, false);
 }
 
 #[allow(clippy::suspicious_operation_groupings)]
@@ -56,6 +63,24 @@ impl<const D: i32> DynamicalFamily for Unicritical<D>
         ZERO
     }
 
+    #[inline]
+    fn scaled_iteration(&self) -> bool
+    {
+        self.scaled_iteration
+    }
+
+    #[inline]
+    fn set_scaled_iteration(&mut self, scaled_iteration: bool)
+    {
+        self.scaled_iteration = scaled_iteration;
+    }
+
+    /// High degrees overflow `f64` well before the escape radius is reached.
+    fn map_scaled(&self, z: ScaledCplx, c: &Self::Param) -> Option<ScaledCplx>
+    {
+        Some((z * Self::D_FLOAT.recip() + ONE).powi(D) * *c)
+    }
+
     fn periodicity_tolerance(&self) -> Real
     {
         1e-18
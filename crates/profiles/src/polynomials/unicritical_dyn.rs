@@ -0,0 +1,194 @@
+use crate::macros::{ext_ray_impl_nonmonic, profile_imports};
+profile_imports!();
+
+/// The unicritical family $f_c(z) = c(1+z/d)^d$ of [`Unicritical`](super::Unicritical), with the
+/// degree $d$ chosen at runtime rather than at compile time.
+///
+/// Non-integer degrees use the principal branch of $u \mapsto u^d$. External rays are only
+/// available for integer degrees.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnicriticalDyn
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    degree: Real,
+}
+
+impl UnicriticalDyn
+{
+    const DEFAULT_DEGREE: Real = 3.;
+    const DEFAULT_BOUNDS: Bounds = Bounds::square(
+        Self::DEFAULT_DEGREE * 1.2,
+        Cplx::new(-Self::DEFAULT_DEGREE + 1.0, 0.0),
+    );
+
+    #[must_use]
+    pub const fn with_degree(mut self, degree: Real) -> Self
+    {
+        self.degree = degree;
+        self
+    }
+
+    /// The degree as an exponent, if $d$ is a whole number.
+    fn whole_degree(&self) -> Option<i32>
+    {
+        if self.degree.fract() == 0. {
+            self.degree.try_round().ok()
+        } else {
+            None
+        }
+    }
+
+    /// $u^{d+k}$, using exact powers for integer degrees.
+    #[inline]
+    fn power(&self, u: Cplx, k: i32) -> Cplx
+    {
+        match self.whole_degree() {
+            Some(d) => u.powi(d + k),
+            None => u.powf(self.degree + Real::from(k)),
+        }
+    }
+
+    #[inline]
+    fn crit(&self) -> Cplx
+    {
+        Cplx::new(-self.degree, 0.0)
+    }
+}
+
+impl Default for UnicriticalDyn
+{
+    fractal_impl!(degree, Self::DEFAULT_DEGREE);
+}
+
+impl DynamicalFamily for UnicriticalDyn
+{
+    parameter_plane_impl!();
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        c * self.power(1. + z / self.degree, 0)
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let u = 1. + z / self.degree;
+        let df = c * self.power(u, -1);
+        (u * df, df)
+    }
+
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let u = 1. + z / self.degree;
+        let v = self.power(u, -1);
+        let df = c * v;
+        (u * df, df, u * v)
+    }
+
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        ZERO
+    }
+
+    #[inline]
+    fn runtime_degree(&self) -> Option<Real>
+    {
+        Some(self.degree)
+    }
+
+    #[inline]
+    fn set_runtime_degree(&mut self, degree: Real)
+    {
+        self.degree = degree;
+    }
+
+    fn periodicity_tolerance(&self) -> Real
+    {
+        1e-18
+    }
+
+    fn name(&self) -> String
+    {
+        format!("Unicritical({})", self.degree)
+    }
+}
+
+impl FamilyDefaults for UnicriticalDyn
+{
+    fn default_bounds(&self) -> Bounds
+    {
+        Bounds::square(self.degree * 1.2, Cplx::new(-self.degree + 1.0, 0.0))
+    }
+
+    fn default_selection(&self) -> Cplx
+    {
+        let zeta = (TAUI / self.degree).exp();
+        (zeta - 1.) * self.degree
+    }
+}
+
+impl HasJulia for UnicriticalDyn
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::square(self.degree * 1.618, self.crit())
+    }
+}
+
+impl MarkedPoints for UnicriticalDyn
+{
+    #[inline]
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        vec![self.crit()]
+    }
+}
+
+impl InfinityFirstReturnMap for UnicriticalDyn
+{
+    /// Zero for non-integer degrees, following the convention for maps without external rays;
+    /// see [`integer_degree`](InfinityFirstReturnMap::integer_degree).
+    #[inline]
+    fn degree(&self) -> AngleNum
+    {
+        self.integer_degree().unwrap_or(0)
+    }
+
+    #[inline]
+    fn integer_degree(&self) -> Option<AngleNum>
+    {
+        self.whole_degree().map(AngleNum::from)
+    }
+
+    #[inline]
+    fn degree_real(&self) -> Real
+    {
+        self.degree
+    }
+
+    #[inline]
+    fn escape_coeff(&self, c: &Self::Param) -> Cplx
+    {
+        c * self.degree.powf(-self.degree)
+    }
+
+    #[inline]
+    fn escape_coeff_d(&self, c: &Self::Param) -> (Cplx, Cplx)
+    {
+        let a = self.degree.powf(-self.degree);
+        (c * a, a.into())
+    }
+}
+
+impl EscapeEncoding for UnicriticalDyn {}
+
+impl ExternalRays for UnicriticalDyn
+{
+    ext_ray_impl_nonmonic!();
+}
@@ -7,6 +7,8 @@ pub struct McMullenFamily<const M: i32, const N: i32>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -17,6 +19,43 @@ impl<const M: i32, const N: i32> McMullenFamily<M, N>
     const M_MINUS_1: i32 = M - 1;
     const M_PLUS_N_INV: Real = 1. / (Self::M_FLOAT + Self::N_FLOAT);
     const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(80. / (Self::M_FLOAT - 1.8));
+
+    /// Number of critical orbits that are distinct up to the symmetry of the family.
+    ///
+    /// Rotating $z$ by an $(m+n)$th root of unity $\omega$ multiplies $f(z)$ by $\omega^m$,
+    /// so the critical values are $\omega^{km} v_0$, and critical points with the same
+    /// critical value share a forward orbit. There are $(m+n)/\gcd(m,n)$ of these.
+    pub const NUM_CRITICAL_ORBITS: i32 = (M + N) / gcd(M, N);
+
+    /// Whether several critical points share their forward orbits.
+    #[must_use]
+    pub const fn has_critical_symmetry() -> bool
+    {
+        Self::NUM_CRITICAL_ORBITS < M + N
+    }
+
+    /// One representative critical point for each distinct critical orbit.
+    ///
+    /// The critical point $\omega^k z_0$ has critical value $\omega^{km} v_0$, so the indices
+    /// $k < (m+n)/\gcd(m,n)$ give pairwise distinct critical values.
+    #[must_use]
+    pub fn distinct_critical_orbits(&self, c: &Cplx) -> Vec<Cplx>
+    {
+        let mut crit_pts = self.critical_points_child(c);
+        crit_pts.truncate(usize::try_from(Self::NUM_CRITICAL_ORBITS).unwrap_or(1));
+        crit_pts
+    }
+}
+
+const fn gcd(a: i32, b: i32) -> i32
+{
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
 }
 
 impl<const M: i32, const N: i32> Default for McMullenFamily<M, N>
@@ -28,6 +67,7 @@ impl<const M: i32, const N: i32> DynamicalFamily for McMullenFamily<M, N>
 {
     parameter_plane_impl!();
 
+    /// All critical orbits are images of this one under rotation, so it suffices to iterate it.
     fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
     {
         let z0 = Self::N_FLOAT / (c * Self::M_FLOAT);
@@ -113,6 +153,17 @@ impl<const M: i32, const N: i32> MarkedPoints for McMullenFamily<M, N>
             .map(|k| (TAUI * f64::from(k) * Self::M_PLUS_N_INV).exp() * z0)
             .collect()
     }
+
+    /// At a critical point, $z^{m+n} = n/(cm)$, so $f(z) = (1 + m/n) z^m$. Critical points
+    /// sharing an orbit have the same critical value, so only one is listed per orbit.
+    fn critical_values_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        let scale = 1. + Self::M_FLOAT / Self::N_FLOAT;
+        self.distinct_critical_orbits(c)
+            .into_iter()
+            .map(|z| scale * z.powi(M))
+            .collect()
+    }
 }
 
 impl<const M: i32, const N: i32> InfinityFirstReturnMap for McMullenFamily<M, N>
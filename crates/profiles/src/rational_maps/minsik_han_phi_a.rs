@@ -11,6 +11,8 @@ pub struct MinsikHanPhi<const D: i32>
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -26,6 +28,7 @@ impl<const D: i32> MinsikHanPhi<D>
         Self {
             point_grid,
             compute_mode: ComputeMode::SmoothPotential,
+            cycle_detection: CycleDetection::Floyd,
             max_iter,
         }
     }
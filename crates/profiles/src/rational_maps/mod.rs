@@ -27,3 +27,6 @@ pub use minsik_han_phi_a::MinsikHanPhi;
 
 pub mod newton_cubic;
 pub use newton_cubic::NewtonCubic;
+
+pub mod nova;
+pub use nova::NovaMandelbrot;
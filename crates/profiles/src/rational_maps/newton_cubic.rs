@@ -10,6 +10,8 @@ pub struct NewtonCubic
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -78,6 +80,13 @@ impl MarkedPoints for NewtonCubic
         vec![r0, r1, r2, ZERO]
     }
 
+    /// The roots are superattracting fixed points, and the free critical point 0 maps to $1/c$.
+    fn critical_values_child(&self, c: &Self::Param) -> Vec<Self::Var>
+    {
+        let [r0, r1, r2] = solve_cubic(-ONE, *c, ZERO);
+        vec![r0, r1, r2, c.inv()]
+    }
+
     fn cycles_child(&self, c: &Self::Param, period: Period) -> Vec<Self::Var>
     {
         match period {
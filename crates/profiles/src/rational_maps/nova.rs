@@ -0,0 +1,168 @@
+use crate::macros::{default_bounds_impl, degree_impl, profile_imports};
+profile_imports!();
+
+/// Nova fractal, the relaxed Newton map of $z^3 - 1$ with an added constant,
+/// $f(z) = z - R\frac{z^3 - 1}{3z^2} + c$.
+///
+/// The parameter plane is the added constant $c$, and the relaxation $R$ is the meta-parameter.
+/// Each map is started at its free critical point, which for $R = 1$ is the root $z = 1$, so the
+/// slice $c = 0$ is ordinary Newton's method. The other two free critical points differ from it
+/// by cube roots of unity, and can be selected with `set_critical_orbit`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NovaMandelbrot
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    relaxation: Cplx,
+    #[cfg_attr(feature = "serde", serde(default))]
+    critical_orbit: CriticalOrbit,
+}
+
+impl NovaMandelbrot
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::square(1.25, Cplx::new(-0.25, 0.));
+
+    /// Relaxation of ordinary Newton's method.
+    pub const NEWTON_RELAXATION: Cplx = ONE;
+
+    /// A critical point of the relaxed Newton map, satisfying $z^3 = \frac{2R}{3 - R}$.
+    #[must_use]
+    pub fn critical_point(&self) -> Cplx
+    {
+        let r = self.relaxation;
+        (2. * r / (3. - r)).cbrt()
+    }
+}
+
+impl Default for NovaMandelbrot
+{
+    fn default() -> Self
+    {
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, Self::DEFAULT_BOUNDS),
+            compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
+            max_iter: 1024,
+            relaxation: Self::NEWTON_RELAXATION,
+            critical_orbit: CriticalOrbit::default(),
+        }
+    }
+}
+
+impl DynamicalFamily for NovaMandelbrot
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, Cplx);
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        let z2 = z * z;
+        z - self.relaxation * (z2 * z - 1.) / (3. * z2) + c
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (f, df_dz, _) = self.gradient(z, c);
+        (f, df_dz)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let z3 = z * z * z;
+        let f = z - self.relaxation * (z3 - 1.) / (3. * z * z) + c;
+        let df_dz = 1. - self.relaxation * (z3 + 2.) / (3. * z3);
+        (f, df_dz, ONE)
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        t
+    }
+
+    #[inline]
+    fn param_map_d(&self, t: Cplx) -> (Self::Param, Self::Deriv)
+    {
+        (t, ONE)
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, c: &Self::Param) -> Self::Var
+    {
+        match self.critical_orbit {
+            CriticalOrbit::Index(index) => self
+                .critical_points_child(c)
+                .get(index)
+                .copied()
+                .unwrap_or_else(|| self.critical_point()),
+            CriticalOrbit::All => self.critical_point(),
+        }
+    }
+
+    #[inline]
+    fn start_point_d(&self, t: Cplx, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        (self.start_point(t, c), ZERO, ZERO)
+    }
+
+    fn critical_orbit(&self) -> CriticalOrbit
+    {
+        self.critical_orbit
+    }
+
+    fn set_critical_orbit(&mut self, critical_orbit: CriticalOrbit)
+    {
+        self.critical_orbit = critical_orbit;
+    }
+
+    fn critical_orbit_view(&self, index: usize) -> Option<Self>
+    {
+        Some(Self {
+            critical_orbit: CriticalOrbit::Index(index),
+            ..self.clone()
+        })
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.relaxation
+    }
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.relaxation = value;
+    }
+
+    fn name(&self) -> String
+    {
+        format!("Nova-Mandelbrot, relaxation {}", self.relaxation)
+    }
+}
+
+default_bounds_impl!(NovaMandelbrot);
+
+impl HasJulia for NovaMandelbrot
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(2.)
+    }
+}
+
+impl MarkedPoints for NovaMandelbrot
+{
+    /// The free critical points are the cube roots of $\frac{2R}{3 - R}$.
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        let z = self.critical_point();
+        vec![z, z * OMEGA, z * OMEGA_BAR]
+    }
+}
+
+degree_impl!(NovaMandelbrot, 1);
@@ -9,6 +9,8 @@ pub struct QuadRatGeneral
 {
     pub point_grid: PointGrid,
     pub compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cycle_detection: CycleDetection,
     pub max_iter: IterCount,
 }
 
@@ -21,6 +23,7 @@ impl Default for QuadRatGeneral
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
         }
     }
@@ -103,6 +103,18 @@ impl DynamicalFamily for QuadRatPer1Lambda
         self.general_plane.compute_mode_mut()
     }
 
+    #[inline]
+    fn cycle_detection(&self) -> CycleDetection
+    {
+        self.general_plane.cycle_detection()
+    }
+
+    #[inline]
+    fn set_cycle_detection(&mut self, cycle_detection: CycleDetection)
+    {
+        self.general_plane.set_cycle_detection(cycle_detection);
+    }
+
     fn param_map(&self, t: Cplx) -> Self::Param
     {
         let alpha = 0.25 * t / self.multiplier;
@@ -272,6 +284,7 @@ pub struct QuadRatPer1LambdaParam
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -293,6 +306,7 @@ impl Default for QuadRatPer1LambdaParam
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
         }
     }
@@ -343,6 +357,12 @@ impl DynamicalFamily for QuadRatPer1LambdaParam
     {
         "QuadRat Per(1, λ) λ-plane".to_owned()
     }
+
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
 }
 
 impl FamilyDefaults for QuadRatPer1LambdaParam
@@ -382,6 +402,7 @@ impl From<QuadRatPer1LambdaParam> for QuadRatPer1Lambda
         let general_plane = QuadRatGeneral {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
         };
         Self {
@@ -476,6 +497,18 @@ impl DynamicalFamily for QuadRatPer1_1
         self.general_plane.compute_mode_mut()
     }
 
+    #[inline]
+    fn cycle_detection(&self) -> CycleDetection
+    {
+        self.general_plane.cycle_detection()
+    }
+
+    #[inline]
+    fn set_cycle_detection(&mut self, cycle_detection: CycleDetection)
+    {
+        self.general_plane.set_cycle_detection(cycle_detection);
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
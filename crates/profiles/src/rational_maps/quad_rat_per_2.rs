@@ -8,6 +8,8 @@ pub struct QuadRatPer2
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -1001,6 +1003,8 @@ pub struct QuadRatPer2Cover
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -1171,6 +1175,13 @@ mod param
     }
 
     impl Describe for Param {}
+    impl MaybeFinite for Param
+    {
+        fn is_finite(&self) -> bool
+        {
+            self.a.is_finite() && self.c.is_finite()
+        }
+    }
     impl Named for Param
     {
         fn name(&self) -> &'static str
@@ -1185,6 +1196,7 @@ pub struct QuadRatPer2InfPuncture
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
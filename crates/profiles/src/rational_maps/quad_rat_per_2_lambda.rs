@@ -82,6 +82,18 @@ impl DynamicalFamily for QuadRatPer2Lambda
         self.general_plane.compute_mode_mut()
     }
 
+    #[inline]
+    fn cycle_detection(&self) -> CycleDetection
+    {
+        self.general_plane.cycle_detection()
+    }
+
+    #[inline]
+    fn set_cycle_detection(&mut self, cycle_detection: CycleDetection)
+    {
+        self.general_plane.set_cycle_detection(cycle_detection);
+    }
+
     #[inline]
     fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
     {
@@ -160,6 +172,7 @@ pub struct QuadRatPer2LambdaParam
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -181,6 +194,7 @@ impl Default for QuadRatPer2LambdaParam
         Self {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: 1024,
         }
     }
@@ -240,6 +254,12 @@ impl DynamicalFamily for QuadRatPer2LambdaParam
     {
         "QuadRat Per(2, λ) λ-plane".to_owned()
     }
+
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
 }
 
 impl FamilyDefaults for QuadRatPer2LambdaParam
@@ -279,6 +299,7 @@ impl From<QuadRatPer2LambdaParam> for QuadRatPer2Lambda
         let general_plane = QuadRatGeneral {
             point_grid,
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: parent.max_iter(),
         };
         Self {
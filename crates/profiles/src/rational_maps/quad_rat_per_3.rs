@@ -11,6 +11,7 @@ pub struct QuadRatPer3
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -214,20 +215,32 @@ impl HasDynamicalCovers for QuadRatPer3
                     let g3 = Cplx::new(1.0 / 27.0, 0.);
 
                     let (p, dp) = weierstrass_p(g2, g3, c, 0.01);
+                    // p'' = 6p^2 - g2/2
+                    let ddp = 6. * p * p - 0.5 * g2;
+
                     let x = p - 1. / 3.;
-                    let y = (dp + 1.) / x - t - 1.;
+                    let dx = dp;
+                    let yq = (dp + 1.) / x;
+                    let y = yq - t - 1.;
+                    let dy = (ddp - yq * dx) / x;
 
                     let u = x / 2.;
+                    let du = dx / 2.;
                     let v = y / 4.;
+                    let dv = dy / 4.;
                     let xx = -(t + 1.) * u + (t + 3.) * v + (t + 4.);
+                    let dxx = -(t + 1.) * du + (t + 3.) * dv;
                     let yy = u - v - (t + 1.) / 4.;
+                    let dyy = du - dv;
                     let zz = -x + 2. * v + (t + 3.) / 2.;
+                    let dzz = -dx + 2. * dv;
 
                     let s0 = xx / zz;
+                    let ds0 = (dxx - s0 * dzz) / zz;
                     let s1 = zz / yy;
+                    let ds1 = (dzz - s1 * dyy) / yy;
 
-                    // TODO: derivative
-                    ((s0 * s1 + s1 + (t + 4.)).into(), ONE)
+                    ((s0 * s1 + s1 + (t + 4.)).into(), ds0 * s1 + s0 * ds1 + ds1)
                     // let l = s0^2*s1 + s0*s1 + (2*t)*s0 + (t - 1);
                 };
                 bounds = Bounds {
@@ -335,6 +348,13 @@ mod param
     }
 
     impl Describe for Param {}
+    impl MaybeFinite for Param
+    {
+        fn is_finite(&self) -> bool
+        {
+            self.a.is_finite() && self.b.is_finite() && self.c.is_finite()
+        }
+    }
     impl Named for Param
     {
         fn name(&self) -> &'static str
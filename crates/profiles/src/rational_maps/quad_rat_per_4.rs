@@ -8,6 +8,7 @@ pub struct QuadRatPer4
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -233,12 +234,13 @@ impl HasDynamicalCovers for QuadRatPer4
                     let g2 = alpha;
                     let g3 = Cplx::new(-19. / 12., 0.);
 
-                    let (p, _dp) = weierstrass_p(g2, g3, c, 0.01);
+                    let (p, dp) = weierstrass_p(g2, g3, c, 0.01);
                     let x = (alpha * p + 1.) / 3.;
+                    let dx = alpha * dp / 3.;
                     // let y = (dp - 1.5) / x;
 
-                    // TODO: derivative
-                    (x / (x + 1.), ONE)
+                    let xx = x + 1.;
+                    (x / xx, dx / (xx * xx))
                     // let xx = x + 1.;
                     // let yy = y - 3. * x - 3.;
                     //
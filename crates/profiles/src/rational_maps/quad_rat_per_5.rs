@@ -49,6 +49,8 @@ pub struct QuadRatPer5
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 impl QuadRatPer5
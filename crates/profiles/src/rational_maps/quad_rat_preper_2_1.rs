@@ -8,6 +8,8 @@ pub struct QuadRatPreper21
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -200,19 +202,26 @@ impl HasDynamicalCovers for QuadRatPreper21
                     let g3 = Cplx::new(-13. / 55296., 0.);
                     let (p, dp) = weierstrass_p(g2, g3, c, 0.01);
 
+                    // p'' = 6p^2 - g2/2
+                    let ddp = 6. * p * p - 0.5 * g2;
+
                     let x = p + 1.0 / 24.0;
+                    let dx = dp;
                     let root_neg2_over_16 = Cplx::new(0., 0.088_388_347_648_318_4);
                     let mut y = dp * root_neg2_over_16;
+                    let mut dy = ddp * root_neg2_over_16;
                     // e4 = 8*x^3 - x^2 + 256*y^2 + x/16 - 1.0/1024.0
 
                     y += (1. - 32. * x) / 512.;
+                    dy -= dx / 16.;
                     // e3 = 8*x^3 + 32*x*y + 256*y^2 - y
 
                     y /= x;
+                    dy = (dy - y * dx) / x;
                     // e2 = 256*x*y^2 + 8*x^2 + 32*x*y - y
 
-                    // derivative unimplemented
-                    (y / x, ONE)
+                    let w = y / x;
+                    (w, (dy - w * dx) / x)
                 };
                 bounds = Bounds {
                     min_x: -16.,
@@ -7,6 +7,7 @@ pub struct QuadRatSymmetryLocus
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -0,0 +1,158 @@
+use crate::macros::{default_bounds, degree_impl_transcendental, fractal_impl, profile_imports};
+profile_imports!();
+
+/// Smooth interpolation of the generalized $3x+1$ map, which sends even $n$ to $n/2$ and odd
+/// $n$ to $mn + b$, given by
+/// $f(z) = \frac{z}{2} \cos^2\left(\frac{\pi z}{2}\right) + (mz + b) \sin^2\left(\frac{\pi z}{2}\right)$.
+///
+/// The parameter plane is the multiplier $m$, and the offset $b$ is the meta-parameter. The
+/// classic Collatz map is $m = 3$, $b = 1$.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeneralizedCollatz
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    offset: Cplx,
+}
+
+impl GeneralizedCollatz
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::square(2.5, Cplx::new(2., 0.));
+
+    /// Multiplier of the classic Collatz map.
+    pub const CLASSIC_MULTIPLIER: Cplx = Cplx::new(3., 0.);
+    /// Offset of the classic Collatz map.
+    pub const CLASSIC_OFFSET: Cplx = Cplx::new(1., 0.);
+
+    /// Starting point for the parameter plane. The orbit of $1$ under the classic map is the
+    /// trivial cycle $1 \mapsto 4 \mapsto 2 \mapsto 1$.
+    const START_POINT: Cplx = ONE;
+}
+
+impl Default for GeneralizedCollatz
+{
+    fractal_impl!(offset, Self::CLASSIC_OFFSET);
+}
+
+impl DynamicalFamily for GeneralizedCollatz
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, Cplx);
+
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "m"
+    }
+
+    #[inline]
+    fn map(&self, z: Self::Var, m: &Self::Param) -> Self::Var
+    {
+        let cos = (PI * z).cos();
+        0.5 * (0.5 * z * (1. + cos) + (m * z + self.offset) * (1. - cos))
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, m: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (f, df, _) = self.gradient(z, m);
+        (f, df)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, m: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let (sin, cos) = ((PI * z).sin(), (PI * z).cos());
+        let odd = m * z + self.offset;
+
+        let f = 0.5 * (0.5 * z * (1. + cos) + odd * (1. - cos));
+        let df_dz = 0.5 * (0.5 * (1. + cos - PI * z * sin) + m * (1. - cos) + PI * odd * sin);
+        let df_dm = 0.5 * z * (1. - cos);
+        (f, df_dz, df_dm)
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        t
+    }
+
+    #[inline]
+    fn param_map_d(&self, t: Cplx) -> (Self::Param, Self::Deriv)
+    {
+        (t, ONE)
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, _m: &Self::Param) -> Self::Var
+    {
+        Self::START_POINT
+    }
+
+    #[inline]
+    fn start_point_d(&self, _t: Cplx, _m: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        (Self::START_POINT, ZERO, ZERO)
+    }
+
+    #[inline]
+    fn extra_stop_condition(
+        &self,
+        z: Self::Var,
+        _m: &Self::Param,
+        iter: IterCount,
+    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    {
+        if z.im.abs() > 350. {
+            Some(EscapeResult::Escaped {
+                iters: iter,
+                final_value: z,
+            })
+        } else if z.re.abs() > 1e15 {
+            Some(EscapeResult::Unknown)
+        } else {
+            None
+        }
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.offset
+    }
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.offset = value;
+    }
+
+    fn name(&self) -> String
+    {
+        format!("Generalized Collatz, offset {}", self.offset)
+    }
+}
+
+impl FamilyDefaults for GeneralizedCollatz
+{
+    default_bounds!();
+
+    #[inline]
+    fn default_selection(&self) -> Cplx
+    {
+        Self::CLASSIC_MULTIPLIER
+    }
+}
+
+impl HasJulia for GeneralizedCollatz
+{
+    fn default_bounds_child(&self, _point: Cplx, _m: &Self::Param) -> Bounds
+    {
+        Bounds::rect(6., 3., Cplx::new(4., 0.))
+    }
+}
+
+impl MarkedPoints for GeneralizedCollatz {}
+
+degree_impl_transcendental!(GeneralizedCollatz);
@@ -12,6 +12,8 @@ pub struct CosineAdd
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -99,7 +101,11 @@ pub struct Cosine
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
+    #[cfg_attr(feature = "serde", serde(default))]
+    escape_annulus: Option<EscapeAnnulus>,
 }
 
 impl Cosine
@@ -110,10 +116,21 @@ impl Cosine
         min_y: -7.,
         max_y: 7.,
     };
+
+    /// Orbits escape along $|\Im z| \to \infty$, where $|\cos z| \approx e^{|\Im z|}/2$.
+    pub const DEFAULT_ESCAPE_ANNULUS: EscapeAnnulus = EscapeAnnulus::new(3., 50.);
+
+    /// Use an annulus-based escape criterion instead of the plain escape test.
+    #[must_use]
+    pub const fn with_escape_annulus(mut self, escape_annulus: Option<EscapeAnnulus>) -> Self
+    {
+        self.escape_annulus = escape_annulus;
+        self
+    }
 }
 impl Default for Cosine
 {
-    fractal_impl!();
+    fractal_impl!(escape_annulus, None);
 }
 
 impl DynamicalFamily for Cosine
@@ -121,12 +138,30 @@ impl DynamicalFamily for Cosine
     parameter_plane_impl!();
     default_name!();
 
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
+
     #[inline]
     fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
     {
         z.cos() * lambda
     }
 
+    #[inline]
+    fn escape_annulus(&self) -> Option<EscapeAnnulus>
+    {
+        self.escape_annulus
+    }
+
+    #[inline]
+    fn set_escape_annulus_enabled(&mut self, enabled: bool)
+    {
+        self.escape_annulus = enabled.then_some(Self::DEFAULT_ESCAPE_ANNULUS);
+    }
+
     #[inline]
     fn map_and_multiplier(&self, z: Self::Var, lambda: &Self::Param) -> (Self::Var, Self::Deriv)
     {
@@ -151,10 +186,17 @@ impl DynamicalFamily for Cosine
     fn extra_stop_condition(
         &self,
         z: Self::Var,
-        _c: &Self::Param,
+        lambda: &Self::Param,
         iter: IterCount,
     ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
     {
+        if let Some(annulus) = &self.escape_annulus {
+            if let Some(result) = annulus.escape(z, |z| self.map(z, lambda), iter) {
+                return Some(result);
+            }
+            return (z.re.abs() > 1e15).then_some(EscapeResult::Unknown);
+        }
+
         if z.im.abs() > 350. {
             Some(EscapeResult::Escaped {
                 iters: iter,
@@ -177,6 +219,8 @@ pub struct SineWander
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -326,6 +370,8 @@ pub struct CoshNewton
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
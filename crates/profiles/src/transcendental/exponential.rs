@@ -10,7 +10,11 @@ pub struct Exponential
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
+    #[cfg_attr(feature = "serde", serde(default))]
+    escape_annulus: Option<EscapeAnnulus>,
 }
 
 impl Exponential
@@ -21,10 +25,22 @@ impl Exponential
         min_y: -7.,
         max_y: 7.,
     };
+
+    /// Orbits escape along $\Re z \to +\infty$, so only test points with $|z| > e^3$, and
+    /// require their images to pass $e^{50}$.
+    pub const DEFAULT_ESCAPE_ANNULUS: EscapeAnnulus = EscapeAnnulus::new(3., 50.);
+
+    /// Use an annulus-based escape criterion instead of the plain escape test.
+    #[must_use]
+    pub const fn with_escape_annulus(mut self, escape_annulus: Option<EscapeAnnulus>) -> Self
+    {
+        self.escape_annulus = escape_annulus;
+        self
+    }
 }
 impl Default for Exponential
 {
-    fractal_impl!();
+    fractal_impl!(escape_annulus, None);
 }
 
 impl DynamicalFamily for Exponential
@@ -32,6 +48,12 @@ impl DynamicalFamily for Exponential
     parameter_plane_impl!();
     default_name!();
 
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
+
     #[inline]
     fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
     {
@@ -45,14 +67,33 @@ impl DynamicalFamily for Exponential
         (u, u)
     }
 
+    #[inline]
+    fn escape_annulus(&self) -> Option<EscapeAnnulus>
+    {
+        self.escape_annulus
+    }
+
+    #[inline]
+    fn set_escape_annulus_enabled(&mut self, enabled: bool)
+    {
+        self.escape_annulus = enabled.then_some(Self::DEFAULT_ESCAPE_ANNULUS);
+    }
+
     #[inline]
     fn extra_stop_condition(
         &self,
         z: Self::Var,
-        _c: &Self::Param,
+        lambda: &Self::Param,
         iter: IterCount,
     ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
     {
+        if let Some(annulus) = &self.escape_annulus {
+            if let Some(result) = annulus.escape(z, |z| self.map(z, lambda), iter) {
+                return Some(result);
+            }
+            return (z.im.abs() > 1e15).then_some(EscapeResult::Unknown);
+        }
+
         if z.re > 250. {
             Some(EscapeResult::Escaped {
                 iters: iter,
@@ -85,6 +126,12 @@ impl DynamicalFamily for Exponential
 impl FamilyDefaults for Exponential
 {
     default_bounds!();
+
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        256
+    }
 }
 
 impl HasJulia for Exponential
@@ -93,6 +140,17 @@ impl HasJulia for Exponential
     {
         Bounds::square(5., *lambda)
     }
+
+    /// $\lambda e^z$ has period $2\pi i$.
+    fn fundamental_domain_child(&self, _lambda: &Self::Param) -> Option<Bounds>
+    {
+        Some(Bounds {
+            min_x: Real::NEG_INFINITY,
+            max_x: Real::INFINITY,
+            min_y: -PI,
+            max_y: PI,
+        })
+    }
 }
 
 impl MarkedPoints for Exponential
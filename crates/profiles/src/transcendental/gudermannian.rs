@@ -12,6 +12,8 @@ pub struct Gudermannian
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 
@@ -34,6 +36,12 @@ impl DynamicalFamily for Gudermannian
     parameter_plane_impl!();
     default_name!();
 
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
+
     #[inline]
     fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
     {
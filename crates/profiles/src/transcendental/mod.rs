@@ -9,3 +9,12 @@ pub use zeta::{RiemannXi, RiemannXiNewton};
 
 pub mod gudermannian;
 pub use gudermannian::Gudermannian;
+
+pub mod collatz;
+pub use collatz::GeneralizedCollatz;
+
+pub mod tangent;
+pub use tangent::Tangent;
+
+pub mod trig_wander;
+pub use trig_wander::{TrigKind, TrigWander};
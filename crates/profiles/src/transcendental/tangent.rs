@@ -0,0 +1,130 @@
+use crate::macros::{
+    default_bounds, default_name, degree_impl_transcendental, fractal_impl, profile_imports,
+};
+profile_imports!();
+
+/// Beyond this height, $\tan z$ equals $\pm i$ to within rounding, and the textbook formula
+/// overflows to `NaN`.
+const TAN_SATURATION_HEIGHT: Real = 20.;
+
+/// Value standing in for $\tan z$ at a pole, large enough to escape at once.
+const POLE_VALUE: Real = 1e150;
+
+/// $\tan z$ and its derivative $\sec^2 z = 1 + \tan^2 z$.
+///
+/// Within rounding of a pole the value is huge but finite, so the orbit escapes instead of
+/// producing `NaN`.
+#[inline]
+fn tan_d(z: Cplx) -> (Cplx, Cplx)
+{
+    if z.im.abs() > TAN_SATURATION_HEIGHT {
+        return (Cplx::new(0., z.im.signum()), ZERO);
+    }
+    let cos = z.cos();
+    if cos.norm_sqr() < Real::EPSILON * Real::EPSILON {
+        return (Cplx::new(POLE_VALUE, 0.), Cplx::new(POLE_VALUE, 0.));
+    }
+    let tan = z.sin() / cos;
+    (tan, 1. + tan * tan)
+}
+
+/// The tangent family $f(z) = \lambda \tan z$.
+///
+/// These maps have no critical points, but two asymptotic values $\pm i\lambda$, which are
+/// exchanged by the symmetry $z \mapsto -z$. Each parameter is colored by the orbit of
+/// $i\lambda$; it escapes when it lands on a pole of $\tan$.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tangent
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+}
+
+impl Tangent
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(4.);
+}
+
+impl Default for Tangent
+{
+    fractal_impl!();
+}
+
+impl DynamicalFamily for Tangent
+{
+    parameter_plane_impl!();
+    default_name!();
+
+    #[inline]
+    fn param_symbol(&self) -> &str
+    {
+        "λ"
+    }
+
+    #[inline]
+    fn map(&self, z: Cplx, lambda: &Cplx) -> Cplx
+    {
+        lambda * tan_d(z).0
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, lambda: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (tan, sec2) = tan_d(z);
+        (lambda * tan, lambda * sec2)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Cplx, lambda: &Cplx) -> (Cplx, Cplx, Cplx)
+    {
+        let (tan, sec2) = tan_d(z);
+        (lambda * tan, lambda * sec2, tan)
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, lambda: &Self::Param) -> Self::Var
+    {
+        Cplx::new(0., 1.) * lambda
+    }
+
+    fn description(&self) -> String
+    {
+        "The tangent family $z \\mapsto \\lambda \\tan z$. \
+            Each parameter is colored according to the orbit of the asymptotic value $i\\lambda$, \
+            which escapes exactly when it lands on a pole."
+            .to_owned()
+    }
+}
+
+impl FamilyDefaults for Tangent
+{
+    default_bounds!();
+}
+
+impl HasJulia for Tangent
+{
+    /// Three periods of $\tan$ across.
+    fn default_bounds_child(&self, _point: Cplx, _lambda: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(1.5 * PI)
+    }
+
+    /// $\lambda \tan z$ has period $\pi$.
+    fn fundamental_domain_child(&self, _lambda: &Self::Param) -> Option<Bounds>
+    {
+        Some(Bounds {
+            min_x: -0.5 * PI,
+            max_x: 0.5 * PI,
+            min_y: Real::NEG_INFINITY,
+            max_y: Real::INFINITY,
+        })
+    }
+}
+
+impl MarkedPoints for Tangent {}
+
+degree_impl_transcendental!(Tangent);
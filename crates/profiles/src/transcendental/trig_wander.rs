@@ -0,0 +1,266 @@
+use crate::macros::{default_bounds, degree_impl_transcendental, profile_imports};
+profile_imports!();
+
+/// Trigonometric function driving a [`TrigWander`] map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TrigKind
+{
+    #[default]
+    Sin,
+    Cos,
+    Tan,
+}
+
+impl std::fmt::Display for TrigKind
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            Self::Sin => write!(f, "sin"),
+            Self::Cos => write!(f, "cos"),
+            Self::Tan => write!(f, "tan"),
+        }
+    }
+}
+
+impl TrigKind
+{
+    /// Value and derivative at $z$.
+    #[inline]
+    #[must_use]
+    pub fn eval_d(self, z: Cplx) -> (Cplx, Cplx)
+    {
+        match self {
+            Self::Sin => (z.sin(), z.cos()),
+            Self::Cos => (z.cos(), -z.sin()),
+            Self::Tan => {
+                let tan = z.tan();
+                (tan, 1. + tan * tan)
+            }
+        }
+    }
+
+    /// Period of the function along the real axis.
+    #[must_use]
+    pub const fn period(self) -> Real
+    {
+        match self {
+            Self::Sin | Self::Cos => TAU,
+            Self::Tan => PI,
+        }
+    }
+}
+
+/// Trigonometric function with an added linear term, $f(z) = g(z) + az + \tau c$, where $g$ is
+/// one of $\sin$, $\cos$ or $\tan$.
+///
+/// The parameter plane is $c$, and the linear coefficient $a$ is the meta-parameter. Each map is
+/// started at a critical point, a solution of $g'(z) = -a$. With $g = \sin$ and $a = 1$ this is
+/// [`SineWander`](super::SineWander).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrigWander
+{
+    point_grid: PointGrid,
+    compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
+    max_iter: IterCount,
+    trig: TrigKind,
+    coefficient: Cplx,
+}
+
+impl TrigWander
+{
+    const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(7.);
+
+    /// Linear coefficient of `SineWander`.
+    pub const WANDER_COEFFICIENT: Cplx = ONE;
+
+    #[must_use]
+    pub const fn with_trig(mut self, trig: TrigKind) -> Self
+    {
+        self.trig = trig;
+        self
+    }
+
+    #[must_use]
+    pub const fn trig(&self) -> TrigKind
+    {
+        self.trig
+    }
+
+    /// The two families of critical points are $w + nP$ and $w' + nP$, where $P$ is the period
+    /// of $g$. Returns $(w, w')$.
+    #[must_use]
+    pub fn critical_pair(&self) -> (Cplx, Cplx)
+    {
+        let a = self.coefficient;
+        match self.trig {
+            TrigKind::Sin => {
+                let w = (-a).acos();
+                (w, -w)
+            }
+            TrigKind::Cos => {
+                let w = a.asin();
+                (w, PI - w)
+            }
+            TrigKind::Tan => {
+                let w = (-a.inv()).sqrt().acos();
+                (w, -w)
+            }
+        }
+    }
+}
+
+impl Default for TrigWander
+{
+    fn default() -> Self
+    {
+        Self {
+            point_grid: PointGrid::new_by_res_y(1024, Self::DEFAULT_BOUNDS),
+            compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
+            max_iter: 1024,
+            trig: TrigKind::default(),
+            coefficient: Self::WANDER_COEFFICIENT,
+        }
+    }
+}
+
+impl DynamicalFamily for TrigWander
+{
+    parameter_plane_impl!(Cplx, Cplx, Cplx, Cplx);
+
+    #[inline]
+    fn map(&self, z: Self::Var, c: &Self::Param) -> Self::Var
+    {
+        let (g, _) = self.trig.eval_d(z);
+        g + self.coefficient * z + TAU * c
+    }
+
+    #[inline]
+    fn map_and_multiplier(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv)
+    {
+        let (g, dg) = self.trig.eval_d(z);
+        (g + self.coefficient * z + TAU * c, dg + self.coefficient)
+    }
+
+    #[inline]
+    fn gradient(&self, z: Self::Var, c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        let (f, df_dz) = self.map_and_multiplier(z, c);
+        (f, df_dz, TAU.into())
+    }
+
+    #[inline]
+    fn param_map(&self, t: Cplx) -> Self::Param
+    {
+        t
+    }
+
+    #[inline]
+    fn param_map_d(&self, t: Cplx) -> (Self::Param, Self::Deriv)
+    {
+        (t, ONE)
+    }
+
+    #[inline]
+    fn start_point(&self, _point: Cplx, _c: &Self::Param) -> Self::Var
+    {
+        self.critical_pair().0
+    }
+
+    #[inline]
+    fn start_point_d(&self, _t: Cplx, _c: &Self::Param) -> (Self::Var, Self::Deriv, Self::Deriv)
+    {
+        (self.critical_pair().0, ZERO, ZERO)
+    }
+
+    #[inline]
+    fn extra_stop_condition(
+        &self,
+        z: Self::Var,
+        _c: &Self::Param,
+        iter: IterCount,
+    ) -> Option<EscapeResult<Self::Var, Self::Deriv>>
+    {
+        if z.im.abs() > 350. {
+            Some(EscapeResult::Escaped {
+                iters: iter,
+                final_value: z,
+            })
+        } else if z.re.abs() > 1e15 {
+            Some(EscapeResult::Unknown)
+        } else {
+            None
+        }
+    }
+
+    fn get_param(&self) -> <Self::MetaParam as ParamList>::Param
+    {
+        self.coefficient
+    }
+
+    fn set_param(&mut self, value: <Self::MetaParam as ParamList>::Param)
+    {
+        self.coefficient = value;
+    }
+
+    fn name(&self) -> String
+    {
+        format!(
+            "{}(z) + az + τc, coefficient {}",
+            self.trig, self.coefficient
+        )
+    }
+}
+
+impl FamilyDefaults for TrigWander
+{
+    default_bounds!();
+
+    #[inline]
+    fn default_selection(&self) -> Cplx
+    {
+        ONE
+    }
+}
+
+impl HasJulia for TrigWander
+{
+    fn default_bounds_child(&self, _point: Cplx, _c: &Self::Param) -> Bounds
+    {
+        Bounds::centered_square(5.5)
+    }
+}
+
+impl MarkedPoints for TrigWander
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn critical_points_child(&self, _c: &Self::Param) -> Vec<Self::Var>
+    {
+        let period = self.trig.period();
+        let (w0, w1) = self.critical_pair();
+        // The two families coincide when $w' - w$ is a multiple of the period
+        let shift = (w1 - w0) / period;
+        let distinct = (shift.re - shift.re.round()).abs() > 1e-9 || shift.im.abs() > 1e-9;
+
+        let grid = self.point_grid();
+        let n_min = ((grid.min_x - w0.re.abs().max(w1.re.abs())) / period).floor() as i32;
+        let n_max = ((grid.max_x + w0.re.abs().max(w1.re.abs())) / period).ceil() as i32;
+
+        let mut pts = Vec::new();
+        for n in n_min..=n_max {
+            let offset = Real::from(n) * period;
+            pts.push(w0 + offset);
+            if distinct {
+                pts.push(w1 + offset);
+            }
+        }
+        pts
+    }
+}
+
+degree_impl_transcendental!(TrigWander);
@@ -10,6 +10,8 @@ pub struct RiemannXi
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
 }
 impl RiemannXi
@@ -80,6 +82,13 @@ impl FamilyDefaults for RiemannXi
 {
     default_bounds!();
 
+    // Each iteration evaluates the xi function, which is expensive
+    #[inline]
+    fn recommended_max_iter(&self) -> IterCount
+    {
+        128
+    }
+
     #[inline]
     fn default_selection(&self) -> Cplx
     {
@@ -103,6 +112,8 @@ pub struct RiemannXiNewton
 {
     point_grid: PointGrid,
     compute_mode: ComputeMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    cycle_detection: CycleDetection,
     max_iter: IterCount,
     param: Cplx,
 }
@@ -121,6 +132,7 @@ impl From<RiemannXi> for RiemannXiNewton
         Self {
             point_grid: plane.point_grid.clone(),
             compute_mode: ComputeMode::default(),
+            cycle_detection: CycleDetection::default(),
             max_iter: plane.max_iter,
             param: plane.default_selection(),
         }
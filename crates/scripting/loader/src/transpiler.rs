@@ -66,6 +66,13 @@ impl Transpiler
             .map(|c| format!("{c}: {{}}"))
             .collect::<Vec<_>>()
             .join(", ");
+        let all_finite = self
+            .parsed_input
+            .param_names
+            .iter()
+            .map(|c| format!("self.{c}.is_finite()"))
+            .collect::<Vec<_>>()
+            .join(" && ");
         let display_impl = format!(
             "impl std::fmt::Display for Parameters {{\n\
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result\
@@ -87,6 +94,11 @@ impl Transpiler
             fn describe(&self, conf: &DescriptionConf) -> Option<String> {{
                 conf.is_enabled.then(|| self.to_string())
             }}
+        }}\n\
+        impl MaybeFinite for Parameters {{
+            fn is_finite(&self) -> bool {{
+                {all_finite}
+            }}
         }}"
         )
     }
@@ -113,6 +125,7 @@ impl Transpiler
                 point_grid: PointGrid,\n\
                 max_iter: IterCount,\n\
                 compute_mode: ComputeMode,\n\
+                cycle_detection: CycleDetection,\n\
             }}\n\
             impl UserPlane {{\n\
                 const DEFAULT_BOUNDS: Bounds = Bounds::centered_square(2.5);\n\